@@ -0,0 +1,230 @@
+// Generates the LC_MESSAGES catalogs from the gettext sources in po/. The
+// template po/strogino.pot lists the C locale's messages in the order of
+// their tables, and each po/<tag>.po file becomes one complete message set,
+// keyed by its file name. Entries are told apart by their msgctxt, which
+// names the table they belong to. Missing, empty and fuzzy translations fall
+// back to the untranslated message, as with gettext.
+
+use std::{
+  collections::HashMap,
+  env,
+  fmt::Write as _,
+  fs,
+  path::{Path, PathBuf}
+};
+
+#[derive(Default)]
+struct Entry {
+  context: String,
+  id: String,
+  text: String,
+  fuzzy: bool
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Field {
+  Context,
+  Id,
+  Text
+}
+
+fn unquote(
+  path: &Path,
+  number: usize,
+  s: &str
+) -> String {
+  let fail = || panic!("{}:{number}: malformed string", path.display());
+
+  let s = s.trim();
+  let Some(s) = s.strip_prefix('"').and_then(|s| s.strip_suffix('"')) else {
+    fail()
+  };
+
+  let mut result = String::new();
+  let mut chars = s.chars();
+  while let Some(c) = chars.next() {
+    if c != '\\' {
+      result.push(c);
+      continue;
+    }
+    match chars.next() {
+      | Some('n') => result.push('\n'),
+      | Some('t') => result.push('\t'),
+      | Some(c @ ('"' | '\\')) => result.push(c),
+      | _ => fail()
+    }
+  }
+
+  result
+}
+
+fn parse(path: &Path) -> Vec<Entry> {
+  let source = fs::read_to_string(path)
+    .unwrap_or_else(|e| panic!("{}: {e}", path.display()));
+
+  let mut entries = Vec::new();
+  let mut entry = Entry::default();
+  let mut field = None;
+
+  for (i, line) in source.lines().enumerate() {
+    let number = i + 1;
+    let line = line.trim();
+
+    let keyword = if line.starts_with('"') {
+      None
+    } else if let Some(rest) = line.strip_prefix("msgctxt ") {
+      Some((Field::Context, rest))
+    } else if let Some(rest) = line.strip_prefix("msgid ") {
+      Some((Field::Id, rest))
+    } else if let Some(rest) = line.strip_prefix("msgstr ") {
+      Some((Field::Text, rest))
+    } else {
+      // A blank line or comment ends the entry, and a flag comment belongs
+      // to the next one
+      if field == Some(Field::Text) {
+        entries.push(std::mem::take(&mut entry));
+        field = None;
+      }
+      if line.starts_with("#,") && line.contains("fuzzy") {
+        entry.fuzzy = true;
+      }
+      continue;
+    };
+
+    let (next, value) = match keyword {
+      | Some((next, rest)) => {
+        // A new msgctxt or msgid after a msgstr starts the next entry
+        if field == Some(Field::Text) && next != Field::Text {
+          entries.push(std::mem::take(&mut entry));
+        }
+        (next, unquote(path, number, rest))
+      },
+      | None => match field {
+        | Some(field) => (field, unquote(path, number, line)),
+        | None => panic!("{}:{number}: unexpected string", path.display())
+      }
+    };
+
+    match next {
+      | Field::Context => entry.context.push_str(&value),
+      | Field::Id => entry.id.push_str(&value),
+      | Field::Text => entry.text.push_str(&value)
+    }
+    field = Some(next);
+  }
+  if field == Some(Field::Text) {
+    entries.push(entry);
+  }
+
+  entries
+}
+
+// The tables of a message set, by the msgctxt of their entries
+const TABLES: [(&str, &str); 6] = [
+  ("misc_messages", "misc"),
+  ("strerror", "strerror"),
+  ("strsignal", "strsignal"),
+  ("regerror", "regerror"),
+  ("hstrerror", "hstrerror"),
+  ("gai_strerror", "gai_strerror")
+];
+const STRINGS: [(&str, &str); 2] =
+  [("yesexpr", "yesexpr"), ("noexpr", "noexpr")];
+
+fn get_msgids<'a>(
+  template: &'a [Entry],
+  context: &str
+) -> Vec<&'a str> {
+  template
+    .iter()
+    .filter(|entry| entry.context == context)
+    .map(|entry| entry.id.as_str())
+    .collect()
+}
+
+fn write_set(
+  output: &mut String,
+  template: &[Entry],
+  translations: &HashMap<(String, String), String>
+) {
+  let translate = |context: &str, msgid: &str| -> String {
+    let key = (context.to_owned(), msgid.to_owned());
+    translations.get(&key).map_or(msgid, String::as_str).to_owned()
+  };
+
+  let _ = writeln!(output, "MessageSet {{");
+  for (field, context) in TABLES {
+    let _ = writeln!(output, "  {field}: [");
+    for msgid in get_msgids(template, context) {
+      let _ = writeln!(output, "    {:?},", translate(context, msgid));
+    }
+    let _ = writeln!(output, "  ],");
+  }
+  for (field, context) in STRINGS {
+    let msgids = get_msgids(template, context);
+    let [msgid] = msgids.as_slice() else {
+      panic!("po/strogino.pot: expected one {context} message");
+    };
+    let _ = writeln!(output, "  {field}: {:?},", translate(context, msgid));
+  }
+  let _ = write!(output, "}}");
+}
+
+fn main() {
+  let root = PathBuf::from(env::var_os("CARGO_MANIFEST_DIR").unwrap());
+  let po = root.join("po");
+
+  println!("cargo::rerun-if-changed=po");
+
+  let template_path = po.join("strogino.pot");
+  println!("cargo::rerun-if-changed={}", template_path.display());
+  let template: Vec<Entry> = parse(&template_path)
+    .into_iter()
+    .filter(|entry| !entry.id.is_empty())
+    .collect();
+
+  let mut catalogs = Vec::new();
+  for file in fs::read_dir(&po).expect("po directory") {
+    let path = file.expect("po directory entry").path();
+    if path.extension().is_none_or(|extension| extension != "po") {
+      continue;
+    }
+    let tag = path.file_stem().unwrap().to_str().unwrap().to_owned();
+
+    let mut translations = HashMap::new();
+    for entry in parse(&path) {
+      // The header has an empty msgid
+      if entry.fuzzy || entry.id.is_empty() || entry.text.is_empty() {
+        continue;
+      }
+      let key = (entry.context, entry.id);
+      if translations.insert(key.clone(), entry.text).is_some() {
+        panic!("{}: duplicate message {:?}", path.display(), key.1);
+      }
+    }
+
+    println!("cargo::rerun-if-changed={}", path.display());
+    catalogs.push((tag, translations));
+  }
+  catalogs.sort_by(|a, b| a.0.cmp(&b.0));
+
+  let mut output = String::new();
+  let _ = writeln!(output, "// Generated by build.rs from po/, do not edit.");
+  let _ = write!(output, "pub static UNTRANSLATED: MessageSet = ");
+  write_set(&mut output, &template, &HashMap::new());
+  let _ = writeln!(output, ";");
+  let _ = writeln!(
+    output,
+    "pub static CATALOGS: [(&str, MessageSet); {}] = [",
+    catalogs.len()
+  );
+  for (tag, translations) in &catalogs {
+    let _ = write!(output, "({tag:?}, ");
+    write_set(&mut output, &template, translations);
+    let _ = writeln!(output, "),");
+  }
+  let _ = writeln!(output, "];");
+
+  let out = PathBuf::from(env::var_os("OUT_DIR").unwrap());
+  fs::write(out.join("catalogs.rs"), output).expect("write catalogs.rs");
+}
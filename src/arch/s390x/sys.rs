@@ -1,3 +1,23 @@
-use syscalls::Sysno;
+use syscalls::{Sysno, raw_syscall};
 
+pub const SYS_CLOSE: Sysno = Sysno::close;
 pub const SYS_FUTEX: Sysno = Sysno::futex;
+pub const SYS_LSEEK: Sysno = Sysno::lseek;
+pub const SYS_MMAP: Sysno = Sysno::mmap;
+pub const SYS_MUNMAP: Sysno = Sysno::munmap;
+pub const SYS_OPENAT: Sysno = Sysno::openat;
+
+// The s390x mmap system call takes its arguments in memory
+#[inline]
+pub unsafe fn mmap(
+  addr: usize,
+  len: usize,
+  prot: usize,
+  flags: usize,
+  fd: usize,
+  offset: usize
+) -> usize {
+  let args: [usize; 6] = [addr, len, prot, flags, fd, offset];
+
+  unsafe { raw_syscall!(SYS_MMAP, args.as_ptr()) }
+}
@@ -37,3 +37,23 @@ macro_rules! cfg_if {
         $(#[$m] $it)*
     };
 }
+
+// Exports functions under their standard C names as well, so that strogino
+// can be linked as the libc of a C program. The rs_ names are always kept.
+macro_rules! standard_names {
+  ($(
+    fn $name:ident($($arg:ident: $ty:ty),*) $(-> $ret:ty)? = $target:ident;
+  )*) => {
+    #[cfg(feature = "standard-names")]
+    mod standard_names {
+      use super::*;
+
+      $(
+        #[unsafe(no_mangle)]
+        pub extern "C" fn $name($($arg: $ty),*) $(-> $ret)? {
+          $target($($arg),*)
+        }
+      )*
+    }
+  };
+}
@@ -1,15 +1,23 @@
-use crate::{
-  MBState,
-  c_char,
-  c_int,
-  c_uchar,
-  char32_t,
-  locale_t,
-  std::wctype,
-  support::locale,
-  wctrans_t,
-  wctype_t,
-  wint_t
+use {
+  crate::{
+    MBState,
+    c_char,
+    c_int,
+    c_uchar,
+    c_ushort,
+    char32_t,
+    int32_t,
+    locale_t,
+    std::{errno, wctype},
+    support::{locale, locale::ctype::glibc},
+    wctrans_t,
+    wctype_t,
+    wint_t
+  },
+  core::{
+    ptr,
+    sync::atomic::{AtomicUsize, Ordering}
+  }
 };
 
 #[inline]
@@ -19,37 +27,26 @@ fn inner_isctype(
   locale: locale_t<'static>
 ) -> c_int {
   let locale_real: &locale::Locale = locale::get_real_locale(locale);
-  let ctype = locale::get_slot(&locale_real.ctype).unwrap_or_default();
-  let mut ps = MBState::new();
 
   if c < 0 || c > c_uchar::MAX as c_int {
     return 0;
   }
 
-  let c = c as c_char;
-  let buf = [c as u8];
-  let mut c32: char32_t = 0;
+  locale::with_slot(&locale_real.ctype, |ctype| {
+    let mut ps = MBState::new();
+    let c = c as c_char;
+    let buf = [c as u8];
+    let mut c32: char32_t = 0;
 
-  if (ctype.converter.mbtoc32)(&mut c32, &buf, &mut ps) != 1 {
-    return 0;
-  }
+    if (ctype.converter.mbtoc32)(&mut c32, &buf, &mut ps) != 1 {
+      return 0;
+    }
 
-  match cc {
-    | wctype::WCTYPE_ALNUM => c_int::from((ctype.casemap.isalnum)(c32)),
-    | wctype::WCTYPE_ALPHA => c_int::from((ctype.casemap.isalpha)(c32)),
-    | wctype::WCTYPE_ASCII => c_int::from(wctype::inner_iswascii(c32)),
-    | wctype::WCTYPE_BLANK => c_int::from((ctype.casemap.isblank)(c32)),
-    | wctype::WCTYPE_CNTRL => c_int::from((ctype.casemap.iscntrl)(c32)),
-    | wctype::WCTYPE_DIGIT => c_int::from((ctype.casemap.isdigit)(c32)),
-    | wctype::WCTYPE_GRAPH => c_int::from((ctype.casemap.isgraph)(c32)),
-    | wctype::WCTYPE_LOWER => c_int::from((ctype.casemap.islower)(c32)),
-    | wctype::WCTYPE_PRINT => c_int::from((ctype.casemap.isprint)(c32)),
-    | wctype::WCTYPE_PUNCT => c_int::from((ctype.casemap.ispunct)(c32)),
-    | wctype::WCTYPE_SPACE => c_int::from((ctype.casemap.isspace)(c32)),
-    | wctype::WCTYPE_UPPER => c_int::from((ctype.casemap.isupper)(c32)),
-    | wctype::WCTYPE_XDIGIT => c_int::from((ctype.casemap.isxdigit)(c32)),
-    | _ => 0
-  }
+    match cc {
+      | wctype::WCTYPE_ASCII => c_int::from(wctype::inner_iswascii(c32)),
+      | _ => c_int::from(ctype.is_class(cc, c32))
+    }
+  })
 }
 
 #[inline]
@@ -74,12 +71,23 @@ fn inner_totrans(
     return c as c_uchar as c_int;
   }
 
-  match cc {
-    | wctype::WCTRANS_TOASCII => (c32 as wint_t & 0x7F) as c_int,
-    | wctype::WCTRANS_TOLOWER => (ctype.casemap.tolower)(c32) as c_int,
-    | wctype::WCTRANS_TOUPPER => (ctype.casemap.toupper)(c32) as c_int,
-    | _ => c as c_uchar as c_int
+  let mapped = match cc {
+    | wctype::WCTRANS_TOASCII => return (c32 as wint_t & 0x7F) as c_int,
+    | wctype::WCTRANS_TOLOWER => ctype.tolower(c32),
+    | wctype::WCTRANS_TOUPPER => ctype.toupper(c32),
+    | _ => return c as c_uchar as c_int
+  };
+
+  // Mappings that leave the single byte range, such as the Turkish dotted
+  // capital I, can not be expressed by a byte and leave it unchanged
+  let mut buf = [0u8; 4];
+  let saved_errno = errno::get_errno();
+  if (ctype.converter.c32tomb)(&mut buf, mapped) != 1 {
+    errno::set_errno(saved_errno);
+    return c as c_uchar as c_int;
   }
+
+  buf[0] as c_int
 }
 
 #[unsafe(no_mangle)]
@@ -279,3 +287,120 @@ pub extern "C" fn rs_toupper_l(
 ) -> c_int {
   inner_totrans(c, wctype::WCTRANS_TOUPPER, locale)
 }
+
+// Objects compiled against glibc headers expand the ctype macros into
+// lookups through these per-thread pointers, which follow the tables of the
+// thread's locale. uselocale refreshes them for its own thread. setlocale can
+// not reach the other threads, so it bumps a generation instead, and every
+// thread refreshes its pointers on its next lookup once its generation is
+// behind. The tables are never freed, so a thread that has not looked up the
+// new ones yet still reads valid memory.
+
+static GENERATION: AtomicUsize = AtomicUsize::new(1);
+
+#[thread_local]
+static mut THREAD_GENERATION: usize = 0;
+#[thread_local]
+static mut CTYPE_B: *const c_ushort = ptr::null();
+#[thread_local]
+static mut CTYPE_TOLOWER: *const int32_t = ptr::null();
+#[thread_local]
+static mut CTYPE_TOUPPER: *const int32_t = ptr::null();
+
+pub fn update_ctype_tables() {
+  // The generation is read before the locale, so a setlocale racing with
+  // this leaves the thread behind and the next lookup refreshes again
+  let generation = GENERATION.load(Ordering::Acquire);
+  let locale = locale::get_thread_locale();
+  let tables = locale::with_slot(&locale.ctype, |ctype| ctype.glibc_tables());
+
+  unsafe {
+    CTYPE_B = tables.class.as_ptr().add(glibc::TABLE_OFFSET);
+    CTYPE_TOLOWER = tables.tolower.as_ptr().add(glibc::TABLE_OFFSET);
+    CTYPE_TOUPPER = tables.toupper.as_ptr().add(glibc::TABLE_OFFSET);
+    THREAD_GENERATION = generation;
+  }
+}
+
+/// Makes every thread refresh its ctype pointers on its next lookup.
+pub fn invalidate_ctype_tables() {
+  GENERATION.fetch_add(1, Ordering::Release);
+}
+
+#[inline]
+fn init_ctype_tables() {
+  if unsafe { THREAD_GENERATION } != GENERATION.load(Ordering::Acquire) {
+    update_ctype_tables();
+  }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn __rs_ctype_b_loc() -> *mut *const c_ushort {
+  init_ctype_tables();
+  &raw mut CTYPE_B
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn __rs_ctype_tolower_loc() -> *mut *const int32_t {
+  init_ctype_tables();
+  &raw mut CTYPE_TOLOWER
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn __rs_ctype_toupper_loc() -> *mut *const int32_t {
+  init_ctype_tables();
+  &raw mut CTYPE_TOUPPER
+}
+
+standard_names! {
+  fn isascii(wc: c_int) -> c_int = rs_isascii;
+  fn isascii_l(c: c_int, locale: locale_t<'static>) -> c_int = rs_isascii_l;
+  fn isalnum(c: c_int) -> c_int = rs_isalnum;
+  fn isalnum_l(c: c_int, locale: locale_t<'static>) -> c_int = rs_isalnum_l;
+  fn isalpha(c: c_int) -> c_int = rs_isalpha;
+  fn isalpha_l(c: c_int, locale: locale_t<'static>) -> c_int = rs_isalpha_l;
+  fn isblank(c: c_int) -> c_int = rs_isblank;
+  fn isblank_l(c: c_int, locale: locale_t<'static>) -> c_int = rs_isblank_l;
+  fn iscntrl(c: c_int) -> c_int = rs_iscntrl;
+  fn iscntrl_l(c: c_int, locale: locale_t<'static>) -> c_int = rs_iscntrl_l;
+  fn isdigit(c: c_int) -> c_int = rs_isdigit;
+  fn isdigit_l(c: c_int, locale: locale_t<'static>) -> c_int = rs_isdigit_l;
+  fn isgraph(c: c_int) -> c_int = rs_isgraph;
+  fn isgraph_l(c: c_int, locale: locale_t<'static>) -> c_int = rs_isgraph_l;
+  fn islower(c: c_int) -> c_int = rs_islower;
+  fn islower_l(c: c_int, locale: locale_t<'static>) -> c_int = rs_islower_l;
+  fn isprint(c: c_int) -> c_int = rs_isprint;
+  fn isprint_l(c: c_int, locale: locale_t<'static>) -> c_int = rs_isprint_l;
+  fn ispunct(c: c_int) -> c_int = rs_ispunct;
+  fn ispunct_l(c: c_int, locale: locale_t<'static>) -> c_int = rs_ispunct_l;
+  fn isspace(c: c_int) -> c_int = rs_isspace;
+  fn isspace_l(c: c_int, locale: locale_t<'static>) -> c_int = rs_isspace_l;
+  fn isupper(c: c_int) -> c_int = rs_isupper;
+  fn isupper_l(c: c_int, locale: locale_t<'static>) -> c_int = rs_isupper_l;
+  fn isxdigit(c: c_int) -> c_int = rs_isxdigit;
+  fn isxdigit_l(c: c_int, locale: locale_t<'static>) -> c_int = rs_isxdigit_l;
+  fn toascii(c: c_int) -> c_int = rs_toascii;
+  fn toascii_l(c: c_int, locale: locale_t<'static>) -> c_int = rs_toascii_l;
+  fn tolower(c: c_int) -> c_int = rs_tolower;
+  fn tolower_l(c: c_int, locale: locale_t<'static>) -> c_int = rs_tolower_l;
+  fn toupper(c: c_int) -> c_int = rs_toupper;
+  fn toupper_l(c: c_int, locale: locale_t<'static>) -> c_int = rs_toupper_l;
+}
+
+// Exported regardless of the standard names, as objects built against
+// glibc headers call them from the ctype macros rather than by name
+
+#[unsafe(no_mangle)]
+pub extern "C" fn __ctype_b_loc() -> *mut *const c_ushort {
+  __rs_ctype_b_loc()
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn __ctype_tolower_loc() -> *mut *const int32_t {
+  __rs_ctype_tolower_loc()
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn __ctype_toupper_loc() -> *mut *const int32_t {
+  __rs_ctype_toupper_loc()
+}
@@ -151,3 +151,7 @@ pub const EOWNERDEAD: c_int = 130;
 pub const ENOTRECOVERABLE: c_int = 131;
 pub const ERFKILL: c_int = 132;
 pub const EHWPOISON: c_int = 133;
+
+standard_names! {
+  fn __errno_location() -> *mut c_int = __rs_errno_location;
+}
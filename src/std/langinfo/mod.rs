@@ -0,0 +1,254 @@
+use {
+  crate::{
+    c_char,
+    c_int,
+    locale_t,
+    std::locale::{
+      LC_ADDRESS,
+      LC_CTYPE,
+      LC_IDENTIFICATION,
+      LC_MEASUREMENT,
+      LC_MESSAGES,
+      LC_NAME,
+      LC_NUMERIC,
+      LC_PAPER,
+      LC_TELEPHONE,
+      LC_TIME
+    },
+    support::locale::{self, measurement}
+  },
+  core::ffi
+};
+
+pub type nl_item = c_int;
+
+// Items are numbered as in glibc, by category and index
+const fn nl_item(
+  category: c_int,
+  index: c_int
+) -> nl_item {
+  (category << 16) | index
+}
+
+pub const CODESET: nl_item = nl_item(LC_CTYPE, 14);
+pub const _NL_CTYPE_OUTDIGIT0_MB: nl_item = nl_item(LC_CTYPE, 41);
+pub const _NL_CTYPE_OUTDIGIT9_MB: nl_item = nl_item(LC_CTYPE, 50);
+
+pub const ABDAY_1: nl_item = nl_item(LC_TIME, 0);
+pub const ABDAY_7: nl_item = nl_item(LC_TIME, 6);
+pub const DAY_1: nl_item = nl_item(LC_TIME, 7);
+pub const DAY_7: nl_item = nl_item(LC_TIME, 13);
+pub const ABMON_1: nl_item = nl_item(LC_TIME, 14);
+pub const ABMON_12: nl_item = nl_item(LC_TIME, 25);
+pub const MON_1: nl_item = nl_item(LC_TIME, 26);
+pub const MON_12: nl_item = nl_item(LC_TIME, 37);
+pub const AM_STR: nl_item = nl_item(LC_TIME, 38);
+pub const PM_STR: nl_item = nl_item(LC_TIME, 39);
+pub const D_T_FMT: nl_item = nl_item(LC_TIME, 40);
+pub const D_FMT: nl_item = nl_item(LC_TIME, 41);
+pub const T_FMT: nl_item = nl_item(LC_TIME, 42);
+pub const T_FMT_AMPM: nl_item = nl_item(LC_TIME, 43);
+
+pub const YESEXPR: nl_item = nl_item(LC_MESSAGES, 0);
+pub const NOEXPR: nl_item = nl_item(LC_MESSAGES, 1);
+pub const YESSTR: nl_item = nl_item(LC_MESSAGES, 2);
+pub const NOSTR: nl_item = nl_item(LC_MESSAGES, 3);
+
+pub const RADIXCHAR: nl_item = nl_item(LC_NUMERIC, 0);
+pub const THOUSEP: nl_item = nl_item(LC_NUMERIC, 1);
+
+pub const _NL_PAPER_HEIGHT: nl_item = nl_item(LC_PAPER, 0);
+pub const _NL_PAPER_WIDTH: nl_item = nl_item(LC_PAPER, 1);
+
+pub const _NL_NAME_NAME_FMT: nl_item = nl_item(LC_NAME, 0);
+pub const _NL_NAME_NAME_GEN: nl_item = nl_item(LC_NAME, 1);
+pub const _NL_NAME_NAME_MR: nl_item = nl_item(LC_NAME, 2);
+pub const _NL_NAME_NAME_MRS: nl_item = nl_item(LC_NAME, 3);
+pub const _NL_NAME_NAME_MISS: nl_item = nl_item(LC_NAME, 4);
+pub const _NL_NAME_NAME_MS: nl_item = nl_item(LC_NAME, 5);
+
+pub const _NL_ADDRESS_POSTAL_FMT: nl_item = nl_item(LC_ADDRESS, 0);
+pub const _NL_ADDRESS_COUNTRY_NAME: nl_item = nl_item(LC_ADDRESS, 1);
+pub const _NL_ADDRESS_COUNTRY_POST: nl_item = nl_item(LC_ADDRESS, 2);
+pub const _NL_ADDRESS_COUNTRY_AB2: nl_item = nl_item(LC_ADDRESS, 3);
+pub const _NL_ADDRESS_COUNTRY_AB3: nl_item = nl_item(LC_ADDRESS, 4);
+pub const _NL_ADDRESS_COUNTRY_CAR: nl_item = nl_item(LC_ADDRESS, 5);
+pub const _NL_ADDRESS_COUNTRY_NUM: nl_item = nl_item(LC_ADDRESS, 6);
+pub const _NL_ADDRESS_COUNTRY_ISBN: nl_item = nl_item(LC_ADDRESS, 7);
+pub const _NL_ADDRESS_LANG_NAME: nl_item = nl_item(LC_ADDRESS, 8);
+pub const _NL_ADDRESS_LANG_AB: nl_item = nl_item(LC_ADDRESS, 9);
+pub const _NL_ADDRESS_LANG_TERM: nl_item = nl_item(LC_ADDRESS, 10);
+pub const _NL_ADDRESS_LANG_LIB: nl_item = nl_item(LC_ADDRESS, 11);
+
+pub const _NL_TELEPHONE_TEL_INT_FMT: nl_item = nl_item(LC_TELEPHONE, 0);
+pub const _NL_TELEPHONE_TEL_DOM_FMT: nl_item = nl_item(LC_TELEPHONE, 1);
+pub const _NL_TELEPHONE_INT_SELECT: nl_item = nl_item(LC_TELEPHONE, 2);
+pub const _NL_TELEPHONE_INT_PREFIX: nl_item = nl_item(LC_TELEPHONE, 3);
+
+pub const _NL_MEASUREMENT_MEASUREMENT: nl_item = nl_item(LC_MEASUREMENT, 0);
+
+pub const _NL_IDENTIFICATION_TITLE: nl_item = nl_item(LC_IDENTIFICATION, 0);
+pub const _NL_IDENTIFICATION_SOURCE: nl_item = nl_item(LC_IDENTIFICATION, 1);
+pub const _NL_IDENTIFICATION_ADDRESS: nl_item = nl_item(LC_IDENTIFICATION, 2);
+pub const _NL_IDENTIFICATION_CONTACT: nl_item = nl_item(LC_IDENTIFICATION, 3);
+pub const _NL_IDENTIFICATION_EMAIL: nl_item = nl_item(LC_IDENTIFICATION, 4);
+pub const _NL_IDENTIFICATION_TEL: nl_item = nl_item(LC_IDENTIFICATION, 5);
+pub const _NL_IDENTIFICATION_FAX: nl_item = nl_item(LC_IDENTIFICATION, 6);
+pub const _NL_IDENTIFICATION_LANGUAGE: nl_item = nl_item(LC_IDENTIFICATION, 7);
+pub const _NL_IDENTIFICATION_TERRITORY: nl_item = nl_item(LC_IDENTIFICATION, 8);
+pub const _NL_IDENTIFICATION_AUDIENCE: nl_item = nl_item(LC_IDENTIFICATION, 9);
+pub const _NL_IDENTIFICATION_APPLICATION: nl_item =
+  nl_item(LC_IDENTIFICATION, 10);
+pub const _NL_IDENTIFICATION_ABBREVIATION: nl_item =
+  nl_item(LC_IDENTIFICATION, 11);
+pub const _NL_IDENTIFICATION_REVISION: nl_item = nl_item(LC_IDENTIFICATION, 12);
+pub const _NL_IDENTIFICATION_DATE: nl_item = nl_item(LC_IDENTIFICATION, 13);
+
+// Numeric items are returned in place of the pointer, as glibc does
+fn word(value: u32) -> *const c_char {
+  value as usize as *const c_char
+}
+
+fn get_item(
+  locale: &locale::Locale<'static>,
+  item: nl_item
+) -> *const c_char {
+  // The strings belong to the category objects, which stay in place until
+  // the category is changed
+  let ptr = |s: &ffi::CStr| s.as_ptr();
+
+  match item {
+    | CODESET => {
+      locale::with_slot(&locale.ctype, |ctype| ptr(ctype.converter.codeset))
+    },
+    | _NL_CTYPE_OUTDIGIT0_MB..=_NL_CTYPE_OUTDIGIT9_MB => {
+      let digit = (item - _NL_CTYPE_OUTDIGIT0_MB) as usize;
+      locale::with_slot(&locale.numeric, |numeric| {
+        numeric.digits[digit].as_ptr().cast()
+      })
+    },
+    | RADIXCHAR => {
+      locale::with_slot(&locale.numeric, |numeric| ptr(&numeric.decimal_point))
+    },
+    | THOUSEP => {
+      locale::with_slot(&locale.numeric, |numeric| ptr(&numeric.thousands_sep))
+    },
+    | ABDAY_1..=T_FMT_AMPM => locale::with_slot(&locale.time, |time| {
+      let index = |first: nl_item| (item - first) as usize;
+      ptr(match item {
+        | ABDAY_1..=ABDAY_7 => &time.abday[index(ABDAY_1)],
+        | DAY_1..=DAY_7 => &time.day[index(DAY_1)],
+        | ABMON_1..=ABMON_12 => &time.abmon[index(ABMON_1)],
+        | MON_1..=MON_12 => &time.mon[index(MON_1)],
+        | AM_STR..=PM_STR => &time.am_pm[index(AM_STR)],
+        | D_T_FMT => &time.d_t_fmt,
+        | D_FMT => &time.d_fmt,
+        | T_FMT => &time.t_fmt,
+        | _ => &time.t_fmt_ampm
+      })
+    }),
+    | YESEXPR..=NOSTR => locale::with_slot(&locale.messages, |messages| {
+      ptr(match item {
+        | YESEXPR => &messages.yesexpr,
+        | NOEXPR => &messages.noexpr,
+        | YESSTR => &messages.yesstr,
+        | _ => &messages.nostr
+      })
+    }),
+    | _NL_PAPER_HEIGHT => locale::with_slot(&locale.paper, |p| word(p.height)),
+    | _NL_PAPER_WIDTH => locale::with_slot(&locale.paper, |p| word(p.width)),
+    | _NL_NAME_NAME_FMT..=_NL_NAME_NAME_MS => {
+      locale::with_slot(&locale.name, |name| {
+        ptr(match item {
+          | _NL_NAME_NAME_FMT => name.name_fmt,
+          | _NL_NAME_NAME_GEN => name.name_gen,
+          | _NL_NAME_NAME_MR => name.name_mr,
+          | _NL_NAME_NAME_MRS => name.name_mrs,
+          | _NL_NAME_NAME_MISS => name.name_miss,
+          | _ => name.name_ms
+        })
+      })
+    },
+    | _NL_ADDRESS_COUNTRY_NUM => {
+      locale::with_slot(&locale.address, |address| word(address.country_num))
+    },
+    | _NL_ADDRESS_POSTAL_FMT..=_NL_ADDRESS_LANG_LIB => {
+      locale::with_slot(&locale.address, |address| {
+        ptr(match item {
+          | _NL_ADDRESS_POSTAL_FMT => address.postal_fmt,
+          | _NL_ADDRESS_COUNTRY_NAME => address.country_name,
+          | _NL_ADDRESS_COUNTRY_POST => address.country_post,
+          | _NL_ADDRESS_COUNTRY_AB2 => address.country_ab2,
+          | _NL_ADDRESS_COUNTRY_AB3 => address.country_ab3,
+          | _NL_ADDRESS_COUNTRY_CAR => address.country_car,
+          | _NL_ADDRESS_COUNTRY_ISBN => address.country_isbn,
+          | _NL_ADDRESS_LANG_NAME => address.lang_name,
+          | _NL_ADDRESS_LANG_AB => address.lang_ab,
+          | _NL_ADDRESS_LANG_TERM => address.lang_term,
+          | _ => address.lang_lib
+        })
+      })
+    },
+    | _NL_TELEPHONE_TEL_INT_FMT..=_NL_TELEPHONE_INT_PREFIX => {
+      locale::with_slot(&locale.telephone, |telephone| {
+        ptr(match item {
+          | _NL_TELEPHONE_TEL_INT_FMT => telephone.tel_int_fmt,
+          | _NL_TELEPHONE_TEL_DOM_FMT => telephone.tel_dom_fmt,
+          | _NL_TELEPHONE_INT_SELECT => telephone.int_select,
+          | _ => telephone.int_prefix
+        })
+      })
+    },
+    // A string of one byte, 1 for metric and 2 for US customary units
+    | _NL_MEASUREMENT_MEASUREMENT => {
+      locale::with_slot(&locale.measurement, |m| match m.measurement {
+        | measurement::MEASUREMENT_US => ptr(c"\x02"),
+        | _ => ptr(c"\x01")
+      })
+    },
+    | _NL_IDENTIFICATION_TITLE => {
+      locale::with_slot(&locale.identification, |id| ptr(&id.title))
+    },
+    | _NL_IDENTIFICATION_SOURCE..=_NL_IDENTIFICATION_DATE => {
+      locale::with_slot(&locale.identification, |id| {
+        ptr(match item {
+          | _NL_IDENTIFICATION_SOURCE => id.source,
+          | _NL_IDENTIFICATION_ADDRESS => id.address,
+          | _NL_IDENTIFICATION_CONTACT => id.contact,
+          | _NL_IDENTIFICATION_EMAIL => id.email,
+          | _NL_IDENTIFICATION_TEL => id.tel,
+          | _NL_IDENTIFICATION_FAX => id.fax,
+          | _NL_IDENTIFICATION_LANGUAGE => id.language,
+          | _NL_IDENTIFICATION_TERRITORY => id.territory,
+          | _NL_IDENTIFICATION_AUDIENCE => id.audience,
+          | _NL_IDENTIFICATION_APPLICATION => id.application,
+          | _NL_IDENTIFICATION_ABBREVIATION => id.abbreviation,
+          | _NL_IDENTIFICATION_REVISION => id.revision,
+          | _ => id.date
+        })
+      })
+    },
+    | _ => c"".as_ptr()
+  }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn rs_nl_langinfo(item: nl_item) -> *mut c_char {
+  get_item(locale::get_thread_locale(), item).cast_mut()
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn rs_nl_langinfo_l(
+  item: nl_item,
+  locale: locale_t<'static>
+) -> *mut c_char {
+  get_item(locale::get_real_locale(locale), item).cast_mut()
+}
+
+standard_names! {
+  fn nl_langinfo(item: nl_item) -> *mut c_char = rs_nl_langinfo;
+  fn nl_langinfo_l(
+    item: nl_item,
+    locale: locale_t<'static>
+  ) -> *mut c_char = rs_nl_langinfo_l;
+}
@@ -0,0 +1,159 @@
+use {
+  crate::{
+    c_char,
+    c_int,
+    c_ulong,
+    std::locale::LC_MESSAGES,
+    support::intl::{self, Domains}
+  },
+  core::{cell::RefCell, ffi, ptr},
+  critical_section::Mutex
+};
+
+static DOMAINS: Mutex<RefCell<Domains>> =
+  Mutex::new(RefCell::new(Domains::new()));
+
+#[inline]
+fn with_domains<R>(f: impl FnOnce(&mut Domains) -> R) -> R {
+  critical_section::with(|cs| f(&mut DOMAINS.borrow_ref_mut(cs)))
+}
+
+#[inline]
+fn to_cstr<'a>(s: *const c_char) -> Option<&'a ffi::CStr> {
+  if s.is_null() { None } else { Some(unsafe { ffi::CStr::from_ptr(s) }) }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn rs_gettext(msgid: *const c_char) -> *mut c_char {
+  rs_dcgettext(ptr::null(), msgid, LC_MESSAGES)
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn rs_dgettext(
+  domainname: *const c_char,
+  msgid: *const c_char
+) -> *mut c_char {
+  rs_dcgettext(domainname, msgid, LC_MESSAGES)
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn rs_dcgettext(
+  domainname: *const c_char,
+  msgid: *const c_char,
+  category: c_int
+) -> *mut c_char {
+  let Some(msgid) = to_cstr(msgid) else {
+    return ptr::null_mut();
+  };
+
+  intl::translate(&DOMAINS, to_cstr(domainname), msgid, None, category)
+    .cast_mut()
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn rs_ngettext(
+  msgid1: *const c_char,
+  msgid2: *const c_char,
+  n: c_ulong
+) -> *mut c_char {
+  rs_dcngettext(ptr::null(), msgid1, msgid2, n, LC_MESSAGES)
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn rs_dngettext(
+  domainname: *const c_char,
+  msgid1: *const c_char,
+  msgid2: *const c_char,
+  n: c_ulong
+) -> *mut c_char {
+  rs_dcngettext(domainname, msgid1, msgid2, n, LC_MESSAGES)
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn rs_dcngettext(
+  domainname: *const c_char,
+  msgid1: *const c_char,
+  msgid2: *const c_char,
+  n: c_ulong,
+  category: c_int
+) -> *mut c_char {
+  let (Some(msgid1), Some(msgid2)) = (to_cstr(msgid1), to_cstr(msgid2)) else {
+    return ptr::null_mut();
+  };
+
+  let plural = Some((msgid2, n));
+  intl::translate(&DOMAINS, to_cstr(domainname), msgid1, plural, category)
+    .cast_mut()
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn rs_textdomain(domainname: *const c_char) -> *mut c_char {
+  with_domains(|d| d.textdomain(to_cstr(domainname))).cast_mut()
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn rs_bindtextdomain(
+  domainname: *const c_char,
+  dirname: *const c_char
+) -> *mut c_char {
+  match to_cstr(domainname) {
+    | Some(domain) if !domain.is_empty() => {
+      with_domains(|d| d.bindtextdomain(domain, to_cstr(dirname))).cast_mut()
+    },
+    | _ => ptr::null_mut()
+  }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn rs_bind_textdomain_codeset(
+  domainname: *const c_char,
+  codeset: *const c_char
+) -> *mut c_char {
+  match to_cstr(domainname) {
+    | Some(domain) if !domain.is_empty() => {
+      with_domains(|d| d.bind_textdomain_codeset(domain, to_cstr(codeset)))
+        .cast_mut()
+    },
+    | _ => ptr::null_mut()
+  }
+}
+
+standard_names! {
+  fn gettext(msgid: *const c_char) -> *mut c_char = rs_gettext;
+  fn dgettext(
+    domainname: *const c_char,
+    msgid: *const c_char
+  ) -> *mut c_char = rs_dgettext;
+  fn dcgettext(
+    domainname: *const c_char,
+    msgid: *const c_char,
+    category: c_int
+  ) -> *mut c_char = rs_dcgettext;
+  fn ngettext(
+    msgid1: *const c_char,
+    msgid2: *const c_char,
+    n: c_ulong
+  ) -> *mut c_char = rs_ngettext;
+  fn dngettext(
+    domainname: *const c_char,
+    msgid1: *const c_char,
+    msgid2: *const c_char,
+    n: c_ulong
+  ) -> *mut c_char = rs_dngettext;
+  fn dcngettext(
+    domainname: *const c_char,
+    msgid1: *const c_char,
+    msgid2: *const c_char,
+    n: c_ulong,
+    category: c_int
+  ) -> *mut c_char = rs_dcngettext;
+  fn textdomain(domainname: *const c_char) -> *mut c_char = rs_textdomain;
+  fn bindtextdomain(
+    domainname: *const c_char,
+    dirname: *const c_char
+  ) -> *mut c_char = rs_bindtextdomain;
+  fn bind_textdomain_codeset(
+    domainname: *const c_char,
+    codeset: *const c_char
+  ) -> *mut c_char = rs_bind_textdomain_codeset;
+}
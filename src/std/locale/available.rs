@@ -1,149 +1,93 @@
-use core::ffi;
+use {
+  super::LC_ALL_MASK,
+  crate::{
+    allocation::{borrow::Cow, string::String, vec::Vec},
+    support::{locale, string::strtocstr}
+  },
+  core::ffi,
+  once_cell::sync::OnceCell
+};
 
-pub const AVAILABLE_LOCALES: [&'static ffi::CStr; 145] = [
-  c"C",
-  c"POSIX",
-  c"en_US",
-  c"C.UTF-8",
-  c"POSIX.UTF-8",
-  c"ca_AD.UTF-8",
-  c"ca_ES.UTF-8",
-  c"ca_FR.UTF-8",
-  c"ca_IT.UTF-8",
-  c"cs_CZ.UTF-8",
-  c"da_DK.UTF-8",
-  c"da_GL.UTF-8",
-  c"de_AT.UTF-8",
-  c"de_BE.UTF-8",
-  c"de_CH.UTF-8",
-  c"de_DE.UTF-8",
-  c"de_IT.UTF-8",
-  c"de_LI.UTF-8",
-  c"de_LU.UTF-8",
-  c"de_US.UTF-8",
-  c"el_CY.UTF-8",
-  c"el_GR.UTF-8",
-  c"en_001.UTF-8",
-  c"en_150.UTF-8",
-  c"en_AT.UTF-8",
-  c"en_AU.UTF-8",
-  c"en_CA.UTF-8",
-  c"en_CH.UTF-8",
-  c"en_DE.UTF-8",
-  c"en_DK.UTF-8",
-  c"en_FI.UTF-8",
-  c"en_FK.UTF-8",
-  c"en_GB.UTF-8",
-  c"en_GI.UTF-8",
-  c"en_HK.UTF-8",
-  c"en_IO.UTF-8",
-  c"en_JE.UTF-8",
-  c"en_JM.UTF-8",
-  c"en_KN.UTF-8",
-  c"en_KY.UTF-8",
-  c"en_MH.UTF-8",
-  c"en_MO.UTF-8",
-  c"en_MP.UTF-8",
-  c"en_MT.UTF-8",
-  c"en_NF.UTF-8",
-  c"en_NZ.UTF-8",
-  c"en_PR.UTF-8",
-  c"en_SB.UTF-8",
-  c"en_SC.UTF-8",
-  c"en_SE.UTF-8",
-  c"en_SG.UTF-8",
-  c"en_SH.UTF-8",
-  c"en_SI.UTF-8",
-  c"en_UM.UTF-8",
-  c"en_US.UTF-8",
-  c"en_VC.UTF-8",
-  c"en_VG.UTF-8",
-  c"en_VI.UTF-8",
-  c"en_ZA.UTF-8",
-  c"et_ET.UTF-8",
-  c"fi_FI.UTF-8",
-  c"fr_BE.UTF-8",
-  c"fr_BL.UTF-8",
-  c"fr_CA.UTF-8",
-  c"fr_CH.UTF-8",
-  c"fr_DZ.UTF-8",
-  c"fr_FR.UTF-8",
-  c"fr_LU.UTF-8",
-  c"fr_MA.UTF-8",
-  c"fr_MC.UTF-8",
-  c"fr_MF.UTF-8",
-  c"fr_NC.UTF-8",
-  c"fr_PM.UTF-8",
-  c"fr_RE.UTF-8",
-  c"fr_SC.UTF-8",
-  c"fr_SY.UTF-8",
-  c"fr_TN.UTF-8",
-  c"hak_CN.UTF-8",
-  c"hak_TW.UTF-8",
-  c"he_IL.UTF-8",
-  c"hr_BA.UTF-8",
-  c"hr_HR.UTF-8",
-  c"it_CH.UTF-8",
-  c"it_IT.UTF-8",
-  c"it_SM.UTF-8",
-  c"it_VA.UTF-8",
-  c"ja_JP.UTF-8",
-  c"ko_KP.UTF-8",
-  c"ko_KR.UTF-8",
-  c"mt_MT.UTF-8",
-  c"nan_TW.UTF-8",
-  c"nb_NO.UTF-8",
-  c"nl_AW.UTF-8",
-  c"nl_BE.UTF-8",
-  c"nl_BQ.UTF-8",
-  c"nl_CW.UTF-8",
-  c"nl_NL.UTF-8",
-  c"nl_SR.UTF-8",
-  c"nl_SX.UTF-8",
-  c"pdc_US.UTF-8",
-  c"pl_PL.UTF-8",
-  c"pt_AO.UTF-8",
-  c"pt_BR.UTF-8",
-  c"pt_CH.UTF-8",
-  c"pt_CV.UTF-8",
-  c"pt_GQ.UTF-8",
-  c"pt_GW.UTF-8",
-  c"pt_LU.UTF-8",
-  c"pt_MO.UTF-8",
-  c"pt_MZ.UTF-8",
-  c"pt_PT.UTF-8",
-  c"pt_ST.UTF-8",
-  c"pt_TL.UTF-8",
-  c"rm_CH.UTF-8",
-  c"ru_BY.UTF-8",
-  c"ru_KG.UTF-8",
-  c"ru_KZ.UTF-8",
-  c"ru_MD.UTF-8",
-  c"ru_RU.UTF-8",
-  c"ru_UA.UTF-8",
-  c"sr_BA.UTF-8",
-  c"sr_BA.UTF-8@latin",
-  c"sr_ME.UTF-8",
-  c"sr_ME.UTF-8@latin",
-  c"sr_RS.UTF-8",
-  c"sr_RS.UTF-8@latin",
-  c"sr_XK.UTF-8",
-  c"sr_XK.UTF-8@latin",
-  c"sv_AX.UTF-8",
-  c"sv_FI.UTF-8",
-  c"sv_SE.UTF-8",
-  c"uk_PL.UTF-8",
-  c"uk_UA.UTF-8",
-  c"vi_VN.UTF-8",
-  c"wa_BE.UTF-8",
-  c"wae_CH.UTF-8",
-  c"wuu_CN.UTF-8",
-  c"yue_CN.UTF-8",
-  c"yue_HK.UTF-8",
-  c"yue_MO.UTF-8",
-  c"zh_CN.UTF-8",
-  c"zh_HK.UTF-8",
-  c"zh_MO.UTF-8",
-  c"zh_SG.UTF-8",
-  c"zh_TW.UTF-8"
+// Locales are not installed anywhere, so whether one is available is
+// decided by loading it. The list below only drives enumeration: every
+// language and territory pair that CLDR has data for and that we expect
+// users to ask for. Pairs that do not load in this build are left out.
+
+#[rustfmt::skip]
+const TERRITORIES: [&str; 196] = [
+  "ar_AE", "ar_BH", "ar_DZ", "ar_EG", "ar_IQ", "ar_JO", "ar_KW", "ar_LB",
+  "ar_LY", "ar_MA", "ar_OM", "ar_QA", "ar_SA", "ar_SD", "ar_SY", "ar_TN",
+  "ar_YE", "az_AZ", "bg_BG", "bn_BD", "bn_IN", "ca_AD", "ca_ES", "ca_FR",
+  "ca_IT", "cs_CZ", "cy_GB", "da_DK", "da_GL", "de_AT", "de_BE", "de_CH",
+  "de_DE", "de_IT", "de_LI", "de_LU", "de_US", "el_CY", "el_GR", "en_AT",
+  "en_AU", "en_CA", "en_CH", "en_DE", "en_DK", "en_FI", "en_FK", "en_GB",
+  "en_GI", "en_HK", "en_JE", "en_JM", "en_KN", "en_KY", "en_MH", "en_MO",
+  "en_MP", "en_MT", "en_NZ", "en_PR", "en_SB", "en_SE", "en_SG", "en_SH",
+  "en_SI", "en_UM", "en_US", "en_VC", "en_VG", "en_VI", "en_ZA", "es_AR",
+  "es_BO", "es_CL", "es_CO", "es_CR", "es_CU", "es_DO", "es_EC", "es_ES",
+  "es_GT", "es_HN", "es_MX", "es_NI", "es_PA", "es_PE", "es_PR", "es_PY",
+  "es_SV", "es_US", "es_UY", "es_VE", "et_ET", "eu_ES", "fa_IR", "fi_FI",
+  "fr_BE", "fr_BL", "fr_CA", "fr_CH", "fr_DZ", "fr_FR", "fr_LU", "fr_MA",
+  "fr_MC", "fr_MF", "fr_NC", "fr_PM", "fr_RE", "fr_SY", "fr_TN", "ga_IE",
+  "gl_ES", "hak_CN", "hak_TW", "he_IL", "hi_IN", "hr_BA", "hr_HR", "hu_HU",
+  "id_ID", "is_IS", "it_CH", "it_IT", "it_SM", "it_VA", "ja_JP", "ka_GE",
+  "kk_KZ", "ko_KR", "lt_LT", "lv_LV", "mk_MK", "ms_MY", "mt_MT", "nan_TW",
+  "nb_NO", "nl_BE", "nl_BQ", "nl_CW", "nl_NL", "nl_SR", "nl_SX", "pdc_US",
+  "pl_PL", "pt_AO", "pt_BR", "pt_CH", "pt_CV", "pt_GQ", "pt_LU", "pt_MO",
+  "pt_MZ", "pt_PT", "pt_ST", "pt_TL", "rm_CH", "ro_RO", "ru_BY", "ru_KG",
+  "ru_KZ", "ru_RU", "ru_UA", "sk_SK", "sl_SI", "sq_AL", "sr_BA", "sr_BA@latin",
+  "sr_ME", "sr_ME@latin", "sr_RS", "sr_RS@latin", "sr_XK", "sr_XK@latin",
+  "sv_AX", "sv_FI", "sv_SE", "th_TH", "tr_CY", "tr_TR", "uk_PL", "uk_UA",
+  "ur_PK", "uz_UZ", "vi_VN", "wa_BE", "wae_CH", "wuu_CN", "yue_CN", "yue_HK",
+  "yue_MO", "zh_CN", "zh_HK", "zh_MO", "zh_SG", "zh_TW"
 ];
+
+const CODESETS: [&str; 1] = ["UTF-8"];
+
+static AVAILABLE_LOCALES: OnceCell<Vec<Cow<'static, ffi::CStr>>> =
+  OnceCell::new();
+
+fn with_codeset(
+  territory: &str,
+  codeset: &str
+) -> Cow<'static, ffi::CStr> {
+  let (territory, modifier) = match territory.split_once('@') {
+    | Some((territory, modifier)) => (territory, Some(modifier)),
+    | None => (territory, None)
+  };
+
+  let mut name = String::from(territory);
+  name.push('.');
+  name.push_str(codeset);
+  if let Some(modifier) = modifier {
+    name.push('@');
+    name.push_str(modifier);
+  }
+
+  strtocstr(&name)
+}
+
+/// Returns the names of every locale that setlocale and newlocale accept,
+/// leaving out aliases such as other spellings of a codeset.
+pub fn get_available_locales() -> &'static [Cow<'static, ffi::CStr>] {
+  AVAILABLE_LOCALES.get_or_init(|| {
+    let mut scratch = locale::Locale::new();
+    let mut names = Vec::new();
+
+    // en_US without a codeset is the ASCII-only variant
+    let plain = [c"C", c"POSIX", c"en_US"].into_iter().map(Cow::Borrowed);
+    let posix = ["C", "POSIX"].iter();
+    let candidates =
+      plain.chain(posix.chain(TERRITORIES.iter()).flat_map(|territory| {
+        CODESETS.iter().map(move |codeset| with_codeset(territory, codeset))
+      }));
+
+    for name in candidates {
+      if scratch.load(LC_ALL_MASK, &name).is_ok() {
+        names.push(name);
+      }
+    }
+
+    names
+  })
+}
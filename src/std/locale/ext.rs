@@ -0,0 +1,29 @@
+use {
+  super::available,
+  crate::{c_char, size_t},
+  core::slice
+};
+
+// Lists the available locales, like `locale -a`. Up to `n` names are stored
+// in `names` and the total number of locales is returned, so a first call
+// with `n` set to 0 gives the size to allocate. The names stay valid for the
+// lifetime of the process.
+
+#[unsafe(no_mangle)]
+pub extern "C" fn rs_getavailablelocales(
+  names: *mut *const c_char,
+  n: size_t
+) -> size_t {
+  let available = available::get_available_locales();
+
+  if n > 0 {
+    let names: &mut [*const c_char] =
+      unsafe { slice::from_raw_parts_mut(names, n) };
+
+    for (d, name) in names.iter_mut().zip(available) {
+      *d = name.as_ptr();
+    }
+  }
+
+  available.len()
+}
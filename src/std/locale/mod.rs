@@ -8,15 +8,15 @@ use {
     c_int,
     intptr_t,
     locale_t,
-    std::errno,
+    std::{ctype, errno},
     support::locale
   },
-  atomic_refcell::AtomicRefCell,
   core::{ffi, ptr},
   smallvec::SmallVec
 };
 
 mod available;
+pub mod ext;
 
 pub const LC_CTYPE: c_int = 0;
 pub const LC_NUMERIC: c_int = 1;
@@ -25,6 +25,12 @@ pub const LC_COLLATE: c_int = 3;
 pub const LC_MONETARY: c_int = 4;
 pub const LC_MESSAGES: c_int = 5;
 pub const LC_ALL: c_int = 6;
+pub const LC_PAPER: c_int = 7;
+pub const LC_NAME: c_int = 8;
+pub const LC_ADDRESS: c_int = 9;
+pub const LC_TELEPHONE: c_int = 10;
+pub const LC_MEASUREMENT: c_int = 11;
+pub const LC_IDENTIFICATION: c_int = 12;
 
 pub const LC_CTYPE_MASK: c_int = 1 << LC_CTYPE;
 pub const LC_NUMERIC_MASK: c_int = 1 << LC_NUMERIC;
@@ -32,13 +38,25 @@ pub const LC_TIME_MASK: c_int = 1 << LC_TIME;
 pub const LC_COLLATE_MASK: c_int = 1 << LC_COLLATE;
 pub const LC_MONETARY_MASK: c_int = 1 << LC_MONETARY;
 pub const LC_MESSAGES_MASK: c_int = 1 << LC_MESSAGES;
+pub const LC_PAPER_MASK: c_int = 1 << LC_PAPER;
+pub const LC_NAME_MASK: c_int = 1 << LC_NAME;
+pub const LC_ADDRESS_MASK: c_int = 1 << LC_ADDRESS;
+pub const LC_TELEPHONE_MASK: c_int = 1 << LC_TELEPHONE;
+pub const LC_MEASUREMENT_MASK: c_int = 1 << LC_MEASUREMENT;
+pub const LC_IDENTIFICATION_MASK: c_int = 1 << LC_IDENTIFICATION;
 
 pub const LC_ALL_MASK: c_int = LC_CTYPE_MASK |
   LC_NUMERIC_MASK |
   LC_TIME_MASK |
   LC_COLLATE_MASK |
   LC_MONETARY_MASK |
-  LC_MESSAGES_MASK;
+  LC_MESSAGES_MASK |
+  LC_PAPER_MASK |
+  LC_NAME_MASK |
+  LC_ADDRESS_MASK |
+  LC_TELEPHONE_MASK |
+  LC_MEASUREMENT_MASK |
+  LC_IDENTIFICATION_MASK;
 
 pub const LC_GLOBAL_LOCALE: locale_t = -1 as intptr_t as locale_t;
 
@@ -74,30 +92,29 @@ unsafe impl Send for lconv {}
 unsafe impl Sync for lconv {}
 
 impl lconv {
-  pub fn from_locale(locale: &locale::Locale<'static>) -> Self {
-    let monetary = locale::get_slot_mut(&locale.monetary);
-    let numeric = locale::get_slot_mut(&locale.numeric);
+  /// Shared locales are only read, so the pointers stay valid after the
+  /// borrows end, for as long as the locale.
+  pub fn from_locale(locale: &locale::Locale<'static>) -> Option<Self> {
+    let monetary = locale::get_slot_ref(&locale.monetary, |l| &l.monetary)?;
+    let numeric = locale::get_slot_ref(&locale.numeric, |l| &l.numeric)?;
 
     let grouping: *mut c_char = if numeric.grouping.is_empty() {
-      let slice: &[u8] = &[b'\0'];
-      slice.as_ptr() as *mut u8 as *mut c_char
+      c"".as_ptr().cast_mut()
     } else {
       numeric.grouping.as_slice().as_ptr() as *mut u8 as *mut c_char
     };
     let mon_grouping: *mut c_char = if monetary.mon_grouping.is_empty() {
-      let slice: &[u8] = &[b'\0'];
-      slice.as_ptr() as *mut u8 as *mut c_char
+      c"".as_ptr().cast_mut()
     } else {
       monetary.mon_grouping.as_slice().as_ptr() as *mut u8 as *mut c_char
     };
     let int_curr_symbol: *mut c_char = if monetary.int_curr_symbol.is_empty() {
-      let slice: &[u8] = &[b'\0'];
-      slice.as_ptr() as *mut u8 as *mut c_char
+      c"".as_ptr().cast_mut()
     } else {
       monetary.int_curr_symbol.as_slice().as_ptr() as *mut u8 as *mut c_char
     };
 
-    Self {
+    Some(Self {
       decimal_point: numeric.decimal_point.as_ptr().cast_mut(),
       thousands_sep: numeric.thousands_sep.as_ptr().cast_mut(),
       grouping: grouping,
@@ -122,7 +139,7 @@ impl lconv {
       int_n_sep_by_space: monetary.int_n_sep_by_space,
       int_p_sign_posn: monetary.int_p_sign_posn,
       int_n_sign_posn: monetary.int_n_sign_posn
-    }
+    })
   }
 }
 
@@ -170,18 +187,6 @@ fn normalize_locale_name<'a>(name: &'a ffi::CStr) -> Cow<'a, ffi::CStr> {
   Cow::Owned(cstr.to_owned())
 }
 
-#[inline]
-fn swap<T>(
-  lhs: &AtomicRefCell<T>,
-  rhs: &AtomicRefCell<T>
-) {
-  let (first, second) =
-    if (lhs as *const _) < (rhs as *const _) { (lhs, rhs) } else { (rhs, lhs) };
-  let mut g1 = first.borrow_mut();
-  let mut g2 = second.borrow_mut();
-  core::mem::swap(&mut *g1, &mut *g2);
-}
-
 #[unsafe(no_mangle)]
 pub extern "C" fn rs_localeconv() -> *mut lconv {
   let locale = locale::get_thread_locale_ptr();
@@ -191,12 +196,17 @@ pub extern "C" fn rs_localeconv() -> *mut lconv {
 #[unsafe(no_mangle)]
 pub extern "C" fn rs_localeconv_l(locale: locale_t<'static>) -> *mut lconv {
   let locale: &locale::Locale = locale::get_real_locale(locale);
-  let lconv = lconv::from_locale(&locale);
 
-  let mut result = locale.localeconv.borrow_mut();
-  *result = lconv;
-
-  &raw mut *result as *mut lconv
+  // Made once per locale. Versions of the global locale are never freed, so
+  // for it the result stays valid for the life of the process, and for a
+  // locale object until freelocale.
+  match locale
+    .localeconv
+    .get_or_try_init(|| lconv::from_locale(locale).map(Box::new).ok_or(()))
+  {
+    | Ok(lconv) => ptr::from_ref(lconv).cast_mut(),
+    | Err(()) => ptr::null_mut()
+  }
 }
 
 #[unsafe(no_mangle)]
@@ -204,21 +214,27 @@ extern "C" fn rs_setlocale(
   category: c_int,
   locale: *const c_char
 ) -> *mut c_char {
-  let mut locales: [Option<&'static ffi::CStr>; 7] = [
+  let mut locales: [Option<&'static ffi::CStr>; 13] = [
     None, // LC_CTYPE
     None, // LC_NUMERIC
     None, // LC_TIME
     None, // LC_COLLATE
     None, // LC_MONETARY
     None, // LC_MESSAGES
-    None  // LC_ALL
+    None, // LC_ALL
+    None, // LC_PAPER
+    None, // LC_NAME
+    None, // LC_ADDRESS
+    None, // LC_TELEPHONE
+    None, // LC_MEASUREMENT
+    None  // LC_IDENTIFICATION
   ];
 
-  if category < 0 || category > LC_ALL {
+  if category < 0 || category > LC_IDENTIFICATION {
     return ptr::null_mut();
   }
   if locale.is_null() {
-    return locale::get_thread_locale().querylocale(category);
+    return locale::GLOBAL_LOCALE.read().querylocale(category);
   }
 
   let locale = unsafe { ffi::CStr::from_ptr(locale) };
@@ -232,13 +248,27 @@ extern "C" fn rs_setlocale(
     if let Some(l) = lc {
       let l: &ffi::CStr = &normalize_locale_name(l);
 
-      if !available::AVAILABLE_LOCALES.contains(&&l) {
-        return ptr::null_mut();
-      }
-
-      let changed = locale::get_thread_locale();
-      if let Ok(result) = changed.setlocale(c as c_int, l) {
-        return result.querylocale(c as c_int);
+      // The new version starts from the current one, so that the other
+      // categories are kept
+      let changed = locale::GLOBAL_LOCALE.update(
+        |current| {
+          let mut changed = locale::Locale::new();
+          changed.setlocale(c as c_int, l)?;
+          changed.inherit(
+            current,
+            match c as c_int {
+              | LC_ALL => LC_ALL_MASK,
+              | c => 1 << c
+            }
+          );
+          Ok::<_, c_int>(changed)
+        },
+        locale::is_same_locale
+      );
+      if changed.is_ok() {
+        ctype::invalidate_ctype_tables();
+        ctype::update_ctype_tables();
+        return locale::GLOBAL_LOCALE.read().querylocale(c as c_int);
       }
     }
   }
@@ -271,34 +301,13 @@ fn newlocale_inner(
     locale::get_real_locale(base)
   };
 
-  let newloc =
+  let name: &ffi::CStr = &normalize_locale_name(name);
+
+  let mut newloc =
     Box::try_new(locale::Locale::new()).map_err(|_| errno::ENOENT)?;
 
-  locale::set_slot(&newloc.collate, name)?;
-  locale::set_slot(&newloc.ctype, name)?;
-  locale::set_slot(&newloc.messages, name)?;
-  locale::set_slot(&newloc.monetary, name)?;
-  locale::set_slot(&newloc.numeric, name)?;
-  locale::set_slot(&newloc.time, name)?;
-
-  if (mask & LC_COLLATE_MASK) == 0 {
-    swap(&newloc.collate, &base.collate);
-  }
-  if (mask & LC_CTYPE_MASK) == 0 {
-    swap(&newloc.ctype, &base.ctype);
-  }
-  if (mask & LC_MESSAGES_MASK) == 0 {
-    swap(&newloc.messages, &base.messages);
-  }
-  if (mask & LC_MONETARY_MASK) == 0 {
-    swap(&newloc.monetary, &base.monetary);
-  }
-  if (mask & LC_NUMERIC_MASK) == 0 {
-    swap(&newloc.numeric, &base.numeric);
-  }
-  if (mask & LC_TIME_MASK) == 0 {
-    swap(&newloc.time, &base.time);
-  }
+  newloc.load(mask, name)?;
+  newloc.inherit(base, mask);
 
   Ok(Box::into_raw(newloc))
 }
@@ -338,6 +347,7 @@ pub extern "C" fn rs_uselocale(new: locale_t<'static>) -> locale_t<'static> {
 
   if !new.is_null() {
     locale::set_thread_locale_ptr(new);
+    ctype::update_ctype_tables();
   }
 
   old
@@ -348,7 +358,7 @@ pub extern "C" fn rs_getlocalename_l(
   category: c_int,
   locale: locale_t<'static>
 ) -> *const c_char {
-  if locale.is_null() || category < 0 || category > LC_ALL {
+  if locale.is_null() || category < 0 || category > LC_IDENTIFICATION {
     return ptr::null();
   }
 
@@ -356,3 +366,24 @@ pub extern "C" fn rs_getlocalename_l(
 
   locale.querylocale(category)
 }
+
+standard_names! {
+  fn localeconv() -> *mut lconv = rs_localeconv;
+  fn localeconv_l(locale: locale_t<'static>) -> *mut lconv = rs_localeconv_l;
+  fn setlocale(
+    category: c_int,
+    locale: *const c_char
+  ) -> *mut c_char = rs_setlocale;
+  fn newlocale(
+    mask: c_int,
+    locale: *const c_char,
+    base: locale_t<'static>
+  ) -> locale_t<'static> = rs_newlocale;
+  fn freelocale(locale: locale_t<'static>) = rs_freelocale;
+  fn duplocale(base: locale_t<'static>) -> locale_t<'static> = rs_duplocale;
+  fn uselocale(new: locale_t<'static>) -> locale_t<'static> = rs_uselocale;
+  fn getlocalename_l(
+    category: c_int,
+    locale: locale_t<'static>
+  ) -> *const c_char = rs_getlocalename_l;
+}
@@ -1,6 +1,9 @@
 pub mod ctype;
 pub mod errno;
+pub mod langinfo;
+pub mod libintl;
 pub mod locale;
+pub mod nl_types;
 //pub mod stdio; // TODO: Implement stdio
 pub mod stdlib; // TODO: Implement stdlib
 pub mod string;
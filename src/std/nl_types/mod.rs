@@ -0,0 +1,106 @@
+use {
+  crate::{
+    allocation::{borrow::ToOwned, boxed::Box},
+    c_char,
+    c_int,
+    std::errno,
+    support::{
+      environment,
+      intl::cat::{self, MessageCatalog},
+      locale::{self, LocaleObject}
+    }
+  },
+  core::{ffi, ffi::c_void}
+};
+
+pub type nl_catd = *mut c_void;
+
+pub const NL_SETD: c_int = 1;
+pub const NL_CAT_LOCALE: c_int = 1;
+
+const INVALID_CATD: nl_catd = -1isize as nl_catd;
+
+#[unsafe(no_mangle)]
+pub extern "C" fn rs_catopen(
+  name: *const c_char,
+  oflag: c_int
+) -> nl_catd {
+  if name.is_null() {
+    errno::set_errno(errno::ENOENT);
+    return INVALID_CATD;
+  }
+  let name = unsafe { ffi::CStr::from_ptr(name) };
+
+  // NL_CAT_LOCALE takes the LC_MESSAGES locale of the calling thread,
+  // otherwise LANG is used
+  let locale = if oflag == NL_CAT_LOCALE {
+    locale::with_slot(&locale::get_thread_locale().messages, |m| {
+      m.get_name().to_owned()
+    })
+  } else {
+    environment::getenv("LANG")
+      .filter(|lang| !lang.is_empty())
+      .unwrap_or(c"C")
+      .to_owned()
+  };
+  let locale = locale.to_str().unwrap_or("C");
+
+  match cat::find_catalog(name, locale) {
+    | Ok(catalog) => match Box::try_new(catalog) {
+      | Ok(catalog) => Box::into_raw(catalog).cast(),
+      | Err(_) => {
+        errno::set_errno(errno::ENOMEM);
+        INVALID_CATD
+      }
+    },
+    | Err(e) => {
+      errno::set_errno(e);
+      INVALID_CATD
+    }
+  }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn rs_catgets(
+  catd: nl_catd,
+  set_id: c_int,
+  msg_id: c_int,
+  s: *const c_char
+) -> *mut c_char {
+  if catd.is_null() || catd == INVALID_CATD {
+    errno::set_errno(errno::EBADF);
+    return s.cast_mut();
+  }
+  let catalog = unsafe { &*catd.cast::<MessageCatalog>() };
+
+  match catalog.get(set_id, msg_id) {
+    | Some(message) => message.cast_mut(),
+    | None => {
+      errno::set_errno(errno::ENOMSG);
+      s.cast_mut()
+    }
+  }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn rs_catclose(catd: nl_catd) -> c_int {
+  if catd.is_null() || catd == INVALID_CATD {
+    errno::set_errno(errno::EBADF);
+    return -1;
+  }
+
+  unsafe { drop(Box::from_raw(catd.cast::<MessageCatalog>())) };
+
+  0
+}
+
+standard_names! {
+  fn catopen(name: *const c_char, oflag: c_int) -> nl_catd = rs_catopen;
+  fn catgets(
+    catd: nl_catd,
+    set_id: c_int,
+    msg_id: c_int,
+    s: *const c_char
+  ) -> *mut c_char = rs_catgets;
+  fn catclose(catd: nl_catd) -> c_int = rs_catclose;
+}
@@ -8,3 +8,7 @@ pub extern "C" fn __stroginointernal_get_mb_cur_max() -> size_t {
     locale::get_slot(&locale::get_thread_locale().ctype).unwrap_or_default();
   ctype.converter.mb_cur_max
 }
+
+standard_names! {
+  fn __ctype_get_mb_cur_max() -> size_t = __stroginointernal_get_mb_cur_max;
+}
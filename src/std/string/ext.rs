@@ -1 +1,190 @@
+use {
+  super::rs_strlen,
+  crate::{
+    allocation::{string::String, vec::Vec},
+    c_char,
+    c_int,
+    locale_t,
+    size_t,
+    std::errno,
+    support::{
+      locale,
+      locale::{ctype::casemap::CaseMapObject, numeric::NumericObject}
+    }
+  },
+  allocation::borrow::Cow,
+  core::slice
+};
 
+// Full case mappings may change the length of a string ("ß" uppercases to
+// "SS"), so these follow strxfrm: the mapped string is stored only when it
+// fits in n bytes including the terminator, and its length is returned.
+// Strings that are invalid in the locale codeset return (size_t)-1.
+
+#[inline]
+fn inner_strmap(
+  dest: *mut c_char,
+  src: *const c_char,
+  n: size_t,
+  locale: locale_t<'static>,
+  map: for<'s> fn(&CaseMapObject, &'s str) -> Cow<'s, str>
+) -> size_t {
+  let locale = locale::get_real_locale(locale);
+  let ctype = locale::get_slot(&locale.ctype).unwrap_or_default();
+
+  let source: &[u8] =
+    unsafe { slice::from_raw_parts(src as *const u8, rs_strlen(src)) };
+
+  let mapped =
+    ctype.decode(source).and_then(|s| ctype.encode(&map(&ctype.casemap, &s)));
+
+  store_mapped(dest, n, mapped)
+}
+
+// The digits of LC_NUMERIC, as for the printf "I" flag: these write the
+// ASCII digits of a formatted number as the digits of the locale, and read
+// the digits of the locale back as ASCII ones for the strto* functions.
+
+#[inline]
+fn inner_strdigits(
+  dest: *mut c_char,
+  src: *const c_char,
+  n: size_t,
+  locale: locale_t<'static>,
+  map: fn(&NumericObject, &str) -> String
+) -> size_t {
+  let locale = locale::get_real_locale(locale);
+  let ctype = locale::get_slot(&locale.ctype).unwrap_or_default();
+  let numeric = locale::get_slot(&locale.numeric).unwrap_or_default();
+
+  let source: &[u8] =
+    unsafe { slice::from_raw_parts(src as *const u8, rs_strlen(src)) };
+
+  let mapped =
+    ctype.decode(source).and_then(|s| ctype.encode(&map(&numeric, &s)));
+
+  store_mapped(dest, n, mapped)
+}
+
+#[inline]
+fn store_mapped(
+  dest: *mut c_char,
+  n: size_t,
+  mapped: Result<Vec<u8>, c_int>
+) -> size_t {
+  let mapped = match mapped {
+    | Ok(mapped) => mapped,
+    | Err(e) => {
+      errno::set_errno(e);
+      return size_t::MAX;
+    }
+  };
+
+  if mapped.len() < n {
+    let destination: &mut [u8] =
+      unsafe { slice::from_raw_parts_mut(dest as *mut u8, n) };
+
+    destination[..mapped.len()].copy_from_slice(&mapped);
+
+    destination[mapped.len()] = b'\0';
+  }
+
+  mapped.len()
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn rs_strlower(
+  dest: *mut c_char,
+  src: *const c_char,
+  n: size_t
+) -> size_t {
+  rs_strlower_l(dest, src, n, locale::get_thread_locale_ptr())
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn rs_strlower_l(
+  dest: *mut c_char,
+  src: *const c_char,
+  n: size_t,
+  locale: locale_t<'static>
+) -> size_t {
+  inner_strmap(dest, src, n, locale, CaseMapObject::lower)
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn rs_strupper(
+  dest: *mut c_char,
+  src: *const c_char,
+  n: size_t
+) -> size_t {
+  rs_strupper_l(dest, src, n, locale::get_thread_locale_ptr())
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn rs_strupper_l(
+  dest: *mut c_char,
+  src: *const c_char,
+  n: size_t,
+  locale: locale_t<'static>
+) -> size_t {
+  inner_strmap(dest, src, n, locale, CaseMapObject::upper)
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn rs_strfold(
+  dest: *mut c_char,
+  src: *const c_char,
+  n: size_t
+) -> size_t {
+  rs_strfold_l(dest, src, n, locale::get_thread_locale_ptr())
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn rs_strfold_l(
+  dest: *mut c_char,
+  src: *const c_char,
+  n: size_t,
+  locale: locale_t<'static>
+) -> size_t {
+  inner_strmap(dest, src, n, locale, CaseMapObject::fold)
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn rs_strtonative(
+  dest: *mut c_char,
+  src: *const c_char,
+  n: size_t
+) -> size_t {
+  rs_strtonative_l(dest, src, n, locale::get_thread_locale_ptr())
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn rs_strtonative_l(
+  dest: *mut c_char,
+  src: *const c_char,
+  n: size_t,
+  locale: locale_t<'static>
+) -> size_t {
+  inner_strdigits(dest, src, n, locale, |numeric, s| {
+    numeric.to_native_digits(s)
+  })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn rs_strfromnative(
+  dest: *mut c_char,
+  src: *const c_char,
+  n: size_t
+) -> size_t {
+  rs_strfromnative_l(dest, src, n, locale::get_thread_locale_ptr())
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn rs_strfromnative_l(
+  dest: *mut c_char,
+  src: *const c_char,
+  n: size_t,
+  locale: locale_t<'static>
+) -> size_t {
+  inner_strdigits(dest, src, n, locale, |numeric, s| numeric.to_ascii_digits(s))
+}
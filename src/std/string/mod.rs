@@ -651,3 +651,82 @@ pub extern "C" fn rs_strerror(num: c_int) -> *mut c_char {
 // do strsignal
 
 // Allocated memory stuff: strdup, strndup
+
+// memcpy, memmove, memset and memcmp are left to compiler_builtins: the
+// compiler lowers the loops above into calls to them, so exporting these
+// under the same names would make them call themselves.
+standard_names! {
+  fn memccpy(
+    dest: *mut c_void,
+    src: *const c_void,
+    c: c_int,
+    n: size_t
+  ) -> *mut c_void = rs_memccpy;
+  fn memchr(s: *const c_void, c: c_int, n: size_t) -> *mut c_void = rs_memchr;
+  fn memset_explicit(
+    s: *mut c_void,
+    c: c_int,
+    n: size_t
+  ) -> *mut c_void = rs_memset_explicit;
+  fn stpcpy(dest: *mut c_char, src: *const c_char) -> *mut c_char = rs_stpcpy;
+  fn stpncpy(
+    dest: *mut c_char,
+    src: *const c_char,
+    n: size_t
+  ) -> *mut c_char = rs_stpncpy;
+  fn strcat(dest: *mut c_char, src: *const c_char) -> *mut c_char = rs_strcat;
+  fn strchr(s: *const c_char, c: c_int) -> *mut c_char = rs_strchr;
+  fn strcmp(left: *const c_char, right: *const c_char) -> c_int = rs_strcmp;
+  fn strcoll(lhs: *const c_char, rhs: *const c_char) -> c_int = rs_strcoll;
+  fn strcoll_l(
+    lhs: *const c_char,
+    rhs: *const c_char,
+    locale: locale_t<'static>
+  ) -> c_int = rs_strcoll_l;
+  fn strcpy(dest: *mut c_char, src: *const c_char) -> *mut c_char = rs_strcpy;
+  fn strcspn(src: *const c_char, segment: *const c_char) -> size_t = rs_strcspn;
+  fn strlen(s: *const c_char) -> size_t = rs_strlen;
+  fn strncat(
+    dest: *mut c_char,
+    src: *const c_char,
+    n: size_t
+  ) -> *mut c_char = rs_strncat;
+  fn strncmp(
+    left: *const c_char,
+    right: *const c_char,
+    n: size_t
+  ) -> c_int = rs_strncmp;
+  fn strncpy(
+    dest: *mut c_char,
+    src: *const c_char,
+    n: size_t
+  ) -> *mut c_char = rs_strncpy;
+  fn strnlen(s: *const c_char, n: size_t) -> size_t = rs_strnlen;
+  fn strpbrk(
+    src: *const c_char,
+    breakset: *const c_char
+  ) -> *mut c_char = rs_strpbrk;
+  fn strrchr(s: *const c_char, c: c_int) -> *mut c_char = rs_strrchr;
+  fn strspn(src: *const c_char, segment: *const c_char) -> size_t = rs_strspn;
+  fn strstr(
+    haystack: *const c_char,
+    needle: *const c_char
+  ) -> *mut c_char = rs_strstr;
+  fn strtok(s: *mut c_char, sep: *const c_char) -> *mut c_char = rs_strtok;
+  fn strtok_r(
+    s: *mut c_char,
+    sep: *const c_char,
+    lasts: *mut *mut c_char
+  ) -> *mut c_char = rs_strtok_r;
+  fn strxfrm(
+    dest: *mut c_char,
+    src: *const c_char,
+    n: size_t
+  ) -> size_t = rs_strxfrm;
+  fn strxfrm_l(
+    dest: *mut c_char,
+    src: *const c_char,
+    n: size_t,
+    locale: locale_t<'static>
+  ) -> size_t = rs_strxfrm_l;
+}
@@ -1,53 +1,43 @@
 use {
   crate::{
-    MBState,
     c_char,
     c_int,
-    char32_t,
     locale_t,
     size_t,
     support::{locale, locale::ctype::CtypeObject}
   },
-  core::ffi
+  core::{cmp::Ordering, ffi}
 };
 
-#[inline]
-fn fetchchar(
-  s: &mut &[u8],
-  ctype: &CtypeObject,
-  mb: &mut MBState
-) -> char32_t {
-  let mut c32: char32_t = 0;
-  let ret = (ctype.converter.mbtoc32)(&mut c32, s, mb);
-  if ret < 0 {
-    return 0;
-  }
-
-  *s = &s[ret as usize..];
-
-  c32
+// The characters that start within the first n bytes. Bytes that are not
+// characters of the locale codeset come after every character.
+fn get_units<'s>(
+  s: &'s [u8],
+  n: size_t,
+  ctype: &'s CtypeObject
+) -> impl Iterator<Item = Result<char, u32>> + 's {
+  ctype
+    .decode_chars(s)
+    .take_while(move |&(start, _)| start < n)
+    .map(|(_, unit)| unit.map_err(|b| 0x110000 + u32::from(b)))
 }
 
 #[inline]
-fn fetchchar_with_size(
-  s: &mut &[u8],
-  n: &mut size_t,
-  ctype: &CtypeObject,
-  mb: &mut MBState
-) -> char32_t {
-  let mut c32: char32_t = 0;
-  let limit = s.len().min(*n as usize);
-
-  let ret = (ctype.converter.mbtoc32)(&mut c32, &s[..limit], mb);
-  if ret < 0 {
-    return 0;
+fn inner_casecmp(
+  left: &[u8],
+  right: &[u8],
+  n: size_t,
+  ctype: &CtypeObject
+) -> c_int {
+  let ordering = ctype
+    .casemap
+    .casecmp(get_units(left, n, ctype), get_units(right, n, ctype));
+
+  match ordering {
+    | Ordering::Less => -1,
+    | Ordering::Equal => 0,
+    | Ordering::Greater => 1
   }
-
-  let ret = ret as usize;
-  *s = &s[ret..];
-  *n -= ret;
-
-  c32
 }
 
 #[unsafe(no_mangle)]
@@ -68,27 +58,13 @@ pub extern "C" fn rs_strcasecmp_l(
     return 0;
   }
 
-  let mut left = unsafe { ffi::CStr::from_ptr(left).to_bytes() };
-  let mut right = unsafe { ffi::CStr::from_ptr(right).to_bytes() };
-
-  let mut mbl = MBState::new();
-  let mut mbr = MBState::new();
+  let left = unsafe { ffi::CStr::from_ptr(left).to_bytes() };
+  let right = unsafe { ffi::CStr::from_ptr(right).to_bytes() };
 
   let locale = locale::get_real_locale(locale);
   let ctype = locale::get_slot(&locale.ctype).unwrap_or_default();
 
-  loop {
-    let l = (ctype.casemap.tolower)(fetchchar(&mut left, &ctype, &mut mbl));
-    let r = (ctype.casemap.tolower)(fetchchar(&mut right, &ctype, &mut mbr));
-
-    if l != r {
-      return if l < r { -1 } else { 1 };
-    }
-
-    if l == 0 {
-      return 0;
-    }
-  }
+  inner_casecmp(left, right, size_t::MAX, &ctype)
 }
 
 #[unsafe(no_mangle)]
@@ -115,31 +91,34 @@ pub extern "C" fn rs_strncasecmp_l(
     return 0;
   }
 
-  let mut left = unsafe { ffi::CStr::from_ptr(left).to_bytes() };
-  let mut right = unsafe { ffi::CStr::from_ptr(right).to_bytes() };
-  let mut nl = n;
-  let mut nr = n;
-
-  let mut mbl = MBState::new();
-  let mut mbr = MBState::new();
+  let left = unsafe { ffi::CStr::from_ptr(left).to_bytes() };
+  let right = unsafe { ffi::CStr::from_ptr(right).to_bytes() };
 
   let locale = locale::get_real_locale(locale);
   let ctype = locale::get_slot(&locale.ctype).unwrap_or_default();
 
-  loop {
-    let l = (ctype.casemap.tolower)(fetchchar_with_size(
-      &mut left, &mut nl, &ctype, &mut mbl
-    ));
-    let r = (ctype.casemap.tolower)(fetchchar_with_size(
-      &mut right, &mut nr, &ctype, &mut mbr
-    ));
-
-    if l != r {
-      return if l < r { -1 } else { 1 };
-    }
+  inner_casecmp(left, right, n, &ctype)
+}
 
-    if l == 0 {
-      return 0;
-    }
-  }
+standard_names! {
+  fn strcasecmp(
+    left: *const c_char,
+    right: *const c_char
+  ) -> c_int = rs_strcasecmp;
+  fn strcasecmp_l(
+    left: *const c_char,
+    right: *const c_char,
+    locale: locale_t<'static>
+  ) -> c_int = rs_strcasecmp_l;
+  fn strncasecmp(
+    left: *const c_char,
+    right: *const c_char,
+    n: size_t
+  ) -> c_int = rs_strncasecmp;
+  fn strncasecmp_l(
+    left: *const c_char,
+    right: *const c_char,
+    n: size_t,
+    locale: locale_t<'static>
+  ) -> c_int = rs_strncasecmp_l;
 }
@@ -415,3 +415,39 @@ pub extern "C" fn rs_mbrtoc32(
   }
   l as size_t
 }
+
+standard_names! {
+  fn c8rtomb(
+    s: *mut c_char,
+    c8: char8_t,
+    ps: *mut mbstate_t
+  ) -> size_t = rs_c8rtomb;
+  fn c16rtomb(
+    s: *mut c_char,
+    c16: char16_t,
+    ps: *mut mbstate_t
+  ) -> size_t = rs_c16rtomb;
+  fn c32rtomb(
+    s: *mut c_char,
+    c32: char32_t,
+    ps: *mut mbstate_t
+  ) -> size_t = rs_c32rtomb;
+  fn mbrtoc8(
+    pc8: *mut char8_t,
+    s: *const c_char,
+    n: size_t,
+    ps: *mut mbstate_t
+  ) -> size_t = rs_mbrtoc8;
+  fn mbrtoc16(
+    pc16: *mut char16_t,
+    s: *const c_char,
+    n: size_t,
+    ps: *mut mbstate_t
+  ) -> size_t = rs_mbrtoc16;
+  fn mbrtoc32(
+    pc32: *mut char32_t,
+    s: *const c_char,
+    n: size_t,
+    ps: *mut mbstate_t
+  ) -> size_t = rs_mbrtoc32;
+}
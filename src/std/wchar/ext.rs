@@ -1 +1,172 @@
+use {
+  super::{decode_wide, rs_wcslen, rs_wcsnlen},
+  crate::{
+    c_char,
+    c_int,
+    locale_t,
+    size_t,
+    std::{errno, string::rs_strnlen},
+    support::{locale, locale::ctype::casemap::CaseMapObject},
+    wchar_t
+  },
+  allocation::{borrow::Cow, vec::Vec},
+  core::slice
+};
 
+// Wide counterparts of the string case mapping extensions, with the same
+// strxfrm-like contract. Invalid code points return (size_t)-1.
+
+#[inline]
+fn inner_wcsmap(
+  dest: *mut wchar_t,
+  src: *const wchar_t,
+  n: size_t,
+  locale: locale_t<'static>,
+  map: for<'s> fn(&CaseMapObject, &'s str) -> Cow<'s, str>
+) -> size_t {
+  let locale = locale::get_real_locale(locale);
+  let ctype = locale::get_slot(&locale.ctype).unwrap_or_default();
+
+  let source: &[u32] =
+    unsafe { slice::from_raw_parts(src as *const u32, rs_wcslen(src)) };
+
+  let source = match decode_wide(source) {
+    | Ok(source) => source,
+    | Err(e) => {
+      errno::set_errno(e);
+      return size_t::MAX;
+    }
+  };
+  let mapped = map(&ctype.casemap, &source);
+  let len = mapped.chars().count();
+
+  if len < n {
+    let destination: &mut [u32] =
+      unsafe { slice::from_raw_parts_mut(dest as *mut u32, n) };
+
+    for (d, c) in destination.iter_mut().zip(mapped.chars()) {
+      *d = c as u32;
+    }
+
+    destination[len] = '\0' as u32;
+  }
+
+  len
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn rs_wcslwr(
+  dest: *mut wchar_t,
+  src: *const wchar_t,
+  n: size_t
+) -> size_t {
+  rs_wcslwr_l(dest, src, n, locale::get_thread_locale_ptr())
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn rs_wcslwr_l(
+  dest: *mut wchar_t,
+  src: *const wchar_t,
+  n: size_t,
+  locale: locale_t<'static>
+) -> size_t {
+  inner_wcsmap(dest, src, n, locale, CaseMapObject::lower)
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn rs_wcsupr(
+  dest: *mut wchar_t,
+  src: *const wchar_t,
+  n: size_t
+) -> size_t {
+  rs_wcsupr_l(dest, src, n, locale::get_thread_locale_ptr())
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn rs_wcsupr_l(
+  dest: *mut wchar_t,
+  src: *const wchar_t,
+  n: size_t,
+  locale: locale_t<'static>
+) -> size_t {
+  inner_wcsmap(dest, src, n, locale, CaseMapObject::upper)
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn rs_wcsfold(
+  dest: *mut wchar_t,
+  src: *const wchar_t,
+  n: size_t
+) -> size_t {
+  rs_wcsfold_l(dest, src, n, locale::get_thread_locale_ptr())
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn rs_wcsfold_l(
+  dest: *mut wchar_t,
+  src: *const wchar_t,
+  n: size_t,
+  locale: locale_t<'static>
+) -> size_t {
+  inner_wcsmap(dest, src, n, locale, CaseMapObject::fold)
+}
+
+// Display width of a string in terminal columns, measured per extended
+// grapheme cluster rather than per code point as wcswidth does. Returns -1
+// for nonprintable or invalid strings.
+
+#[unsafe(no_mangle)]
+pub extern "C" fn rs_wcsgwidth(
+  s: *const wchar_t,
+  n: size_t
+) -> c_int {
+  rs_wcsgwidth_l(s, n, locale::get_thread_locale_ptr())
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn rs_wcsgwidth_l(
+  s: *const wchar_t,
+  n: size_t,
+  locale: locale_t<'static>
+) -> c_int {
+  let locale = locale::get_real_locale(locale);
+  let ctype = locale::get_slot(&locale.ctype).unwrap_or_default();
+
+  let source: &[u32] =
+    unsafe { slice::from_raw_parts(s as *const u32, rs_wcsnlen(s, n)) };
+
+  ctype.wcsgwidth(source)
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn rs_mbsgwidth(
+  s: *const c_char,
+  n: size_t
+) -> c_int {
+  rs_mbsgwidth_l(s, n, locale::get_thread_locale_ptr())
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn rs_mbsgwidth_l(
+  s: *const c_char,
+  n: size_t,
+  locale: locale_t<'static>
+) -> c_int {
+  let locale = locale::get_real_locale(locale);
+  let ctype = locale::get_slot(&locale.ctype).unwrap_or_default();
+
+  let source: &[u8] =
+    unsafe { slice::from_raw_parts(s as *const u8, rs_strnlen(s, n)) };
+
+  match ctype.decode(source) {
+    | Ok(source) => {
+      let source: Vec<u32> = source.chars().map(|c| c as u32).collect();
+
+      ctype.wcsgwidth(&source)
+    },
+    | Err(e) => {
+      errno::set_errno(e);
+      -1
+    }
+  }
+}
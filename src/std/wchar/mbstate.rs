@@ -279,3 +279,7 @@ pub extern "C" fn rs_wctob(c: wint_t) -> c_int {
   unsafe { *buf.as_ptr() as c_uchar as c_int }
 }
 */
+
+standard_names! {
+  fn mbsinit(ps: *const mbstate_t) -> c_int = rs_mbsinit;
+}
@@ -7,10 +7,11 @@ use {
     c_int,
     locale_t,
     size_t,
-    std::wctype,
+    std::errno,
     support::{algorithm::twoway, locale},
     wchar_t
   },
+  allocation::string::String,
   cbitset::BitSet256,
   core::{cmp::Ordering, ptr, slice}
 };
@@ -565,6 +566,34 @@ pub extern "C" fn rs_wcsxfrm_l(
   sortkey.len()
 }
 
+#[inline]
+fn decode_wide(s: &[u32]) -> Result<String, c_int> {
+  s.iter().map(|&c| char::from_u32(c).ok_or(errno::EILSEQ)).collect()
+}
+
+// Units that are not code points compare by their value
+fn get_units(s: &[u32]) -> impl Iterator<Item = Result<char, u32>> + '_ {
+  s.iter().map(|&c| char::from_u32(c).ok_or(c))
+}
+
+#[inline]
+fn inner_wcscasecmp(
+  left: &[u32],
+  right: &[u32],
+  locale: locale_t<'static>
+) -> c_int {
+  let locale_real = locale::get_real_locale(locale);
+  let ctype = locale::get_slot(&locale_real.ctype).unwrap_or_default();
+
+  let ordering = ctype.casemap.casecmp(get_units(left), get_units(right));
+
+  match ordering {
+    | Ordering::Less => -1,
+    | Ordering::Equal => 0,
+    | Ordering::Greater => 1
+  }
+}
+
 #[unsafe(no_mangle)]
 pub extern "C" fn rs_wcscasecmp(
   left: *const wchar_t,
@@ -583,24 +612,12 @@ pub extern "C" fn rs_wcscasecmp_l(
     return 0;
   }
 
-  let mut left =
+  let left =
     unsafe { slice::from_raw_parts(left as *const u32, rs_wcslen(left)) };
-  let mut right =
+  let right =
     unsafe { slice::from_raw_parts(right as *const u32, rs_wcslen(right)) };
 
-  while let (Some(&l), Some(&r)) = (left.first(), right.first()) {
-    let lr = wctype::rs_towlower_l(l, locale);
-    let rr = wctype::rs_towlower_l(r, locale);
-
-    if lr != rr {
-      return if lr < rr { -1 } else { 1 };
-    }
-
-    left = &left[1..];
-    right = &right[1..];
-  }
-
-  0
+  inner_wcscasecmp(left, right, locale)
 }
 
 #[unsafe(no_mangle)]
@@ -627,40 +644,18 @@ pub extern "C" fn rs_wcsncasecmp_l(
     return 0;
   }
 
-  let mut left =
-    unsafe { slice::from_raw_parts(left as *const u32, rs_wcslen(left)) };
-  let mut right =
-    unsafe { slice::from_raw_parts(right as *const u32, rs_wcslen(right)) };
-  let mut n = n;
-
-  while let (Some(&l), Some(&r)) = (left.first(), right.first()) &&
-    n > 0
-  {
-    let lr = wctype::rs_towlower_l(l, locale);
-    let rr = wctype::rs_towlower_l(r, locale);
+  let left =
+    unsafe { slice::from_raw_parts(left as *const u32, rs_wcsnlen(left, n)) };
+  let right =
+    unsafe { slice::from_raw_parts(right as *const u32, rs_wcsnlen(right, n)) };
 
-    if lr != rr {
-      return if lr < rr { -1 } else { 1 };
-    }
-
-    if lr as u32 == '\0' as u32 {
-      break;
-    }
-
-    left = &left[1..];
-    right = &right[1..];
-    n -= 1;
-  }
-
-  0
+  inner_wcscasecmp(left, right, locale)
 }
 
 #[unsafe(no_mangle)]
 extern "C" fn rs_wcwidth(wc: wchar_t) -> c_int {
   let locale = locale::get_real_locale(locale::get_thread_locale_ptr());
-  let ctype = locale::get_slot(&locale.ctype).unwrap_or_default();
-
-  (ctype.converter.wcwidth)(wc as u32)
+  locale::with_slot(&locale.ctype, |ctype| ctype.wcwidth(wc as u32))
 }
 
 #[unsafe(no_mangle)]
@@ -668,15 +663,18 @@ pub extern "C" fn rs_wcswidth(
   pwcs: *const wchar_t,
   n: size_t
 ) -> c_int {
+  let locale = locale::get_real_locale(locale::get_thread_locale_ptr());
+  let ctype = locale::get_slot(&locale.ctype).unwrap_or_default();
+
   let mut n = n;
   let mut pwcs = pwcs;
   let mut len = 0;
 
   unsafe {
     while n > 0 && *pwcs != 0 {
-      let l = rs_wcwidth(*pwcs);
+      let l = ctype.wcwidth(*pwcs as u32);
       if l == -1 {
-        return 1;
+        return -1;
       }
       pwcs = pwcs.offset(1);
       len += l;
@@ -688,3 +686,131 @@ pub extern "C" fn rs_wcswidth(
 }
 
 // Allocated memory stuff: wcsdup
+
+standard_names! {
+  fn wmemccpy(
+    dest: *mut wchar_t,
+    src: *const wchar_t,
+    c: wchar_t,
+    n: size_t
+  ) -> *mut wchar_t = rs_wmemccpy;
+  fn wmemchr(
+    s: *const wchar_t,
+    c: wchar_t,
+    n: size_t
+  ) -> *mut wchar_t = rs_wmemchr;
+  fn wmemcmp(
+    left: *const wchar_t,
+    right: *const wchar_t,
+    n: size_t
+  ) -> c_int = rs_wmemcmp;
+  fn wmemcpy(
+    dest: *mut wchar_t,
+    src: *const wchar_t,
+    n: size_t
+  ) -> *mut wchar_t = rs_wmemcpy;
+  fn wmemmove(
+    dest: *mut wchar_t,
+    src: *const wchar_t,
+    n: size_t
+  ) -> *mut wchar_t = rs_wmemmove;
+  fn wmemset(
+    s: *mut wchar_t,
+    c: wchar_t,
+    n: size_t
+  ) -> *mut wchar_t = rs_wmemset;
+  fn wcpcpy(
+    dest: *mut wchar_t,
+    src: *const wchar_t
+  ) -> *mut wchar_t = rs_wcpcpy;
+  fn wcpncpy(
+    dest: *mut wchar_t,
+    src: *const wchar_t,
+    n: size_t
+  ) -> *mut wchar_t = rs_wcpncpy;
+  fn wcscat(
+    dest: *mut wchar_t,
+    src: *const wchar_t
+  ) -> *mut wchar_t = rs_wcscat;
+  fn wcschr(s: *const wchar_t, c: wchar_t) -> *mut wchar_t = rs_wcschr;
+  fn wcscmp(left: *const wchar_t, right: *const wchar_t) -> c_int = rs_wcscmp;
+  fn wcscoll(lhs: *const wchar_t, rhs: *const wchar_t) -> c_int = rs_wcscoll;
+  fn wcscoll_l(
+    lhs: *const wchar_t,
+    rhs: *const wchar_t,
+    locale: locale_t<'static>
+  ) -> c_int = rs_wcscoll_l;
+  fn wcscpy(
+    dest: *mut wchar_t,
+    src: *const wchar_t
+  ) -> *mut wchar_t = rs_wcscpy;
+  fn wcscspn(
+    src: *const wchar_t,
+    segment: *const wchar_t
+  ) -> size_t = rs_wcscspn;
+  fn wcslen(s: *const wchar_t) -> size_t = rs_wcslen;
+  fn wcsncat(
+    dest: *mut wchar_t,
+    src: *const wchar_t,
+    n: size_t
+  ) -> *mut wchar_t = rs_wcsncat;
+  fn wcsncmp(
+    left: *const wchar_t,
+    right: *const wchar_t,
+    n: size_t
+  ) -> c_int = rs_wcsncmp;
+  fn wcsncpy(
+    dest: *mut wchar_t,
+    src: *const wchar_t,
+    n: size_t
+  ) -> *mut wchar_t = rs_wcsncpy;
+  fn wcsnlen(s: *const wchar_t, n: size_t) -> size_t = rs_wcsnlen;
+  fn wcspbrk(
+    src: *const wchar_t,
+    breakset: *const wchar_t
+  ) -> *mut wchar_t = rs_wcspbrk;
+  fn wcsrchr(s: *const wchar_t, c: wchar_t) -> *mut wchar_t = rs_wcsrchr;
+  fn wcsspn(src: *const wchar_t, segment: *const wchar_t) -> size_t = rs_wcsspn;
+  fn wcsstr(
+    haystack: *const wchar_t,
+    needle: *const wchar_t
+  ) -> *mut wchar_t = rs_wcsstr;
+  fn wcstok(
+    s: *mut wchar_t,
+    sep: *const wchar_t,
+    lasts: *mut *mut wchar_t
+  ) -> *mut wchar_t = rs_wcstok;
+  fn wcsxfrm(
+    dest: *mut wchar_t,
+    src: *const wchar_t,
+    n: size_t
+  ) -> size_t = rs_wcsxfrm;
+  fn wcsxfrm_l(
+    dest: *mut wchar_t,
+    src: *const wchar_t,
+    n: size_t,
+    locale: locale_t<'static>
+  ) -> size_t = rs_wcsxfrm_l;
+  fn wcscasecmp(
+    left: *const wchar_t,
+    right: *const wchar_t
+  ) -> c_int = rs_wcscasecmp;
+  fn wcscasecmp_l(
+    left: *const wchar_t,
+    right: *const wchar_t,
+    locale: locale_t<'static>
+  ) -> c_int = rs_wcscasecmp_l;
+  fn wcsncasecmp(
+    left: *const wchar_t,
+    right: *const wchar_t,
+    n: size_t
+  ) -> c_int = rs_wcsncasecmp;
+  fn wcsncasecmp_l(
+    left: *const wchar_t,
+    right: *const wchar_t,
+    n: size_t,
+    locale: locale_t<'static>
+  ) -> c_int = rs_wcsncasecmp_l;
+  fn wcwidth(wc: wchar_t) -> c_int = rs_wcwidth;
+  fn wcswidth(pwcs: *const wchar_t, n: size_t) -> c_int = rs_wcswidth;
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "casemap")]
+use icu_properties::{PropertyParser, props::Script};
 use {
   crate::{
     c_char,
@@ -27,9 +29,19 @@ pub const WCTYPE_SPACE: wctype_t = 10;
 pub const WCTYPE_UPPER: wctype_t = 11;
 pub const WCTYPE_XDIGIT: wctype_t = 12;
 
+// Extended classes, backed by Unicode properties
+pub const WCTYPE_COMBINING: wctype_t = 14;
+pub const WCTYPE_EMOJI: wctype_t = 15;
+pub const WCTYPE_IDEOGRAPHIC: wctype_t = 16;
+pub const WCTYPE_JDIGIT: wctype_t = 17;
+// Script classes carry the script code in the low 16 bits
+pub const WCTYPE_SCRIPT: wctype_t = 0x10000;
+
 pub const WCTRANS_TOASCII: wctrans_t = 3 as wctrans_t;
 pub const WCTRANS_TOLOWER: wctrans_t = 1 as wctrans_t;
 pub const WCTRANS_TOUPPER: wctrans_t = 2 as wctrans_t;
+pub const WCTRANS_TOTITLE: wctrans_t = 4 as wctrans_t;
+pub const WCTRANS_TOFOLD: wctrans_t = 5 as wctrans_t;
 
 #[inline]
 pub fn inner_iswascii(wc: wint_t) -> bool {
@@ -57,9 +69,9 @@ pub extern "C" fn rs_iswascii_l(
   locale: locale_t<'static>
 ) -> c_int {
   let locale: &locale::Locale = locale::get_real_locale(locale);
-  let ctype = locale::get_slot(&locale.ctype).unwrap_or_default();
-
-  c_int::from(inner_iswascii(wc) && valid_in_locale(wc, &ctype))
+  locale::with_slot(&locale.ctype, |ctype| {
+    c_int::from(inner_iswascii(wc) && valid_in_locale(wc, ctype))
+  })
 }
 
 #[unsafe(no_mangle)]
@@ -73,9 +85,9 @@ pub extern "C" fn rs_iswalnum_l(
   locale: locale_t<'static>
 ) -> c_int {
   let locale: &locale::Locale = locale::get_real_locale(locale);
-  let ctype = locale::get_slot(&locale.ctype).unwrap_or_default();
-
-  c_int::from((ctype.casemap.isalnum)(wc) && valid_in_locale(wc, &ctype))
+  locale::with_slot(&locale.ctype, |ctype| {
+    c_int::from(ctype.is_class(WCTYPE_ALNUM, wc) && valid_in_locale(wc, ctype))
+  })
 }
 
 #[unsafe(no_mangle)]
@@ -89,9 +101,9 @@ pub extern "C" fn rs_iswalpha_l(
   locale: locale_t<'static>
 ) -> c_int {
   let locale: &locale::Locale = locale::get_real_locale(locale);
-  let ctype = locale::get_slot(&locale.ctype).unwrap_or_default();
-
-  c_int::from((ctype.casemap.isalpha)(wc) && valid_in_locale(wc, &ctype))
+  locale::with_slot(&locale.ctype, |ctype| {
+    c_int::from(ctype.is_class(WCTYPE_ALPHA, wc) && valid_in_locale(wc, ctype))
+  })
 }
 
 #[unsafe(no_mangle)]
@@ -105,9 +117,9 @@ pub extern "C" fn rs_iswblank_l(
   locale: locale_t<'static>
 ) -> c_int {
   let locale: &locale::Locale = locale::get_real_locale(locale);
-  let ctype = locale::get_slot(&locale.ctype).unwrap_or_default();
-
-  c_int::from((ctype.casemap.isblank)(wc) && valid_in_locale(wc, &ctype))
+  locale::with_slot(&locale.ctype, |ctype| {
+    c_int::from(ctype.is_class(WCTYPE_BLANK, wc) && valid_in_locale(wc, ctype))
+  })
 }
 
 #[unsafe(no_mangle)]
@@ -121,9 +133,9 @@ pub extern "C" fn rs_iswcntrl_l(
   locale: locale_t<'static>
 ) -> c_int {
   let locale: &locale::Locale = locale::get_real_locale(locale);
-  let ctype = locale::get_slot(&locale.ctype).unwrap_or_default();
-
-  c_int::from((ctype.casemap.iscntrl)(wc) && valid_in_locale(wc, &ctype))
+  locale::with_slot(&locale.ctype, |ctype| {
+    c_int::from(ctype.is_class(WCTYPE_CNTRL, wc) && valid_in_locale(wc, ctype))
+  })
 }
 
 #[unsafe(no_mangle)]
@@ -153,10 +165,33 @@ pub extern "C" fn rs_iswctype_l(
     | WCTYPE_SPACE => rs_iswspace_l(wc, locale),
     | WCTYPE_UPPER => rs_iswupper_l(wc, locale),
     | WCTYPE_XDIGIT => rs_iswxdigit_l(wc, locale),
-    | _ => 0
+    | _ => inner_iswextra(wc, cc, locale)
   }
 }
 
+#[inline]
+fn inner_iswextra(
+  wc: wint_t,
+  cc: wctype_t,
+  locale: locale_t<'static>
+) -> c_int {
+  let locale: &locale::Locale = locale::get_real_locale(locale);
+  let ctype = locale::get_slot(&locale.ctype).unwrap_or_default();
+
+  let result = match cc {
+    | WCTYPE_COMBINING => (ctype.casemap.iscombining)(wc),
+    | WCTYPE_EMOJI => (ctype.casemap.isemoji)(wc),
+    | WCTYPE_IDEOGRAPHIC => (ctype.casemap.isideographic)(wc),
+    | WCTYPE_JDIGIT => (ctype.casemap.isjdigit)(wc),
+    | _ if cc & !0xffff == WCTYPE_SCRIPT => {
+      (ctype.casemap.isscript)(wc, cc as u16)
+    },
+    | _ => false
+  };
+
+  c_int::from(result && valid_in_locale(wc, &ctype))
+}
+
 #[unsafe(no_mangle)]
 pub extern "C" fn rs_iswdigit(wc: wint_t) -> c_int {
   rs_iswdigit_l(wc, locale::get_thread_locale_ptr())
@@ -168,9 +203,9 @@ pub extern "C" fn rs_iswdigit_l(
   locale: locale_t<'static>
 ) -> c_int {
   let locale: &locale::Locale = locale::get_real_locale(locale);
-  let ctype = locale::get_slot(&locale.ctype).unwrap_or_default();
-
-  c_int::from((ctype.casemap.isdigit)(wc) && valid_in_locale(wc, &ctype))
+  locale::with_slot(&locale.ctype, |ctype| {
+    c_int::from(ctype.is_class(WCTYPE_DIGIT, wc) && valid_in_locale(wc, ctype))
+  })
 }
 
 #[unsafe(no_mangle)]
@@ -184,9 +219,9 @@ pub extern "C" fn rs_iswgraph_l(
   locale: locale_t<'static>
 ) -> c_int {
   let locale: &locale::Locale = locale::get_real_locale(locale);
-  let ctype = locale::get_slot(&locale.ctype).unwrap_or_default();
-
-  c_int::from((ctype.casemap.isgraph)(wc) && valid_in_locale(wc, &ctype))
+  locale::with_slot(&locale.ctype, |ctype| {
+    c_int::from(ctype.is_class(WCTYPE_GRAPH, wc) && valid_in_locale(wc, ctype))
+  })
 }
 
 #[unsafe(no_mangle)]
@@ -200,9 +235,9 @@ pub extern "C" fn rs_iswlower_l(
   locale: locale_t<'static>
 ) -> c_int {
   let locale: &locale::Locale = locale::get_real_locale(locale);
-  let ctype = locale::get_slot(&locale.ctype).unwrap_or_default();
-
-  c_int::from((ctype.casemap.islower)(wc) && valid_in_locale(wc, &ctype))
+  locale::with_slot(&locale.ctype, |ctype| {
+    c_int::from(ctype.is_class(WCTYPE_LOWER, wc) && valid_in_locale(wc, ctype))
+  })
 }
 
 #[unsafe(no_mangle)]
@@ -216,9 +251,9 @@ pub extern "C" fn rs_iswprint_l(
   locale: locale_t<'static>
 ) -> c_int {
   let locale: &locale::Locale = locale::get_real_locale(locale);
-  let ctype = locale::get_slot(&locale.ctype).unwrap_or_default();
-
-  c_int::from((ctype.casemap.isprint)(wc) && valid_in_locale(wc, &ctype))
+  locale::with_slot(&locale.ctype, |ctype| {
+    c_int::from(ctype.is_class(WCTYPE_PRINT, wc) && valid_in_locale(wc, ctype))
+  })
 }
 
 #[unsafe(no_mangle)]
@@ -232,9 +267,9 @@ pub extern "C" fn rs_iswpunct_l(
   locale: locale_t<'static>
 ) -> c_int {
   let locale: &locale::Locale = locale::get_real_locale(locale);
-  let ctype = locale::get_slot(&locale.ctype).unwrap_or_default();
-
-  c_int::from((ctype.casemap.ispunct)(wc) && valid_in_locale(wc, &ctype))
+  locale::with_slot(&locale.ctype, |ctype| {
+    c_int::from(ctype.is_class(WCTYPE_PUNCT, wc) && valid_in_locale(wc, ctype))
+  })
 }
 
 #[unsafe(no_mangle)]
@@ -248,9 +283,9 @@ pub extern "C" fn rs_iswspace_l(
   locale: locale_t<'static>
 ) -> c_int {
   let locale: &locale::Locale = locale::get_real_locale(locale);
-  let ctype = locale::get_slot(&locale.ctype).unwrap_or_default();
-
-  c_int::from((ctype.casemap.isspace)(wc) && valid_in_locale(wc, &ctype))
+  locale::with_slot(&locale.ctype, |ctype| {
+    c_int::from(ctype.is_class(WCTYPE_SPACE, wc) && valid_in_locale(wc, ctype))
+  })
 }
 
 #[unsafe(no_mangle)]
@@ -264,9 +299,9 @@ pub extern "C" fn rs_iswupper_l(
   locale: locale_t<'static>
 ) -> c_int {
   let locale: &locale::Locale = locale::get_real_locale(locale);
-  let ctype = locale::get_slot(&locale.ctype).unwrap_or_default();
-
-  c_int::from((ctype.casemap.isupper)(wc) && valid_in_locale(wc, &ctype))
+  locale::with_slot(&locale.ctype, |ctype| {
+    c_int::from(ctype.is_class(WCTYPE_UPPER, wc) && valid_in_locale(wc, ctype))
+  })
 }
 
 #[unsafe(no_mangle)]
@@ -280,9 +315,9 @@ pub extern "C" fn rs_iswxdigit_l(
   locale: locale_t<'static>
 ) -> c_int {
   let locale: &locale::Locale = locale::get_real_locale(locale);
-  let ctype = locale::get_slot(&locale.ctype).unwrap_or_default();
-
-  c_int::from((ctype.casemap.isxdigit)(wc) && valid_in_locale(wc, &ctype))
+  locale::with_slot(&locale.ctype, |ctype| {
+    c_int::from(ctype.is_class(WCTYPE_XDIGIT, wc) && valid_in_locale(wc, ctype))
+  })
 }
 
 #[unsafe(no_mangle)]
@@ -304,10 +339,10 @@ pub extern "C" fn rs_towlower_l(
   locale: locale_t<'static>
 ) -> c_int {
   let locale: &locale::Locale = locale::get_real_locale(locale);
-  let ctype = locale::get_slot(&locale.ctype).unwrap_or_default();
-
-  let nwc = (ctype.casemap.tolower)(wc) as c_int;
-  if valid_in_locale(nwc as wint_t, &ctype) { nwc } else { wc as c_int }
+  locale::with_slot(&locale.ctype, |ctype| {
+    let nwc = ctype.tolower(wc) as c_int;
+    if valid_in_locale(nwc as wint_t, ctype) { nwc } else { wc as c_int }
+  })
 }
 
 #[unsafe(no_mangle)]
@@ -321,10 +356,10 @@ pub extern "C" fn rs_towupper_l(
   locale: locale_t<'static>
 ) -> c_int {
   let locale: &locale::Locale = locale::get_real_locale(locale);
-  let ctype = locale::get_slot(&locale.ctype).unwrap_or_default();
-
-  let nwc = (ctype.casemap.toupper)(wc) as c_int;
-  if valid_in_locale(nwc as wint_t, &ctype) { nwc } else { wc as c_int }
+  locale::with_slot(&locale.ctype, |ctype| {
+    let nwc = ctype.toupper(wc) as c_int;
+    if valid_in_locale(nwc as wint_t, ctype) { nwc } else { wc as c_int }
+  })
 }
 
 #[unsafe(no_mangle)]
@@ -333,14 +368,22 @@ pub extern "C" fn rs_towctrans_l(
   desc: wctrans_t,
   locale: locale_t<'static>
 ) -> wint_t {
-  match desc {
-    | WCTRANS_TOLOWER => rs_towlower_l(wc, locale) as wint_t,
-    | WCTRANS_TOUPPER => rs_towupper_l(wc, locale) as wint_t,
+  let locale_real: &locale::Locale = locale::get_real_locale(locale);
+  let ctype = locale::get_slot(&locale_real.ctype).unwrap_or_default();
+
+  let mapping = match desc {
+    | WCTRANS_TOLOWER => return rs_towlower_l(wc, locale) as wint_t,
+    | WCTRANS_TOUPPER => return rs_towupper_l(wc, locale) as wint_t,
+    | WCTRANS_TOTITLE => ctype.casemap.totitle,
+    | WCTRANS_TOFOLD => ctype.casemap.tofold,
     | _ => {
       errno::set_errno(errno::EINVAL);
-      0
+      return 0;
     }
-  }
+  };
+
+  let nwc = mapping(wc);
+  if valid_in_locale(nwc, &ctype) { nwc } else { wc }
 }
 
 #[unsafe(no_mangle)]
@@ -349,6 +392,8 @@ pub extern "C" fn rs_wctrans(charclass: *const c_char) -> wctrans_t {
   match c.to_bytes() {
     | b"tolower" => WCTRANS_TOLOWER,
     | b"toupper" => WCTRANS_TOUPPER,
+    | b"totitle" => WCTRANS_TOTITLE,
+    | b"tofold" => WCTRANS_TOFOLD,
     | _ => 0 as wctrans_t
   }
 }
@@ -387,7 +432,33 @@ pub extern "C" fn rs_wctype(property: *const c_char) -> wctype_t {
     }
     i += 1;
   }
-  0
+
+  match c.to_bytes() {
+    | b"combining" => WCTYPE_COMBINING,
+    | b"emoji" => WCTYPE_EMOJI,
+    | b"ideographic" => WCTYPE_IDEOGRAPHIC,
+    | b"jdigit" => WCTYPE_JDIGIT,
+    | _ => c
+      .to_str()
+      .ok()
+      .and_then(get_script)
+      .map_or(0, |script| WCTYPE_SCRIPT | script as wctype_t)
+  }
+}
+
+// Script names and codes, such as "hiragana", "katakana" or "Latn"
+#[cfg(feature = "casemap")]
+fn get_script(name: &str) -> Option<u16> {
+  PropertyParser::<Script>::new()
+    .get_loose(name)
+    .map(|script| script.to_icu4c_value())
+}
+
+// Script classes need the Unicode properties that come with the ICU case
+// mapping
+#[cfg(not(feature = "casemap"))]
+fn get_script(_: &str) -> Option<u16> {
+  None
 }
 
 #[unsafe(no_mangle)]
@@ -397,3 +468,61 @@ pub extern "C" fn rs_wctype_l(
 ) -> wctype_t {
   rs_wctype(property)
 }
+
+standard_names! {
+  fn iswascii(wc: wint_t) -> c_int = rs_iswascii;
+  fn iswascii_l(wc: wint_t, locale: locale_t<'static>) -> c_int = rs_iswascii_l;
+  fn iswalnum(wc: wint_t) -> c_int = rs_iswalnum;
+  fn iswalnum_l(wc: wint_t, locale: locale_t<'static>) -> c_int = rs_iswalnum_l;
+  fn iswalpha(wc: wint_t) -> c_int = rs_iswalpha;
+  fn iswalpha_l(wc: wint_t, locale: locale_t<'static>) -> c_int = rs_iswalpha_l;
+  fn iswblank(wc: wint_t) -> c_int = rs_iswblank;
+  fn iswblank_l(wc: wint_t, locale: locale_t<'static>) -> c_int = rs_iswblank_l;
+  fn iswcntrl(wc: wint_t) -> c_int = rs_iswcntrl;
+  fn iswcntrl_l(wc: wint_t, locale: locale_t<'static>) -> c_int = rs_iswcntrl_l;
+  fn iswctype(wc: wint_t, cc: wctype_t) -> c_int = rs_iswctype;
+  fn iswctype_l(
+    wc: wint_t,
+    cc: wctype_t,
+    locale: locale_t<'static>
+  ) -> c_int = rs_iswctype_l;
+  fn iswdigit(wc: wint_t) -> c_int = rs_iswdigit;
+  fn iswdigit_l(wc: wint_t, locale: locale_t<'static>) -> c_int = rs_iswdigit_l;
+  fn iswgraph(wc: wint_t) -> c_int = rs_iswgraph;
+  fn iswgraph_l(wc: wint_t, locale: locale_t<'static>) -> c_int = rs_iswgraph_l;
+  fn iswlower(wc: wint_t) -> c_int = rs_iswlower;
+  fn iswlower_l(wc: wint_t, locale: locale_t<'static>) -> c_int = rs_iswlower_l;
+  fn iswprint(wc: wint_t) -> c_int = rs_iswprint;
+  fn iswprint_l(wc: wint_t, locale: locale_t<'static>) -> c_int = rs_iswprint_l;
+  fn iswpunct(wc: wint_t) -> c_int = rs_iswpunct;
+  fn iswpunct_l(wc: wint_t, locale: locale_t<'static>) -> c_int = rs_iswpunct_l;
+  fn iswspace(wc: wint_t) -> c_int = rs_iswspace;
+  fn iswspace_l(wc: wint_t, locale: locale_t<'static>) -> c_int = rs_iswspace_l;
+  fn iswupper(wc: wint_t) -> c_int = rs_iswupper;
+  fn iswupper_l(wc: wint_t, locale: locale_t<'static>) -> c_int = rs_iswupper_l;
+  fn iswxdigit(wc: wint_t) -> c_int = rs_iswxdigit;
+  fn iswxdigit_l(
+    wc: wint_t,
+    locale: locale_t<'static>
+  ) -> c_int = rs_iswxdigit_l;
+  fn towctrans(wc: wint_t, desc: wctrans_t) -> wint_t = rs_towctrans;
+  fn towlower(wc: wint_t) -> c_int = rs_towlower;
+  fn towlower_l(wc: wint_t, locale: locale_t<'static>) -> c_int = rs_towlower_l;
+  fn towupper(wc: wint_t) -> c_int = rs_towupper;
+  fn towupper_l(wc: wint_t, locale: locale_t<'static>) -> c_int = rs_towupper_l;
+  fn towctrans_l(
+    wc: wint_t,
+    desc: wctrans_t,
+    locale: locale_t<'static>
+  ) -> wint_t = rs_towctrans_l;
+  fn wctrans(charclass: *const c_char) -> wctrans_t = rs_wctrans;
+  fn wctrans_l(
+    charclass: *const c_char,
+    locale: locale_t<'static>
+  ) -> wctrans_t = rs_wctrans_l;
+  fn wctype(property: *const c_char) -> wctype_t = rs_wctype;
+  fn wctype_l(
+    property: *const c_char,
+    locale: locale_t<'static>
+  ) -> wctype_t = rs_wctype_l;
+}
@@ -0,0 +1,56 @@
+use {
+  crate::{c_char, c_ulong},
+  core::ffi
+};
+
+// The auxiliary vector entry that is set for setuid and setgid programs
+const AT_SECURE: c_ulong = 23;
+
+unsafe extern "C" {
+  // strogino has no startup code of its own yet, so the environment and the
+  // auxiliary vector are the ones set up by the C runtime that started the
+  // program
+  static environ: *const *const c_char;
+  fn getauxval(kind: c_ulong) -> c_ulong;
+}
+
+/// Returns the value of an environment variable, if it is set.
+pub fn getenv(name: &str) -> Option<&'static ffi::CStr> {
+  let mut entry = unsafe { environ };
+  if entry.is_null() {
+    return None;
+  }
+
+  loop {
+    let variable = unsafe { *entry };
+    if variable.is_null() {
+      return None;
+    }
+
+    let bytes = unsafe { ffi::CStr::from_ptr(variable) }.to_bytes_with_nul();
+    if let Some(value) = bytes
+      .strip_prefix(name.as_bytes())
+      .and_then(|rest| rest.strip_prefix(b"="))
+    {
+      return ffi::CStr::from_bytes_with_nul(value).ok();
+    }
+
+    entry = unsafe { entry.add(1) };
+  }
+}
+
+/// Checks whether the program runs with elevated privileges, such as a
+/// setuid or setgid program, where it can not trust its environment.
+pub fn is_secure() -> bool {
+  unsafe { getauxval(AT_SECURE) != 0 }
+}
+
+/// Returns the value of an environment variable, unless the program runs
+/// with elevated privileges.
+pub fn secure_getenv(name: &str) -> Option<&'static ffi::CStr> {
+  if is_secure() {
+    return None;
+  }
+
+  getenv(name)
+}
@@ -0,0 +1,192 @@
+use {
+  crate::{
+    allocation::{ffi::CString, string::String},
+    c_char,
+    c_int,
+    std::errno,
+    support::{environment, mapping::FileMapping}
+  },
+  core::ffi
+};
+
+const MAGIC: u32 = 0x960408de;
+const HEADER_SIZE: usize = 12;
+
+// Used after NLSPATH, as in glibc
+const DEFAULT_NLSPATH: &str = "/usr/share/locale/%L/%N:\
+                               /usr/share/locale/%L/LC_MESSAGES/%N:\
+                               /usr/share/locale/%l/%N:\
+                               /usr/share/locale/%l/LC_MESSAGES/%N";
+
+/// A message catalog in the binary format written by gencat, in either byte
+/// order. Messages are hashed into `plane_size` slots on `plane_depth`
+/// planes, each slot holding the set, the message and a string offset.
+#[derive(Debug)]
+pub struct MessageCatalog {
+  mapping: FileMapping,
+  big_endian: bool,
+  plane_size: usize,
+  plane_depth: usize,
+  strings: usize
+}
+
+impl MessageCatalog {
+  pub fn open(path: &ffi::CStr) -> Result<Self, c_int> {
+    let mapping = FileMapping::open(path)?;
+
+    let magic = match mapping.as_bytes().first_chunk::<4>() {
+      | Some(magic) => *magic,
+      | None => return Err(errno::EINVAL)
+    };
+    let big_endian = if u32::from_le_bytes(magic) == MAGIC {
+      false
+    } else if u32::from_be_bytes(magic) == MAGIC {
+      true
+    } else {
+      return Err(errno::EINVAL);
+    };
+
+    let mut catalog =
+      Self { mapping, big_endian, plane_size: 0, plane_depth: 0, strings: 0 };
+
+    catalog.plane_size = catalog.read_u32(4).ok_or(errno::EINVAL)? as usize;
+    catalog.plane_depth = catalog.read_u32(8).ok_or(errno::EINVAL)? as usize;
+    if catalog.plane_size == 0 {
+      return Err(errno::EINVAL);
+    }
+
+    // The table is stored twice, in the byte order of the file and swapped,
+    // and the strings follow
+    catalog.strings = catalog
+      .plane_size
+      .checked_mul(catalog.plane_depth)
+      .and_then(|slots| slots.checked_mul(3 * 4 * 2))
+      .and_then(|tables| tables.checked_add(HEADER_SIZE))
+      .filter(|&strings| strings <= catalog.mapping.as_bytes().len())
+      .ok_or(errno::EINVAL)?;
+
+    Ok(catalog)
+  }
+
+  #[inline]
+  fn read_u32(
+    &self,
+    offset: usize
+  ) -> Option<u32> {
+    let bytes = self.mapping.as_bytes().get(offset..)?.first_chunk::<4>()?;
+
+    Some(if self.big_endian {
+      u32::from_be_bytes(*bytes)
+    } else {
+      u32::from_le_bytes(*bytes)
+    })
+  }
+
+  /// Returns the message `message` of set `set` as a C string.
+  pub fn get(
+    &self,
+    set: c_int,
+    message: c_int
+  ) -> Option<*const c_char> {
+    if set < 1 || message < 1 {
+      return None;
+    }
+
+    let slot = (set as usize).wrapping_mul(message as usize) % self.plane_size;
+    for plane in 0..self.plane_depth {
+      let entry = HEADER_SIZE + (plane * self.plane_size + slot) * 12;
+
+      if self.read_u32(entry)? == set as u32 &&
+        self.read_u32(entry + 4)? == message as u32
+      {
+        let offset = self.strings + self.read_u32(entry + 8)? as usize;
+        let bytes = self.mapping.as_bytes().get(offset..)?;
+        // Only NUL-terminated strings can be handed out
+        ffi::CStr::from_bytes_until_nul(bytes).ok()?;
+
+        return Some(bytes.as_ptr().cast());
+      }
+    }
+
+    None
+  }
+}
+
+/// Expands one NLSPATH template for the catalog `name` and the locale
+/// `locale`, such as "de_AT.UTF-8@euro". An empty template stands for the
+/// name itself.
+fn expand_template(
+  template: &str,
+  name: &str,
+  locale: &str
+) -> String {
+  if template.is_empty() {
+    return String::from(name);
+  }
+
+  let (rest, _modifier) = locale.split_once('@').unwrap_or((locale, ""));
+  let (rest, codeset) = rest.split_once('.').unwrap_or((rest, ""));
+  let (language, territory) = rest.split_once('_').unwrap_or((rest, ""));
+
+  let mut path = String::new();
+  let mut chars = template.chars();
+  while let Some(c) = chars.next() {
+    if c != '%' {
+      path.push(c);
+      continue;
+    }
+    match chars.next() {
+      | Some('N') => path.push_str(name),
+      | Some('L') => path.push_str(locale),
+      | Some('l') => path.push_str(language),
+      | Some('t') => path.push_str(territory),
+      | Some('c') => path.push_str(codeset),
+      | Some('%') => path.push('%'),
+      // Unknown conversions are kept as they are
+      | Some(c) => {
+        path.push('%');
+        path.push(c);
+      },
+      | None => path.push('%')
+    }
+  }
+
+  path
+}
+
+/// Opens the catalog `name`. Names with a slash are paths, the others are
+/// looked up in the NLSPATH templates and then in the default locations.
+pub fn find_catalog(
+  name: &ffi::CStr,
+  locale: &str
+) -> Result<MessageCatalog, c_int> {
+  if name.to_bytes().contains(&b'/') {
+    return MessageCatalog::open(name);
+  }
+  let name = name.to_str().map_err(|_| errno::ENOENT)?;
+
+  // Privileged programs only look in the default locations
+  let nlspath = environment::secure_getenv("NLSPATH")
+    .and_then(|nlspath| nlspath.to_str().ok())
+    .filter(|nlspath| !nlspath.is_empty());
+  let templates = nlspath
+    .into_iter()
+    .flat_map(|nlspath| nlspath.split(':'))
+    .chain(DEFAULT_NLSPATH.split(':'));
+
+  let mut error = errno::ENOENT;
+  for template in templates {
+    let Ok(path) = CString::new(expand_template(template, name, locale)) else {
+      continue;
+    };
+
+    match MessageCatalog::open(&path) {
+      | Ok(catalog) => return Ok(catalog),
+      // A catalog that exists but is damaged is worth reporting
+      | Err(errno::ENOENT) => {},
+      | Err(e) => error = e
+    }
+  }
+
+  Err(error)
+}
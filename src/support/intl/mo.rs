@@ -0,0 +1,126 @@
+use {
+  crate::{c_int, std::errno, support::mapping::FileMapping},
+  core::{cmp::Ordering, ffi}
+};
+
+const MAGIC: u32 = 0x950412de;
+
+/// A GNU `.mo` catalog, in either byte order. The original strings are
+/// sorted, as msgfmt writes them, so they are found by binary search.
+#[derive(Debug)]
+pub struct MoFile {
+  mapping: FileMapping,
+  big_endian: bool,
+  count: usize,
+  originals: usize,
+  translations: usize
+}
+
+// Compares up to the first NUL, which ends the singular msgid of an entry
+// with plural forms
+fn compare_msgid(
+  original: &[u8],
+  msgid: &[u8]
+) -> Ordering {
+  let end = original.iter().position(|&b| b == 0).unwrap_or(original.len());
+
+  original[..end].cmp(msgid)
+}
+
+impl MoFile {
+  pub fn open(path: &ffi::CStr) -> Result<Self, c_int> {
+    let mapping = FileMapping::open(path)?;
+
+    let magic = match mapping.as_bytes().first_chunk::<4>() {
+      | Some(magic) => *magic,
+      | None => return Err(errno::EINVAL)
+    };
+    let big_endian = if u32::from_le_bytes(magic) == MAGIC {
+      false
+    } else if u32::from_be_bytes(magic) == MAGIC {
+      true
+    } else {
+      return Err(errno::EINVAL);
+    };
+
+    let mut file =
+      Self { mapping, big_endian, count: 0, originals: 0, translations: 0 };
+
+    // Only the major revisions 0 and 1 have this layout
+    let revision = file.read_u32(4).ok_or(errno::EINVAL)?;
+    if revision >> 16 > 1 {
+      return Err(errno::EINVAL);
+    }
+
+    file.count = file.read_u32(8).ok_or(errno::EINVAL)? as usize;
+    file.originals = file.read_u32(12).ok_or(errno::EINVAL)? as usize;
+    file.translations = file.read_u32(16).ok_or(errno::EINVAL)? as usize;
+
+    let len = file.mapping.as_bytes().len();
+    let table = file.count.checked_mul(8).ok_or(errno::EINVAL)?;
+    for offset in [file.originals, file.translations] {
+      if offset.checked_add(table).is_none_or(|end| end > len) {
+        return Err(errno::EINVAL);
+      }
+    }
+
+    Ok(file)
+  }
+
+  #[inline]
+  fn read_u32(
+    &self,
+    offset: usize
+  ) -> Option<u32> {
+    let bytes = self.mapping.as_bytes().get(offset..)?.first_chunk::<4>()?;
+
+    Some(if self.big_endian {
+      u32::from_be_bytes(*bytes)
+    } else {
+      u32::from_le_bytes(*bytes)
+    })
+  }
+
+  /// Returns the string of a table entry, without its terminating NUL.
+  fn get_string(
+    &self,
+    table: usize,
+    index: usize
+  ) -> Option<&[u8]> {
+    let len = self.read_u32(table + index * 8)? as usize;
+    let offset = self.read_u32(table + index * 8 + 4)? as usize;
+
+    let bytes =
+      self.mapping.as_bytes().get(offset..offset.checked_add(len)?)?;
+    // Every string must be NUL-terminated to be handed out as a C string
+    match self.mapping.as_bytes().get(offset + len) {
+      | Some(0) => Some(bytes),
+      | _ => None
+    }
+  }
+
+  /// Returns the translation of a msgid, with the plural forms separated
+  /// by NULs. The header is the translation of the empty msgid.
+  pub fn find(
+    &self,
+    msgid: &[u8]
+  ) -> Option<&[u8]> {
+    let mut low = 0;
+    let mut high = self.count;
+
+    while low < high {
+      let middle = low + (high - low) / 2;
+      let original = self.get_string(self.originals, middle)?;
+
+      match compare_msgid(original, msgid) {
+        | Ordering::Less => low = middle + 1,
+        | Ordering::Greater => high = middle,
+        | Ordering::Equal => {
+          return self.get_string(self.translations, middle);
+        }
+      }
+    }
+
+    None
+  }
+}
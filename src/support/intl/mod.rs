@@ -0,0 +1,490 @@
+pub mod cat;
+pub mod mo;
+pub mod plural;
+
+use {
+  crate::{
+    MBState,
+    allocation::{
+      boxed::Box,
+      collections::BTreeMap,
+      ffi::CString,
+      string::String,
+      vec::Vec
+    },
+    c_char,
+    c_int,
+    char32_t,
+    std::locale,
+    support::{
+      environment,
+      locale::{
+        ctype::converter::{ConverterObject, find_converter},
+        get_thread_locale,
+        is_posix_locale,
+        with_slot
+      }
+    }
+  },
+  core::{cell::RefCell, ffi, ptr},
+  critical_section::Mutex
+};
+
+const DEFAULT_DOMAIN: &ffi::CStr = c"messages";
+const DEFAULT_DIRNAME: &ffi::CStr = c"/usr/share/locale";
+
+const CATEGORY_NAMES: [&str; 13] = [
+  "LC_CTYPE",
+  "LC_NUMERIC",
+  "LC_TIME",
+  "LC_COLLATE",
+  "LC_MONETARY",
+  "LC_MESSAGES",
+  "LC_ALL",
+  "LC_PAPER",
+  "LC_NAME",
+  "LC_ADDRESS",
+  "LC_TELEPHONE",
+  "LC_MEASUREMENT",
+  "LC_IDENTIFICATION"
+];
+
+// The components of an XPG locale name, most specific first
+const XPG_MODIFIER: u32 = 8;
+const XPG_TERRITORY: u32 = 4;
+const XPG_CODESET: u32 = 2;
+const XPG_NORM_CODESET: u32 = 1;
+
+/// Returns the catalog directory names to try for one entry of the language
+/// list, such as "de_AT.UTF-8@euro", from the most to the least specific.
+fn get_variants(name: &str) -> Vec<String> {
+  let (name, modifier) = match name.split_once('@') {
+    | Some((name, modifier)) => (name, Some(modifier)),
+    | None => (name, None)
+  };
+  let (name, codeset) = match name.split_once('.') {
+    | Some((name, codeset)) => (name, Some(codeset)),
+    | None => (name, None)
+  };
+  let (language, territory) = match name.split_once('_') {
+    | Some((language, territory)) => (language, Some(territory)),
+    | None => (name, None)
+  };
+
+  // The normalized codeset is lowercase without punctuation, as "utf8"
+  let normalized = codeset.map(|codeset| {
+    let mut normalized: String = codeset
+      .chars()
+      .filter(char::is_ascii_alphanumeric)
+      .map(|c| c.to_ascii_lowercase())
+      .collect();
+    if normalized.bytes().all(|b| b.is_ascii_digit()) {
+      normalized.insert_str(0, "iso");
+    }
+    normalized
+  });
+
+  let mut mask = 0;
+  if modifier.is_some() {
+    mask |= XPG_MODIFIER;
+  }
+  if territory.is_some() {
+    mask |= XPG_TERRITORY;
+  }
+  if codeset.is_some() {
+    mask |= XPG_CODESET;
+  }
+  if normalized.as_deref().is_some_and(|n| Some(n) != codeset) {
+    mask |= XPG_NORM_CODESET;
+  }
+
+  let mut variants = Vec::new();
+  for bits in (0..=mask).rev() {
+    let both_codesets = XPG_CODESET | XPG_NORM_CODESET;
+    if bits & !mask != 0 || bits & both_codesets == both_codesets {
+      continue;
+    }
+
+    let mut variant = String::from(language);
+    if let Some(territory) = territory.filter(|_| bits & XPG_TERRITORY != 0) {
+      variant.push('_');
+      variant.push_str(territory);
+    }
+    if let Some(codeset) = codeset.filter(|_| bits & XPG_CODESET != 0) {
+      variant.push('.');
+      variant.push_str(codeset);
+    }
+    if let Some(normalized) =
+      normalized.as_deref().filter(|_| bits & XPG_NORM_CODESET != 0)
+    {
+      variant.push('.');
+      variant.push_str(normalized);
+    }
+    if let Some(modifier) = modifier.filter(|_| bits & XPG_MODIFIER != 0) {
+      variant.push('@');
+      variant.push_str(modifier);
+    }
+    variants.push(variant);
+  }
+
+  variants
+}
+
+/// Converts a translation between codesets. Characters that can not be
+/// represented become '?', as with transliteration in glibc.
+fn convert(
+  mut s: &[u8],
+  from: &ConverterObject,
+  to: &ConverterObject
+) -> CString {
+  let mut result = Vec::with_capacity(s.len());
+  let mut mb = MBState::new();
+  let mut buf = [0u8; 4];
+
+  while !s.is_empty() {
+    let mut c32: char32_t = 0;
+    let ret = (from.mbtoc32)(&mut c32, s, &mut mb);
+    if ret <= 0 {
+      result.push(b'?');
+      mb = MBState::new();
+      s = &s[1..];
+      continue;
+    }
+    s = &s[ret as usize..];
+
+    match (to.c32tomb)(&mut buf, c32) {
+      | ret if ret > 0 => result.extend_from_slice(&buf[..ret as usize]),
+      | _ => result.push(b'?')
+    }
+  }
+
+  // Neither codeset encodes anything but NUL with a zero byte
+  unsafe { CString::from_vec_unchecked(result) }
+}
+
+#[derive(Debug)]
+struct Catalog {
+  file: mo::MoFile,
+  charset: Option<ConverterObject<'static>>,
+  nplurals: u64,
+  plural: plural::Expression,
+  // Translations converted to another codeset, by address and codeset
+  converted: BTreeMap<(usize, &'static ffi::CStr), CString>
+}
+
+impl Catalog {
+  fn open(path: &ffi::CStr) -> Option<Self> {
+    let file = mo::MoFile::open(path).ok()?;
+
+    let header = file
+      .find(b"")
+      .and_then(|header| core::str::from_utf8(header).ok())
+      .unwrap_or("");
+
+    let charset = header
+      .lines()
+      .find_map(|line| line.strip_prefix("Content-Type:"))
+      .and_then(|field| field.split_once("charset="))
+      .and_then(|(_, charset)| charset.split([' ', ';']).next())
+      .and_then(find_converter);
+
+    let (nplurals, plural) = plural::parse_plural_forms(header)
+      .unwrap_or_else(|| (2, plural::Expression::germanic()));
+
+    Some(Self { file, charset, nplurals, plural, converted: BTreeMap::new() })
+  }
+
+  fn lookup(
+    &mut self,
+    msgid: &ffi::CStr,
+    n: Option<u64>,
+    target: Option<&ConverterObject<'static>>
+  ) -> Option<*const c_char> {
+    let translation = self.file.find(msgid.to_bytes())?;
+
+    let index = match n {
+      | Some(n) => self
+        .plural
+        .evaluate(n)
+        .filter(|&index| index < self.nplurals)
+        .unwrap_or(0),
+      | None => 0
+    };
+    // Missing plural forms fall back to the first one
+    let mut forms = translation.split(|&b| b == 0);
+    let first = forms.next()?;
+    let form = match index {
+      | 0 => first,
+      | index => forms.nth(index as usize - 1).unwrap_or(first)
+    };
+
+    // Catalogs or locales in codesets that strogino does not know are used
+    // as they are
+    let (Some(from), Some(to)) = (self.charset.as_ref(), target) else {
+      return Some(form.as_ptr().cast());
+    };
+    if from.codeset == to.codeset {
+      return Some(form.as_ptr().cast());
+    }
+
+    let converted = self
+      .converted
+      .entry((form.as_ptr() as usize, to.codeset))
+      .or_insert_with(|| convert(form, from, to));
+
+    Some(converted.as_ptr())
+  }
+}
+
+#[derive(Debug)]
+struct Binding {
+  domain: CString,
+  dirname: Option<CString>,
+  codeset: Option<CString>
+}
+
+/// The text domains of the process and the catalogs loaded for them.
+/// Catalogs are never unloaded, so the translations stay valid, and neither
+/// are the names given to textdomain, which it returns.
+#[derive(Debug)]
+pub struct Domains {
+  current: Option<usize>,
+  names: Vec<CString>,
+  bindings: Vec<Binding>,
+  catalogs: Vec<(CString, Option<Box<Catalog>>)>
+}
+
+impl Domains {
+  pub const fn new() -> Self {
+    Self {
+      current: None,
+      names: Vec::new(),
+      bindings: Vec::new(),
+      catalogs: Vec::new()
+    }
+  }
+
+  fn get_current(&self) -> Option<&ffi::CStr> {
+    self.current.map(|index| self.names[index].as_c_str())
+  }
+
+  fn get_binding(
+    &mut self,
+    domain: &ffi::CStr
+  ) -> &mut Binding {
+    let index = match self.bindings.iter().position(|b| *b.domain == *domain) {
+      | Some(index) => index,
+      | None => {
+        self.bindings.push(Binding {
+          domain: domain.into(),
+          dirname: None,
+          codeset: None
+        });
+        self.bindings.len() - 1
+      }
+    };
+
+    &mut self.bindings[index]
+  }
+
+  fn find_binding(
+    &self,
+    domain: &ffi::CStr
+  ) -> Option<&Binding> {
+    self.bindings.iter().find(|b| *b.domain == *domain)
+  }
+
+  /// Returns the catalog at `path`, or `None` if it has not been opened yet.
+  /// A missing catalog is remembered as well, as `Some(None)`.
+  fn find_catalog(
+    &mut self,
+    path: &ffi::CStr
+  ) -> Option<Option<&mut Catalog>> {
+    let index = self.catalogs.iter().position(|(p, _)| **p == *path)?;
+
+    Some(self.catalogs[index].1.as_deref_mut())
+  }
+
+  /// Adds a catalog opened outside of the lock. If another thread added the
+  /// same one in the meantime, that one is kept.
+  fn insert_catalog(
+    &mut self,
+    path: CString,
+    catalog: Option<Box<Catalog>>
+  ) -> Option<&mut Catalog> {
+    let index = match self.catalogs.iter().position(|(p, _)| *p == path) {
+      | Some(index) => index,
+      | None => {
+        self.catalogs.push((path, catalog));
+        self.catalogs.len() - 1
+      }
+    };
+
+    self.catalogs[index].1.as_deref_mut()
+  }
+
+  pub fn textdomain(
+    &mut self,
+    domain: Option<&ffi::CStr>
+  ) -> *const c_char {
+    match domain {
+      | Some(domain) if domain.is_empty() => self.current = None,
+      | Some(domain) => {
+        let index = match self.names.iter().position(|n| **n == *domain) {
+          | Some(index) => index,
+          | None => {
+            self.names.push(domain.into());
+            self.names.len() - 1
+          }
+        };
+        self.current = Some(index);
+      },
+      | None => {}
+    }
+
+    self.get_current().unwrap_or(DEFAULT_DOMAIN).as_ptr()
+  }
+
+  pub fn bindtextdomain(
+    &mut self,
+    domain: &ffi::CStr,
+    dirname: Option<&ffi::CStr>
+  ) -> *const c_char {
+    let binding = self.get_binding(domain);
+    if let Some(dirname) = dirname {
+      binding.dirname = Some(dirname.into());
+    }
+
+    binding.dirname.as_deref().unwrap_or(DEFAULT_DIRNAME).as_ptr()
+  }
+
+  pub fn bind_textdomain_codeset(
+    &mut self,
+    domain: &ffi::CStr,
+    codeset: Option<&ffi::CStr>
+  ) -> *const c_char {
+    let binding = self.get_binding(domain);
+    if let Some(codeset) = codeset {
+      binding.codeset = Some(codeset.into());
+    }
+
+    binding.codeset.as_deref().map_or(ptr::null(), ffi::CStr::as_ptr)
+  }
+}
+
+#[inline]
+fn with_domains<R>(
+  domains: &Mutex<RefCell<Domains>>,
+  f: impl FnOnce(&mut Domains) -> R
+) -> R {
+  critical_section::with(|cs| f(&mut domains.borrow_ref_mut(cs)))
+}
+
+/// Looks up a message in the catalogs of the languages of `category`.
+/// `plural` holds the plural msgid and the count for ngettext. Catalogs are
+/// opened outside of the lock on `domains`, which is only held to find and
+/// add them.
+pub fn translate(
+  domains: &Mutex<RefCell<Domains>>,
+  domain: Option<&ffi::CStr>,
+  msgid: &ffi::CStr,
+  plural: Option<(&ffi::CStr, u64)>,
+  category: c_int
+) -> *const c_char {
+  let untranslated = match plural {
+    | Some((msgid_plural, n)) if n != 1 => msgid_plural.as_ptr(),
+    | _ => msgid.as_ptr()
+  };
+  if category == locale::LC_ALL ||
+    !(0..=locale::LC_IDENTIFICATION).contains(&category)
+  {
+    return untranslated;
+  }
+
+  let locale = get_thread_locale();
+  let name = unsafe { ffi::CStr::from_ptr(locale.querylocale(category)) };
+  let Ok(name) = name.to_str() else {
+    return untranslated;
+  };
+  // LANGUAGE is ignored in the C locale, where nothing is translated
+  if is_posix_locale(name) {
+    return untranslated;
+  }
+  let languages = match environment::secure_getenv("LANGUAGE") {
+    | Some(languages) if !languages.is_empty() => {
+      languages.to_str().unwrap_or(name)
+    },
+    | _ => name
+  };
+
+  let (domain, dirname, codeset) = with_domains(domains, |d| {
+    let domain: CString =
+      domain.or(d.get_current()).unwrap_or(DEFAULT_DOMAIN).into();
+    let binding = d.find_binding(&domain);
+    let dirname: CString = binding
+      .and_then(|b| b.dirname.as_deref())
+      .unwrap_or(DEFAULT_DIRNAME)
+      .into();
+    let codeset = binding.and_then(|b| b.codeset.clone());
+
+    (domain, dirname, codeset)
+  });
+  let target = match codeset {
+    | Some(codeset) => codeset.to_str().ok().and_then(find_converter),
+    | None => Some(with_slot(&locale.ctype, |c| c.converter.clone()))
+  };
+
+  let (Ok(domain), Ok(dirname)) = (domain.to_str(), dirname.to_str()) else {
+    return untranslated;
+  };
+  let n = plural.map(|(_, n)| n);
+  let secure = environment::is_secure();
+
+  for language in languages.split(':') {
+    if language.is_empty() {
+      continue;
+    }
+    // Privileged programs only look in the catalog directory itself, so a
+    // language, and every variant made from it, must not name a path
+    if secure && language.contains('/') {
+      continue;
+    }
+    if is_posix_locale(language) {
+      break;
+    }
+
+    for variant in get_variants(language) {
+      let mut path = String::from(dirname);
+      for part in
+        ["/", &variant, "/", CATEGORY_NAMES[category as usize], "/", domain]
+      {
+        path.push_str(part);
+      }
+      path.push_str(".mo");
+      let Ok(path) = CString::new(path) else {
+        continue;
+      };
+
+      let lookup =
+        |catalog: &mut Catalog| catalog.lookup(msgid, n, target.as_ref());
+      let found = with_domains(domains, |d| {
+        d.find_catalog(&path).map(|catalog| catalog.and_then(lookup))
+      });
+      let translation = match found {
+        | Some(translation) => translation,
+        | None => {
+          // Reading the catalog is left out of the lock
+          let catalog = Catalog::open(&path).map(Box::new);
+          with_domains(domains, |d| {
+            d.insert_catalog(path, catalog).and_then(lookup)
+          })
+        }
+      };
+      if let Some(translation) = translation {
+        return translation;
+      }
+    }
+  }
+
+  untranslated
+}
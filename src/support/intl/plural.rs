@@ -0,0 +1,293 @@
+use allocation::boxed::Box;
+
+// Deeper expressions are rejected rather than risking the stack
+const MAX_DEPTH: usize = 64;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Operator {
+  Or,
+  And,
+  Equal,
+  NotEqual,
+  Less,
+  LessEqual,
+  Greater,
+  GreaterEqual,
+  Add,
+  Subtract,
+  Multiply,
+  Divide,
+  Remainder
+}
+
+/// A parsed `plural=` expression of a `Plural-Forms` header. It is the C
+/// subset used by gettext, evaluated in unsigned long arithmetic.
+#[derive(Debug, Clone)]
+pub enum Expression {
+  Number(u64),
+  Variable,
+  Not(Box<Expression>),
+  Binary(Operator, Box<Expression>, Box<Expression>),
+  Conditional(Box<Expression>, Box<Expression>, Box<Expression>)
+}
+
+impl Expression {
+  /// The Germanic rule, used when a catalog has no `Plural-Forms`.
+  pub fn germanic() -> Self {
+    Expression::Binary(
+      Operator::NotEqual,
+      Box::new(Expression::Variable),
+      Box::new(Expression::Number(1))
+    )
+  }
+
+  pub fn parse(s: &str) -> Option<Self> {
+    let mut parser = Parser { s: s.as_bytes(), depth: 0 };
+
+    let expression = parser.conditional()?;
+    parser.skip_whitespace();
+
+    match parser.s {
+      | [] | [b';', ..] => Some(expression),
+      | _ => None
+    }
+  }
+
+  /// Evaluates the expression for `n`. Division by zero yields `None`.
+  pub fn evaluate(
+    &self,
+    n: u64
+  ) -> Option<u64> {
+    match self {
+      | Expression::Number(value) => Some(*value),
+      | Expression::Variable => Some(n),
+      | Expression::Not(e) => Some(u64::from(e.evaluate(n)? == 0)),
+      | Expression::Conditional(condition, a, b) => {
+        if condition.evaluate(n)? != 0 { a.evaluate(n) } else { b.evaluate(n) }
+      },
+      | Expression::Binary(op, a, b) => {
+        let a = a.evaluate(n)?;
+        // Only the operands that C would evaluate
+        match op {
+          | Operator::Or if a != 0 => return Some(1),
+          | Operator::And if a == 0 => return Some(0),
+          | _ => {}
+        }
+        let b = b.evaluate(n)?;
+
+        Some(match op {
+          | Operator::Or | Operator::And => u64::from(b != 0),
+          | Operator::Equal => u64::from(a == b),
+          | Operator::NotEqual => u64::from(a != b),
+          | Operator::Less => u64::from(a < b),
+          | Operator::LessEqual => u64::from(a <= b),
+          | Operator::Greater => u64::from(a > b),
+          | Operator::GreaterEqual => u64::from(a >= b),
+          | Operator::Add => a.wrapping_add(b),
+          | Operator::Subtract => a.wrapping_sub(b),
+          | Operator::Multiply => a.wrapping_mul(b),
+          | Operator::Divide => a.checked_div(b)?,
+          | Operator::Remainder => a.checked_rem(b)?
+        })
+      }
+    }
+  }
+}
+
+struct Parser<'a> {
+  s: &'a [u8],
+  depth: usize
+}
+
+impl<'a> Parser<'a> {
+  fn skip_whitespace(&mut self) {
+    while let [b' ' | b'\t' | b'\n' | b'\r', rest @ ..] = self.s {
+      self.s = rest;
+    }
+  }
+
+  fn accept(
+    &mut self,
+    token: &str
+  ) -> bool {
+    self.skip_whitespace();
+    match self.s.strip_prefix(token.as_bytes()) {
+      | Some(rest) => {
+        self.s = rest;
+        true
+      },
+      | None => false
+    }
+  }
+
+  fn binary(
+    &mut self,
+    operators: &[(&str, Operator)],
+    operand: fn(&mut Self) -> Option<Expression>
+  ) -> Option<Expression> {
+    let depth = self.depth;
+    let mut left = operand(self)?;
+
+    'outer: loop {
+      for &(token, op) in operators {
+        if self.accept(token) {
+          // Chains such as "n+n+n" nest to the left, one level per operator
+          self.depth += 1;
+          if self.depth > MAX_DEPTH {
+            return None;
+          }
+          let right = operand(self)?;
+          left = Expression::Binary(op, Box::new(left), Box::new(right));
+          continue 'outer;
+        }
+      }
+
+      self.depth = depth;
+      return Some(left);
+    }
+  }
+
+  fn conditional(&mut self) -> Option<Expression> {
+    self.depth += 1;
+    if self.depth > MAX_DEPTH {
+      return None;
+    }
+
+    let condition = self.or()?;
+    let result = if self.accept("?") {
+      let a = self.conditional()?;
+      if !self.accept(":") {
+        return None;
+      }
+      let b = self.conditional()?;
+
+      Expression::Conditional(Box::new(condition), Box::new(a), Box::new(b))
+    } else {
+      condition
+    };
+
+    self.depth -= 1;
+    Some(result)
+  }
+
+  fn or(&mut self) -> Option<Expression> {
+    self.binary(&[("||", Operator::Or)], Self::and)
+  }
+
+  fn and(&mut self) -> Option<Expression> {
+    self.binary(&[("&&", Operator::And)], Self::equality)
+  }
+
+  fn equality(&mut self) -> Option<Expression> {
+    self.binary(
+      &[("==", Operator::Equal), ("!=", Operator::NotEqual)],
+      Self::relational
+    )
+  }
+
+  fn relational(&mut self) -> Option<Expression> {
+    // The two-character operators have to be tried first
+    self.binary(
+      &[
+        ("<=", Operator::LessEqual),
+        (">=", Operator::GreaterEqual),
+        ("<", Operator::Less),
+        (">", Operator::Greater)
+      ],
+      Self::additive
+    )
+  }
+
+  fn additive(&mut self) -> Option<Expression> {
+    self.binary(
+      &[("+", Operator::Add), ("-", Operator::Subtract)],
+      Self::multiplicative
+    )
+  }
+
+  fn multiplicative(&mut self) -> Option<Expression> {
+    self.binary(
+      &[
+        ("*", Operator::Multiply),
+        ("/", Operator::Divide),
+        ("%", Operator::Remainder)
+      ],
+      Self::unary
+    )
+  }
+
+  fn unary(&mut self) -> Option<Expression> {
+    // Not "!=", which is left for the caller
+    self.skip_whitespace();
+    if let [b'!', rest @ ..] = self.s &&
+      !rest.starts_with(b"=")
+    {
+      self.s = rest;
+      self.depth += 1;
+      if self.depth > MAX_DEPTH {
+        return None;
+      }
+      let operand = self.unary()?;
+      self.depth -= 1;
+
+      return Some(Expression::Not(Box::new(operand)));
+    }
+
+    self.primary()
+  }
+
+  fn primary(&mut self) -> Option<Expression> {
+    self.skip_whitespace();
+
+    match self.s {
+      | [b'n', rest @ ..] => {
+        self.s = rest;
+        Some(Expression::Variable)
+      },
+      | [b'(', rest @ ..] => {
+        self.s = rest;
+        let expression = self.conditional()?;
+        if !self.accept(")") {
+          return None;
+        }
+        Some(expression)
+      },
+      | [b'0'..=b'9', ..] => {
+        let mut value: u64 = 0;
+        while let [digit @ b'0'..=b'9', rest @ ..] = self.s {
+          value =
+            value.checked_mul(10)?.checked_add(u64::from(digit - b'0'))?;
+          self.s = rest;
+        }
+        Some(Expression::Number(value))
+      },
+      | _ => None
+    }
+  }
+}
+
+/// Reads `nplurals` and `plural` from the `Plural-Forms` field of a catalog
+/// header, such as "nplurals=2; plural=(n != 1);".
+pub fn parse_plural_forms(header: &str) -> Option<(u64, Expression)> {
+  let field =
+    header.lines().find_map(|line| line.strip_prefix("Plural-Forms:"))?;
+
+  let mut nplurals = None;
+  let mut plural = None;
+  for part in field.split(';') {
+    match part.split_once('=') {
+      | Some((key, value)) if key.trim() == "nplurals" => {
+        nplurals = value.trim().parse::<u64>().ok()
+      },
+      | Some((key, value)) if key.trim() == "plural" => {
+        plural = Expression::parse(value)
+      },
+      | _ => {}
+    }
+  }
+
+  match (nplurals?, plural?) {
+    | (0, _) => None,
+    | (nplurals, plural) => Some((nplurals, plural))
+  }
+}
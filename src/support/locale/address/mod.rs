@@ -0,0 +1,99 @@
+use {
+  super::{LocaleObject, is_posix_locale, territory},
+  crate::{allocation::borrow::ToOwned, c_int, support::locale::errno},
+  allocation::borrow::Cow,
+  core::ffi
+};
+
+/// The codes of the country and the language of a locale. The postal format
+/// uses the escapes of ISO/IEC 14652, such as "%f" for the firm name and
+/// "%z" for the postal code.
+#[derive(Debug, Clone)]
+pub struct AddressObject<'a> {
+  name: Cow<'a, ffi::CStr>,
+  pub postal_fmt: &'static ffi::CStr,
+  pub country_name: &'static ffi::CStr,
+  pub country_post: &'static ffi::CStr,
+  pub country_ab2: &'static ffi::CStr,
+  pub country_ab3: &'static ffi::CStr,
+  pub country_car: &'static ffi::CStr,
+  pub country_num: u32,
+  pub country_isbn: &'static ffi::CStr,
+  pub lang_name: &'static ffi::CStr,
+  pub lang_ab: &'static ffi::CStr,
+  pub lang_term: &'static ffi::CStr,
+  pub lang_lib: &'static ffi::CStr
+}
+
+impl<'a> LocaleObject for AddressObject<'a> {
+  fn setlocale(
+    &mut self,
+    locale: &ffi::CStr
+  ) -> Result<&ffi::CStr, c_int> {
+    let name = locale.to_str().map_err(|_| errno::ENOENT)?;
+
+    if is_posix_locale(name) {
+      return Ok(self.set_to_posix());
+    }
+
+    let language = territory::get_language(name).ok_or(errno::ENOENT)?;
+
+    // The names of the country and the language would be in the language of
+    // the locale, which the ISO tables do not have, so they stay empty
+    *self = DEFAULT_ADDRESS;
+    let region = territory::get_region(name);
+    if let Some(territory) =
+      region.as_deref().and_then(territory::find_territory)
+    {
+      self.country_ab2 = territory.ab2;
+      self.country_ab3 = territory.ab3;
+      self.country_num = territory.num.into();
+    }
+    if let Some(formats) = region
+      .as_deref()
+      .and_then(|region| territory::find_formats(&language, region))
+    {
+      self.postal_fmt = formats.postal_fmt;
+    }
+    if let Some(language) = territory::find_language(&language) {
+      self.lang_ab = language.ab;
+      self.lang_term = language.term;
+      self.lang_lib = language.lib;
+    }
+    self.name = Cow::Owned(locale.to_owned());
+
+    Ok(self.name.as_ref())
+  }
+
+  fn set_to_posix(&mut self) -> &ffi::CStr {
+    *self = DEFAULT_ADDRESS;
+
+    self.name.as_ref()
+  }
+
+  fn get_name(&self) -> &ffi::CStr {
+    self.name.as_ref()
+  }
+}
+
+impl<'a> Default for AddressObject<'a> {
+  fn default() -> Self {
+    DEFAULT_ADDRESS
+  }
+}
+
+pub const DEFAULT_ADDRESS: AddressObject = AddressObject {
+  name: Cow::Borrowed(c"C"),
+  postal_fmt: c"%a%N%f%N%d%N%b%N%s %h %e %r%N%C-%z %T%N%c%N",
+  country_name: c"",
+  country_post: c"",
+  country_ab2: c"",
+  country_ab3: c"",
+  country_car: c"",
+  country_num: 0,
+  country_isbn: c"",
+  lang_name: c"",
+  lang_ab: c"",
+  lang_term: c"",
+  lang_lib: c""
+};
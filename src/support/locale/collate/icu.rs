@@ -0,0 +1,204 @@
+use {
+  super::super::{canonicalize_locale, get_modifier_keywords},
+  crate::{allocation::vec::Vec, c_int, std::errno},
+  allocation::{collections::BTreeMap, string::String, sync::Arc},
+  core::cell::RefCell,
+  critical_section::Mutex,
+  icu_collator::{
+    Collator,
+    CollatorBorrowed,
+    options::{CollatorOptions, Strength}
+  },
+  icu_locale::{
+    Locale,
+    extensions::unicode::{Key, Value, key}
+  },
+  smallvec::SmallVec
+};
+
+pub type SharedCollator = Arc<CollatorBorrowed<'static>>;
+
+// Collators are immutable once built, so every locale object (and every
+// clone of it handed out by get_slot) shares the same instance.
+static COLLATORS: Mutex<RefCell<BTreeMap<String, SharedCollator>>> =
+  Mutex::new(RefCell::new(BTreeMap::new()));
+
+fn get_collation_type(value: &str) -> Option<&'static str> {
+  Some(match value.to_ascii_lowercase().as_str() {
+    | "big5han" => "big5han",
+    | "compat" => "compat",
+    | "dict" | "dictionary" => "dict",
+    | "emoji" => "emoji",
+    | "eor" => "eor",
+    | "gb2312" | "gb2312han" => "gb2312",
+    | "phonebk" | "phonebook" => "phonebk",
+    | "phonetic" => "phonetic",
+    | "pinyin" => "pinyin",
+    | "search" => "search",
+    | "searchjl" => "searchjl",
+    | "standard" => "standard",
+    | "stroke" => "stroke",
+    | "trad" | "traditional" => "trad",
+    | "unihan" => "unihan",
+    | "zhuyin" => "zhuyin",
+    | _ => return None
+  })
+}
+
+fn get_numeric_ordering(value: Option<&str>) -> Option<&'static str> {
+  let Some(value) = value else {
+    return Some("true");
+  };
+
+  Some(match value.to_ascii_lowercase().as_str() {
+    | "" | "1" | "on" | "yes" | "true" => "true",
+    | "0" | "off" | "no" | "false" => "false",
+    | _ => return None
+  })
+}
+
+fn get_case_first(value: &str) -> Option<&'static str> {
+  Some(match value.to_ascii_lowercase().as_str() {
+    | "upper" => "upper",
+    | "lower" => "lower",
+    | "off" | "no" | "false" => "false",
+    | _ => return None
+  })
+}
+
+fn get_strength_level(value: &str) -> Option<&'static str> {
+  Some(match value.to_ascii_lowercase().as_str() {
+    | "1" | "primary" | "level1" => "level1",
+    | "2" | "secondary" | "level2" => "level2",
+    | "3" | "tertiary" | "level3" => "level3",
+    | "4" | "quaternary" | "level4" => "level4",
+    | "i" | "identical" | "identic" => "identic",
+    | _ => return None
+  })
+}
+
+fn get_strength(locale: &Locale) -> Strength {
+  let ks = locale
+    .extensions
+    .unicode
+    .keywords
+    .get(&key!("ks"))
+    .and_then(|value| value.as_single_subtag())
+    .map(|subtag| subtag.as_str());
+
+  match ks {
+    | Some("level1") => Strength::Primary,
+    | Some("level2") => Strength::Secondary,
+    | Some("level3") => Strength::Tertiary,
+    | Some("identic") => Strength::Identical,
+    | _ => Strength::Quaternary
+  }
+}
+
+// Maps modifiers such as "@collation=phonebook;numeric" onto the BCP-47
+// keywords understood by ICU, so both spellings share the same collator.
+fn apply_modifiers(
+  locale: &mut Locale,
+  name: &str
+) -> Result<(), c_int> {
+  for (keyword, value) in get_modifier_keywords(name) {
+    let (key, value): (Key, Option<&str>) = match keyword
+      .to_ascii_lowercase()
+      .as_str()
+    {
+      | "co" | "collation" => (key!("co"), value.and_then(get_collation_type)),
+      | "kn" | "numeric" | "colnumeric" => {
+        (key!("kn"), get_numeric_ordering(value))
+      },
+      | "kf" | "casefirst" | "case-first" | "colcasefirst" => {
+        (key!("kf"), value.and_then(get_case_first))
+      },
+      | "ks" | "strength" | "colstrength" => {
+        (key!("ks"), value.and_then(get_strength_level))
+      },
+      | _ => continue
+    };
+
+    let value = value.ok_or(errno::ENOENT)?;
+    let value = Value::try_from_str(value).map_err(|_| errno::ENOENT)?;
+
+    locale.extensions.unicode.keywords.set(key, value);
+  }
+
+  Ok(())
+}
+
+fn get_collator(locale: Locale) -> Result<SharedCollator, c_int> {
+  let key = locale.to_string();
+
+  let cached =
+    critical_section::with(|cs| COLLATORS.borrow_ref(cs).get(&key).cloned());
+  if let Some(collator) = cached {
+    return Ok(collator);
+  }
+
+  // Loading the collation data is slow, so it happens outside the lock. Two
+  // threads may build the same collator, and the first one to finish wins.
+  let mut options = CollatorOptions::default();
+  options.strength = Some(get_strength(&locale));
+
+  let collator =
+    Collator::try_new(locale.into(), options).map_err(|_| errno::ENOENT)?;
+  let collator = Arc::new(collator);
+
+  Ok(critical_section::with(|cs| {
+    COLLATORS.borrow_ref_mut(cs).entry(key).or_insert(collator).clone()
+  }))
+}
+
+// Invalid code points collate as U+FFFD, the same way ICU treats malformed
+// UTF-8 in strcoll and strxfrm.
+#[inline]
+pub fn to_utf16(source: &[u32]) -> SmallVec<[u16; 256]> {
+  let mut result = SmallVec::<[u16; 256]>::new();
+  let mut buf = [0u16; 2];
+
+  for c in source
+    .iter()
+    .map(|c| char::from_u32(*c).unwrap_or(char::REPLACEMENT_CHARACTER))
+  {
+    result.extend_from_slice(c.encode_utf16(&mut buf));
+  }
+
+  result
+}
+
+const SORTKEY_BYTES_PER_UNIT: usize = 3;
+
+// Packs a byte sort key into wide units, three bytes per unit with the most
+// significant byte first and the last unit padded with zero bytes. ICU never
+// emits zero bytes in a sort key, so no unit is ever zero, every unit stays
+// positive as a wchar_t, and comparing the units with wcscmp gives the same
+// order as comparing the bytes with strcmp. Sort keys may be stored, so this
+// layout must not change.
+#[inline]
+pub fn pack_sortkey(sortkey: &[u8]) -> Vec<u32> {
+  sortkey
+    .chunks(SORTKEY_BYTES_PER_UNIT)
+    .map(|chunk| {
+      let mut unit = [0u8; 4];
+      unit[1..1 + chunk.len()].copy_from_slice(chunk);
+      u32::from_be_bytes(unit)
+    })
+    .collect()
+}
+
+/// Returns the collator for a locale name such as "de_DE@collation=phonebook".
+pub fn get_collator_for_name(
+  name: &str,
+  lang: &str
+) -> Result<SharedCollator, c_int> {
+  let icu_locale_name = canonicalize_locale(lang);
+
+  let mut icu_locale = Locale::try_from_str(&icu_locale_name.replace("_", "-"))
+    .map_err(|_| errno::ENOENT)?;
+
+  apply_modifiers(&mut icu_locale, name)?;
+
+  get_collator(icu_locale)
+}
@@ -0,0 +1,214 @@
+use {
+  super::CollateObject,
+  crate::{
+    allocation::{borrow::ToOwned, sync::Arc, vec::Vec},
+    c_int,
+    std::errno,
+    support::locale::localedef::{self, Source, Statement, Value}
+  },
+  allocation::borrow::Cow,
+  core::{cmp::Ordering, ffi}
+};
+
+// Weights of sort keys take three bytes, none of them zero
+const SORTKEY_BASE: u32 = 255;
+const SORTKEY_DIGITS: usize = 3;
+
+/// The collation order of a localedef source. Characters compare by their
+/// primary weights first, and then by where the order lists them.
+#[derive(Debug, Clone, Default)]
+pub struct Order {
+  /// Primary weight and position of the listed characters, sorted by the
+  /// character, where `None` marks an ignored character
+  weights: Vec<(u32, Option<(u32, u32)>)>,
+  /// Primary weight of the characters the order does not list, which then
+  /// follow each other by code point
+  undefined: u32
+}
+
+impl Order {
+  fn get_weights(
+    &self,
+    c: u32
+  ) -> Option<(u32, u32)> {
+    match self.weights.binary_search_by_key(&c, |&(c, _)| c) {
+      | Ok(i) => self.weights[i].1,
+      | Err(_) => Some((self.undefined, self.undefined + c))
+    }
+  }
+
+  fn get_keys(
+    &self,
+    chars: impl Iterator<Item = u32>
+  ) -> (Vec<u32>, Vec<u32>) {
+    let mut primary = Vec::new();
+    let mut positions = Vec::new();
+    for (weight, position) in chars.filter_map(|c| self.get_weights(c)) {
+      primary.push(weight);
+      positions.push(position);
+    }
+
+    (primary, positions)
+  }
+
+  pub fn compare(
+    &self,
+    lhs: impl Iterator<Item = u32>,
+    rhs: impl Iterator<Item = u32>
+  ) -> Ordering {
+    let lhs = self.get_keys(lhs);
+    let rhs = self.get_keys(rhs);
+
+    lhs.0.cmp(&rhs.0).then_with(|| lhs.1.cmp(&rhs.1))
+  }
+
+  /// Returns the sort key as wide characters: the primary weights and the
+  /// positions, all above 1, with a 1 between them.
+  pub fn get_sortkey_u32(
+    &self,
+    chars: impl Iterator<Item = u32>
+  ) -> Vec<u32> {
+    let (primary, positions) = self.get_keys(chars);
+
+    let mut sortkey = Vec::with_capacity(primary.len() + positions.len() + 1);
+    sortkey.extend(primary.iter().map(|w| w + 2));
+    sortkey.push(1);
+    sortkey.extend(positions.iter().map(|w| w + 2));
+
+    sortkey
+  }
+
+  /// Returns the sort key as bytes, with every unit of the wide sort key as
+  /// three digits of base 255.
+  pub fn get_sortkey_u8(
+    &self,
+    chars: impl Iterator<Item = u32>
+  ) -> Vec<u8> {
+    let wide = self.get_sortkey_u32(chars);
+
+    let mut sortkey = Vec::with_capacity(wide.len() * SORTKEY_DIGITS);
+    for unit in wide {
+      let mut digits = [0u8; SORTKEY_DIGITS];
+      let mut rest = unit;
+      for digit in digits.iter_mut().rev() {
+        *digit = (rest % SORTKEY_BASE) as u8 + 1;
+        rest /= SORTKEY_BASE;
+      }
+      sortkey.extend_from_slice(&digits);
+    }
+
+    sortkey
+  }
+}
+
+/// Decodes the bytes of a string for the collation order.
+pub fn get_chars(s: &[u8]) -> impl Iterator<Item = u32> + '_ {
+  s.utf8_chunks().flat_map(|chunk| {
+    let invalid = (!chunk.invalid().is_empty())
+      .then_some(char::REPLACEMENT_CHARACTER as u32);
+    chunk.valid().chars().map(|c| c as u32).chain(invalid)
+  })
+}
+
+// The element that a line of the order is about, a character or a
+// collating symbol
+#[derive(PartialEq)]
+enum Element<'a> {
+  Char(char),
+  Symbol(&'a str)
+}
+
+fn to_element(value: &Value) -> Option<Element<'_>> {
+  match value {
+    | Value::Char(c) => Some(Element::Char(*c)),
+    | Value::Symbol(name) => Some(Element::Symbol(name)),
+    | _ => None
+  }
+}
+
+fn get_order(statements: &[Statement]) -> Result<Order, c_int> {
+  let mut elements: Vec<(Element, Option<u32>, u32)> = Vec::new();
+  let mut undefined = None;
+
+  for (position, statement) in statements.iter().enumerate() {
+    let position = position as u32;
+    let mut values = statement.values.iter();
+
+    if statement.keyword == "UNDEFINED" {
+      undefined = Some(position);
+      continue;
+    }
+    // Other keywords, such as the sections of glibc's orders, are left out
+    if !statement.keyword.is_empty() {
+      continue;
+    }
+    let element = values.next().and_then(to_element).ok_or(errno::ENOENT)?;
+
+    // The primary weight is the one of an element listed before, or the
+    // element's own position
+    let primary = match values.next() {
+      | Some(Value::Word(word)) if word == "IGNORE" => None,
+      | Some(value) => {
+        let weight = to_element(value).and_then(|weight| {
+          elements
+            .iter()
+            .find(|(element, ..)| *element == weight)
+            .map(|(_, primary, _)| *primary)
+        });
+        weight.unwrap_or(Some(position))
+      },
+      | None => Some(position)
+    };
+    elements.push((element, primary, position));
+  }
+
+  // Positions leave room for the characters that go where UNDEFINED is
+  let count = statements.len() as u32;
+  let shift = |w: u32| match undefined {
+    | Some(undefined) if w > undefined => w + 0x110000,
+    | _ => w
+  };
+  let undefined = undefined.map_or(count, shift);
+
+  let mut weights: Vec<(u32, Option<(u32, u32)>)> = Vec::new();
+  for (element, primary, position) in elements {
+    if let Element::Char(c) = element {
+      let weight = primary.map(|w| (shift(w), shift(position)));
+      weights.push((c as u32, weight));
+    }
+  }
+  weights.sort_by_key(|&(c, _)| c);
+  weights.dedup_by_key(|&mut (c, _)| c);
+
+  Ok(Order { weights, undefined })
+}
+
+pub fn load(
+  collate: &mut CollateObject,
+  locale: &ffi::CStr,
+  source: &Source
+) -> Result<(), c_int> {
+  let section = source.section("LC_COLLATE");
+  localedef::load_base(collate, locale, source, section)?;
+
+  if let Some(section) = section {
+    let start = section
+      .statements
+      .iter()
+      .position(|statement| statement.keyword == "order_start");
+    let end = section
+      .statements
+      .iter()
+      .position(|statement| statement.keyword == "order_end");
+
+    if let Some(start) = start {
+      let end = end.filter(|&end| end > start).ok_or(errno::ENOENT)?;
+      collate.order =
+        Some(Arc::new(get_order(&section.statements[start + 1..end])?));
+    }
+  }
+
+  collate.name = Cow::Owned(locale.to_owned());
+
+  Ok(())
+}
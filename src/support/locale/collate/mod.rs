@@ -1,24 +1,24 @@
+#[cfg(feature = "collate")]
+mod icu;
+mod localedef;
+
 use {
-  super::{LocaleObject, canonicalize_locale, is_posix_locale},
-  crate::{allocation::vec::Vec, c_int, std::errno, support::string},
-  allocation::{
-    borrow::{Cow, ToOwned},
-    string::String
-  },
-  bstr::{B, ByteSlice},
-  core::{cmp::Ordering, ffi},
-  icu_collator::{
-    Collator,
-    CollatorBorrowed,
-    options::{CollatorOptions, Strength}
-  },
-  icu_locale::Locale
+  super::{LocaleObject, is_posix_locale, localedef::Source},
+  crate::{allocation::sync::Arc, c_int, std::errno, wchar_t},
+  allocation::borrow::Cow,
+  core::{cmp::Ordering, ffi}
 };
+#[cfg(feature = "collate")]
+use {crate::allocation::vec::Vec, allocation::borrow::ToOwned};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct CollateObject<'a> {
   name: Cow<'a, ffi::CStr>,
-  collator: Option<CollatorBorrowed<'a>>
+  #[cfg(feature = "collate")]
+  collator: Option<icu::SharedCollator>,
+  /// The collation order of a localedef source, which takes the place of
+  /// the collator
+  order: Option<Arc<localedef::Order>>
 }
 
 impl<'a> CollateObject<'a> {
@@ -26,6 +26,11 @@ impl<'a> CollateObject<'a> {
     &self,
     source: &'a [u8]
   ) -> Cow<'a, [u8]> {
+    if let Some(order) = &self.order {
+      return Cow::Owned(order.get_sortkey_u8(localedef::get_chars(source)));
+    }
+
+    #[cfg(feature = "collate")]
     if let Some(collator) = &self.collator {
       let mut sortkey: Vec<u8> = Vec::new();
 
@@ -33,34 +38,33 @@ impl<'a> CollateObject<'a> {
         return Cow::Borrowed(&[]);
       }
 
-      Cow::Owned(sortkey)
-    } else {
-      Cow::Borrowed(&source)
+      return Cow::Owned(sortkey);
     }
+
+    Cow::Borrowed(source)
   }
 
   pub fn get_sortkey_u32(
     &self,
     source: &'a [u32]
   ) -> Cow<'a, [u32]> {
+    if let Some(order) = &self.order {
+      return Cow::Owned(order.get_sortkey_u32(source.iter().copied()));
+    }
+
+    #[cfg(feature = "collate")]
     if let Some(collator) = &self.collator {
-      let source: &[u8] = &source
-        .iter()
-        .filter_map(|c| char::from_u32(*c))
-        .collect::<String>()
-        .into_bytes();
+      let source = icu::to_utf16(source);
       let mut sortkey: Vec<u8> = Vec::new();
 
-      if collator.write_sort_key_utf8_to(source, &mut sortkey).is_err() {
+      if collator.write_sort_key_utf16_to(&source, &mut sortkey).is_err() {
         return Cow::Borrowed(&[]);
       }
 
-      let result: Vec<u32> = B(&sortkey).chars().map(|c| c as u32).collect();
-
-      Cow::Owned(result)
-    } else {
-      Cow::Borrowed(&source)
+      return Cow::Owned(icu::pack_sortkey(&sortkey));
     }
+
+    Cow::Borrowed(source)
   }
 
   pub fn collate_u8(
@@ -68,11 +72,17 @@ impl<'a> CollateObject<'a> {
     lhs: &[u8],
     rhs: &[u8]
   ) -> Ordering {
+    if let Some(order) = &self.order {
+      return order
+        .compare(localedef::get_chars(lhs), localedef::get_chars(rhs));
+    }
+
+    #[cfg(feature = "collate")]
     if let Some(collator) = &self.collator {
-      collator.compare_utf8(lhs, rhs)
-    } else {
-      lhs.cmp(rhs)
+      return collator.compare_utf8(lhs, rhs);
     }
+
+    lhs.cmp(rhs)
   }
 
   pub fn collate_u32(
@@ -80,22 +90,20 @@ impl<'a> CollateObject<'a> {
     lhs: &[u32],
     rhs: &[u32]
   ) -> Ordering {
+    if let Some(order) = &self.order {
+      return order.compare(lhs.iter().copied(), rhs.iter().copied());
+    }
+
+    #[cfg(feature = "collate")]
     if let Some(collator) = &self.collator {
-      let lhs: &[u8] = &lhs
-        .iter()
-        .filter_map(|c| char::from_u32(*c))
-        .collect::<String>()
-        .into_bytes();
-      let rhs: &[u8] = &rhs
-        .iter()
-        .filter_map(|c| char::from_u32(*c))
-        .collect::<String>()
-        .into_bytes();
-
-      collator.compare_utf8(lhs, rhs)
-    } else {
-      lhs.cmp(rhs)
+      return collator.compare_utf16(&icu::to_utf16(lhs), &icu::to_utf16(rhs));
     }
+
+    // Same order as wcscmp, which compares the units as signed wchar_t
+    let lhs = lhs.iter().map(|&c| c as wchar_t);
+    let rhs = rhs.iter().map(|&c| c as wchar_t);
+
+    lhs.cmp(rhs)
   }
 }
 
@@ -116,21 +124,20 @@ impl<'a> LocaleObject for CollateObject<'a> {
       return Err(errno::ENOENT);
     }
 
-    let icu_locale_name = canonicalize_locale(lang);
-
-    let icu_locale = Locale::try_from_str(&icu_locale_name.replace("_", "-"))
-      .map_err(|_| errno::ENOENT)?;
+    // Without the ICU collation data only the POSIX locales are available
+    #[cfg(not(feature = "collate"))]
+    return Err(errno::ENOENT);
 
-    let mut options = CollatorOptions::default();
-    options.strength = Some(Strength::Quaternary);
+    #[cfg(feature = "collate")]
+    {
+      let collator = icu::get_collator_for_name(name, lang)?;
 
-    let collator = Collator::try_new(icu_locale.into(), options)
-      .map_err(|_| errno::ENOENT)?;
+      self.name = Cow::Owned(locale.to_owned());
+      self.collator = Some(collator);
+      self.order = None;
 
-    self.name = Cow::Owned(locale.to_owned());
-    self.collator = Some(collator);
-
-    Ok(self.name.as_ref())
+      Ok(self.name.as_ref())
+    }
   }
 
   fn set_to_posix(&mut self) -> &ffi::CStr {
@@ -142,44 +149,15 @@ impl<'a> LocaleObject for CollateObject<'a> {
   fn get_name(&self) -> &ffi::CStr {
     self.name.as_ref()
   }
-}
-
-impl<'a> Clone for CollateObject<'a> {
-  fn clone(&self) -> Self {
-    let name = &self.name.to_str();
-    let name = match name {
-      | Ok(name) => name,
-      | Err(_) => return DEFAULT_COLLATE
-    };
-
-    if is_posix_locale(name) {
-      return DEFAULT_COLLATE;
-    }
-
-    let mut parts = name.split(['.', '@']);
-    let lang = parts.next().unwrap_or("");
-    if lang.is_empty() {
-      return DEFAULT_COLLATE;
-    }
-
-    let icu_locale = Locale::try_from_str(&lang.replace("_", "-"));
-    let icu_locale = match icu_locale {
-      | Ok(locale) => locale,
-      | Err(_) => return DEFAULT_COLLATE
-    };
-
-    let mut options = CollatorOptions::default();
-    options.strength = Some(Strength::Quaternary);
-
-    let collator = Collator::try_new(icu_locale.into(), options);
-    let collator = match collator {
-      | Ok(collator) => collator,
-      | Err(_) => return DEFAULT_COLLATE
-    };
 
-    let cstr = string::strtocstr(name);
+  fn load_source(
+    &mut self,
+    locale: &ffi::CStr,
+    source: &Source
+  ) -> Result<&ffi::CStr, c_int> {
+    localedef::load(self, locale, source)?;
 
-    Self { name: cstr, collator: Some(collator) }
+    Ok(self.name.as_ref())
   }
 }
 
@@ -189,5 +167,9 @@ impl<'a> Default for CollateObject<'a> {
   }
 }
 
-pub const DEFAULT_COLLATE: CollateObject =
-  CollateObject { name: Cow::Borrowed(c"C"), collator: None };
+pub const DEFAULT_COLLATE: CollateObject = CollateObject {
+  name: Cow::Borrowed(c"C"),
+  #[cfg(feature = "collate")]
+  collator: None,
+  order: None
+};
@@ -1,4 +1,8 @@
-use super::CaseMapObject;
+use {
+  super::{CaseMapObject, Folded},
+  allocation::borrow::Cow,
+  icu_locale::LanguageIdentifier
+};
 
 fn isalnum(c: u32) -> bool {
   (c >= '0' as u32 && c <= '9' as u32) ||
@@ -55,6 +59,18 @@ fn isxdigit(c: u32) -> bool {
     (c >= 'a' as u32 && c <= 'f' as u32)
 }
 
+// The C locale defines none of the extended classes
+fn isnone(_: u32) -> bool {
+  false
+}
+
+fn isscript(
+  _: u32,
+  _: u16
+) -> bool {
+  false
+}
+
 pub fn tolower(c: u32) -> u32 {
   if c >= 'A' as u32 && c <= 'Z' as u32 {
     return c - 'A' as u32 + 'a' as u32;
@@ -69,19 +85,60 @@ pub fn toupper(c: u32) -> u32 {
   c
 }
 
+fn strlower<'s>(
+  s: &'s str,
+  _: &LanguageIdentifier
+) -> Cow<'s, str> {
+  if s.bytes().any(|c| c.is_ascii_uppercase()) {
+    Cow::Owned(s.to_ascii_lowercase())
+  } else {
+    Cow::Borrowed(s)
+  }
+}
+
+fn strupper<'s>(
+  s: &'s str,
+  _: &LanguageIdentifier
+) -> Cow<'s, str> {
+  if s.bytes().any(|c| c.is_ascii_lowercase()) {
+    Cow::Owned(s.to_ascii_uppercase())
+  } else {
+    Cow::Borrowed(s)
+  }
+}
+
+fn charfold(
+  c: char,
+  _: &LanguageIdentifier
+) -> Folded {
+  [c.to_ascii_lowercase()].into_iter().collect()
+}
+
 pub const CASEMAP_ASCII: CaseMapObject = CaseMapObject {
-  isalnum: isalnum,
-  isalpha: isalpha,
-  isblank: isblank,
-  iscntrl: iscntrl,
-  isdigit: isdigit,
-  isgraph: isgraph,
-  islower: islower,
-  isprint: isprint,
-  ispunct: ispunct,
-  isspace: isspace,
-  isupper: isupper,
-  isxdigit: isxdigit,
-  tolower: tolower,
-  toupper: toupper
+  language: LanguageIdentifier::UNKNOWN,
+  isalnum,
+  isalpha,
+  isblank,
+  iscntrl,
+  isdigit,
+  isgraph,
+  islower,
+  isprint,
+  ispunct,
+  isspace,
+  isupper,
+  isxdigit,
+  iscombining: isnone,
+  isemoji: isnone,
+  isideographic: isnone,
+  isjdigit: isnone,
+  isscript,
+  tolower,
+  toupper,
+  totitle: toupper,
+  tofold: tolower,
+  strlower,
+  strupper,
+  strfold: strlower,
+  charfold
 };
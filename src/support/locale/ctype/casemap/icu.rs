@@ -1,65 +1,96 @@
 use {
-  super::CaseMapObject,
+  super::{super::table, CaseMapObject, Folded},
+  allocation::borrow::Cow,
+  core::fmt,
   icu_casemap::CaseMapper,
-  icu_properties::{CodePointMapData, CodePointSetData, props::*}
+  icu_locale::LanguageIdentifier,
+  icu_properties::{
+    CodePointMapData,
+    CodePointSetData,
+    props::*,
+    script::ScriptWithExtensions
+  },
+  writeable::Writeable
 };
 
 fn isalnum(c: u32) -> bool {
-  CodePointSetData::new::<Alnum>().contains32(c)
+  table::lookup(c) & table::CLASS_ALNUM != 0
 }
 
 fn isalpha(c: u32) -> bool {
-  CodePointSetData::new::<Alphabetic>().contains32(c)
+  table::lookup(c) & table::CLASS_ALPHA != 0
 }
 
 fn isblank(c: u32) -> bool {
-  CodePointSetData::new::<Blank>().contains32(c)
+  table::lookup(c) & table::CLASS_BLANK != 0
 }
 
 fn iscntrl(c: u32) -> bool {
-  CodePointMapData::<GeneralCategory>::new().get32(c) ==
-    GeneralCategory::Control
+  table::lookup(c) & table::CLASS_CNTRL != 0
 }
 
 fn isdigit(c: u32) -> bool {
-  CodePointMapData::<GeneralCategory>::new().get32(c) ==
-    GeneralCategory::DecimalNumber
+  table::lookup(c) & table::CLASS_DIGIT != 0
 }
 
 fn isgraph(c: u32) -> bool {
-  CodePointSetData::new::<Graph>().contains32(c)
+  table::lookup(c) & table::CLASS_GRAPH != 0
 }
 
 fn islower(c: u32) -> bool {
-  CodePointSetData::new::<Lowercase>().contains32(c)
+  table::lookup(c) & table::CLASS_LOWER != 0
 }
 
 fn isprint(c: u32) -> bool {
-  CodePointSetData::new::<Print>().contains32(c)
+  table::lookup(c) & table::CLASS_PRINT != 0
 }
 
 fn ispunct(c: u32) -> bool {
-  CodePointMapData::<GeneralCategory>::new().get32(c) >=
-    GeneralCategory::DashPunctuation &&
-    CodePointMapData::<GeneralCategory>::new().get32(c) <=
-      GeneralCategory::OtherPunctuation
+  table::lookup(c) & table::CLASS_PUNCT != 0
 }
 
 fn isspace(c: u32) -> bool {
-  CodePointSetData::new::<WhiteSpace>().contains32(c)
+  table::lookup(c) & table::CLASS_SPACE != 0
 }
 
 fn isupper(c: u32) -> bool {
-  CodePointSetData::new::<Uppercase>().contains32(c)
+  table::lookup(c) & table::CLASS_UPPER != 0
 }
 
 fn isxdigit(c: u32) -> bool {
-  CodePointSetData::new::<Xdigit>().contains32(c)
+  table::lookup(c) & table::CLASS_XDIGIT != 0
+}
+
+fn iscombining(c: u32) -> bool {
+  GeneralCategoryGroup::Mark
+    .contains(CodePointMapData::<GeneralCategory>::new().get32(c))
+}
+
+fn isemoji(c: u32) -> bool {
+  CodePointSetData::new::<Emoji>().contains32(c)
+}
+
+fn isideographic(c: u32) -> bool {
+  CodePointSetData::new::<Ideographic>().contains32(c)
+}
+
+// Fullwidth digits, as in the jdigit class of Japanese locales
+fn isjdigit(c: u32) -> bool {
+  (0xff10..=0xff19).contains(&c)
+}
+
+// Uses Script_Extensions, so that marks shared between scripts (such as the
+// prolonged sound mark) belong to every script that uses them
+fn isscript(
+  c: u32,
+  script: u16
+) -> bool {
+  ScriptWithExtensions::new().has_script32(c, Script::from_icu4c_value(script))
 }
 
 pub fn tolower(c: u32) -> u32 {
   let Ok(c) = char::try_from(c) else {
-    return c as u32;
+    return c;
   };
   let cm = CaseMapper::new();
 
@@ -68,26 +99,185 @@ pub fn tolower(c: u32) -> u32 {
 
 pub fn toupper(c: u32) -> u32 {
   let Ok(c) = char::try_from(c) else {
-    return c as u32;
+    return c;
   };
   let cm = CaseMapper::new();
 
   cm.simple_uppercase(c) as u32
 }
 
+pub fn totitle(c: u32) -> u32 {
+  let Ok(c) = char::try_from(c) else {
+    return c;
+  };
+  let cm = CaseMapper::new();
+
+  cm.simple_titlecase(c) as u32
+}
+
+pub fn tofold(c: u32) -> u32 {
+  let Ok(c) = char::try_from(c) else {
+    return c;
+  };
+  let cm = CaseMapper::new();
+
+  cm.simple_fold(c) as u32
+}
+
+// Turkish and Azeri pair dotted and dotless i differently
+fn tolower_turkic(c: u32) -> u32 {
+  match c {
+    | 0x49 => 0x131,
+    | 0x130 => 0x69,
+    | _ => tolower(c)
+  }
+}
+
+fn toupper_turkic(c: u32) -> u32 {
+  match c {
+    | 0x69 => 0x130,
+    | _ => toupper(c)
+  }
+}
+
+fn totitle_turkic(c: u32) -> u32 {
+  match c {
+    | 0x69 => 0x130,
+    | _ => totitle(c)
+  }
+}
+
+fn tofold_turkic(c: u32) -> u32 {
+  let Ok(c) = char::try_from(c) else {
+    return c;
+  };
+  let cm = CaseMapper::new();
+
+  cm.simple_fold_turkic(c) as u32
+}
+
+fn is_turkic(language: &LanguageIdentifier) -> bool {
+  matches!(language.language.as_str(), "tr" | "az")
+}
+
+fn strlower<'s>(
+  s: &'s str,
+  language: &LanguageIdentifier
+) -> Cow<'s, str> {
+  CaseMapper::new().lowercase_to_string(s, language)
+}
+
+fn strupper<'s>(
+  s: &'s str,
+  language: &LanguageIdentifier
+) -> Cow<'s, str> {
+  CaseMapper::new().uppercase_to_string(s, language)
+}
+
+fn strfold<'s>(
+  s: &'s str,
+  language: &LanguageIdentifier
+) -> Cow<'s, str> {
+  let cm = CaseMapper::new();
+
+  if is_turkic(language) { cm.fold_turkic_string(s) } else { cm.fold_string(s) }
+}
+
+// Collects the characters that the case mapper writes
+struct FoldedSink(Folded);
+
+impl fmt::Write for FoldedSink {
+  fn write_str(
+    &mut self,
+    s: &str
+  ) -> fmt::Result {
+    self.0.extend(s.chars());
+    Ok(())
+  }
+}
+
+fn charfold(
+  c: char,
+  language: &LanguageIdentifier
+) -> Folded {
+  let mut buf = [0; 4];
+  let s = c.encode_utf8(&mut buf);
+  let cm = CaseMapper::new();
+  let mut sink = FoldedSink(Folded::new());
+
+  let _ = if is_turkic(language) {
+    cm.fold_turkic(s).write_to(&mut sink)
+  } else {
+    cm.fold(s).write_to(&mut sink)
+  };
+
+  sink.0
+}
+
+/// Returns the casemap for the given language, with its language-specific
+/// rules for both single character and full string mappings.
+pub fn casemap_for_language(language: LanguageIdentifier) -> CaseMapObject {
+  let mut casemap =
+    if is_turkic(&language) { CASEMAP_ICU_TURKIC } else { CASEMAP_ICU };
+
+  casemap.language = language;
+  casemap
+}
+
 pub const CASEMAP_ICU: CaseMapObject = CaseMapObject {
-  isalnum: isalnum,
-  isalpha: isalpha,
-  isblank: isblank,
-  isdigit: isdigit,
-  iscntrl: iscntrl,
-  isgraph: isgraph,
-  islower: islower,
-  isprint: isprint,
-  ispunct: ispunct,
-  isspace: isspace,
-  isupper: isupper,
-  isxdigit: isxdigit,
-  tolower: tolower,
-  toupper: toupper
+  language: LanguageIdentifier::UNKNOWN,
+  isalnum,
+  isalpha,
+  isblank,
+  isdigit,
+  iscntrl,
+  isgraph,
+  islower,
+  isprint,
+  ispunct,
+  isspace,
+  isupper,
+  isxdigit,
+  iscombining,
+  isemoji,
+  isideographic,
+  isjdigit,
+  isscript,
+  tolower,
+  toupper,
+  totitle,
+  tofold,
+  strlower,
+  strupper,
+  strfold,
+  charfold
+};
+
+pub const CASEMAP_ICU_TURKIC: CaseMapObject = CaseMapObject {
+  language: LanguageIdentifier::UNKNOWN,
+  isalnum,
+  isalpha,
+  isblank,
+  isdigit,
+  iscntrl,
+  isgraph,
+  islower,
+  isprint,
+  ispunct,
+  isspace,
+  isupper,
+  isxdigit,
+  iscombining,
+  isemoji,
+  isideographic,
+  isjdigit,
+  isscript,
+  tolower: tolower_turkic,
+  toupper: toupper_turkic,
+  totitle: totitle_turkic,
+  tofold: tofold_turkic,
+  strlower,
+  strupper,
+  strfold,
+  charfold
 };
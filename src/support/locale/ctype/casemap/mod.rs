@@ -1,8 +1,21 @@
 pub mod ascii;
+#[cfg(feature = "casemap")]
 pub mod icu;
 
+use {
+  allocation::borrow::Cow,
+  core::cmp::Ordering,
+  icu_locale::LanguageIdentifier,
+  smallvec::SmallVec
+};
+
+/// The full case folding of one character, which is never longer than three
+/// characters.
+pub type Folded = SmallVec<[char; 3]>;
+
 #[derive(Debug, Clone)]
 pub struct CaseMapObject {
+  pub language: LanguageIdentifier,
   pub isalnum: fn(u32) -> bool,
   pub isalpha: fn(u32) -> bool,
   pub isblank: fn(u32) -> bool,
@@ -15,6 +28,64 @@ pub struct CaseMapObject {
   pub isspace: fn(u32) -> bool,
   pub isupper: fn(u32) -> bool,
   pub isxdigit: fn(u32) -> bool,
+  pub iscombining: fn(u32) -> bool,
+  pub isemoji: fn(u32) -> bool,
+  pub isideographic: fn(u32) -> bool,
+  pub isjdigit: fn(u32) -> bool,
+  pub isscript: fn(u32, u16) -> bool,
   pub tolower: fn(u32) -> u32,
-  pub toupper: fn(u32) -> u32
+  pub toupper: fn(u32) -> u32,
+  pub totitle: fn(u32) -> u32,
+  pub tofold: fn(u32) -> u32,
+  pub strlower: for<'s> fn(&'s str, &LanguageIdentifier) -> Cow<'s, str>,
+  pub strupper: for<'s> fn(&'s str, &LanguageIdentifier) -> Cow<'s, str>,
+  pub strfold: for<'s> fn(&'s str, &LanguageIdentifier) -> Cow<'s, str>,
+  pub charfold: fn(char, &LanguageIdentifier) -> Folded
+}
+
+impl CaseMapObject {
+  /// Full lowercase mapping of `s` using the rules of the locale language.
+  pub fn lower<'s>(
+    &self,
+    s: &'s str
+  ) -> Cow<'s, str> {
+    (self.strlower)(s, &self.language)
+  }
+
+  /// Full uppercase mapping of `s` using the rules of the locale language.
+  pub fn upper<'s>(
+    &self,
+    s: &'s str
+  ) -> Cow<'s, str> {
+    (self.strupper)(s, &self.language)
+  }
+
+  /// Full case folding of `s`, suitable for caseless matching.
+  pub fn fold<'s>(
+    &self,
+    s: &'s str
+  ) -> Cow<'s, str> {
+    (self.strfold)(s, &self.language)
+  }
+
+  /// Compares two strings after full case folding, code point by code point.
+  /// The characters are folded one at a time, and units that are not
+  /// characters compare by their value.
+  pub fn casecmp(
+    &self,
+    left: impl Iterator<Item = Result<char, u32>>,
+    right: impl Iterator<Item = Result<char, u32>>
+  ) -> Ordering {
+    let fold = |unit: Result<char, u32>| -> SmallVec<[u32; 3]> {
+      match unit {
+        | Ok(c) => (self.charfold)(c, &self.language)
+          .into_iter()
+          .map(u32::from)
+          .collect(),
+        | Err(unit) => [unit].into_iter().collect()
+      }
+    };
+
+    left.flat_map(fold).cmp(right.flat_map(fold))
+  }
 }
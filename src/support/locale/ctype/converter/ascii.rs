@@ -32,12 +32,15 @@ fn mbtoc32(
   1
 }
 
-fn wcwidth(c: u32) -> i32 {
+fn wcwidth(
+  c: u32,
+  _: i32
+) -> i32 {
   if c >= ' ' as u32 && c <= '~' as u32 {
     return 1;
   }
 
-  if (c >= '\0' as u32 && c < ' ' as u32) || c == 0x7f || c == '\0' as u32 {
+  if c == '\0' as u32 {
     return 0;
   }
 
@@ -12,7 +12,8 @@ pub struct ConverterObject<'a> {
   pub mb_cur_max: size_t,
   pub c32tomb: fn(&mut [u8], char32_t) -> ssize_t,
   pub mbtoc32: fn(&mut char32_t, &[u8], &mut mbstate_t) -> ssize_t,
-  pub wcwidth: fn(u32) -> i32
+  // The second argument is the width of East Asian Ambiguous characters
+  pub wcwidth: fn(u32, i32) -> i32
 }
 
 pub struct AvailableConverters<'a> {
@@ -28,3 +29,11 @@ pub const AVAILABLE_CONVERTERS: [AvailableConverters; 6] = [
   AvailableConverters { name: "utf8", converter: utf8::CONVERTER_UTF8 },
   AvailableConverters { name: "utf-8", converter: utf8::CONVERTER_UTF8 }
 ];
+
+/// Finds the converter of a codeset name, ignoring case.
+pub fn find_converter(name: &str) -> Option<ConverterObject<'static>> {
+  AVAILABLE_CONVERTERS
+    .into_iter()
+    .find(|c| c.name.eq_ignore_ascii_case(name))
+    .map(|c| c.converter)
+}
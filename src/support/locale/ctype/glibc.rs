@@ -0,0 +1,159 @@
+use {
+  super::{CtypeObject, converter::ConverterObject},
+  crate::{MBState, char32_t, std::wctype},
+  allocation::{boxed::Box, vec::Vec},
+  core::cell::RefCell,
+  critical_section::Mutex,
+  once_cell::sync::OnceCell
+};
+
+// Classification and case tables laid out like glibc's, for objects compiled
+// against glibc headers whose <ctype.h> macros index them directly. Every
+// table covers -128..=255, so both EOF and signed chars are valid indices.
+
+pub const TABLE_OFFSET: usize = 128;
+pub const TABLE_SIZE: usize = 384;
+
+// glibc numbers the classes by bit, but on little endian targets stores them
+// byte swapped so that the table is the same on every architecture
+const fn isbit(bit: u32) -> u16 {
+  if cfg!(target_endian = "big") {
+    1 << bit
+  } else if bit < 8 {
+    (1 << bit) << 8
+  } else {
+    (1 << bit) >> 8
+  }
+}
+
+pub const IS_UPPER: u16 = isbit(0);
+pub const IS_LOWER: u16 = isbit(1);
+pub const IS_ALPHA: u16 = isbit(2);
+pub const IS_DIGIT: u16 = isbit(3);
+pub const IS_XDIGIT: u16 = isbit(4);
+pub const IS_SPACE: u16 = isbit(5);
+pub const IS_PRINT: u16 = isbit(6);
+pub const IS_GRAPH: u16 = isbit(7);
+pub const IS_BLANK: u16 = isbit(8);
+pub const IS_CNTRL: u16 = isbit(9);
+pub const IS_PUNCT: u16 = isbit(10);
+pub const IS_ALNUM: u16 = isbit(11);
+
+#[derive(Debug, PartialEq)]
+pub struct CtypeTables {
+  pub class: [u16; TABLE_SIZE],
+  pub tolower: [i32; TABLE_SIZE],
+  pub toupper: [i32; TABLE_SIZE]
+}
+
+// Most locales end up with identical tables, so they are built once, never
+// freed, and shared by every locale object that needs them
+static TABLES: Mutex<RefCell<Vec<&'static CtypeTables>>> =
+  Mutex::new(RefCell::new(Vec::new()));
+
+static DEFAULT_TABLES: OnceCell<&'static CtypeTables> = OnceCell::new();
+
+fn decode_byte(
+  converter: &ConverterObject,
+  byte: u8
+) -> Option<u32> {
+  let mut c32: char32_t = 0;
+  let mut ps = MBState::new();
+
+  ((converter.mbtoc32)(&mut c32, &[byte], &mut ps) == 1).then_some(c32)
+}
+
+fn encode_byte(
+  converter: &ConverterObject,
+  c: u32
+) -> Option<u8> {
+  let mut buf = [0u8; 4];
+
+  ((converter.c32tomb)(&mut buf, c) == 1).then_some(buf[0])
+}
+
+fn classify(
+  ctype: &CtypeObject,
+  c: u32
+) -> u16 {
+  let classes = [
+    (wctype::WCTYPE_UPPER, IS_UPPER),
+    (wctype::WCTYPE_LOWER, IS_LOWER),
+    (wctype::WCTYPE_ALPHA, IS_ALPHA),
+    (wctype::WCTYPE_DIGIT, IS_DIGIT),
+    (wctype::WCTYPE_XDIGIT, IS_XDIGIT),
+    (wctype::WCTYPE_SPACE, IS_SPACE),
+    (wctype::WCTYPE_PRINT, IS_PRINT),
+    (wctype::WCTYPE_GRAPH, IS_GRAPH),
+    (wctype::WCTYPE_BLANK, IS_BLANK),
+    (wctype::WCTYPE_CNTRL, IS_CNTRL),
+    (wctype::WCTYPE_PUNCT, IS_PUNCT),
+    (wctype::WCTYPE_ALNUM, IS_ALNUM)
+  ];
+
+  classes
+    .iter()
+    .filter(|(class, _)| ctype.is_class(*class, c))
+    .fold(0, |result, (_, bit)| result | bit)
+}
+
+fn build(ctype: &CtypeObject) -> CtypeTables {
+  let converter = &ctype.converter;
+  let mut tables = CtypeTables {
+    class: [0; TABLE_SIZE],
+    tolower: [0; TABLE_SIZE],
+    toupper: [0; TABLE_SIZE]
+  };
+
+  for i in 0..TABLE_SIZE {
+    let value = i as i32 - TABLE_OFFSET as i32;
+    tables.tolower[i] = value;
+    tables.toupper[i] = value;
+
+    // EOF belongs to no class and maps to itself
+    if value == -1 {
+      continue;
+    }
+
+    let Some(c) = decode_byte(converter, value as u8) else {
+      continue;
+    };
+
+    // Negative indices are signed chars and map to signed chars, so that
+    // the result compares equal to a char holding the same byte
+    let map = |mapped: u32| match encode_byte(converter, mapped) {
+      | Some(byte) if value < 0 => byte as i8 as i32,
+      | Some(byte) => byte as i32,
+      | None => value
+    };
+
+    tables.class[i] = classify(ctype, c);
+    tables.tolower[i] = map(ctype.tolower(c));
+    tables.toupper[i] = map(ctype.toupper(c));
+  }
+
+  tables
+}
+
+/// Returns the tables of a locale with the given classes and codeset.
+pub fn get_tables(ctype: &CtypeObject) -> &'static CtypeTables {
+  let tables = build(ctype);
+
+  critical_section::with(|cs| {
+    let mut cache = TABLES.borrow_ref_mut(cs);
+
+    if let Some(shared) = cache.iter().find(|shared| ***shared == tables) {
+      return *shared;
+    }
+
+    let shared: &'static CtypeTables = Box::leak(Box::new(tables));
+    cache.push(shared);
+
+    shared
+  })
+}
+
+/// Returns the tables of the C locale.
+pub fn get_default_tables() -> &'static CtypeTables {
+  DEFAULT_TABLES.get_or_init(|| get_tables(&super::DEFAULT_CTYPE))
+}
@@ -0,0 +1,151 @@
+use super::{
+  table::{contains, search},
+  unicode_data::{
+    CONJUNCT_BREAKS,
+    EXTENDED_PICTOGRAPHIC,
+    GRAPHEME_BREAKS,
+    GraphemeBreak as GraphemeClusterBreak,
+    IndicConjunctBreak
+  }
+};
+
+// Extended grapheme cluster boundaries, following the rules of UAX #29
+
+#[derive(Clone, Copy, PartialEq)]
+enum ConjunctState {
+  None,
+  Consonant,
+  Linker
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum EmojiState {
+  None,
+  Pictographic,
+  Joiner
+}
+
+#[inline]
+fn gcb(c: u32) -> GraphemeClusterBreak {
+  search(&GRAPHEME_BREAKS, c).unwrap_or(GraphemeClusterBreak::Other)
+}
+
+#[inline]
+fn incb(c: u32) -> IndicConjunctBreak {
+  search(&CONJUNCT_BREAKS, c).unwrap_or(IndicConjunctBreak::None)
+}
+
+#[inline]
+fn is_pictographic(c: u32) -> bool {
+  contains(&EXTENDED_PICTOGRAPHIC, c)
+}
+
+/// Returns the length of the extended grapheme cluster at the start of `s`.
+pub fn cluster_len(s: &[u32]) -> usize {
+  let Some(&first) = s.first() else {
+    return 0;
+  };
+
+  let mut prev = gcb(first);
+  let mut conjunct = if incb(first) == IndicConjunctBreak::Consonant {
+    ConjunctState::Consonant
+  } else {
+    ConjunctState::None
+  };
+  let mut emoji = if is_pictographic(first) {
+    EmojiState::Pictographic
+  } else {
+    EmojiState::None
+  };
+  let mut regional =
+    usize::from(prev == GraphemeClusterBreak::RegionalIndicator);
+
+  for (i, &c) in s.iter().enumerate().skip(1) {
+    let next = gcb(c);
+    let next_incb = incb(c);
+    let next_pictographic = is_pictographic(c);
+
+    let join = match (prev, next) {
+      // GB3, GB4 and GB5
+      | (GraphemeClusterBreak::CR, GraphemeClusterBreak::LF) => true,
+      | (
+        GraphemeClusterBreak::Control |
+        GraphemeClusterBreak::CR |
+        GraphemeClusterBreak::LF,
+        _
+      ) => false,
+      | (
+        _,
+        GraphemeClusterBreak::Control |
+        GraphemeClusterBreak::CR |
+        GraphemeClusterBreak::LF
+      ) => false,
+      // GB6, GB7 and GB8: Hangul syllable sequences
+      | (
+        GraphemeClusterBreak::L,
+        GraphemeClusterBreak::L |
+        GraphemeClusterBreak::V |
+        GraphemeClusterBreak::LV |
+        GraphemeClusterBreak::Lvt
+      ) => true,
+      | (
+        GraphemeClusterBreak::LV | GraphemeClusterBreak::V,
+        GraphemeClusterBreak::V | GraphemeClusterBreak::T
+      ) => true,
+      | (
+        GraphemeClusterBreak::Lvt | GraphemeClusterBreak::T,
+        GraphemeClusterBreak::T
+      ) => true,
+      // GB9, GB9a and GB9b
+      | (
+        _,
+        GraphemeClusterBreak::Extend |
+        GraphemeClusterBreak::Zwj |
+        GraphemeClusterBreak::SpacingMark
+      ) => true,
+      | (GraphemeClusterBreak::Prepend, _) => true,
+      // GB12 and GB13: regional indicators pair up into flags
+      | (
+        GraphemeClusterBreak::RegionalIndicator,
+        GraphemeClusterBreak::RegionalIndicator
+      ) => regional % 2 == 1,
+      | _ => {
+        // GB9c: Indic conjuncts and GB11: emoji ZWJ sequences
+        (conjunct == ConjunctState::Linker &&
+          next_incb == IndicConjunctBreak::Consonant) ||
+          (emoji == EmojiState::Joiner && next_pictographic)
+      }
+    };
+
+    if !join {
+      return i;
+    }
+
+    conjunct = match next_incb {
+      | IndicConjunctBreak::Consonant => ConjunctState::Consonant,
+      | IndicConjunctBreak::Linker if conjunct != ConjunctState::None => {
+        ConjunctState::Linker
+      },
+      | IndicConjunctBreak::Extend => conjunct,
+      | _ => ConjunctState::None
+    };
+    emoji = match next {
+      | _ if next_pictographic => EmojiState::Pictographic,
+      | GraphemeClusterBreak::Extend if emoji == EmojiState::Pictographic => {
+        EmojiState::Pictographic
+      },
+      | GraphemeClusterBreak::Zwj if emoji == EmojiState::Pictographic => {
+        EmojiState::Joiner
+      },
+      | _ => EmojiState::None
+    };
+    regional = if next == GraphemeClusterBreak::RegionalIndicator {
+      regional + 1
+    } else {
+      0
+    };
+    prev = next;
+  }
+
+  s.len()
+}
@@ -0,0 +1,204 @@
+use {
+  super::{CtypeObject, converter, glibc, table},
+  crate::{
+    allocation::{borrow::ToOwned, sync::Arc, vec::Vec},
+    c_int,
+    std::{errno, wctype},
+    support::locale::localedef::{self, Source, Value},
+    wctype_t
+  },
+  allocation::borrow::Cow,
+  core::ffi
+};
+
+const CLASS_KEYWORDS: [(&str, wctype_t); 11] = [
+  ("upper", wctype::WCTYPE_UPPER),
+  ("lower", wctype::WCTYPE_LOWER),
+  ("alpha", wctype::WCTYPE_ALPHA),
+  ("digit", wctype::WCTYPE_DIGIT),
+  ("space", wctype::WCTYPE_SPACE),
+  ("cntrl", wctype::WCTYPE_CNTRL),
+  ("punct", wctype::WCTYPE_PUNCT),
+  ("graph", wctype::WCTYPE_GRAPH),
+  ("print", wctype::WCTYPE_PRINT),
+  ("xdigit", wctype::WCTYPE_XDIGIT),
+  ("blank", wctype::WCTYPE_BLANK)
+];
+
+/// The character classes and case mappings of a localedef source. A class
+/// it lists replaces the one of the case map, while the case mappings only
+/// replace those of the characters they list.
+#[derive(Debug, Clone, Default)]
+pub struct Classes {
+  /// Sorted ranges of the characters of each class, by `wctype_t`
+  classes: [Option<Vec<(u32, u32)>>; 12],
+  /// Pairs of characters, sorted by the first one
+  toupper: Vec<(u32, u32)>,
+  tolower: Vec<(u32, u32)>
+}
+
+impl Classes {
+  #[inline]
+  fn get(
+    &self,
+    class: wctype_t
+  ) -> Option<&[(u32, u32)]> {
+    let index = (class as usize).checked_sub(1)?;
+
+    self.classes.get(index)?.as_deref()
+  }
+
+  pub fn defines(
+    &self,
+    class: wctype_t
+  ) -> bool {
+    self.get(class).is_some()
+  }
+
+  /// Checks whether `c` is in `class`, or returns `None` if the source
+  /// does not list the class.
+  pub fn contains(
+    &self,
+    class: wctype_t,
+    c: u32
+  ) -> Option<bool> {
+    self.get(class).map(|ranges| table::contains(ranges, c))
+  }
+
+  fn map(
+    pairs: &[(u32, u32)],
+    c: u32
+  ) -> Option<u32> {
+    pairs.binary_search_by_key(&c, |&(from, _)| from).ok().map(|i| pairs[i].1)
+  }
+
+  #[inline]
+  pub fn toupper(
+    &self,
+    c: u32
+  ) -> Option<u32> {
+    Self::map(&self.toupper, c)
+  }
+
+  #[inline]
+  pub fn tolower(
+    &self,
+    c: u32
+  ) -> Option<u32> {
+    Self::map(&self.tolower, c)
+  }
+}
+
+// A class lists characters and ranges such as "<U0041>..<U005A>"
+fn get_ranges(values: &[Value]) -> Result<Vec<(u32, u32)>, c_int> {
+  let mut ranges: Vec<(u32, u32)> = Vec::new();
+  let mut values = values.iter();
+
+  while let Some(value) = values.next() {
+    match value {
+      | Value::Char(c) => ranges.push((*c as u32, *c as u32)),
+      | Value::Range => {
+        let (Some(Value::Char(end)), Some(last)) =
+          (values.next(), ranges.last_mut())
+        else {
+          return Err(errno::ENOENT);
+        };
+        if (*end as u32) < last.0 {
+          return Err(errno::ENOENT);
+        }
+        last.1 = *end as u32;
+      },
+      | _ => return Err(errno::ENOENT)
+    }
+  }
+
+  ranges.sort_unstable();
+  let mut merged: Vec<(u32, u32)> = Vec::with_capacity(ranges.len());
+  for (start, end) in ranges {
+    match merged.last_mut() {
+      | Some(last) if start <= last.1.saturating_add(1) => {
+        last.1 = last.1.max(end)
+      },
+      | _ => merged.push((start, end))
+    }
+  }
+
+  Ok(merged)
+}
+
+// A case mapping lists pairs such as "(<U0061>,<U0041>)"
+fn get_pairs(values: &[Value]) -> Result<Vec<(u32, u32)>, c_int> {
+  let mut pairs = Vec::with_capacity(values.len() / 2);
+  for pair in values.chunks(2) {
+    match pair {
+      | [Value::Char(from), Value::Char(to)] => {
+        pairs.push((*from as u32, *to as u32))
+      },
+      | _ => return Err(errno::ENOENT)
+    }
+  }
+
+  pairs.sort_by_key(|&(from, _)| from);
+  pairs.dedup_by_key(|&mut (from, _)| from);
+
+  Ok(pairs)
+}
+
+fn merge_pairs(
+  base: &mut Vec<(u32, u32)>,
+  pairs: Vec<(u32, u32)>
+) {
+  // Pairs of the source come first, so that they win over the base
+  let mut merged = pairs;
+  merged.append(base);
+  merged.sort_by_key(|&(from, _)| from);
+  merged.dedup_by_key(|&mut (from, _)| from);
+
+  *base = merged;
+}
+
+pub fn load(
+  ctype: &mut CtypeObject,
+  locale: &ffi::CStr,
+  source: &Source
+) -> Result<(), c_int> {
+  let section = source.section("LC_CTYPE");
+  localedef::load_base(ctype, locale, source, section)?;
+
+  // Sources are written for any codeset, so it comes from the name
+  let name = locale.to_str().map_err(|_| errno::ENOENT)?;
+  let base = name.split('@').next().unwrap_or("");
+  if let Some((_, codeset)) = base.split_once('.') {
+    ctype.converter =
+      converter::find_converter(codeset).ok_or(errno::ENOENT)?;
+  }
+
+  if let Some(section) = section {
+    let mut classes = ctype.classes.as_deref().cloned().unwrap_or_default();
+    let mut defined = false;
+
+    for (keyword, class) in CLASS_KEYWORDS {
+      if let Some(values) = section.get(keyword) {
+        classes.classes[class as usize - 1] = Some(get_ranges(values)?);
+        defined = true;
+      }
+    }
+    if let Some(values) = section.get("toupper") {
+      merge_pairs(&mut classes.toupper, get_pairs(values)?);
+      defined = true;
+    }
+    if let Some(values) = section.get("tolower") {
+      merge_pairs(&mut classes.tolower, get_pairs(values)?);
+      defined = true;
+    }
+
+    if defined {
+      ctype.classes = Some(Arc::new(classes));
+    }
+  }
+
+  ctype.tables = Some(glibc::get_tables(ctype));
+  ctype.name = Cow::Owned(locale.to_owned());
+
+  Ok(())
+}
@@ -1,18 +1,271 @@
 pub mod casemap;
 pub mod converter;
+pub mod glibc;
+pub mod grapheme;
+mod localedef;
+pub mod table;
+pub mod unicode_data;
 
 use {
-  super::LocaleObject,
-  crate::{c_int, std::errno},
-  allocation::borrow::{Cow, ToOwned},
-  core::ffi
+  super::{LocaleObject, get_modifier_keywords, localedef::Source},
+  crate::{
+    MBState,
+    c_int,
+    char32_t,
+    std::{errno, wctype},
+    wctype_t
+  },
+  allocation::{
+    borrow::{Cow, ToOwned},
+    string::String,
+    sync::Arc,
+    vec::Vec
+  },
+  core::ffi,
+  icu_locale::LanguageIdentifier
 };
 
 #[derive(Debug, Clone)]
 pub struct CtypeObject<'a> {
   name: Cow<'a, ffi::CStr>,
   pub casemap: casemap::CaseMapObject,
-  pub converter: converter::ConverterObject<'a>
+  pub converter: converter::ConverterObject<'a>,
+  pub ambiguous_width: i32,
+  /// The classes and case mappings of a localedef source, if any
+  classes: Option<Arc<localedef::Classes>>,
+  tables: Option<&'static glibc::CtypeTables>
+}
+
+// East Asian Ambiguous characters are wide in CJK locales, unless the
+// @cjk_narrow or @cjk_wide modifiers say otherwise
+fn get_ambiguous_width(
+  language: &LanguageIdentifier,
+  name: &str
+) -> i32 {
+  let mut width = match language.language.as_str() {
+    | "ja" | "ko" | "zh" | "yue" | "wuu" | "hak" | "nan" => 2,
+    | _ => 1
+  };
+
+  for (key, _) in get_modifier_keywords(name) {
+    match key {
+      | "cjk_narrow" => width = 1,
+      | "cjk_wide" => width = 2,
+      | _ => ()
+    }
+  }
+
+  width
+}
+
+impl<'a> CtypeObject<'a> {
+  /// Checks whether a character belongs to one of the standard classes,
+  /// from `WCTYPE_ALNUM` to `WCTYPE_XDIGIT`.
+  pub fn is_class(
+    &self,
+    class: wctype_t,
+    c: u32
+  ) -> bool {
+    if let Some(classes) = &self.classes {
+      if let Some(result) = classes.contains(class, c) {
+        return result;
+      }
+      // Letters and digits make up alnum, which sources can not list
+      if class == wctype::WCTYPE_ALNUM &&
+        (classes.defines(wctype::WCTYPE_ALPHA) ||
+          classes.defines(wctype::WCTYPE_DIGIT))
+      {
+        return self.is_class(wctype::WCTYPE_ALPHA, c) ||
+          self.is_class(wctype::WCTYPE_DIGIT, c);
+      }
+    }
+
+    let is = match class {
+      | wctype::WCTYPE_ALNUM => self.casemap.isalnum,
+      | wctype::WCTYPE_ALPHA => self.casemap.isalpha,
+      | wctype::WCTYPE_BLANK => self.casemap.isblank,
+      | wctype::WCTYPE_CNTRL => self.casemap.iscntrl,
+      | wctype::WCTYPE_DIGIT => self.casemap.isdigit,
+      | wctype::WCTYPE_GRAPH => self.casemap.isgraph,
+      | wctype::WCTYPE_LOWER => self.casemap.islower,
+      | wctype::WCTYPE_PRINT => self.casemap.isprint,
+      | wctype::WCTYPE_PUNCT => self.casemap.ispunct,
+      | wctype::WCTYPE_SPACE => self.casemap.isspace,
+      | wctype::WCTYPE_UPPER => self.casemap.isupper,
+      | wctype::WCTYPE_XDIGIT => self.casemap.isxdigit,
+      | _ => return false
+    };
+
+    is(c)
+  }
+
+  #[inline]
+  pub fn tolower(
+    &self,
+    c: u32
+  ) -> u32 {
+    match self.classes.as_ref().and_then(|classes| classes.tolower(c)) {
+      | Some(lower) => lower,
+      | None => (self.casemap.tolower)(c)
+    }
+  }
+
+  #[inline]
+  pub fn toupper(
+    &self,
+    c: u32
+  ) -> u32 {
+    match self.classes.as_ref().and_then(|classes| classes.toupper(c)) {
+      | Some(upper) => upper,
+      | None => (self.casemap.toupper)(c)
+    }
+  }
+
+  /// Returns the glibc compatible classification and case tables.
+  #[inline]
+  pub fn glibc_tables(&self) -> &'static glibc::CtypeTables {
+    self.tables.unwrap_or_else(glibc::get_default_tables)
+  }
+
+  /// Returns the number of columns needed to display a wide character, or -1
+  /// if it is not printable.
+  #[inline]
+  pub fn wcwidth(
+    &self,
+    c: u32
+  ) -> i32 {
+    (self.converter.wcwidth)(c, self.ambiguous_width)
+  }
+
+  /// Returns the number of columns needed to display a string of wide
+  /// characters, measured per extended grapheme cluster, or -1 if it is not
+  /// printable.
+  pub fn wcsgwidth(
+    &self,
+    mut s: &[u32]
+  ) -> i32 {
+    let mut width = 0;
+
+    while !s.is_empty() {
+      let len = grapheme::cluster_len(s);
+      let (cluster, rest) = s.split_at(len);
+
+      // A cluster is as wide as its base character, unless a variation
+      // selector asks for emoji or text presentation
+      let mut w = self.wcwidth(cluster[0]);
+      if w < 0 {
+        return -1;
+      }
+      if cluster[1..].contains(&0xfe0f) {
+        w = 2;
+      } else if cluster[1..].contains(&0xfe0e) {
+        w = w.min(1);
+      }
+
+      width += w;
+      s = rest;
+    }
+
+    width
+  }
+
+  /// Decodes a multibyte string in the locale codeset. Invalid or incomplete
+  /// sequences fail with `EILSEQ`.
+  pub fn decode(
+    &self,
+    mut s: &[u8]
+  ) -> Result<String, c_int> {
+    let mut result = String::with_capacity(s.len());
+    let mut mb = MBState::new();
+
+    while !s.is_empty() {
+      let mut c32: char32_t = 0;
+      let ret = (self.converter.mbtoc32)(&mut c32, s, &mut mb);
+      if ret <= 0 {
+        return Err(errno::EILSEQ);
+      }
+
+      result.push(char::from_u32(c32).ok_or(errno::EILSEQ)?);
+      s = &s[ret as usize..];
+    }
+
+    Ok(result)
+  }
+
+  /// Decodes a multibyte string in the locale codeset one character at a
+  /// time, along with the offset each character starts at. A byte that does
+  /// not start a valid sequence comes out on its own as an error.
+  pub fn decode_chars<'s>(
+    &'s self,
+    mut s: &'s [u8]
+  ) -> impl Iterator<Item = (usize, Result<char, u8>)> + 's {
+    let mut offset = 0;
+
+    core::iter::from_fn(move || {
+      let &first = s.first()?;
+      let mut c32: char32_t = 0;
+      let mut mb = MBState::new();
+
+      let ret = (self.converter.mbtoc32)(&mut c32, s, &mut mb);
+      let (unit, len) = match char::from_u32(c32) {
+        | Some(c) if ret > 0 => (Ok(c), ret as usize),
+        | _ => (Err(first), 1)
+      };
+
+      let start = offset;
+      offset += len;
+      s = &s[len..];
+
+      Some((start, unit))
+    })
+  }
+
+  /// Encodes a string into the locale codeset. Characters that the codeset
+  /// can not represent fail with `EILSEQ`.
+  pub fn encode(
+    &self,
+    s: &str
+  ) -> Result<Vec<u8>, c_int> {
+    let mut result = Vec::with_capacity(s.len());
+    let mut buf = [0u8; 4];
+
+    for c in s.chars() {
+      let ret = (self.converter.c32tomb)(&mut buf, c as char32_t);
+      if ret < 0 {
+        return Err(errno::EILSEQ);
+      }
+
+      result.extend_from_slice(&buf[..ret as usize]);
+    }
+
+    Ok(result)
+  }
+
+  #[cfg(feature = "casemap")]
+  fn set_language(
+    &mut self,
+    lang: &str,
+    name: &str
+  ) -> Result<(), c_int> {
+    let language =
+      LanguageIdentifier::try_from_str(&super::canonicalize_locale(lang))
+        .unwrap_or(LanguageIdentifier::UNKNOWN);
+
+    self.ambiguous_width = get_ambiguous_width(&language, name);
+    self.casemap = casemap::icu::casemap_for_language(language);
+
+    Ok(())
+  }
+
+  // Languages other than C need the ICU case mapping
+  #[cfg(not(feature = "casemap"))]
+  fn set_language(
+    &mut self,
+    _: &str,
+    _: &str
+  ) -> Result<(), c_int> {
+    Err(errno::ENOENT)
+  }
 }
 
 impl<'a> LocaleObject for CtypeObject<'a> {
@@ -26,11 +279,14 @@ impl<'a> LocaleObject for CtypeObject<'a> {
       return Ok(self.set_to_posix());
     }
 
+    self.classes = None;
+
     // Special case: en_US but with ASCII only
     if name == "en_US" {
       self.name = Cow::Owned(locale.to_owned());
       self.casemap = casemap::ascii::CASEMAP_ASCII;
       self.converter = converter::ascii::CONVERTER_ASCII;
+      self.tables = None;
 
       return Ok(self.name.as_ref());
     }
@@ -41,8 +297,9 @@ impl<'a> LocaleObject for CtypeObject<'a> {
       // Handle locales such as C.UTF-8 and POSIX.UTF-8
       if lang == "C" || lang == "POSIX" || lang.is_empty() {
         self.casemap = casemap::ascii::CASEMAP_ASCII;
+        self.ambiguous_width = 1;
       } else {
-        self.casemap = casemap::icu::CASEMAP_ICU;
+        self.set_language(lang, name)?;
       }
     }
     if let Some(codeset) = parts.next() {
@@ -50,6 +307,7 @@ impl<'a> LocaleObject for CtypeObject<'a> {
         if c.name == codeset {
           self.name = Cow::Owned(locale.to_owned());
           self.converter = c.converter;
+          self.tables = Some(glibc::get_tables(self));
 
           return Ok(self.name.as_ref());
         }
@@ -68,6 +326,16 @@ impl<'a> LocaleObject for CtypeObject<'a> {
   fn get_name(&self) -> &ffi::CStr {
     self.name.as_ref()
   }
+
+  fn load_source(
+    &mut self,
+    locale: &ffi::CStr,
+    source: &Source
+  ) -> Result<&ffi::CStr, c_int> {
+    localedef::load(self, locale, source)?;
+
+    Ok(self.name.as_ref())
+  }
 }
 
 impl<'a> Default for CtypeObject<'a> {
@@ -79,5 +347,8 @@ impl<'a> Default for CtypeObject<'a> {
 pub const DEFAULT_CTYPE: CtypeObject = CtypeObject {
   name: Cow::Borrowed(c"C"),
   casemap: casemap::ascii::CASEMAP_ASCII,
-  converter: converter::ascii::CONVERTER_ASCII
+  converter: converter::ascii::CONVERTER_ASCII,
+  ambiguous_width: 1,
+  classes: None,
+  tables: None
 };
@@ -0,0 +1,266 @@
+use {super::unicode_data, core::cmp::Ordering};
+
+// Character classes and display widths of every code point, packed into one
+// u16 each and served from a two-stage table. The table is built once from
+// the ICU property data and the built-in width ranges, so classification
+// costs a single lookup instead of a property query per call. Builds without
+// the ICU case mapping have no classes beyond ASCII and only need widths,
+// which are looked up in the ranges directly.
+
+pub const CLASS_ALNUM: u16 = 1 << 0;
+pub const CLASS_ALPHA: u16 = 1 << 1;
+pub const CLASS_BLANK: u16 = 1 << 2;
+pub const CLASS_CNTRL: u16 = 1 << 3;
+pub const CLASS_DIGIT: u16 = 1 << 4;
+pub const CLASS_GRAPH: u16 = 1 << 5;
+pub const CLASS_LOWER: u16 = 1 << 6;
+pub const CLASS_PRINT: u16 = 1 << 7;
+pub const CLASS_PUNCT: u16 = 1 << 8;
+pub const CLASS_SPACE: u16 = 1 << 9;
+pub const CLASS_UPPER: u16 = 1 << 10;
+pub const CLASS_XDIGIT: u16 = 1 << 11;
+
+const WIDTH_SHIFT: u32 = 12;
+const WIDTH_MASK: u16 = 0x7 << WIDTH_SHIFT;
+
+pub const WIDTH_ZERO: u16 = 0;
+pub const WIDTH_ONE: u16 = 1;
+pub const WIDTH_TWO: u16 = 2;
+pub const WIDTH_AMBIGUOUS: u16 = 3;
+pub const WIDTH_NONPRINTABLE: u16 = 4;
+
+const BLOCK_SHIFT: u32 = 8;
+const BLOCK_SIZE: usize = 1 << BLOCK_SHIFT;
+const CODE_POINTS: usize = 0x110000;
+
+// Same values as the full table, so ASCII never has to build it
+#[rustfmt::skip]
+const ASCII: [u16; 128] = [
+  0x0008, 0x4008, 0x4008, 0x4008, 0x4008, 0x4008, 0x4008, 0x4008,
+  0x4008, 0x420c, 0x4208, 0x4208, 0x4208, 0x4208, 0x4008, 0x4008,
+  0x4008, 0x4008, 0x4008, 0x4008, 0x4008, 0x4008, 0x4008, 0x4008,
+  0x4008, 0x4008, 0x4008, 0x4008, 0x4008, 0x4008, 0x4008, 0x4008,
+  0x1284, 0x11a0, 0x11a0, 0x11a0, 0x11a0, 0x11a0, 0x11a0, 0x11a0,
+  0x11a0, 0x11a0, 0x11a0, 0x11a0, 0x11a0, 0x11a0, 0x11a0, 0x11a0,
+  0x18b1, 0x18b1, 0x18b1, 0x18b1, 0x18b1, 0x18b1, 0x18b1, 0x18b1,
+  0x18b1, 0x18b1, 0x11a0, 0x11a0, 0x11a0, 0x11a0, 0x11a0, 0x11a0,
+  0x11a0, 0x1ca3, 0x1ca3, 0x1ca3, 0x1ca3, 0x1ca3, 0x1ca3, 0x14a3,
+  0x14a3, 0x14a3, 0x14a3, 0x14a3, 0x14a3, 0x14a3, 0x14a3, 0x14a3,
+  0x14a3, 0x14a3, 0x14a3, 0x14a3, 0x14a3, 0x14a3, 0x14a3, 0x14a3,
+  0x14a3, 0x14a3, 0x14a3, 0x11a0, 0x11a0, 0x11a0, 0x11a0, 0x11a0,
+  0x11a0, 0x18e3, 0x18e3, 0x18e3, 0x18e3, 0x18e3, 0x18e3, 0x10e3,
+  0x10e3, 0x10e3, 0x10e3, 0x10e3, 0x10e3, 0x10e3, 0x10e3, 0x10e3,
+  0x10e3, 0x10e3, 0x10e3, 0x10e3, 0x10e3, 0x10e3, 0x10e3, 0x10e3,
+  0x10e3, 0x10e3, 0x10e3, 0x11a0, 0x11a0, 0x11a0, 0x11a0, 0x4008
+];
+
+/// Returns the packed classes and width of a code point.
+#[inline]
+pub fn lookup(c: u32) -> u16 {
+  if c < 0x80 {
+    return ASCII[c as usize];
+  }
+  if c as usize >= CODE_POINTS {
+    return WIDTH_NONPRINTABLE << WIDTH_SHIFT;
+  }
+
+  lookup_table(c)
+}
+
+#[inline]
+pub fn width(properties: u16) -> u16 {
+  (properties & WIDTH_MASK) >> WIDTH_SHIFT
+}
+
+#[inline]
+fn compare_range(
+  start: u32,
+  end: u32,
+  c: u32
+) -> Ordering {
+  if end < c {
+    Ordering::Less
+  } else if start > c {
+    Ordering::Greater
+  } else {
+    Ordering::Equal
+  }
+}
+
+/// Finds the value of the range containing `c` in a sorted range table.
+pub fn search<T: Copy>(
+  ranges: &[(u32, u32, T)],
+  c: u32
+) -> Option<T> {
+  ranges
+    .binary_search_by(|&(start, end, _)| compare_range(start, end, c))
+    .ok()
+    .map(|i| ranges[i].2)
+}
+
+/// Checks whether `c` is in a sorted range set.
+pub fn contains(
+  ranges: &[(u32, u32)],
+  c: u32
+) -> bool {
+  ranges.binary_search_by(|&(start, end)| compare_range(start, end, c)).is_ok()
+}
+
+#[cfg(not(feature = "casemap"))]
+#[inline]
+fn lookup_table(c: u32) -> u16 {
+  search(&unicode_data::WIDTHS, c).unwrap_or(WIDTH_ONE) << WIDTH_SHIFT
+}
+
+#[cfg(feature = "casemap")]
+use icu::lookup as lookup_table;
+
+#[cfg(feature = "casemap")]
+mod icu {
+  use {
+    super::*,
+    allocation::{
+      collections::BTreeMap,
+      vec::{self, Vec}
+    },
+    core::ops::RangeInclusive,
+    icu_properties::{CodePointMapData, CodePointSetData, props::*},
+    once_cell::sync::OnceCell
+  };
+
+  pub struct PropertyTable {
+    index: Vec<u16>,
+    blocks: Vec<u16>
+  }
+
+  static TABLE: OnceCell<PropertyTable> = OnceCell::new();
+
+  #[inline]
+  pub fn lookup(c: u32) -> u16 {
+    let table = TABLE.get_or_init(build);
+    let block = table.index[(c >> BLOCK_SHIFT) as usize] as usize;
+
+    table.blocks[(block << BLOCK_SHIFT) | (c as usize & (BLOCK_SIZE - 1))]
+  }
+
+  fn paint(
+    flat: &mut [u16],
+    range: RangeInclusive<u32>,
+    f: impl Fn(u16) -> u16
+  ) {
+    let end = (*range.end() as usize).min(CODE_POINTS - 1);
+
+    for v in &mut flat[*range.start() as usize..=end] {
+      *v = f(*v);
+    }
+  }
+
+  fn set_class(
+    flat: &mut [u16],
+    ranges: impl Iterator<Item = RangeInclusive<u32>>,
+    class: u16
+  ) {
+    for range in ranges {
+      paint(flat, range, |v| v | class);
+    }
+  }
+
+  fn set_width(
+    flat: &mut [u16],
+    ranges: impl Iterator<Item = RangeInclusive<u32>>,
+    width: u16
+  ) {
+    for range in ranges {
+      paint(flat, range, |v| (v & !WIDTH_MASK) | (width << WIDTH_SHIFT));
+    }
+  }
+
+  fn build() -> PropertyTable {
+    let mut flat = vec::from_elem(WIDTH_ONE << WIDTH_SHIFT, CODE_POINTS);
+    let gc = CodePointMapData::<GeneralCategory>::new();
+
+    set_class(
+      &mut flat,
+      CodePointSetData::new::<Alnum>().iter_ranges(),
+      CLASS_ALNUM
+    );
+    set_class(
+      &mut flat,
+      CodePointSetData::new::<Alphabetic>().iter_ranges(),
+      CLASS_ALPHA
+    );
+    set_class(
+      &mut flat,
+      CodePointSetData::new::<Blank>().iter_ranges(),
+      CLASS_BLANK
+    );
+    set_class(
+      &mut flat,
+      gc.iter_ranges_for_value(GeneralCategory::Control),
+      CLASS_CNTRL
+    );
+    set_class(
+      &mut flat,
+      gc.iter_ranges_for_value(GeneralCategory::DecimalNumber),
+      CLASS_DIGIT
+    );
+    set_class(
+      &mut flat,
+      CodePointSetData::new::<Graph>().iter_ranges(),
+      CLASS_GRAPH
+    );
+    set_class(
+      &mut flat,
+      CodePointSetData::new::<Lowercase>().iter_ranges(),
+      CLASS_LOWER
+    );
+    set_class(
+      &mut flat,
+      CodePointSetData::new::<Print>().iter_ranges(),
+      CLASS_PRINT
+    );
+    // POSIX punctuation is every graphic character that is not alphanumeric,
+    // so symbols such as `$` and `+` are punctuation too
+    let punct =
+      GeneralCategoryGroup::Punctuation.union(GeneralCategoryGroup::Symbol);
+    set_class(
+      &mut flat,
+      gc.iter_ranges().filter(|r| punct.contains(r.value)).map(|r| r.range),
+      CLASS_PUNCT
+    );
+    set_class(
+      &mut flat,
+      CodePointSetData::new::<WhiteSpace>().iter_ranges(),
+      CLASS_SPACE
+    );
+    set_class(
+      &mut flat,
+      CodePointSetData::new::<Uppercase>().iter_ranges(),
+      CLASS_UPPER
+    );
+    set_class(
+      &mut flat,
+      CodePointSetData::new::<Xdigit>().iter_ranges(),
+      CLASS_XDIGIT
+    );
+
+    for &(start, end, width) in unicode_data::WIDTHS.iter() {
+      set_width(&mut flat, core::iter::once(start..=end), width);
+    }
+
+    // Identical blocks are stored once
+    let mut index = Vec::with_capacity(CODE_POINTS / BLOCK_SIZE);
+    let mut blocks = Vec::new();
+    let mut seen = BTreeMap::<&[u16], u16>::new();
+
+    for block in flat.chunks(BLOCK_SIZE) {
+      let i = *seen.entry(block).or_insert_with(|| {
+        blocks.extend_from_slice(block);
+        (blocks.len() / BLOCK_SIZE - 1) as u16
+      });
+      index.push(i);
+    }
+
+    PropertyTable { index, blocks }
+  }
+}
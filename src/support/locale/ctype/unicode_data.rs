@@ -0,0 +1,1238 @@
+// Generated by tools/unicode_data.rs, do not edit.
+
+use {
+  super::table::{WIDTH_AMBIGUOUS, WIDTH_NONPRINTABLE, WIDTH_TWO, WIDTH_ZERO},
+  GraphemeBreak::*,
+  IndicConjunctBreak as InCB
+};
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum GraphemeBreak {
+  Other,
+  Control,
+  CR,
+  LF,
+  Extend,
+  Zwj,
+  RegionalIndicator,
+  Prepend,
+  SpacingMark,
+  L,
+  V,
+  T,
+  LV,
+  Lvt
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum IndicConjunctBreak {
+  None,
+  Consonant,
+  Linker,
+  Extend
+}
+
+// Code points that are not one column wide
+#[rustfmt::skip]
+pub static WIDTHS: [(u32, u32, u16); 756] = [
+  (0x0, 0x0, WIDTH_ZERO), (0x1, 0x1f, WIDTH_NONPRINTABLE),
+  (0x7f, 0x9f, WIDTH_NONPRINTABLE), (0xa1, 0xa1, WIDTH_AMBIGUOUS),
+  (0xa4, 0xa4, WIDTH_AMBIGUOUS), (0xa7, 0xa8, WIDTH_AMBIGUOUS),
+  (0xa9, 0xa9, WIDTH_TWO), (0xaa, 0xaa, WIDTH_AMBIGUOUS),
+  (0xad, 0xad, WIDTH_ZERO), (0xae, 0xae, WIDTH_TWO),
+  (0xb0, 0xb4, WIDTH_AMBIGUOUS), (0xb6, 0xba, WIDTH_AMBIGUOUS),
+  (0xbc, 0xbf, WIDTH_AMBIGUOUS), (0xc6, 0xc6, WIDTH_AMBIGUOUS),
+  (0xd0, 0xd0, WIDTH_AMBIGUOUS), (0xd7, 0xd8, WIDTH_AMBIGUOUS),
+  (0xde, 0xe1, WIDTH_AMBIGUOUS), (0xe6, 0xe6, WIDTH_AMBIGUOUS),
+  (0xe8, 0xea, WIDTH_AMBIGUOUS), (0xec, 0xed, WIDTH_AMBIGUOUS),
+  (0xf0, 0xf0, WIDTH_AMBIGUOUS), (0xf2, 0xf3, WIDTH_AMBIGUOUS),
+  (0xf7, 0xfa, WIDTH_AMBIGUOUS), (0xfc, 0xfc, WIDTH_AMBIGUOUS),
+  (0xfe, 0xfe, WIDTH_AMBIGUOUS), (0x101, 0x101, WIDTH_AMBIGUOUS),
+  (0x111, 0x111, WIDTH_AMBIGUOUS), (0x113, 0x113, WIDTH_AMBIGUOUS),
+  (0x11b, 0x11b, WIDTH_AMBIGUOUS), (0x126, 0x127, WIDTH_AMBIGUOUS),
+  (0x12b, 0x12b, WIDTH_AMBIGUOUS), (0x131, 0x133, WIDTH_AMBIGUOUS),
+  (0x138, 0x138, WIDTH_AMBIGUOUS), (0x13f, 0x142, WIDTH_AMBIGUOUS),
+  (0x144, 0x144, WIDTH_AMBIGUOUS), (0x148, 0x14b, WIDTH_AMBIGUOUS),
+  (0x14d, 0x14d, WIDTH_AMBIGUOUS), (0x152, 0x153, WIDTH_AMBIGUOUS),
+  (0x166, 0x167, WIDTH_AMBIGUOUS), (0x16b, 0x16b, WIDTH_AMBIGUOUS),
+  (0x1ce, 0x1ce, WIDTH_AMBIGUOUS), (0x1d0, 0x1d0, WIDTH_AMBIGUOUS),
+  (0x1d2, 0x1d2, WIDTH_AMBIGUOUS), (0x1d4, 0x1d4, WIDTH_AMBIGUOUS),
+  (0x1d6, 0x1d6, WIDTH_AMBIGUOUS), (0x1d8, 0x1d8, WIDTH_AMBIGUOUS),
+  (0x1da, 0x1da, WIDTH_AMBIGUOUS), (0x1dc, 0x1dc, WIDTH_AMBIGUOUS),
+  (0x251, 0x251, WIDTH_AMBIGUOUS), (0x261, 0x261, WIDTH_AMBIGUOUS),
+  (0x2c4, 0x2c4, WIDTH_AMBIGUOUS), (0x2c7, 0x2c7, WIDTH_AMBIGUOUS),
+  (0x2c9, 0x2cb, WIDTH_AMBIGUOUS), (0x2cd, 0x2cd, WIDTH_AMBIGUOUS),
+  (0x2d0, 0x2d0, WIDTH_AMBIGUOUS), (0x2d8, 0x2db, WIDTH_AMBIGUOUS),
+  (0x2dd, 0x2dd, WIDTH_AMBIGUOUS), (0x2df, 0x2df, WIDTH_AMBIGUOUS),
+  (0x300, 0x36f, WIDTH_ZERO), (0x391, 0x3a1, WIDTH_AMBIGUOUS),
+  (0x3a3, 0x3a9, WIDTH_AMBIGUOUS), (0x3b1, 0x3c1, WIDTH_AMBIGUOUS),
+  (0x3c3, 0x3c9, WIDTH_AMBIGUOUS), (0x401, 0x401, WIDTH_AMBIGUOUS),
+  (0x410, 0x44f, WIDTH_AMBIGUOUS), (0x451, 0x451, WIDTH_AMBIGUOUS),
+  (0x483, 0x489, WIDTH_ZERO), (0x591, 0x5bd, WIDTH_ZERO),
+  (0x5bf, 0x5bf, WIDTH_ZERO), (0x5c1, 0x5c2, WIDTH_ZERO),
+  (0x5c4, 0x5c5, WIDTH_ZERO), (0x5c7, 0x5c7, WIDTH_ZERO),
+  (0x610, 0x61a, WIDTH_ZERO), (0x61c, 0x61c, WIDTH_ZERO),
+  (0x64b, 0x65f, WIDTH_ZERO), (0x670, 0x670, WIDTH_ZERO),
+  (0x6d6, 0x6dc, WIDTH_ZERO), (0x6df, 0x6e4, WIDTH_ZERO),
+  (0x6e7, 0x6e8, WIDTH_ZERO), (0x6ea, 0x6ed, WIDTH_ZERO),
+  (0x711, 0x711, WIDTH_ZERO), (0x730, 0x74a, WIDTH_ZERO),
+  (0x7a6, 0x7b0, WIDTH_ZERO), (0x7eb, 0x7f3, WIDTH_ZERO),
+  (0x7fd, 0x7fd, WIDTH_ZERO), (0x816, 0x819, WIDTH_ZERO),
+  (0x81b, 0x823, WIDTH_ZERO), (0x825, 0x827, WIDTH_ZERO),
+  (0x829, 0x82d, WIDTH_ZERO), (0x859, 0x85b, WIDTH_ZERO),
+  (0x897, 0x89f, WIDTH_ZERO), (0x8ca, 0x8e1, WIDTH_ZERO),
+  (0x8e3, 0x902, WIDTH_ZERO), (0x93a, 0x93a, WIDTH_ZERO),
+  (0x93c, 0x93c, WIDTH_ZERO), (0x941, 0x948, WIDTH_ZERO),
+  (0x94d, 0x94d, WIDTH_ZERO), (0x951, 0x957, WIDTH_ZERO),
+  (0x962, 0x963, WIDTH_ZERO), (0x981, 0x981, WIDTH_ZERO),
+  (0x9bc, 0x9bc, WIDTH_ZERO), (0x9be, 0x9be, WIDTH_ZERO),
+  (0x9c1, 0x9c4, WIDTH_ZERO), (0x9cd, 0x9cd, WIDTH_ZERO),
+  (0x9d7, 0x9d7, WIDTH_ZERO), (0x9e2, 0x9e3, WIDTH_ZERO),
+  (0x9fe, 0x9fe, WIDTH_ZERO), (0xa01, 0xa02, WIDTH_ZERO),
+  (0xa3c, 0xa3c, WIDTH_ZERO), (0xa41, 0xa42, WIDTH_ZERO),
+  (0xa47, 0xa48, WIDTH_ZERO), (0xa4b, 0xa4d, WIDTH_ZERO),
+  (0xa51, 0xa51, WIDTH_ZERO), (0xa70, 0xa71, WIDTH_ZERO),
+  (0xa75, 0xa75, WIDTH_ZERO), (0xa81, 0xa82, WIDTH_ZERO),
+  (0xabc, 0xabc, WIDTH_ZERO), (0xac1, 0xac5, WIDTH_ZERO),
+  (0xac7, 0xac8, WIDTH_ZERO), (0xacd, 0xacd, WIDTH_ZERO),
+  (0xae2, 0xae3, WIDTH_ZERO), (0xafa, 0xaff, WIDTH_ZERO),
+  (0xb01, 0xb01, WIDTH_ZERO), (0xb3c, 0xb3c, WIDTH_ZERO),
+  (0xb3e, 0xb3f, WIDTH_ZERO), (0xb41, 0xb44, WIDTH_ZERO),
+  (0xb4d, 0xb4d, WIDTH_ZERO), (0xb55, 0xb57, WIDTH_ZERO),
+  (0xb62, 0xb63, WIDTH_ZERO), (0xb82, 0xb82, WIDTH_ZERO),
+  (0xbbe, 0xbbe, WIDTH_ZERO), (0xbc0, 0xbc0, WIDTH_ZERO),
+  (0xbcd, 0xbcd, WIDTH_ZERO), (0xbd7, 0xbd7, WIDTH_ZERO),
+  (0xc00, 0xc00, WIDTH_ZERO), (0xc04, 0xc04, WIDTH_ZERO),
+  (0xc3c, 0xc3c, WIDTH_ZERO), (0xc3e, 0xc40, WIDTH_ZERO),
+  (0xc46, 0xc48, WIDTH_ZERO), (0xc4a, 0xc4d, WIDTH_ZERO),
+  (0xc55, 0xc56, WIDTH_ZERO), (0xc62, 0xc63, WIDTH_ZERO),
+  (0xc81, 0xc81, WIDTH_ZERO), (0xcbc, 0xcbc, WIDTH_ZERO),
+  (0xcbf, 0xcc0, WIDTH_ZERO), (0xcc2, 0xcc2, WIDTH_ZERO),
+  (0xcc6, 0xcc8, WIDTH_ZERO), (0xcca, 0xccd, WIDTH_ZERO),
+  (0xcd5, 0xcd6, WIDTH_ZERO), (0xce2, 0xce3, WIDTH_ZERO),
+  (0xd00, 0xd01, WIDTH_ZERO), (0xd3b, 0xd3c, WIDTH_ZERO),
+  (0xd3e, 0xd3e, WIDTH_ZERO), (0xd41, 0xd44, WIDTH_ZERO),
+  (0xd4d, 0xd4d, WIDTH_ZERO), (0xd57, 0xd57, WIDTH_ZERO),
+  (0xd62, 0xd63, WIDTH_ZERO), (0xd81, 0xd81, WIDTH_ZERO),
+  (0xdca, 0xdca, WIDTH_ZERO), (0xdcf, 0xdcf, WIDTH_ZERO),
+  (0xdd2, 0xdd4, WIDTH_ZERO), (0xdd6, 0xdd6, WIDTH_ZERO),
+  (0xddf, 0xddf, WIDTH_ZERO), (0xe31, 0xe31, WIDTH_ZERO),
+  (0xe34, 0xe3a, WIDTH_ZERO), (0xe47, 0xe4e, WIDTH_ZERO),
+  (0xeb1, 0xeb1, WIDTH_ZERO), (0xeb4, 0xebc, WIDTH_ZERO),
+  (0xec8, 0xece, WIDTH_ZERO), (0xf18, 0xf19, WIDTH_ZERO),
+  (0xf35, 0xf35, WIDTH_ZERO), (0xf37, 0xf37, WIDTH_ZERO),
+  (0xf39, 0xf39, WIDTH_ZERO), (0xf71, 0xf7e, WIDTH_ZERO),
+  (0xf80, 0xf84, WIDTH_ZERO), (0xf86, 0xf87, WIDTH_ZERO),
+  (0xf8d, 0xf97, WIDTH_ZERO), (0xf99, 0xfbc, WIDTH_ZERO),
+  (0xfc6, 0xfc6, WIDTH_ZERO), (0x102d, 0x1030, WIDTH_ZERO),
+  (0x1032, 0x1037, WIDTH_ZERO), (0x1039, 0x103a, WIDTH_ZERO),
+  (0x103d, 0x103e, WIDTH_ZERO), (0x1058, 0x1059, WIDTH_ZERO),
+  (0x105e, 0x1060, WIDTH_ZERO), (0x1071, 0x1074, WIDTH_ZERO),
+  (0x1082, 0x1082, WIDTH_ZERO), (0x1085, 0x1086, WIDTH_ZERO),
+  (0x108d, 0x108d, WIDTH_ZERO), (0x109d, 0x109d, WIDTH_ZERO),
+  (0x1100, 0x115e, WIDTH_TWO), (0x115f, 0x11ff, WIDTH_ZERO),
+  (0x135d, 0x135f, WIDTH_ZERO), (0x1712, 0x1715, WIDTH_ZERO),
+  (0x1732, 0x1734, WIDTH_ZERO), (0x1752, 0x1753, WIDTH_ZERO),
+  (0x1772, 0x1773, WIDTH_ZERO), (0x17b4, 0x17b5, WIDTH_ZERO),
+  (0x17b7, 0x17bd, WIDTH_ZERO), (0x17c6, 0x17c6, WIDTH_ZERO),
+  (0x17c9, 0x17d3, WIDTH_ZERO), (0x17dd, 0x17dd, WIDTH_ZERO),
+  (0x180b, 0x180f, WIDTH_ZERO), (0x1885, 0x1886, WIDTH_ZERO),
+  (0x18a9, 0x18a9, WIDTH_ZERO), (0x1920, 0x1922, WIDTH_ZERO),
+  (0x1927, 0x1928, WIDTH_ZERO), (0x1932, 0x1932, WIDTH_ZERO),
+  (0x1939, 0x193b, WIDTH_ZERO), (0x1a17, 0x1a18, WIDTH_ZERO),
+  (0x1a1b, 0x1a1b, WIDTH_ZERO), (0x1a56, 0x1a56, WIDTH_ZERO),
+  (0x1a58, 0x1a5e, WIDTH_ZERO), (0x1a60, 0x1a60, WIDTH_ZERO),
+  (0x1a62, 0x1a62, WIDTH_ZERO), (0x1a65, 0x1a6c, WIDTH_ZERO),
+  (0x1a73, 0x1a7c, WIDTH_ZERO), (0x1a7f, 0x1a7f, WIDTH_ZERO),
+  (0x1ab0, 0x1add, WIDTH_ZERO), (0x1ae0, 0x1aeb, WIDTH_ZERO),
+  (0x1b00, 0x1b03, WIDTH_ZERO), (0x1b34, 0x1b3d, WIDTH_ZERO),
+  (0x1b42, 0x1b44, WIDTH_ZERO), (0x1b6b, 0x1b73, WIDTH_ZERO),
+  (0x1b80, 0x1b81, WIDTH_ZERO), (0x1ba2, 0x1ba5, WIDTH_ZERO),
+  (0x1ba8, 0x1bad, WIDTH_ZERO), (0x1be6, 0x1be6, WIDTH_ZERO),
+  (0x1be8, 0x1be9, WIDTH_ZERO), (0x1bed, 0x1bed, WIDTH_ZERO),
+  (0x1bef, 0x1bf3, WIDTH_ZERO), (0x1c2c, 0x1c33, WIDTH_ZERO),
+  (0x1c36, 0x1c37, WIDTH_ZERO), (0x1cd0, 0x1cd2, WIDTH_ZERO),
+  (0x1cd4, 0x1ce0, WIDTH_ZERO), (0x1ce2, 0x1ce8, WIDTH_ZERO),
+  (0x1ced, 0x1ced, WIDTH_ZERO), (0x1cf4, 0x1cf4, WIDTH_ZERO),
+  (0x1cf8, 0x1cf9, WIDTH_ZERO), (0x1dc0, 0x1dff, WIDTH_ZERO),
+  (0x200b, 0x200f, WIDTH_ZERO), (0x2010, 0x2010, WIDTH_AMBIGUOUS),
+  (0x2013, 0x2016, WIDTH_AMBIGUOUS), (0x2018, 0x2019, WIDTH_AMBIGUOUS),
+  (0x201c, 0x201d, WIDTH_AMBIGUOUS), (0x2020, 0x2022, WIDTH_AMBIGUOUS),
+  (0x2024, 0x2027, WIDTH_AMBIGUOUS), (0x202a, 0x202e, WIDTH_ZERO),
+  (0x2030, 0x2030, WIDTH_AMBIGUOUS), (0x2032, 0x2033, WIDTH_AMBIGUOUS),
+  (0x2035, 0x2035, WIDTH_AMBIGUOUS), (0x203b, 0x203b, WIDTH_AMBIGUOUS),
+  (0x203c, 0x203c, WIDTH_TWO), (0x203e, 0x203e, WIDTH_AMBIGUOUS),
+  (0x2049, 0x2049, WIDTH_TWO), (0x2060, 0x206f, WIDTH_ZERO),
+  (0x2074, 0x2074, WIDTH_AMBIGUOUS), (0x207f, 0x207f, WIDTH_AMBIGUOUS),
+  (0x2081, 0x2084, WIDTH_AMBIGUOUS), (0x20ac, 0x20ac, WIDTH_AMBIGUOUS),
+  (0x20d0, 0x20f0, WIDTH_ZERO), (0x2103, 0x2103, WIDTH_AMBIGUOUS),
+  (0x2105, 0x2105, WIDTH_AMBIGUOUS), (0x2109, 0x2109, WIDTH_AMBIGUOUS),
+  (0x2113, 0x2113, WIDTH_AMBIGUOUS), (0x2116, 0x2116, WIDTH_AMBIGUOUS),
+  (0x2121, 0x2121, WIDTH_AMBIGUOUS), (0x2122, 0x2122, WIDTH_TWO),
+  (0x2126, 0x2126, WIDTH_AMBIGUOUS), (0x212b, 0x212b, WIDTH_AMBIGUOUS),
+  (0x2139, 0x2139, WIDTH_TWO), (0x2153, 0x2154, WIDTH_AMBIGUOUS),
+  (0x215b, 0x215e, WIDTH_AMBIGUOUS), (0x2160, 0x216b, WIDTH_AMBIGUOUS),
+  (0x2170, 0x2179, WIDTH_AMBIGUOUS), (0x2189, 0x2189, WIDTH_AMBIGUOUS),
+  (0x2190, 0x2193, WIDTH_AMBIGUOUS), (0x2194, 0x2199, WIDTH_TWO),
+  (0x21a9, 0x21aa, WIDTH_TWO), (0x21b8, 0x21b9, WIDTH_AMBIGUOUS),
+  (0x21d2, 0x21d2, WIDTH_AMBIGUOUS), (0x21d4, 0x21d4, WIDTH_AMBIGUOUS),
+  (0x21e7, 0x21e7, WIDTH_AMBIGUOUS), (0x2200, 0x2200, WIDTH_AMBIGUOUS),
+  (0x2202, 0x2203, WIDTH_AMBIGUOUS), (0x2207, 0x2208, WIDTH_AMBIGUOUS),
+  (0x220b, 0x220b, WIDTH_AMBIGUOUS), (0x220f, 0x220f, WIDTH_AMBIGUOUS),
+  (0x2211, 0x2211, WIDTH_AMBIGUOUS), (0x2215, 0x2215, WIDTH_AMBIGUOUS),
+  (0x221a, 0x221a, WIDTH_AMBIGUOUS), (0x221d, 0x2220, WIDTH_AMBIGUOUS),
+  (0x2223, 0x2223, WIDTH_AMBIGUOUS), (0x2225, 0x2225, WIDTH_AMBIGUOUS),
+  (0x2227, 0x222c, WIDTH_AMBIGUOUS), (0x222e, 0x222e, WIDTH_AMBIGUOUS),
+  (0x2234, 0x2237, WIDTH_AMBIGUOUS), (0x223c, 0x223d, WIDTH_AMBIGUOUS),
+  (0x2248, 0x2248, WIDTH_AMBIGUOUS), (0x224c, 0x224c, WIDTH_AMBIGUOUS),
+  (0x2252, 0x2252, WIDTH_AMBIGUOUS), (0x2260, 0x2261, WIDTH_AMBIGUOUS),
+  (0x2264, 0x2267, WIDTH_AMBIGUOUS), (0x226a, 0x226b, WIDTH_AMBIGUOUS),
+  (0x226e, 0x226f, WIDTH_AMBIGUOUS), (0x2282, 0x2283, WIDTH_AMBIGUOUS),
+  (0x2286, 0x2287, WIDTH_AMBIGUOUS), (0x2295, 0x2295, WIDTH_AMBIGUOUS),
+  (0x2299, 0x2299, WIDTH_AMBIGUOUS), (0x22a5, 0x22a5, WIDTH_AMBIGUOUS),
+  (0x22bf, 0x22bf, WIDTH_AMBIGUOUS), (0x2312, 0x2312, WIDTH_AMBIGUOUS),
+  (0x231a, 0x231b, WIDTH_TWO), (0x2328, 0x232a, WIDTH_TWO),
+  (0x23cf, 0x23cf, WIDTH_TWO), (0x23e9, 0x23f3, WIDTH_TWO),
+  (0x23f8, 0x23fa, WIDTH_TWO), (0x2460, 0x24c1, WIDTH_AMBIGUOUS),
+  (0x24c2, 0x24c2, WIDTH_TWO), (0x24c3, 0x24e9, WIDTH_AMBIGUOUS),
+  (0x24eb, 0x254b, WIDTH_AMBIGUOUS), (0x2550, 0x2573, WIDTH_AMBIGUOUS),
+  (0x2580, 0x258f, WIDTH_AMBIGUOUS), (0x2592, 0x2595, WIDTH_AMBIGUOUS),
+  (0x25a0, 0x25a1, WIDTH_AMBIGUOUS), (0x25a3, 0x25a9, WIDTH_AMBIGUOUS),
+  (0x25aa, 0x25ab, WIDTH_TWO), (0x25b2, 0x25b3, WIDTH_AMBIGUOUS),
+  (0x25b6, 0x25b6, WIDTH_TWO), (0x25b7, 0x25b7, WIDTH_AMBIGUOUS),
+  (0x25bc, 0x25bd, WIDTH_AMBIGUOUS), (0x25c0, 0x25c0, WIDTH_TWO),
+  (0x25c1, 0x25c1, WIDTH_AMBIGUOUS), (0x25c6, 0x25c8, WIDTH_AMBIGUOUS),
+  (0x25cb, 0x25cb, WIDTH_AMBIGUOUS), (0x25ce, 0x25d1, WIDTH_AMBIGUOUS),
+  (0x25e2, 0x25e5, WIDTH_AMBIGUOUS), (0x25ef, 0x25ef, WIDTH_AMBIGUOUS),
+  (0x25fb, 0x25fe, WIDTH_TWO), (0x2600, 0x2604, WIDTH_TWO),
+  (0x2605, 0x2606, WIDTH_AMBIGUOUS), (0x2609, 0x2609, WIDTH_AMBIGUOUS),
+  (0x260e, 0x260e, WIDTH_TWO), (0x260f, 0x260f, WIDTH_AMBIGUOUS),
+  (0x2611, 0x2611, WIDTH_TWO), (0x2614, 0x2615, WIDTH_TWO),
+  (0x2618, 0x2618, WIDTH_TWO), (0x261c, 0x261c, WIDTH_AMBIGUOUS),
+  (0x261d, 0x261d, WIDTH_TWO), (0x261e, 0x261e, WIDTH_AMBIGUOUS),
+  (0x2620, 0x2620, WIDTH_TWO), (0x2622, 0x2623, WIDTH_TWO),
+  (0x2626, 0x2626, WIDTH_TWO), (0x262a, 0x262a, WIDTH_TWO),
+  (0x262e, 0x263a, WIDTH_TWO), (0x2640, 0x2640, WIDTH_TWO),
+  (0x2642, 0x2642, WIDTH_TWO), (0x2648, 0x2653, WIDTH_TWO),
+  (0x265f, 0x2660, WIDTH_TWO), (0x2661, 0x2661, WIDTH_AMBIGUOUS),
+  (0x2663, 0x2663, WIDTH_TWO), (0x2664, 0x2664, WIDTH_AMBIGUOUS),
+  (0x2665, 0x2666, WIDTH_TWO), (0x2667, 0x2667, WIDTH_AMBIGUOUS),
+  (0x2668, 0x2668, WIDTH_TWO), (0x2669, 0x266a, WIDTH_AMBIGUOUS),
+  (0x266c, 0x266d, WIDTH_AMBIGUOUS), (0x266f, 0x266f, WIDTH_AMBIGUOUS),
+  (0x267b, 0x267b, WIDTH_TWO), (0x267e, 0x267f, WIDTH_TWO),
+  (0x268a, 0x268f, WIDTH_TWO), (0x2692, 0x2697, WIDTH_TWO),
+  (0x2699, 0x2699, WIDTH_TWO), (0x269b, 0x269c, WIDTH_TWO),
+  (0x269e, 0x269f, WIDTH_AMBIGUOUS), (0x26a0, 0x26a1, WIDTH_TWO),
+  (0x26a7, 0x26a7, WIDTH_TWO), (0x26aa, 0x26ab, WIDTH_TWO),
+  (0x26b0, 0x26b1, WIDTH_TWO), (0x26bd, 0x26be, WIDTH_TWO),
+  (0x26bf, 0x26bf, WIDTH_AMBIGUOUS), (0x26c4, 0x26c5, WIDTH_TWO),
+  (0x26c6, 0x26c7, WIDTH_AMBIGUOUS), (0x26c8, 0x26c8, WIDTH_TWO),
+  (0x26c9, 0x26cd, WIDTH_AMBIGUOUS), (0x26ce, 0x26cf, WIDTH_TWO),
+  (0x26d0, 0x26d0, WIDTH_AMBIGUOUS), (0x26d1, 0x26d1, WIDTH_TWO),
+  (0x26d2, 0x26d2, WIDTH_AMBIGUOUS), (0x26d3, 0x26d4, WIDTH_TWO),
+  (0x26d5, 0x26e1, WIDTH_AMBIGUOUS), (0x26e3, 0x26e3, WIDTH_AMBIGUOUS),
+  (0x26e8, 0x26e8, WIDTH_AMBIGUOUS), (0x26e9, 0x26ea, WIDTH_TWO),
+  (0x26eb, 0x26ef, WIDTH_AMBIGUOUS), (0x26f0, 0x26f5, WIDTH_TWO),
+  (0x26f6, 0x26f6, WIDTH_AMBIGUOUS), (0x26f7, 0x26fa, WIDTH_TWO),
+  (0x26fb, 0x26fc, WIDTH_AMBIGUOUS), (0x26fd, 0x26fd, WIDTH_TWO),
+  (0x26fe, 0x26ff, WIDTH_AMBIGUOUS), (0x2702, 0x2702, WIDTH_TWO),
+  (0x2705, 0x2705, WIDTH_TWO), (0x2708, 0x270d, WIDTH_TWO),
+  (0x270f, 0x270f, WIDTH_TWO), (0x2712, 0x2712, WIDTH_TWO),
+  (0x2714, 0x2714, WIDTH_TWO), (0x2716, 0x2716, WIDTH_TWO),
+  (0x271d, 0x271d, WIDTH_TWO), (0x2721, 0x2721, WIDTH_TWO),
+  (0x2728, 0x2728, WIDTH_TWO), (0x2733, 0x2734, WIDTH_TWO),
+  (0x273d, 0x273d, WIDTH_AMBIGUOUS), (0x2744, 0x2744, WIDTH_TWO),
+  (0x2747, 0x2747, WIDTH_TWO), (0x274c, 0x274c, WIDTH_TWO),
+  (0x274e, 0x274e, WIDTH_TWO), (0x2753, 0x2755, WIDTH_TWO),
+  (0x2757, 0x2757, WIDTH_TWO), (0x2763, 0x2764, WIDTH_TWO),
+  (0x2776, 0x277f, WIDTH_AMBIGUOUS), (0x2795, 0x2797, WIDTH_TWO),
+  (0x27a1, 0x27a1, WIDTH_TWO), (0x27b0, 0x27b0, WIDTH_TWO),
+  (0x27bf, 0x27bf, WIDTH_TWO), (0x2934, 0x2935, WIDTH_TWO),
+  (0x2b05, 0x2b07, WIDTH_TWO), (0x2b1b, 0x2b1c, WIDTH_TWO),
+  (0x2b50, 0x2b50, WIDTH_TWO), (0x2b55, 0x2b55, WIDTH_TWO),
+  (0x2b56, 0x2b59, WIDTH_AMBIGUOUS), (0x2cef, 0x2cf1, WIDTH_ZERO),
+  (0x2d7f, 0x2d7f, WIDTH_ZERO), (0x2de0, 0x2dff, WIDTH_ZERO),
+  (0x2e80, 0x2e99, WIDTH_TWO), (0x2e9b, 0x2ef3, WIDTH_TWO),
+  (0x2f00, 0x2fd5, WIDTH_TWO), (0x2ff0, 0x3029, WIDTH_TWO),
+  (0x302a, 0x302f, WIDTH_ZERO), (0x3030, 0x303e, WIDTH_TWO),
+  (0x3041, 0x3096, WIDTH_TWO), (0x3099, 0x309a, WIDTH_ZERO),
+  (0x309b, 0x30ff, WIDTH_TWO), (0x3105, 0x312f, WIDTH_TWO),
+  (0x3131, 0x3163, WIDTH_TWO), (0x3164, 0x3164, WIDTH_ZERO),
+  (0x3165, 0x318e, WIDTH_TWO), (0x3190, 0x31e5, WIDTH_TWO),
+  (0x31ef, 0x321e, WIDTH_TWO), (0x3220, 0xa48c, WIDTH_TWO),
+  (0xa490, 0xa4c6, WIDTH_TWO), (0xa66f, 0xa672, WIDTH_ZERO),
+  (0xa674, 0xa67d, WIDTH_ZERO), (0xa69e, 0xa69f, WIDTH_ZERO),
+  (0xa6f0, 0xa6f1, WIDTH_ZERO), (0xa802, 0xa802, WIDTH_ZERO),
+  (0xa806, 0xa806, WIDTH_ZERO), (0xa80b, 0xa80b, WIDTH_ZERO),
+  (0xa825, 0xa826, WIDTH_ZERO), (0xa82c, 0xa82c, WIDTH_ZERO),
+  (0xa8c4, 0xa8c5, WIDTH_ZERO), (0xa8e0, 0xa8f1, WIDTH_ZERO),
+  (0xa8ff, 0xa8ff, WIDTH_ZERO), (0xa926, 0xa92d, WIDTH_ZERO),
+  (0xa947, 0xa951, WIDTH_ZERO), (0xa953, 0xa953, WIDTH_ZERO),
+  (0xa960, 0xa97c, WIDTH_TWO), (0xa980, 0xa982, WIDTH_ZERO),
+  (0xa9b3, 0xa9b3, WIDTH_ZERO), (0xa9b6, 0xa9b9, WIDTH_ZERO),
+  (0xa9bc, 0xa9bd, WIDTH_ZERO), (0xa9c0, 0xa9c0, WIDTH_ZERO),
+  (0xa9e5, 0xa9e5, WIDTH_ZERO), (0xaa29, 0xaa2e, WIDTH_ZERO),
+  (0xaa31, 0xaa32, WIDTH_ZERO), (0xaa35, 0xaa36, WIDTH_ZERO),
+  (0xaa43, 0xaa43, WIDTH_ZERO), (0xaa4c, 0xaa4c, WIDTH_ZERO),
+  (0xaa7c, 0xaa7c, WIDTH_ZERO), (0xaab0, 0xaab0, WIDTH_ZERO),
+  (0xaab2, 0xaab4, WIDTH_ZERO), (0xaab7, 0xaab8, WIDTH_ZERO),
+  (0xaabe, 0xaabf, WIDTH_ZERO), (0xaac1, 0xaac1, WIDTH_ZERO),
+  (0xaaec, 0xaaed, WIDTH_ZERO), (0xaaf6, 0xaaf6, WIDTH_ZERO),
+  (0xabe5, 0xabe5, WIDTH_ZERO), (0xabe8, 0xabe8, WIDTH_ZERO),
+  (0xabed, 0xabed, WIDTH_ZERO), (0xac00, 0xd7a3, WIDTH_TWO),
+  (0xd7b0, 0xd7c6, WIDTH_ZERO), (0xd7cb, 0xd7fb, WIDTH_ZERO),
+  (0xd800, 0xdfff, WIDTH_NONPRINTABLE), (0xe000, 0xf8ff, WIDTH_AMBIGUOUS),
+  (0xf900, 0xfaff, WIDTH_TWO), (0xfb1e, 0xfb1e, WIDTH_ZERO),
+  (0xfe00, 0xfe0f, WIDTH_ZERO), (0xfe10, 0xfe19, WIDTH_TWO),
+  (0xfe20, 0xfe2f, WIDTH_ZERO), (0xfe30, 0xfe52, WIDTH_TWO),
+  (0xfe54, 0xfe66, WIDTH_TWO), (0xfe68, 0xfe6b, WIDTH_TWO),
+  (0xfeff, 0xfeff, WIDTH_ZERO), (0xff01, 0xff60, WIDTH_TWO),
+  (0xff9e, 0xffa0, WIDTH_ZERO), (0xffe0, 0xffe6, WIDTH_TWO),
+  (0xfff0, 0xfff8, WIDTH_ZERO), (0xfffd, 0xfffd, WIDTH_AMBIGUOUS),
+  (0x101fd, 0x101fd, WIDTH_ZERO), (0x102e0, 0x102e0, WIDTH_ZERO),
+  (0x10376, 0x1037a, WIDTH_ZERO), (0x10a01, 0x10a03, WIDTH_ZERO),
+  (0x10a05, 0x10a06, WIDTH_ZERO), (0x10a0c, 0x10a0f, WIDTH_ZERO),
+  (0x10a38, 0x10a3a, WIDTH_ZERO), (0x10a3f, 0x10a3f, WIDTH_ZERO),
+  (0x10ae5, 0x10ae6, WIDTH_ZERO), (0x10d24, 0x10d27, WIDTH_ZERO),
+  (0x10d69, 0x10d6d, WIDTH_ZERO), (0x10eab, 0x10eac, WIDTH_ZERO),
+  (0x10efa, 0x10eff, WIDTH_ZERO), (0x10f46, 0x10f50, WIDTH_ZERO),
+  (0x10f82, 0x10f85, WIDTH_ZERO), (0x11001, 0x11001, WIDTH_ZERO),
+  (0x11038, 0x11046, WIDTH_ZERO), (0x11070, 0x11070, WIDTH_ZERO),
+  (0x11073, 0x11074, WIDTH_ZERO), (0x1107f, 0x11081, WIDTH_ZERO),
+  (0x110b3, 0x110b6, WIDTH_ZERO), (0x110b9, 0x110ba, WIDTH_ZERO),
+  (0x110c2, 0x110c2, WIDTH_ZERO), (0x11100, 0x11102, WIDTH_ZERO),
+  (0x11127, 0x1112b, WIDTH_ZERO), (0x1112d, 0x11134, WIDTH_ZERO),
+  (0x11173, 0x11173, WIDTH_ZERO), (0x11180, 0x11181, WIDTH_ZERO),
+  (0x111b6, 0x111be, WIDTH_ZERO), (0x111c0, 0x111c0, WIDTH_ZERO),
+  (0x111c9, 0x111cc, WIDTH_ZERO), (0x111cf, 0x111cf, WIDTH_ZERO),
+  (0x1122f, 0x11231, WIDTH_ZERO), (0x11234, 0x11237, WIDTH_ZERO),
+  (0x1123e, 0x1123e, WIDTH_ZERO), (0x11241, 0x11241, WIDTH_ZERO),
+  (0x112df, 0x112df, WIDTH_ZERO), (0x112e3, 0x112ea, WIDTH_ZERO),
+  (0x11300, 0x11301, WIDTH_ZERO), (0x1133b, 0x1133c, WIDTH_ZERO),
+  (0x1133e, 0x1133e, WIDTH_ZERO), (0x11340, 0x11340, WIDTH_ZERO),
+  (0x1134d, 0x1134d, WIDTH_ZERO), (0x11357, 0x11357, WIDTH_ZERO),
+  (0x11366, 0x1136c, WIDTH_ZERO), (0x11370, 0x11374, WIDTH_ZERO),
+  (0x113b8, 0x113b8, WIDTH_ZERO), (0x113bb, 0x113c0, WIDTH_ZERO),
+  (0x113c2, 0x113c2, WIDTH_ZERO), (0x113c5, 0x113c5, WIDTH_ZERO),
+  (0x113c7, 0x113c9, WIDTH_ZERO), (0x113ce, 0x113d0, WIDTH_ZERO),
+  (0x113d2, 0x113d2, WIDTH_ZERO), (0x113e1, 0x113e2, WIDTH_ZERO),
+  (0x11438, 0x1143f, WIDTH_ZERO), (0x11442, 0x11444, WIDTH_ZERO),
+  (0x11446, 0x11446, WIDTH_ZERO), (0x1145e, 0x1145e, WIDTH_ZERO),
+  (0x114b0, 0x114b0, WIDTH_ZERO), (0x114b3, 0x114b8, WIDTH_ZERO),
+  (0x114ba, 0x114ba, WIDTH_ZERO), (0x114bd, 0x114bd, WIDTH_ZERO),
+  (0x114bf, 0x114c0, WIDTH_ZERO), (0x114c2, 0x114c3, WIDTH_ZERO),
+  (0x115af, 0x115af, WIDTH_ZERO), (0x115b2, 0x115b5, WIDTH_ZERO),
+  (0x115bc, 0x115bd, WIDTH_ZERO), (0x115bf, 0x115c0, WIDTH_ZERO),
+  (0x115dc, 0x115dd, WIDTH_ZERO), (0x11633, 0x1163a, WIDTH_ZERO),
+  (0x1163d, 0x1163d, WIDTH_ZERO), (0x1163f, 0x11640, WIDTH_ZERO),
+  (0x116ab, 0x116ab, WIDTH_ZERO), (0x116ad, 0x116ad, WIDTH_ZERO),
+  (0x116b0, 0x116b7, WIDTH_ZERO), (0x1171d, 0x1171d, WIDTH_ZERO),
+  (0x1171f, 0x1171f, WIDTH_ZERO), (0x11722, 0x11725, WIDTH_ZERO),
+  (0x11727, 0x1172b, WIDTH_ZERO), (0x1182f, 0x11837, WIDTH_ZERO),
+  (0x11839, 0x1183a, WIDTH_ZERO), (0x11930, 0x11930, WIDTH_ZERO),
+  (0x1193b, 0x1193e, WIDTH_ZERO), (0x11943, 0x11943, WIDTH_ZERO),
+  (0x119d4, 0x119d7, WIDTH_ZERO), (0x119da, 0x119db, WIDTH_ZERO),
+  (0x119e0, 0x119e0, WIDTH_ZERO), (0x11a01, 0x11a0a, WIDTH_ZERO),
+  (0x11a33, 0x11a38, WIDTH_ZERO), (0x11a3b, 0x11a3e, WIDTH_ZERO),
+  (0x11a47, 0x11a47, WIDTH_ZERO), (0x11a51, 0x11a56, WIDTH_ZERO),
+  (0x11a59, 0x11a5b, WIDTH_ZERO), (0x11a8a, 0x11a96, WIDTH_ZERO),
+  (0x11a98, 0x11a99, WIDTH_ZERO), (0x11b60, 0x11b60, WIDTH_ZERO),
+  (0x11b62, 0x11b64, WIDTH_ZERO), (0x11b66, 0x11b66, WIDTH_ZERO),
+  (0x11c30, 0x11c36, WIDTH_ZERO), (0x11c38, 0x11c3d, WIDTH_ZERO),
+  (0x11c3f, 0x11c3f, WIDTH_ZERO), (0x11c92, 0x11ca7, WIDTH_ZERO),
+  (0x11caa, 0x11cb0, WIDTH_ZERO), (0x11cb2, 0x11cb3, WIDTH_ZERO),
+  (0x11cb5, 0x11cb6, WIDTH_ZERO), (0x11d31, 0x11d36, WIDTH_ZERO),
+  (0x11d3a, 0x11d3a, WIDTH_ZERO), (0x11d3c, 0x11d3d, WIDTH_ZERO),
+  (0x11d3f, 0x11d45, WIDTH_ZERO), (0x11d47, 0x11d47, WIDTH_ZERO),
+  (0x11d90, 0x11d91, WIDTH_ZERO), (0x11d95, 0x11d95, WIDTH_ZERO),
+  (0x11d97, 0x11d97, WIDTH_ZERO), (0x11ef3, 0x11ef4, WIDTH_ZERO),
+  (0x11f00, 0x11f01, WIDTH_ZERO), (0x11f36, 0x11f3a, WIDTH_ZERO),
+  (0x11f40, 0x11f42, WIDTH_ZERO), (0x11f5a, 0x11f5a, WIDTH_ZERO),
+  (0x13440, 0x13440, WIDTH_ZERO), (0x13447, 0x13455, WIDTH_ZERO),
+  (0x1611e, 0x16129, WIDTH_ZERO), (0x1612d, 0x1612f, WIDTH_ZERO),
+  (0x16af0, 0x16af4, WIDTH_ZERO), (0x16b30, 0x16b36, WIDTH_ZERO),
+  (0x16f4f, 0x16f4f, WIDTH_ZERO), (0x16f8f, 0x16f92, WIDTH_ZERO),
+  (0x16fe0, 0x16fe3, WIDTH_TWO), (0x16fe4, 0x16fe4, WIDTH_ZERO),
+  (0x16ff0, 0x16ff1, WIDTH_ZERO), (0x16ff2, 0x16ff6, WIDTH_TWO),
+  (0x17000, 0x18cd5, WIDTH_TWO), (0x18cff, 0x18d1e, WIDTH_TWO),
+  (0x18d80, 0x18df2, WIDTH_TWO), (0x1aff0, 0x1aff3, WIDTH_TWO),
+  (0x1aff5, 0x1affb, WIDTH_TWO), (0x1affd, 0x1affe, WIDTH_TWO),
+  (0x1b000, 0x1b122, WIDTH_TWO), (0x1b132, 0x1b132, WIDTH_TWO),
+  (0x1b150, 0x1b152, WIDTH_TWO), (0x1b155, 0x1b155, WIDTH_TWO),
+  (0x1b164, 0x1b167, WIDTH_TWO), (0x1b170, 0x1b2fb, WIDTH_TWO),
+  (0x1bc9d, 0x1bc9e, WIDTH_ZERO), (0x1bca0, 0x1bca3, WIDTH_ZERO),
+  (0x1cf00, 0x1cf2d, WIDTH_ZERO), (0x1cf30, 0x1cf46, WIDTH_ZERO),
+  (0x1d165, 0x1d169, WIDTH_ZERO), (0x1d16d, 0x1d182, WIDTH_ZERO),
+  (0x1d185, 0x1d18b, WIDTH_ZERO), (0x1d1aa, 0x1d1ad, WIDTH_ZERO),
+  (0x1d242, 0x1d244, WIDTH_ZERO), (0x1d300, 0x1d356, WIDTH_TWO),
+  (0x1d360, 0x1d376, WIDTH_TWO), (0x1da00, 0x1da36, WIDTH_ZERO),
+  (0x1da3b, 0x1da6c, WIDTH_ZERO), (0x1da75, 0x1da75, WIDTH_ZERO),
+  (0x1da84, 0x1da84, WIDTH_ZERO), (0x1da9b, 0x1da9f, WIDTH_ZERO),
+  (0x1daa1, 0x1daaf, WIDTH_ZERO), (0x1e000, 0x1e006, WIDTH_ZERO),
+  (0x1e008, 0x1e018, WIDTH_ZERO), (0x1e01b, 0x1e021, WIDTH_ZERO),
+  (0x1e023, 0x1e024, WIDTH_ZERO), (0x1e026, 0x1e02a, WIDTH_ZERO),
+  (0x1e08f, 0x1e08f, WIDTH_ZERO), (0x1e130, 0x1e136, WIDTH_ZERO),
+  (0x1e2ae, 0x1e2ae, WIDTH_ZERO), (0x1e2ec, 0x1e2ef, WIDTH_ZERO),
+  (0x1e4ec, 0x1e4ef, WIDTH_ZERO), (0x1e5ee, 0x1e5ef, WIDTH_ZERO),
+  (0x1e6e3, 0x1e6e3, WIDTH_ZERO), (0x1e6e6, 0x1e6e6, WIDTH_ZERO),
+  (0x1e6ee, 0x1e6ef, WIDTH_ZERO), (0x1e6f5, 0x1e6f5, WIDTH_ZERO),
+  (0x1e8d0, 0x1e8d6, WIDTH_ZERO), (0x1e944, 0x1e94a, WIDTH_ZERO),
+  (0x1f004, 0x1f004, WIDTH_TWO), (0x1f0cf, 0x1f0cf, WIDTH_TWO),
+  (0x1f100, 0x1f10a, WIDTH_AMBIGUOUS), (0x1f110, 0x1f12d, WIDTH_AMBIGUOUS),
+  (0x1f130, 0x1f169, WIDTH_AMBIGUOUS), (0x1f170, 0x1f171, WIDTH_TWO),
+  (0x1f172, 0x1f17d, WIDTH_AMBIGUOUS), (0x1f17e, 0x1f17f, WIDTH_TWO),
+  (0x1f180, 0x1f18d, WIDTH_AMBIGUOUS), (0x1f18e, 0x1f18e, WIDTH_TWO),
+  (0x1f18f, 0x1f190, WIDTH_AMBIGUOUS), (0x1f191, 0x1f19a, WIDTH_TWO),
+  (0x1f19b, 0x1f1ac, WIDTH_AMBIGUOUS), (0x1f1e6, 0x1f202, WIDTH_TWO),
+  (0x1f210, 0x1f23b, WIDTH_TWO), (0x1f240, 0x1f248, WIDTH_TWO),
+  (0x1f250, 0x1f251, WIDTH_TWO), (0x1f260, 0x1f265, WIDTH_TWO),
+  (0x1f300, 0x1f321, WIDTH_TWO), (0x1f324, 0x1f393, WIDTH_TWO),
+  (0x1f396, 0x1f397, WIDTH_TWO), (0x1f399, 0x1f39b, WIDTH_TWO),
+  (0x1f39e, 0x1f3f0, WIDTH_TWO), (0x1f3f3, 0x1f3f5, WIDTH_TWO),
+  (0x1f3f7, 0x1f4fd, WIDTH_TWO), (0x1f4ff, 0x1f53d, WIDTH_TWO),
+  (0x1f549, 0x1f54e, WIDTH_TWO), (0x1f550, 0x1f567, WIDTH_TWO),
+  (0x1f56f, 0x1f570, WIDTH_TWO), (0x1f573, 0x1f57a, WIDTH_TWO),
+  (0x1f587, 0x1f587, WIDTH_TWO), (0x1f58a, 0x1f58d, WIDTH_TWO),
+  (0x1f590, 0x1f590, WIDTH_TWO), (0x1f595, 0x1f596, WIDTH_TWO),
+  (0x1f5a4, 0x1f5a5, WIDTH_TWO), (0x1f5a8, 0x1f5a8, WIDTH_TWO),
+  (0x1f5b1, 0x1f5b2, WIDTH_TWO), (0x1f5bc, 0x1f5bc, WIDTH_TWO),
+  (0x1f5c2, 0x1f5c4, WIDTH_TWO), (0x1f5d1, 0x1f5d3, WIDTH_TWO),
+  (0x1f5dc, 0x1f5de, WIDTH_TWO), (0x1f5e1, 0x1f5e1, WIDTH_TWO),
+  (0x1f5e3, 0x1f5e3, WIDTH_TWO), (0x1f5e8, 0x1f5e8, WIDTH_TWO),
+  (0x1f5ef, 0x1f5ef, WIDTH_TWO), (0x1f5f3, 0x1f5f3, WIDTH_TWO),
+  (0x1f5fa, 0x1f64f, WIDTH_TWO), (0x1f680, 0x1f6c5, WIDTH_TWO),
+  (0x1f6cb, 0x1f6d2, WIDTH_TWO), (0x1f6d5, 0x1f6d8, WIDTH_TWO),
+  (0x1f6dc, 0x1f6e5, WIDTH_TWO), (0x1f6e9, 0x1f6e9, WIDTH_TWO),
+  (0x1f6eb, 0x1f6ec, WIDTH_TWO), (0x1f6f0, 0x1f6f0, WIDTH_TWO),
+  (0x1f6f3, 0x1f6fc, WIDTH_TWO), (0x1f7e0, 0x1f7eb, WIDTH_TWO),
+  (0x1f7f0, 0x1f7f0, WIDTH_TWO), (0x1f90c, 0x1f93a, WIDTH_TWO),
+  (0x1f93c, 0x1f945, WIDTH_TWO), (0x1f947, 0x1f9ff, WIDTH_TWO),
+  (0x1fa70, 0x1fa7c, WIDTH_TWO), (0x1fa80, 0x1fa8a, WIDTH_TWO),
+  (0x1fa8e, 0x1fac6, WIDTH_TWO), (0x1fac8, 0x1fac8, WIDTH_TWO),
+  (0x1facd, 0x1fadc, WIDTH_TWO), (0x1fadf, 0x1faea, WIDTH_TWO),
+  (0x1faef, 0x1faf8, WIDTH_TWO), (0x20000, 0x2fffd, WIDTH_TWO),
+  (0x30000, 0x3fffd, WIDTH_TWO), (0xe0000, 0xe0fff, WIDTH_ZERO),
+  (0xf0000, 0xffffd, WIDTH_AMBIGUOUS), (0x100000, 0x10fffd, WIDTH_AMBIGUOUS)
+];
+
+// Grapheme_Cluster_Break values other than Other
+#[rustfmt::skip]
+pub static GRAPHEME_BREAKS: [(u32, u32, GraphemeBreak); 1386] = [
+  (0x0, 0x9, Control), (0xa, 0xa, LF), (0xb, 0xc, Control), (0xd, 0xd, CR),
+  (0xe, 0x1f, Control), (0x7f, 0x9f, Control), (0xad, 0xad, Control),
+  (0x300, 0x36f, Extend), (0x483, 0x489, Extend), (0x591, 0x5bd, Extend),
+  (0x5bf, 0x5bf, Extend), (0x5c1, 0x5c2, Extend), (0x5c4, 0x5c5, Extend),
+  (0x5c7, 0x5c7, Extend), (0x600, 0x605, Prepend), (0x610, 0x61a, Extend),
+  (0x61c, 0x61c, Control), (0x64b, 0x65f, Extend), (0x670, 0x670, Extend),
+  (0x6d6, 0x6dc, Extend), (0x6dd, 0x6dd, Prepend), (0x6df, 0x6e4, Extend),
+  (0x6e7, 0x6e8, Extend), (0x6ea, 0x6ed, Extend), (0x70f, 0x70f, Prepend),
+  (0x711, 0x711, Extend), (0x730, 0x74a, Extend), (0x7a6, 0x7b0, Extend),
+  (0x7eb, 0x7f3, Extend), (0x7fd, 0x7fd, Extend), (0x816, 0x819, Extend),
+  (0x81b, 0x823, Extend), (0x825, 0x827, Extend), (0x829, 0x82d, Extend),
+  (0x859, 0x85b, Extend), (0x890, 0x891, Prepend), (0x897, 0x89f, Extend),
+  (0x8ca, 0x8e1, Extend), (0x8e2, 0x8e2, Prepend), (0x8e3, 0x902, Extend),
+  (0x903, 0x903, SpacingMark), (0x93a, 0x93a, Extend),
+  (0x93b, 0x93b, SpacingMark), (0x93c, 0x93c, Extend),
+  (0x93e, 0x940, SpacingMark), (0x941, 0x948, Extend),
+  (0x949, 0x94c, SpacingMark), (0x94d, 0x94d, Extend),
+  (0x94e, 0x94f, SpacingMark), (0x951, 0x957, Extend), (0x962, 0x963, Extend),
+  (0x981, 0x981, Extend), (0x982, 0x983, SpacingMark), (0x9bc, 0x9bc, Extend),
+  (0x9be, 0x9be, Extend), (0x9bf, 0x9c0, SpacingMark), (0x9c1, 0x9c4, Extend),
+  (0x9c7, 0x9c8, SpacingMark), (0x9cb, 0x9cc, SpacingMark),
+  (0x9cd, 0x9cd, Extend), (0x9d7, 0x9d7, Extend), (0x9e2, 0x9e3, Extend),
+  (0x9fe, 0x9fe, Extend), (0xa01, 0xa02, Extend), (0xa03, 0xa03, SpacingMark),
+  (0xa3c, 0xa3c, Extend), (0xa3e, 0xa40, SpacingMark), (0xa41, 0xa42, Extend),
+  (0xa47, 0xa48, Extend), (0xa4b, 0xa4d, Extend), (0xa51, 0xa51, Extend),
+  (0xa70, 0xa71, Extend), (0xa75, 0xa75, Extend), (0xa81, 0xa82, Extend),
+  (0xa83, 0xa83, SpacingMark), (0xabc, 0xabc, Extend),
+  (0xabe, 0xac0, SpacingMark), (0xac1, 0xac5, Extend), (0xac7, 0xac8, Extend),
+  (0xac9, 0xac9, SpacingMark), (0xacb, 0xacc, SpacingMark),
+  (0xacd, 0xacd, Extend), (0xae2, 0xae3, Extend), (0xafa, 0xaff, Extend),
+  (0xb01, 0xb01, Extend), (0xb02, 0xb03, SpacingMark), (0xb3c, 0xb3c, Extend),
+  (0xb3e, 0xb3f, Extend), (0xb40, 0xb40, SpacingMark), (0xb41, 0xb44, Extend),
+  (0xb47, 0xb48, SpacingMark), (0xb4b, 0xb4c, SpacingMark),
+  (0xb4d, 0xb4d, Extend), (0xb55, 0xb57, Extend), (0xb62, 0xb63, Extend),
+  (0xb82, 0xb82, Extend), (0xbbe, 0xbbe, Extend), (0xbbf, 0xbbf, SpacingMark),
+  (0xbc0, 0xbc0, Extend), (0xbc1, 0xbc2, SpacingMark),
+  (0xbc6, 0xbc8, SpacingMark), (0xbca, 0xbcc, SpacingMark),
+  (0xbcd, 0xbcd, Extend), (0xbd7, 0xbd7, Extend), (0xc00, 0xc00, Extend),
+  (0xc01, 0xc03, SpacingMark), (0xc04, 0xc04, Extend), (0xc3c, 0xc3c, Extend),
+  (0xc3e, 0xc40, Extend), (0xc41, 0xc44, SpacingMark), (0xc46, 0xc48, Extend),
+  (0xc4a, 0xc4d, Extend), (0xc55, 0xc56, Extend), (0xc62, 0xc63, Extend),
+  (0xc81, 0xc81, Extend), (0xc82, 0xc83, SpacingMark), (0xcbc, 0xcbc, Extend),
+  (0xcbe, 0xcbe, SpacingMark), (0xcbf, 0xcc0, Extend),
+  (0xcc1, 0xcc1, SpacingMark), (0xcc2, 0xcc2, Extend),
+  (0xcc3, 0xcc4, SpacingMark), (0xcc6, 0xcc8, Extend), (0xcca, 0xccd, Extend),
+  (0xcd5, 0xcd6, Extend), (0xce2, 0xce3, Extend), (0xcf3, 0xcf3, SpacingMark),
+  (0xd00, 0xd01, Extend), (0xd02, 0xd03, SpacingMark), (0xd3b, 0xd3c, Extend),
+  (0xd3e, 0xd3e, Extend), (0xd3f, 0xd40, SpacingMark), (0xd41, 0xd44, Extend),
+  (0xd46, 0xd48, SpacingMark), (0xd4a, 0xd4c, SpacingMark),
+  (0xd4d, 0xd4d, Extend), (0xd4e, 0xd4e, Prepend), (0xd57, 0xd57, Extend),
+  (0xd62, 0xd63, Extend), (0xd81, 0xd81, Extend), (0xd82, 0xd83, SpacingMark),
+  (0xdca, 0xdca, Extend), (0xdcf, 0xdcf, Extend), (0xdd0, 0xdd1, SpacingMark),
+  (0xdd2, 0xdd4, Extend), (0xdd6, 0xdd6, Extend), (0xdd8, 0xdde, SpacingMark),
+  (0xddf, 0xddf, Extend), (0xdf2, 0xdf3, SpacingMark), (0xe31, 0xe31, Extend),
+  (0xe33, 0xe33, SpacingMark), (0xe34, 0xe3a, Extend), (0xe47, 0xe4e, Extend),
+  (0xeb1, 0xeb1, Extend), (0xeb3, 0xeb3, SpacingMark), (0xeb4, 0xebc, Extend),
+  (0xec8, 0xece, Extend), (0xf18, 0xf19, Extend), (0xf35, 0xf35, Extend),
+  (0xf37, 0xf37, Extend), (0xf39, 0xf39, Extend), (0xf3e, 0xf3f, SpacingMark),
+  (0xf71, 0xf7e, Extend), (0xf7f, 0xf7f, SpacingMark), (0xf80, 0xf84, Extend),
+  (0xf86, 0xf87, Extend), (0xf8d, 0xf97, Extend), (0xf99, 0xfbc, Extend),
+  (0xfc6, 0xfc6, Extend), (0x102d, 0x1030, Extend),
+  (0x1031, 0x1031, SpacingMark), (0x1032, 0x1037, Extend),
+  (0x1039, 0x103a, Extend), (0x103b, 0x103c, SpacingMark),
+  (0x103d, 0x103e, Extend), (0x1056, 0x1057, SpacingMark),
+  (0x1058, 0x1059, Extend), (0x105e, 0x1060, Extend), (0x1071, 0x1074, Extend),
+  (0x1082, 0x1082, Extend), (0x1084, 0x1084, SpacingMark),
+  (0x1085, 0x1086, Extend), (0x108d, 0x108d, Extend), (0x109d, 0x109d, Extend),
+  (0x1100, 0x115f, L), (0x1160, 0x11a7, V), (0x11a8, 0x11ff, T),
+  (0x135d, 0x135f, Extend), (0x1712, 0x1715, Extend), (0x1732, 0x1734, Extend),
+  (0x1752, 0x1753, Extend), (0x1772, 0x1773, Extend), (0x17b4, 0x17b5, Extend),
+  (0x17b6, 0x17b6, SpacingMark), (0x17b7, 0x17bd, Extend),
+  (0x17be, 0x17c5, SpacingMark), (0x17c6, 0x17c6, Extend),
+  (0x17c7, 0x17c8, SpacingMark), (0x17c9, 0x17d3, Extend),
+  (0x17dd, 0x17dd, Extend), (0x180b, 0x180d, Extend), (0x180e, 0x180e, Control),
+  (0x180f, 0x180f, Extend), (0x1885, 0x1886, Extend), (0x18a9, 0x18a9, Extend),
+  (0x1920, 0x1922, Extend), (0x1923, 0x1926, SpacingMark),
+  (0x1927, 0x1928, Extend), (0x1929, 0x192b, SpacingMark),
+  (0x1930, 0x1931, SpacingMark), (0x1932, 0x1932, Extend),
+  (0x1933, 0x1938, SpacingMark), (0x1939, 0x193b, Extend),
+  (0x1a17, 0x1a18, Extend), (0x1a19, 0x1a1a, SpacingMark),
+  (0x1a1b, 0x1a1b, Extend), (0x1a55, 0x1a55, SpacingMark),
+  (0x1a56, 0x1a56, Extend), (0x1a57, 0x1a57, SpacingMark),
+  (0x1a58, 0x1a5e, Extend), (0x1a60, 0x1a60, Extend), (0x1a62, 0x1a62, Extend),
+  (0x1a65, 0x1a6c, Extend), (0x1a6d, 0x1a72, SpacingMark),
+  (0x1a73, 0x1a7c, Extend), (0x1a7f, 0x1a7f, Extend), (0x1ab0, 0x1add, Extend),
+  (0x1ae0, 0x1aeb, Extend), (0x1b00, 0x1b03, Extend),
+  (0x1b04, 0x1b04, SpacingMark), (0x1b34, 0x1b3d, Extend),
+  (0x1b3e, 0x1b41, SpacingMark), (0x1b42, 0x1b44, Extend),
+  (0x1b6b, 0x1b73, Extend), (0x1b80, 0x1b81, Extend),
+  (0x1b82, 0x1b82, SpacingMark), (0x1ba1, 0x1ba1, SpacingMark),
+  (0x1ba2, 0x1ba5, Extend), (0x1ba6, 0x1ba7, SpacingMark),
+  (0x1ba8, 0x1bad, Extend), (0x1be6, 0x1be6, Extend),
+  (0x1be7, 0x1be7, SpacingMark), (0x1be8, 0x1be9, Extend),
+  (0x1bea, 0x1bec, SpacingMark), (0x1bed, 0x1bed, Extend),
+  (0x1bee, 0x1bee, SpacingMark), (0x1bef, 0x1bf3, Extend),
+  (0x1c24, 0x1c2b, SpacingMark), (0x1c2c, 0x1c33, Extend),
+  (0x1c34, 0x1c35, SpacingMark), (0x1c36, 0x1c37, Extend),
+  (0x1cd0, 0x1cd2, Extend), (0x1cd4, 0x1ce0, Extend),
+  (0x1ce1, 0x1ce1, SpacingMark), (0x1ce2, 0x1ce8, Extend),
+  (0x1ced, 0x1ced, Extend), (0x1cf4, 0x1cf4, Extend),
+  (0x1cf7, 0x1cf7, SpacingMark), (0x1cf8, 0x1cf9, Extend),
+  (0x1dc0, 0x1dff, Extend), (0x200b, 0x200b, Control), (0x200c, 0x200c, Extend),
+  (0x200d, 0x200d, Zwj), (0x200e, 0x200f, Control), (0x2028, 0x202e, Control),
+  (0x2060, 0x206f, Control), (0x20d0, 0x20f0, Extend), (0x2cef, 0x2cf1, Extend),
+  (0x2d7f, 0x2d7f, Extend), (0x2de0, 0x2dff, Extend), (0x302a, 0x302f, Extend),
+  (0x3099, 0x309a, Extend), (0xa66f, 0xa672, Extend), (0xa674, 0xa67d, Extend),
+  (0xa69e, 0xa69f, Extend), (0xa6f0, 0xa6f1, Extend), (0xa802, 0xa802, Extend),
+  (0xa806, 0xa806, Extend), (0xa80b, 0xa80b, Extend),
+  (0xa823, 0xa824, SpacingMark), (0xa825, 0xa826, Extend),
+  (0xa827, 0xa827, SpacingMark), (0xa82c, 0xa82c, Extend),
+  (0xa880, 0xa881, SpacingMark), (0xa8b4, 0xa8c3, SpacingMark),
+  (0xa8c4, 0xa8c5, Extend), (0xa8e0, 0xa8f1, Extend), (0xa8ff, 0xa8ff, Extend),
+  (0xa926, 0xa92d, Extend), (0xa947, 0xa951, Extend),
+  (0xa952, 0xa952, SpacingMark), (0xa953, 0xa953, Extend), (0xa960, 0xa97c, L),
+  (0xa980, 0xa982, Extend), (0xa983, 0xa983, SpacingMark),
+  (0xa9b3, 0xa9b3, Extend), (0xa9b4, 0xa9b5, SpacingMark),
+  (0xa9b6, 0xa9b9, Extend), (0xa9ba, 0xa9bb, SpacingMark),
+  (0xa9bc, 0xa9bd, Extend), (0xa9be, 0xa9bf, SpacingMark),
+  (0xa9c0, 0xa9c0, Extend), (0xa9e5, 0xa9e5, Extend), (0xaa29, 0xaa2e, Extend),
+  (0xaa2f, 0xaa30, SpacingMark), (0xaa31, 0xaa32, Extend),
+  (0xaa33, 0xaa34, SpacingMark), (0xaa35, 0xaa36, Extend),
+  (0xaa43, 0xaa43, Extend), (0xaa4c, 0xaa4c, Extend),
+  (0xaa4d, 0xaa4d, SpacingMark), (0xaa7c, 0xaa7c, Extend),
+  (0xaab0, 0xaab0, Extend), (0xaab2, 0xaab4, Extend), (0xaab7, 0xaab8, Extend),
+  (0xaabe, 0xaabf, Extend), (0xaac1, 0xaac1, Extend),
+  (0xaaeb, 0xaaeb, SpacingMark), (0xaaec, 0xaaed, Extend),
+  (0xaaee, 0xaaef, SpacingMark), (0xaaf5, 0xaaf5, SpacingMark),
+  (0xaaf6, 0xaaf6, Extend), (0xabe3, 0xabe4, SpacingMark),
+  (0xabe5, 0xabe5, Extend), (0xabe6, 0xabe7, SpacingMark),
+  (0xabe8, 0xabe8, Extend), (0xabe9, 0xabea, SpacingMark),
+  (0xabec, 0xabec, SpacingMark), (0xabed, 0xabed, Extend), (0xac00, 0xac00, LV),
+  (0xac01, 0xac1b, Lvt), (0xac1c, 0xac1c, LV), (0xac1d, 0xac37, Lvt),
+  (0xac38, 0xac38, LV), (0xac39, 0xac53, Lvt), (0xac54, 0xac54, LV),
+  (0xac55, 0xac6f, Lvt), (0xac70, 0xac70, LV), (0xac71, 0xac8b, Lvt),
+  (0xac8c, 0xac8c, LV), (0xac8d, 0xaca7, Lvt), (0xaca8, 0xaca8, LV),
+  (0xaca9, 0xacc3, Lvt), (0xacc4, 0xacc4, LV), (0xacc5, 0xacdf, Lvt),
+  (0xace0, 0xace0, LV), (0xace1, 0xacfb, Lvt), (0xacfc, 0xacfc, LV),
+  (0xacfd, 0xad17, Lvt), (0xad18, 0xad18, LV), (0xad19, 0xad33, Lvt),
+  (0xad34, 0xad34, LV), (0xad35, 0xad4f, Lvt), (0xad50, 0xad50, LV),
+  (0xad51, 0xad6b, Lvt), (0xad6c, 0xad6c, LV), (0xad6d, 0xad87, Lvt),
+  (0xad88, 0xad88, LV), (0xad89, 0xada3, Lvt), (0xada4, 0xada4, LV),
+  (0xada5, 0xadbf, Lvt), (0xadc0, 0xadc0, LV), (0xadc1, 0xaddb, Lvt),
+  (0xaddc, 0xaddc, LV), (0xaddd, 0xadf7, Lvt), (0xadf8, 0xadf8, LV),
+  (0xadf9, 0xae13, Lvt), (0xae14, 0xae14, LV), (0xae15, 0xae2f, Lvt),
+  (0xae30, 0xae30, LV), (0xae31, 0xae4b, Lvt), (0xae4c, 0xae4c, LV),
+  (0xae4d, 0xae67, Lvt), (0xae68, 0xae68, LV), (0xae69, 0xae83, Lvt),
+  (0xae84, 0xae84, LV), (0xae85, 0xae9f, Lvt), (0xaea0, 0xaea0, LV),
+  (0xaea1, 0xaebb, Lvt), (0xaebc, 0xaebc, LV), (0xaebd, 0xaed7, Lvt),
+  (0xaed8, 0xaed8, LV), (0xaed9, 0xaef3, Lvt), (0xaef4, 0xaef4, LV),
+  (0xaef5, 0xaf0f, Lvt), (0xaf10, 0xaf10, LV), (0xaf11, 0xaf2b, Lvt),
+  (0xaf2c, 0xaf2c, LV), (0xaf2d, 0xaf47, Lvt), (0xaf48, 0xaf48, LV),
+  (0xaf49, 0xaf63, Lvt), (0xaf64, 0xaf64, LV), (0xaf65, 0xaf7f, Lvt),
+  (0xaf80, 0xaf80, LV), (0xaf81, 0xaf9b, Lvt), (0xaf9c, 0xaf9c, LV),
+  (0xaf9d, 0xafb7, Lvt), (0xafb8, 0xafb8, LV), (0xafb9, 0xafd3, Lvt),
+  (0xafd4, 0xafd4, LV), (0xafd5, 0xafef, Lvt), (0xaff0, 0xaff0, LV),
+  (0xaff1, 0xb00b, Lvt), (0xb00c, 0xb00c, LV), (0xb00d, 0xb027, Lvt),
+  (0xb028, 0xb028, LV), (0xb029, 0xb043, Lvt), (0xb044, 0xb044, LV),
+  (0xb045, 0xb05f, Lvt), (0xb060, 0xb060, LV), (0xb061, 0xb07b, Lvt),
+  (0xb07c, 0xb07c, LV), (0xb07d, 0xb097, Lvt), (0xb098, 0xb098, LV),
+  (0xb099, 0xb0b3, Lvt), (0xb0b4, 0xb0b4, LV), (0xb0b5, 0xb0cf, Lvt),
+  (0xb0d0, 0xb0d0, LV), (0xb0d1, 0xb0eb, Lvt), (0xb0ec, 0xb0ec, LV),
+  (0xb0ed, 0xb107, Lvt), (0xb108, 0xb108, LV), (0xb109, 0xb123, Lvt),
+  (0xb124, 0xb124, LV), (0xb125, 0xb13f, Lvt), (0xb140, 0xb140, LV),
+  (0xb141, 0xb15b, Lvt), (0xb15c, 0xb15c, LV), (0xb15d, 0xb177, Lvt),
+  (0xb178, 0xb178, LV), (0xb179, 0xb193, Lvt), (0xb194, 0xb194, LV),
+  (0xb195, 0xb1af, Lvt), (0xb1b0, 0xb1b0, LV), (0xb1b1, 0xb1cb, Lvt),
+  (0xb1cc, 0xb1cc, LV), (0xb1cd, 0xb1e7, Lvt), (0xb1e8, 0xb1e8, LV),
+  (0xb1e9, 0xb203, Lvt), (0xb204, 0xb204, LV), (0xb205, 0xb21f, Lvt),
+  (0xb220, 0xb220, LV), (0xb221, 0xb23b, Lvt), (0xb23c, 0xb23c, LV),
+  (0xb23d, 0xb257, Lvt), (0xb258, 0xb258, LV), (0xb259, 0xb273, Lvt),
+  (0xb274, 0xb274, LV), (0xb275, 0xb28f, Lvt), (0xb290, 0xb290, LV),
+  (0xb291, 0xb2ab, Lvt), (0xb2ac, 0xb2ac, LV), (0xb2ad, 0xb2c7, Lvt),
+  (0xb2c8, 0xb2c8, LV), (0xb2c9, 0xb2e3, Lvt), (0xb2e4, 0xb2e4, LV),
+  (0xb2e5, 0xb2ff, Lvt), (0xb300, 0xb300, LV), (0xb301, 0xb31b, Lvt),
+  (0xb31c, 0xb31c, LV), (0xb31d, 0xb337, Lvt), (0xb338, 0xb338, LV),
+  (0xb339, 0xb353, Lvt), (0xb354, 0xb354, LV), (0xb355, 0xb36f, Lvt),
+  (0xb370, 0xb370, LV), (0xb371, 0xb38b, Lvt), (0xb38c, 0xb38c, LV),
+  (0xb38d, 0xb3a7, Lvt), (0xb3a8, 0xb3a8, LV), (0xb3a9, 0xb3c3, Lvt),
+  (0xb3c4, 0xb3c4, LV), (0xb3c5, 0xb3df, Lvt), (0xb3e0, 0xb3e0, LV),
+  (0xb3e1, 0xb3fb, Lvt), (0xb3fc, 0xb3fc, LV), (0xb3fd, 0xb417, Lvt),
+  (0xb418, 0xb418, LV), (0xb419, 0xb433, Lvt), (0xb434, 0xb434, LV),
+  (0xb435, 0xb44f, Lvt), (0xb450, 0xb450, LV), (0xb451, 0xb46b, Lvt),
+  (0xb46c, 0xb46c, LV), (0xb46d, 0xb487, Lvt), (0xb488, 0xb488, LV),
+  (0xb489, 0xb4a3, Lvt), (0xb4a4, 0xb4a4, LV), (0xb4a5, 0xb4bf, Lvt),
+  (0xb4c0, 0xb4c0, LV), (0xb4c1, 0xb4db, Lvt), (0xb4dc, 0xb4dc, LV),
+  (0xb4dd, 0xb4f7, Lvt), (0xb4f8, 0xb4f8, LV), (0xb4f9, 0xb513, Lvt),
+  (0xb514, 0xb514, LV), (0xb515, 0xb52f, Lvt), (0xb530, 0xb530, LV),
+  (0xb531, 0xb54b, Lvt), (0xb54c, 0xb54c, LV), (0xb54d, 0xb567, Lvt),
+  (0xb568, 0xb568, LV), (0xb569, 0xb583, Lvt), (0xb584, 0xb584, LV),
+  (0xb585, 0xb59f, Lvt), (0xb5a0, 0xb5a0, LV), (0xb5a1, 0xb5bb, Lvt),
+  (0xb5bc, 0xb5bc, LV), (0xb5bd, 0xb5d7, Lvt), (0xb5d8, 0xb5d8, LV),
+  (0xb5d9, 0xb5f3, Lvt), (0xb5f4, 0xb5f4, LV), (0xb5f5, 0xb60f, Lvt),
+  (0xb610, 0xb610, LV), (0xb611, 0xb62b, Lvt), (0xb62c, 0xb62c, LV),
+  (0xb62d, 0xb647, Lvt), (0xb648, 0xb648, LV), (0xb649, 0xb663, Lvt),
+  (0xb664, 0xb664, LV), (0xb665, 0xb67f, Lvt), (0xb680, 0xb680, LV),
+  (0xb681, 0xb69b, Lvt), (0xb69c, 0xb69c, LV), (0xb69d, 0xb6b7, Lvt),
+  (0xb6b8, 0xb6b8, LV), (0xb6b9, 0xb6d3, Lvt), (0xb6d4, 0xb6d4, LV),
+  (0xb6d5, 0xb6ef, Lvt), (0xb6f0, 0xb6f0, LV), (0xb6f1, 0xb70b, Lvt),
+  (0xb70c, 0xb70c, LV), (0xb70d, 0xb727, Lvt), (0xb728, 0xb728, LV),
+  (0xb729, 0xb743, Lvt), (0xb744, 0xb744, LV), (0xb745, 0xb75f, Lvt),
+  (0xb760, 0xb760, LV), (0xb761, 0xb77b, Lvt), (0xb77c, 0xb77c, LV),
+  (0xb77d, 0xb797, Lvt), (0xb798, 0xb798, LV), (0xb799, 0xb7b3, Lvt),
+  (0xb7b4, 0xb7b4, LV), (0xb7b5, 0xb7cf, Lvt), (0xb7d0, 0xb7d0, LV),
+  (0xb7d1, 0xb7eb, Lvt), (0xb7ec, 0xb7ec, LV), (0xb7ed, 0xb807, Lvt),
+  (0xb808, 0xb808, LV), (0xb809, 0xb823, Lvt), (0xb824, 0xb824, LV),
+  (0xb825, 0xb83f, Lvt), (0xb840, 0xb840, LV), (0xb841, 0xb85b, Lvt),
+  (0xb85c, 0xb85c, LV), (0xb85d, 0xb877, Lvt), (0xb878, 0xb878, LV),
+  (0xb879, 0xb893, Lvt), (0xb894, 0xb894, LV), (0xb895, 0xb8af, Lvt),
+  (0xb8b0, 0xb8b0, LV), (0xb8b1, 0xb8cb, Lvt), (0xb8cc, 0xb8cc, LV),
+  (0xb8cd, 0xb8e7, Lvt), (0xb8e8, 0xb8e8, LV), (0xb8e9, 0xb903, Lvt),
+  (0xb904, 0xb904, LV), (0xb905, 0xb91f, Lvt), (0xb920, 0xb920, LV),
+  (0xb921, 0xb93b, Lvt), (0xb93c, 0xb93c, LV), (0xb93d, 0xb957, Lvt),
+  (0xb958, 0xb958, LV), (0xb959, 0xb973, Lvt), (0xb974, 0xb974, LV),
+  (0xb975, 0xb98f, Lvt), (0xb990, 0xb990, LV), (0xb991, 0xb9ab, Lvt),
+  (0xb9ac, 0xb9ac, LV), (0xb9ad, 0xb9c7, Lvt), (0xb9c8, 0xb9c8, LV),
+  (0xb9c9, 0xb9e3, Lvt), (0xb9e4, 0xb9e4, LV), (0xb9e5, 0xb9ff, Lvt),
+  (0xba00, 0xba00, LV), (0xba01, 0xba1b, Lvt), (0xba1c, 0xba1c, LV),
+  (0xba1d, 0xba37, Lvt), (0xba38, 0xba38, LV), (0xba39, 0xba53, Lvt),
+  (0xba54, 0xba54, LV), (0xba55, 0xba6f, Lvt), (0xba70, 0xba70, LV),
+  (0xba71, 0xba8b, Lvt), (0xba8c, 0xba8c, LV), (0xba8d, 0xbaa7, Lvt),
+  (0xbaa8, 0xbaa8, LV), (0xbaa9, 0xbac3, Lvt), (0xbac4, 0xbac4, LV),
+  (0xbac5, 0xbadf, Lvt), (0xbae0, 0xbae0, LV), (0xbae1, 0xbafb, Lvt),
+  (0xbafc, 0xbafc, LV), (0xbafd, 0xbb17, Lvt), (0xbb18, 0xbb18, LV),
+  (0xbb19, 0xbb33, Lvt), (0xbb34, 0xbb34, LV), (0xbb35, 0xbb4f, Lvt),
+  (0xbb50, 0xbb50, LV), (0xbb51, 0xbb6b, Lvt), (0xbb6c, 0xbb6c, LV),
+  (0xbb6d, 0xbb87, Lvt), (0xbb88, 0xbb88, LV), (0xbb89, 0xbba3, Lvt),
+  (0xbba4, 0xbba4, LV), (0xbba5, 0xbbbf, Lvt), (0xbbc0, 0xbbc0, LV),
+  (0xbbc1, 0xbbdb, Lvt), (0xbbdc, 0xbbdc, LV), (0xbbdd, 0xbbf7, Lvt),
+  (0xbbf8, 0xbbf8, LV), (0xbbf9, 0xbc13, Lvt), (0xbc14, 0xbc14, LV),
+  (0xbc15, 0xbc2f, Lvt), (0xbc30, 0xbc30, LV), (0xbc31, 0xbc4b, Lvt),
+  (0xbc4c, 0xbc4c, LV), (0xbc4d, 0xbc67, Lvt), (0xbc68, 0xbc68, LV),
+  (0xbc69, 0xbc83, Lvt), (0xbc84, 0xbc84, LV), (0xbc85, 0xbc9f, Lvt),
+  (0xbca0, 0xbca0, LV), (0xbca1, 0xbcbb, Lvt), (0xbcbc, 0xbcbc, LV),
+  (0xbcbd, 0xbcd7, Lvt), (0xbcd8, 0xbcd8, LV), (0xbcd9, 0xbcf3, Lvt),
+  (0xbcf4, 0xbcf4, LV), (0xbcf5, 0xbd0f, Lvt), (0xbd10, 0xbd10, LV),
+  (0xbd11, 0xbd2b, Lvt), (0xbd2c, 0xbd2c, LV), (0xbd2d, 0xbd47, Lvt),
+  (0xbd48, 0xbd48, LV), (0xbd49, 0xbd63, Lvt), (0xbd64, 0xbd64, LV),
+  (0xbd65, 0xbd7f, Lvt), (0xbd80, 0xbd80, LV), (0xbd81, 0xbd9b, Lvt),
+  (0xbd9c, 0xbd9c, LV), (0xbd9d, 0xbdb7, Lvt), (0xbdb8, 0xbdb8, LV),
+  (0xbdb9, 0xbdd3, Lvt), (0xbdd4, 0xbdd4, LV), (0xbdd5, 0xbdef, Lvt),
+  (0xbdf0, 0xbdf0, LV), (0xbdf1, 0xbe0b, Lvt), (0xbe0c, 0xbe0c, LV),
+  (0xbe0d, 0xbe27, Lvt), (0xbe28, 0xbe28, LV), (0xbe29, 0xbe43, Lvt),
+  (0xbe44, 0xbe44, LV), (0xbe45, 0xbe5f, Lvt), (0xbe60, 0xbe60, LV),
+  (0xbe61, 0xbe7b, Lvt), (0xbe7c, 0xbe7c, LV), (0xbe7d, 0xbe97, Lvt),
+  (0xbe98, 0xbe98, LV), (0xbe99, 0xbeb3, Lvt), (0xbeb4, 0xbeb4, LV),
+  (0xbeb5, 0xbecf, Lvt), (0xbed0, 0xbed0, LV), (0xbed1, 0xbeeb, Lvt),
+  (0xbeec, 0xbeec, LV), (0xbeed, 0xbf07, Lvt), (0xbf08, 0xbf08, LV),
+  (0xbf09, 0xbf23, Lvt), (0xbf24, 0xbf24, LV), (0xbf25, 0xbf3f, Lvt),
+  (0xbf40, 0xbf40, LV), (0xbf41, 0xbf5b, Lvt), (0xbf5c, 0xbf5c, LV),
+  (0xbf5d, 0xbf77, Lvt), (0xbf78, 0xbf78, LV), (0xbf79, 0xbf93, Lvt),
+  (0xbf94, 0xbf94, LV), (0xbf95, 0xbfaf, Lvt), (0xbfb0, 0xbfb0, LV),
+  (0xbfb1, 0xbfcb, Lvt), (0xbfcc, 0xbfcc, LV), (0xbfcd, 0xbfe7, Lvt),
+  (0xbfe8, 0xbfe8, LV), (0xbfe9, 0xc003, Lvt), (0xc004, 0xc004, LV),
+  (0xc005, 0xc01f, Lvt), (0xc020, 0xc020, LV), (0xc021, 0xc03b, Lvt),
+  (0xc03c, 0xc03c, LV), (0xc03d, 0xc057, Lvt), (0xc058, 0xc058, LV),
+  (0xc059, 0xc073, Lvt), (0xc074, 0xc074, LV), (0xc075, 0xc08f, Lvt),
+  (0xc090, 0xc090, LV), (0xc091, 0xc0ab, Lvt), (0xc0ac, 0xc0ac, LV),
+  (0xc0ad, 0xc0c7, Lvt), (0xc0c8, 0xc0c8, LV), (0xc0c9, 0xc0e3, Lvt),
+  (0xc0e4, 0xc0e4, LV), (0xc0e5, 0xc0ff, Lvt), (0xc100, 0xc100, LV),
+  (0xc101, 0xc11b, Lvt), (0xc11c, 0xc11c, LV), (0xc11d, 0xc137, Lvt),
+  (0xc138, 0xc138, LV), (0xc139, 0xc153, Lvt), (0xc154, 0xc154, LV),
+  (0xc155, 0xc16f, Lvt), (0xc170, 0xc170, LV), (0xc171, 0xc18b, Lvt),
+  (0xc18c, 0xc18c, LV), (0xc18d, 0xc1a7, Lvt), (0xc1a8, 0xc1a8, LV),
+  (0xc1a9, 0xc1c3, Lvt), (0xc1c4, 0xc1c4, LV), (0xc1c5, 0xc1df, Lvt),
+  (0xc1e0, 0xc1e0, LV), (0xc1e1, 0xc1fb, Lvt), (0xc1fc, 0xc1fc, LV),
+  (0xc1fd, 0xc217, Lvt), (0xc218, 0xc218, LV), (0xc219, 0xc233, Lvt),
+  (0xc234, 0xc234, LV), (0xc235, 0xc24f, Lvt), (0xc250, 0xc250, LV),
+  (0xc251, 0xc26b, Lvt), (0xc26c, 0xc26c, LV), (0xc26d, 0xc287, Lvt),
+  (0xc288, 0xc288, LV), (0xc289, 0xc2a3, Lvt), (0xc2a4, 0xc2a4, LV),
+  (0xc2a5, 0xc2bf, Lvt), (0xc2c0, 0xc2c0, LV), (0xc2c1, 0xc2db, Lvt),
+  (0xc2dc, 0xc2dc, LV), (0xc2dd, 0xc2f7, Lvt), (0xc2f8, 0xc2f8, LV),
+  (0xc2f9, 0xc313, Lvt), (0xc314, 0xc314, LV), (0xc315, 0xc32f, Lvt),
+  (0xc330, 0xc330, LV), (0xc331, 0xc34b, Lvt), (0xc34c, 0xc34c, LV),
+  (0xc34d, 0xc367, Lvt), (0xc368, 0xc368, LV), (0xc369, 0xc383, Lvt),
+  (0xc384, 0xc384, LV), (0xc385, 0xc39f, Lvt), (0xc3a0, 0xc3a0, LV),
+  (0xc3a1, 0xc3bb, Lvt), (0xc3bc, 0xc3bc, LV), (0xc3bd, 0xc3d7, Lvt),
+  (0xc3d8, 0xc3d8, LV), (0xc3d9, 0xc3f3, Lvt), (0xc3f4, 0xc3f4, LV),
+  (0xc3f5, 0xc40f, Lvt), (0xc410, 0xc410, LV), (0xc411, 0xc42b, Lvt),
+  (0xc42c, 0xc42c, LV), (0xc42d, 0xc447, Lvt), (0xc448, 0xc448, LV),
+  (0xc449, 0xc463, Lvt), (0xc464, 0xc464, LV), (0xc465, 0xc47f, Lvt),
+  (0xc480, 0xc480, LV), (0xc481, 0xc49b, Lvt), (0xc49c, 0xc49c, LV),
+  (0xc49d, 0xc4b7, Lvt), (0xc4b8, 0xc4b8, LV), (0xc4b9, 0xc4d3, Lvt),
+  (0xc4d4, 0xc4d4, LV), (0xc4d5, 0xc4ef, Lvt), (0xc4f0, 0xc4f0, LV),
+  (0xc4f1, 0xc50b, Lvt), (0xc50c, 0xc50c, LV), (0xc50d, 0xc527, Lvt),
+  (0xc528, 0xc528, LV), (0xc529, 0xc543, Lvt), (0xc544, 0xc544, LV),
+  (0xc545, 0xc55f, Lvt), (0xc560, 0xc560, LV), (0xc561, 0xc57b, Lvt),
+  (0xc57c, 0xc57c, LV), (0xc57d, 0xc597, Lvt), (0xc598, 0xc598, LV),
+  (0xc599, 0xc5b3, Lvt), (0xc5b4, 0xc5b4, LV), (0xc5b5, 0xc5cf, Lvt),
+  (0xc5d0, 0xc5d0, LV), (0xc5d1, 0xc5eb, Lvt), (0xc5ec, 0xc5ec, LV),
+  (0xc5ed, 0xc607, Lvt), (0xc608, 0xc608, LV), (0xc609, 0xc623, Lvt),
+  (0xc624, 0xc624, LV), (0xc625, 0xc63f, Lvt), (0xc640, 0xc640, LV),
+  (0xc641, 0xc65b, Lvt), (0xc65c, 0xc65c, LV), (0xc65d, 0xc677, Lvt),
+  (0xc678, 0xc678, LV), (0xc679, 0xc693, Lvt), (0xc694, 0xc694, LV),
+  (0xc695, 0xc6af, Lvt), (0xc6b0, 0xc6b0, LV), (0xc6b1, 0xc6cb, Lvt),
+  (0xc6cc, 0xc6cc, LV), (0xc6cd, 0xc6e7, Lvt), (0xc6e8, 0xc6e8, LV),
+  (0xc6e9, 0xc703, Lvt), (0xc704, 0xc704, LV), (0xc705, 0xc71f, Lvt),
+  (0xc720, 0xc720, LV), (0xc721, 0xc73b, Lvt), (0xc73c, 0xc73c, LV),
+  (0xc73d, 0xc757, Lvt), (0xc758, 0xc758, LV), (0xc759, 0xc773, Lvt),
+  (0xc774, 0xc774, LV), (0xc775, 0xc78f, Lvt), (0xc790, 0xc790, LV),
+  (0xc791, 0xc7ab, Lvt), (0xc7ac, 0xc7ac, LV), (0xc7ad, 0xc7c7, Lvt),
+  (0xc7c8, 0xc7c8, LV), (0xc7c9, 0xc7e3, Lvt), (0xc7e4, 0xc7e4, LV),
+  (0xc7e5, 0xc7ff, Lvt), (0xc800, 0xc800, LV), (0xc801, 0xc81b, Lvt),
+  (0xc81c, 0xc81c, LV), (0xc81d, 0xc837, Lvt), (0xc838, 0xc838, LV),
+  (0xc839, 0xc853, Lvt), (0xc854, 0xc854, LV), (0xc855, 0xc86f, Lvt),
+  (0xc870, 0xc870, LV), (0xc871, 0xc88b, Lvt), (0xc88c, 0xc88c, LV),
+  (0xc88d, 0xc8a7, Lvt), (0xc8a8, 0xc8a8, LV), (0xc8a9, 0xc8c3, Lvt),
+  (0xc8c4, 0xc8c4, LV), (0xc8c5, 0xc8df, Lvt), (0xc8e0, 0xc8e0, LV),
+  (0xc8e1, 0xc8fb, Lvt), (0xc8fc, 0xc8fc, LV), (0xc8fd, 0xc917, Lvt),
+  (0xc918, 0xc918, LV), (0xc919, 0xc933, Lvt), (0xc934, 0xc934, LV),
+  (0xc935, 0xc94f, Lvt), (0xc950, 0xc950, LV), (0xc951, 0xc96b, Lvt),
+  (0xc96c, 0xc96c, LV), (0xc96d, 0xc987, Lvt), (0xc988, 0xc988, LV),
+  (0xc989, 0xc9a3, Lvt), (0xc9a4, 0xc9a4, LV), (0xc9a5, 0xc9bf, Lvt),
+  (0xc9c0, 0xc9c0, LV), (0xc9c1, 0xc9db, Lvt), (0xc9dc, 0xc9dc, LV),
+  (0xc9dd, 0xc9f7, Lvt), (0xc9f8, 0xc9f8, LV), (0xc9f9, 0xca13, Lvt),
+  (0xca14, 0xca14, LV), (0xca15, 0xca2f, Lvt), (0xca30, 0xca30, LV),
+  (0xca31, 0xca4b, Lvt), (0xca4c, 0xca4c, LV), (0xca4d, 0xca67, Lvt),
+  (0xca68, 0xca68, LV), (0xca69, 0xca83, Lvt), (0xca84, 0xca84, LV),
+  (0xca85, 0xca9f, Lvt), (0xcaa0, 0xcaa0, LV), (0xcaa1, 0xcabb, Lvt),
+  (0xcabc, 0xcabc, LV), (0xcabd, 0xcad7, Lvt), (0xcad8, 0xcad8, LV),
+  (0xcad9, 0xcaf3, Lvt), (0xcaf4, 0xcaf4, LV), (0xcaf5, 0xcb0f, Lvt),
+  (0xcb10, 0xcb10, LV), (0xcb11, 0xcb2b, Lvt), (0xcb2c, 0xcb2c, LV),
+  (0xcb2d, 0xcb47, Lvt), (0xcb48, 0xcb48, LV), (0xcb49, 0xcb63, Lvt),
+  (0xcb64, 0xcb64, LV), (0xcb65, 0xcb7f, Lvt), (0xcb80, 0xcb80, LV),
+  (0xcb81, 0xcb9b, Lvt), (0xcb9c, 0xcb9c, LV), (0xcb9d, 0xcbb7, Lvt),
+  (0xcbb8, 0xcbb8, LV), (0xcbb9, 0xcbd3, Lvt), (0xcbd4, 0xcbd4, LV),
+  (0xcbd5, 0xcbef, Lvt), (0xcbf0, 0xcbf0, LV), (0xcbf1, 0xcc0b, Lvt),
+  (0xcc0c, 0xcc0c, LV), (0xcc0d, 0xcc27, Lvt), (0xcc28, 0xcc28, LV),
+  (0xcc29, 0xcc43, Lvt), (0xcc44, 0xcc44, LV), (0xcc45, 0xcc5f, Lvt),
+  (0xcc60, 0xcc60, LV), (0xcc61, 0xcc7b, Lvt), (0xcc7c, 0xcc7c, LV),
+  (0xcc7d, 0xcc97, Lvt), (0xcc98, 0xcc98, LV), (0xcc99, 0xccb3, Lvt),
+  (0xccb4, 0xccb4, LV), (0xccb5, 0xcccf, Lvt), (0xccd0, 0xccd0, LV),
+  (0xccd1, 0xcceb, Lvt), (0xccec, 0xccec, LV), (0xcced, 0xcd07, Lvt),
+  (0xcd08, 0xcd08, LV), (0xcd09, 0xcd23, Lvt), (0xcd24, 0xcd24, LV),
+  (0xcd25, 0xcd3f, Lvt), (0xcd40, 0xcd40, LV), (0xcd41, 0xcd5b, Lvt),
+  (0xcd5c, 0xcd5c, LV), (0xcd5d, 0xcd77, Lvt), (0xcd78, 0xcd78, LV),
+  (0xcd79, 0xcd93, Lvt), (0xcd94, 0xcd94, LV), (0xcd95, 0xcdaf, Lvt),
+  (0xcdb0, 0xcdb0, LV), (0xcdb1, 0xcdcb, Lvt), (0xcdcc, 0xcdcc, LV),
+  (0xcdcd, 0xcde7, Lvt), (0xcde8, 0xcde8, LV), (0xcde9, 0xce03, Lvt),
+  (0xce04, 0xce04, LV), (0xce05, 0xce1f, Lvt), (0xce20, 0xce20, LV),
+  (0xce21, 0xce3b, Lvt), (0xce3c, 0xce3c, LV), (0xce3d, 0xce57, Lvt),
+  (0xce58, 0xce58, LV), (0xce59, 0xce73, Lvt), (0xce74, 0xce74, LV),
+  (0xce75, 0xce8f, Lvt), (0xce90, 0xce90, LV), (0xce91, 0xceab, Lvt),
+  (0xceac, 0xceac, LV), (0xcead, 0xcec7, Lvt), (0xcec8, 0xcec8, LV),
+  (0xcec9, 0xcee3, Lvt), (0xcee4, 0xcee4, LV), (0xcee5, 0xceff, Lvt),
+  (0xcf00, 0xcf00, LV), (0xcf01, 0xcf1b, Lvt), (0xcf1c, 0xcf1c, LV),
+  (0xcf1d, 0xcf37, Lvt), (0xcf38, 0xcf38, LV), (0xcf39, 0xcf53, Lvt),
+  (0xcf54, 0xcf54, LV), (0xcf55, 0xcf6f, Lvt), (0xcf70, 0xcf70, LV),
+  (0xcf71, 0xcf8b, Lvt), (0xcf8c, 0xcf8c, LV), (0xcf8d, 0xcfa7, Lvt),
+  (0xcfa8, 0xcfa8, LV), (0xcfa9, 0xcfc3, Lvt), (0xcfc4, 0xcfc4, LV),
+  (0xcfc5, 0xcfdf, Lvt), (0xcfe0, 0xcfe0, LV), (0xcfe1, 0xcffb, Lvt),
+  (0xcffc, 0xcffc, LV), (0xcffd, 0xd017, Lvt), (0xd018, 0xd018, LV),
+  (0xd019, 0xd033, Lvt), (0xd034, 0xd034, LV), (0xd035, 0xd04f, Lvt),
+  (0xd050, 0xd050, LV), (0xd051, 0xd06b, Lvt), (0xd06c, 0xd06c, LV),
+  (0xd06d, 0xd087, Lvt), (0xd088, 0xd088, LV), (0xd089, 0xd0a3, Lvt),
+  (0xd0a4, 0xd0a4, LV), (0xd0a5, 0xd0bf, Lvt), (0xd0c0, 0xd0c0, LV),
+  (0xd0c1, 0xd0db, Lvt), (0xd0dc, 0xd0dc, LV), (0xd0dd, 0xd0f7, Lvt),
+  (0xd0f8, 0xd0f8, LV), (0xd0f9, 0xd113, Lvt), (0xd114, 0xd114, LV),
+  (0xd115, 0xd12f, Lvt), (0xd130, 0xd130, LV), (0xd131, 0xd14b, Lvt),
+  (0xd14c, 0xd14c, LV), (0xd14d, 0xd167, Lvt), (0xd168, 0xd168, LV),
+  (0xd169, 0xd183, Lvt), (0xd184, 0xd184, LV), (0xd185, 0xd19f, Lvt),
+  (0xd1a0, 0xd1a0, LV), (0xd1a1, 0xd1bb, Lvt), (0xd1bc, 0xd1bc, LV),
+  (0xd1bd, 0xd1d7, Lvt), (0xd1d8, 0xd1d8, LV), (0xd1d9, 0xd1f3, Lvt),
+  (0xd1f4, 0xd1f4, LV), (0xd1f5, 0xd20f, Lvt), (0xd210, 0xd210, LV),
+  (0xd211, 0xd22b, Lvt), (0xd22c, 0xd22c, LV), (0xd22d, 0xd247, Lvt),
+  (0xd248, 0xd248, LV), (0xd249, 0xd263, Lvt), (0xd264, 0xd264, LV),
+  (0xd265, 0xd27f, Lvt), (0xd280, 0xd280, LV), (0xd281, 0xd29b, Lvt),
+  (0xd29c, 0xd29c, LV), (0xd29d, 0xd2b7, Lvt), (0xd2b8, 0xd2b8, LV),
+  (0xd2b9, 0xd2d3, Lvt), (0xd2d4, 0xd2d4, LV), (0xd2d5, 0xd2ef, Lvt),
+  (0xd2f0, 0xd2f0, LV), (0xd2f1, 0xd30b, Lvt), (0xd30c, 0xd30c, LV),
+  (0xd30d, 0xd327, Lvt), (0xd328, 0xd328, LV), (0xd329, 0xd343, Lvt),
+  (0xd344, 0xd344, LV), (0xd345, 0xd35f, Lvt), (0xd360, 0xd360, LV),
+  (0xd361, 0xd37b, Lvt), (0xd37c, 0xd37c, LV), (0xd37d, 0xd397, Lvt),
+  (0xd398, 0xd398, LV), (0xd399, 0xd3b3, Lvt), (0xd3b4, 0xd3b4, LV),
+  (0xd3b5, 0xd3cf, Lvt), (0xd3d0, 0xd3d0, LV), (0xd3d1, 0xd3eb, Lvt),
+  (0xd3ec, 0xd3ec, LV), (0xd3ed, 0xd407, Lvt), (0xd408, 0xd408, LV),
+  (0xd409, 0xd423, Lvt), (0xd424, 0xd424, LV), (0xd425, 0xd43f, Lvt),
+  (0xd440, 0xd440, LV), (0xd441, 0xd45b, Lvt), (0xd45c, 0xd45c, LV),
+  (0xd45d, 0xd477, Lvt), (0xd478, 0xd478, LV), (0xd479, 0xd493, Lvt),
+  (0xd494, 0xd494, LV), (0xd495, 0xd4af, Lvt), (0xd4b0, 0xd4b0, LV),
+  (0xd4b1, 0xd4cb, Lvt), (0xd4cc, 0xd4cc, LV), (0xd4cd, 0xd4e7, Lvt),
+  (0xd4e8, 0xd4e8, LV), (0xd4e9, 0xd503, Lvt), (0xd504, 0xd504, LV),
+  (0xd505, 0xd51f, Lvt), (0xd520, 0xd520, LV), (0xd521, 0xd53b, Lvt),
+  (0xd53c, 0xd53c, LV), (0xd53d, 0xd557, Lvt), (0xd558, 0xd558, LV),
+  (0xd559, 0xd573, Lvt), (0xd574, 0xd574, LV), (0xd575, 0xd58f, Lvt),
+  (0xd590, 0xd590, LV), (0xd591, 0xd5ab, Lvt), (0xd5ac, 0xd5ac, LV),
+  (0xd5ad, 0xd5c7, Lvt), (0xd5c8, 0xd5c8, LV), (0xd5c9, 0xd5e3, Lvt),
+  (0xd5e4, 0xd5e4, LV), (0xd5e5, 0xd5ff, Lvt), (0xd600, 0xd600, LV),
+  (0xd601, 0xd61b, Lvt), (0xd61c, 0xd61c, LV), (0xd61d, 0xd637, Lvt),
+  (0xd638, 0xd638, LV), (0xd639, 0xd653, Lvt), (0xd654, 0xd654, LV),
+  (0xd655, 0xd66f, Lvt), (0xd670, 0xd670, LV), (0xd671, 0xd68b, Lvt),
+  (0xd68c, 0xd68c, LV), (0xd68d, 0xd6a7, Lvt), (0xd6a8, 0xd6a8, LV),
+  (0xd6a9, 0xd6c3, Lvt), (0xd6c4, 0xd6c4, LV), (0xd6c5, 0xd6df, Lvt),
+  (0xd6e0, 0xd6e0, LV), (0xd6e1, 0xd6fb, Lvt), (0xd6fc, 0xd6fc, LV),
+  (0xd6fd, 0xd717, Lvt), (0xd718, 0xd718, LV), (0xd719, 0xd733, Lvt),
+  (0xd734, 0xd734, LV), (0xd735, 0xd74f, Lvt), (0xd750, 0xd750, LV),
+  (0xd751, 0xd76b, Lvt), (0xd76c, 0xd76c, LV), (0xd76d, 0xd787, Lvt),
+  (0xd788, 0xd788, LV), (0xd789, 0xd7a3, Lvt), (0xd7b0, 0xd7c6, V),
+  (0xd7cb, 0xd7fb, T), (0xfb1e, 0xfb1e, Extend), (0xfe00, 0xfe0f, Extend),
+  (0xfe20, 0xfe2f, Extend), (0xfeff, 0xfeff, Control), (0xff9e, 0xff9f, Extend),
+  (0xfff0, 0xfffb, Control), (0x101fd, 0x101fd, Extend),
+  (0x102e0, 0x102e0, Extend), (0x10376, 0x1037a, Extend),
+  (0x10a01, 0x10a03, Extend), (0x10a05, 0x10a06, Extend),
+  (0x10a0c, 0x10a0f, Extend), (0x10a38, 0x10a3a, Extend),
+  (0x10a3f, 0x10a3f, Extend), (0x10ae5, 0x10ae6, Extend),
+  (0x10d24, 0x10d27, Extend), (0x10d69, 0x10d6d, Extend),
+  (0x10eab, 0x10eac, Extend), (0x10efa, 0x10eff, Extend),
+  (0x10f46, 0x10f50, Extend), (0x10f82, 0x10f85, Extend),
+  (0x11000, 0x11000, SpacingMark), (0x11001, 0x11001, Extend),
+  (0x11002, 0x11002, SpacingMark), (0x11038, 0x11046, Extend),
+  (0x11070, 0x11070, Extend), (0x11073, 0x11074, Extend),
+  (0x1107f, 0x11081, Extend), (0x11082, 0x11082, SpacingMark),
+  (0x110b0, 0x110b2, SpacingMark), (0x110b3, 0x110b6, Extend),
+  (0x110b7, 0x110b8, SpacingMark), (0x110b9, 0x110ba, Extend),
+  (0x110bd, 0x110bd, Prepend), (0x110c2, 0x110c2, Extend),
+  (0x110cd, 0x110cd, Prepend), (0x11100, 0x11102, Extend),
+  (0x11127, 0x1112b, Extend), (0x1112c, 0x1112c, SpacingMark),
+  (0x1112d, 0x11134, Extend), (0x11145, 0x11146, SpacingMark),
+  (0x11173, 0x11173, Extend), (0x11180, 0x11181, Extend),
+  (0x11182, 0x11182, SpacingMark), (0x111b3, 0x111b5, SpacingMark),
+  (0x111b6, 0x111be, Extend), (0x111bf, 0x111bf, SpacingMark),
+  (0x111c0, 0x111c0, Extend), (0x111c2, 0x111c3, Prepend),
+  (0x111c9, 0x111cc, Extend), (0x111ce, 0x111ce, SpacingMark),
+  (0x111cf, 0x111cf, Extend), (0x1122c, 0x1122e, SpacingMark),
+  (0x1122f, 0x11231, Extend), (0x11232, 0x11233, SpacingMark),
+  (0x11234, 0x11237, Extend), (0x1123e, 0x1123e, Extend),
+  (0x11241, 0x11241, Extend), (0x112df, 0x112df, Extend),
+  (0x112e0, 0x112e2, SpacingMark), (0x112e3, 0x112ea, Extend),
+  (0x11300, 0x11301, Extend), (0x11302, 0x11303, SpacingMark),
+  (0x1133b, 0x1133c, Extend), (0x1133e, 0x1133e, Extend),
+  (0x1133f, 0x1133f, SpacingMark), (0x11340, 0x11340, Extend),
+  (0x11341, 0x11344, SpacingMark), (0x11347, 0x11348, SpacingMark),
+  (0x1134b, 0x1134c, SpacingMark), (0x1134d, 0x1134d, Extend),
+  (0x11357, 0x11357, Extend), (0x11362, 0x11363, SpacingMark),
+  (0x11366, 0x1136c, Extend), (0x11370, 0x11374, Extend),
+  (0x113b8, 0x113b8, Extend), (0x113b9, 0x113ba, SpacingMark),
+  (0x113bb, 0x113c0, Extend), (0x113c2, 0x113c2, Extend),
+  (0x113c5, 0x113c5, Extend), (0x113c7, 0x113c9, Extend),
+  (0x113ca, 0x113ca, SpacingMark), (0x113cc, 0x113cd, SpacingMark),
+  (0x113ce, 0x113d0, Extend), (0x113d1, 0x113d1, Prepend),
+  (0x113d2, 0x113d2, Extend), (0x113e1, 0x113e2, Extend),
+  (0x11435, 0x11437, SpacingMark), (0x11438, 0x1143f, Extend),
+  (0x11440, 0x11441, SpacingMark), (0x11442, 0x11444, Extend),
+  (0x11445, 0x11445, SpacingMark), (0x11446, 0x11446, Extend),
+  (0x1145e, 0x1145e, Extend), (0x114b0, 0x114b0, Extend),
+  (0x114b1, 0x114b2, SpacingMark), (0x114b3, 0x114b8, Extend),
+  (0x114b9, 0x114b9, SpacingMark), (0x114ba, 0x114ba, Extend),
+  (0x114bb, 0x114bc, SpacingMark), (0x114bd, 0x114bd, Extend),
+  (0x114be, 0x114be, SpacingMark), (0x114bf, 0x114c0, Extend),
+  (0x114c1, 0x114c1, SpacingMark), (0x114c2, 0x114c3, Extend),
+  (0x115af, 0x115af, Extend), (0x115b0, 0x115b1, SpacingMark),
+  (0x115b2, 0x115b5, Extend), (0x115b8, 0x115bb, SpacingMark),
+  (0x115bc, 0x115bd, Extend), (0x115be, 0x115be, SpacingMark),
+  (0x115bf, 0x115c0, Extend), (0x115dc, 0x115dd, Extend),
+  (0x11630, 0x11632, SpacingMark), (0x11633, 0x1163a, Extend),
+  (0x1163b, 0x1163c, SpacingMark), (0x1163d, 0x1163d, Extend),
+  (0x1163e, 0x1163e, SpacingMark), (0x1163f, 0x11640, Extend),
+  (0x116ab, 0x116ab, Extend), (0x116ac, 0x116ac, SpacingMark),
+  (0x116ad, 0x116ad, Extend), (0x116ae, 0x116af, SpacingMark),
+  (0x116b0, 0x116b7, Extend), (0x1171d, 0x1171d, Extend),
+  (0x1171e, 0x1171e, SpacingMark), (0x1171f, 0x1171f, Extend),
+  (0x11722, 0x11725, Extend), (0x11726, 0x11726, SpacingMark),
+  (0x11727, 0x1172b, Extend), (0x1182c, 0x1182e, SpacingMark),
+  (0x1182f, 0x11837, Extend), (0x11838, 0x11838, SpacingMark),
+  (0x11839, 0x1183a, Extend), (0x11930, 0x11930, Extend),
+  (0x11931, 0x11935, SpacingMark), (0x11937, 0x11938, SpacingMark),
+  (0x1193b, 0x1193e, Extend), (0x1193f, 0x1193f, Prepend),
+  (0x11940, 0x11940, SpacingMark), (0x11941, 0x11941, Prepend),
+  (0x11942, 0x11942, SpacingMark), (0x11943, 0x11943, Extend),
+  (0x119d1, 0x119d3, SpacingMark), (0x119d4, 0x119d7, Extend),
+  (0x119da, 0x119db, Extend), (0x119dc, 0x119df, SpacingMark),
+  (0x119e0, 0x119e0, Extend), (0x119e4, 0x119e4, SpacingMark),
+  (0x11a01, 0x11a0a, Extend), (0x11a33, 0x11a38, Extend),
+  (0x11a39, 0x11a39, SpacingMark), (0x11a3b, 0x11a3e, Extend),
+  (0x11a47, 0x11a47, Extend), (0x11a51, 0x11a56, Extend),
+  (0x11a57, 0x11a58, SpacingMark), (0x11a59, 0x11a5b, Extend),
+  (0x11a84, 0x11a89, Prepend), (0x11a8a, 0x11a96, Extend),
+  (0x11a97, 0x11a97, SpacingMark), (0x11a98, 0x11a99, Extend),
+  (0x11b60, 0x11b60, Extend), (0x11b61, 0x11b61, SpacingMark),
+  (0x11b62, 0x11b64, Extend), (0x11b65, 0x11b65, SpacingMark),
+  (0x11b66, 0x11b66, Extend), (0x11b67, 0x11b67, SpacingMark),
+  (0x11c2f, 0x11c2f, SpacingMark), (0x11c30, 0x11c36, Extend),
+  (0x11c38, 0x11c3d, Extend), (0x11c3e, 0x11c3e, SpacingMark),
+  (0x11c3f, 0x11c3f, Extend), (0x11c92, 0x11ca7, Extend),
+  (0x11ca9, 0x11ca9, SpacingMark), (0x11caa, 0x11cb0, Extend),
+  (0x11cb1, 0x11cb1, SpacingMark), (0x11cb2, 0x11cb3, Extend),
+  (0x11cb4, 0x11cb4, SpacingMark), (0x11cb5, 0x11cb6, Extend),
+  (0x11d31, 0x11d36, Extend), (0x11d3a, 0x11d3a, Extend),
+  (0x11d3c, 0x11d3d, Extend), (0x11d3f, 0x11d45, Extend),
+  (0x11d46, 0x11d46, Prepend), (0x11d47, 0x11d47, Extend),
+  (0x11d8a, 0x11d8e, SpacingMark), (0x11d90, 0x11d91, Extend),
+  (0x11d93, 0x11d94, SpacingMark), (0x11d95, 0x11d95, Extend),
+  (0x11d96, 0x11d96, SpacingMark), (0x11d97, 0x11d97, Extend),
+  (0x11ef3, 0x11ef4, Extend), (0x11ef5, 0x11ef6, SpacingMark),
+  (0x11f00, 0x11f01, Extend), (0x11f02, 0x11f02, Prepend),
+  (0x11f03, 0x11f03, SpacingMark), (0x11f34, 0x11f35, SpacingMark),
+  (0x11f36, 0x11f3a, Extend), (0x11f3e, 0x11f3f, SpacingMark),
+  (0x11f40, 0x11f42, Extend), (0x11f5a, 0x11f5a, Extend),
+  (0x13430, 0x1343f, Control), (0x13440, 0x13440, Extend),
+  (0x13447, 0x13455, Extend), (0x1611e, 0x16129, Extend),
+  (0x1612a, 0x1612c, SpacingMark), (0x1612d, 0x1612f, Extend),
+  (0x16af0, 0x16af4, Extend), (0x16b30, 0x16b36, Extend), (0x16d63, 0x16d63, V),
+  (0x16d67, 0x16d6a, V), (0x16f4f, 0x16f4f, Extend),
+  (0x16f51, 0x16f87, SpacingMark), (0x16f8f, 0x16f92, Extend),
+  (0x16fe4, 0x16fe4, Extend), (0x16ff0, 0x16ff1, Extend),
+  (0x1bc9d, 0x1bc9e, Extend), (0x1bca0, 0x1bca3, Control),
+  (0x1cf00, 0x1cf2d, Extend), (0x1cf30, 0x1cf46, Extend),
+  (0x1d165, 0x1d169, Extend), (0x1d16d, 0x1d172, Extend),
+  (0x1d173, 0x1d17a, Control), (0x1d17b, 0x1d182, Extend),
+  (0x1d185, 0x1d18b, Extend), (0x1d1aa, 0x1d1ad, Extend),
+  (0x1d242, 0x1d244, Extend), (0x1da00, 0x1da36, Extend),
+  (0x1da3b, 0x1da6c, Extend), (0x1da75, 0x1da75, Extend),
+  (0x1da84, 0x1da84, Extend), (0x1da9b, 0x1da9f, Extend),
+  (0x1daa1, 0x1daaf, Extend), (0x1e000, 0x1e006, Extend),
+  (0x1e008, 0x1e018, Extend), (0x1e01b, 0x1e021, Extend),
+  (0x1e023, 0x1e024, Extend), (0x1e026, 0x1e02a, Extend),
+  (0x1e08f, 0x1e08f, Extend), (0x1e130, 0x1e136, Extend),
+  (0x1e2ae, 0x1e2ae, Extend), (0x1e2ec, 0x1e2ef, Extend),
+  (0x1e4ec, 0x1e4ef, Extend), (0x1e5ee, 0x1e5ef, Extend),
+  (0x1e6e3, 0x1e6e3, Extend), (0x1e6e6, 0x1e6e6, Extend),
+  (0x1e6ee, 0x1e6ef, Extend), (0x1e6f5, 0x1e6f5, Extend),
+  (0x1e8d0, 0x1e8d6, Extend), (0x1e944, 0x1e94a, Extend),
+  (0x1f1e6, 0x1f1ff, RegionalIndicator), (0x1f3fb, 0x1f3ff, Extend),
+  (0xe0000, 0xe001f, Control), (0xe0020, 0xe007f, Extend),
+  (0xe0080, 0xe00ff, Control), (0xe0100, 0xe01ef, Extend),
+  (0xe01f0, 0xe0fff, Control)
+];
+
+// Indic_Conjunct_Break values other than None
+#[rustfmt::skip]
+pub static CONJUNCT_BREAKS: [(u32, u32, IndicConjunctBreak); 473] = [
+  (0x300, 0x36f, InCB::Extend), (0x483, 0x489, InCB::Extend),
+  (0x591, 0x5bd, InCB::Extend), (0x5bf, 0x5bf, InCB::Extend),
+  (0x5c1, 0x5c2, InCB::Extend), (0x5c4, 0x5c5, InCB::Extend),
+  (0x5c7, 0x5c7, InCB::Extend), (0x610, 0x61a, InCB::Extend),
+  (0x64b, 0x65f, InCB::Extend), (0x670, 0x670, InCB::Extend),
+  (0x6d6, 0x6dc, InCB::Extend), (0x6df, 0x6e4, InCB::Extend),
+  (0x6e7, 0x6e8, InCB::Extend), (0x6ea, 0x6ed, InCB::Extend),
+  (0x711, 0x711, InCB::Extend), (0x730, 0x74a, InCB::Extend),
+  (0x7a6, 0x7b0, InCB::Extend), (0x7eb, 0x7f3, InCB::Extend),
+  (0x7fd, 0x7fd, InCB::Extend), (0x816, 0x819, InCB::Extend),
+  (0x81b, 0x823, InCB::Extend), (0x825, 0x827, InCB::Extend),
+  (0x829, 0x82d, InCB::Extend), (0x859, 0x85b, InCB::Extend),
+  (0x897, 0x89f, InCB::Extend), (0x8ca, 0x8e1, InCB::Extend),
+  (0x8e3, 0x902, InCB::Extend), (0x915, 0x939, InCB::Consonant),
+  (0x93a, 0x93a, InCB::Extend), (0x93c, 0x93c, InCB::Extend),
+  (0x941, 0x948, InCB::Extend), (0x94d, 0x94d, InCB::Linker),
+  (0x951, 0x957, InCB::Extend), (0x958, 0x95f, InCB::Consonant),
+  (0x962, 0x963, InCB::Extend), (0x978, 0x97f, InCB::Consonant),
+  (0x981, 0x981, InCB::Extend), (0x995, 0x9a8, InCB::Consonant),
+  (0x9aa, 0x9b0, InCB::Consonant), (0x9b2, 0x9b2, InCB::Consonant),
+  (0x9b6, 0x9b9, InCB::Consonant), (0x9bc, 0x9bc, InCB::Extend),
+  (0x9be, 0x9be, InCB::Extend), (0x9c1, 0x9c4, InCB::Extend),
+  (0x9cd, 0x9cd, InCB::Linker), (0x9d7, 0x9d7, InCB::Extend),
+  (0x9dc, 0x9dd, InCB::Consonant), (0x9df, 0x9df, InCB::Consonant),
+  (0x9e2, 0x9e3, InCB::Extend), (0x9f0, 0x9f1, InCB::Consonant),
+  (0x9fe, 0x9fe, InCB::Extend), (0xa01, 0xa02, InCB::Extend),
+  (0xa3c, 0xa3c, InCB::Extend), (0xa41, 0xa42, InCB::Extend),
+  (0xa47, 0xa48, InCB::Extend), (0xa4b, 0xa4d, InCB::Extend),
+  (0xa51, 0xa51, InCB::Extend), (0xa70, 0xa71, InCB::Extend),
+  (0xa75, 0xa75, InCB::Extend), (0xa81, 0xa82, InCB::Extend),
+  (0xa95, 0xaa8, InCB::Consonant), (0xaaa, 0xab0, InCB::Consonant),
+  (0xab2, 0xab3, InCB::Consonant), (0xab5, 0xab9, InCB::Consonant),
+  (0xabc, 0xabc, InCB::Extend), (0xac1, 0xac5, InCB::Extend),
+  (0xac7, 0xac8, InCB::Extend), (0xacd, 0xacd, InCB::Linker),
+  (0xae2, 0xae3, InCB::Extend), (0xaf9, 0xaf9, InCB::Consonant),
+  (0xafa, 0xaff, InCB::Extend), (0xb01, 0xb01, InCB::Extend),
+  (0xb15, 0xb28, InCB::Consonant), (0xb2a, 0xb30, InCB::Consonant),
+  (0xb32, 0xb33, InCB::Consonant), (0xb35, 0xb39, InCB::Consonant),
+  (0xb3c, 0xb3c, InCB::Extend), (0xb3e, 0xb3f, InCB::Extend),
+  (0xb41, 0xb44, InCB::Extend), (0xb4d, 0xb4d, InCB::Linker),
+  (0xb55, 0xb57, InCB::Extend), (0xb5c, 0xb5d, InCB::Consonant),
+  (0xb5f, 0xb5f, InCB::Consonant), (0xb62, 0xb63, InCB::Extend),
+  (0xb71, 0xb71, InCB::Consonant), (0xb82, 0xb82, InCB::Extend),
+  (0xbbe, 0xbbe, InCB::Extend), (0xbc0, 0xbc0, InCB::Extend),
+  (0xbcd, 0xbcd, InCB::Extend), (0xbd7, 0xbd7, InCB::Extend),
+  (0xc00, 0xc00, InCB::Extend), (0xc04, 0xc04, InCB::Extend),
+  (0xc15, 0xc28, InCB::Consonant), (0xc2a, 0xc39, InCB::Consonant),
+  (0xc3c, 0xc3c, InCB::Extend), (0xc3e, 0xc40, InCB::Extend),
+  (0xc46, 0xc48, InCB::Extend), (0xc4a, 0xc4c, InCB::Extend),
+  (0xc4d, 0xc4d, InCB::Linker), (0xc55, 0xc56, InCB::Extend),
+  (0xc58, 0xc5a, InCB::Consonant), (0xc62, 0xc63, InCB::Extend),
+  (0xc81, 0xc81, InCB::Extend), (0xcbc, 0xcbc, InCB::Extend),
+  (0xcbf, 0xcc0, InCB::Extend), (0xcc2, 0xcc2, InCB::Extend),
+  (0xcc6, 0xcc8, InCB::Extend), (0xcca, 0xccd, InCB::Extend),
+  (0xcd5, 0xcd6, InCB::Extend), (0xce2, 0xce3, InCB::Extend),
+  (0xd00, 0xd01, InCB::Extend), (0xd15, 0xd3a, InCB::Consonant),
+  (0xd3b, 0xd3c, InCB::Extend), (0xd3e, 0xd3e, InCB::Extend),
+  (0xd41, 0xd44, InCB::Extend), (0xd4d, 0xd4d, InCB::Linker),
+  (0xd57, 0xd57, InCB::Extend), (0xd62, 0xd63, InCB::Extend),
+  (0xd81, 0xd81, InCB::Extend), (0xdca, 0xdca, InCB::Extend),
+  (0xdcf, 0xdcf, InCB::Extend), (0xdd2, 0xdd4, InCB::Extend),
+  (0xdd6, 0xdd6, InCB::Extend), (0xddf, 0xddf, InCB::Extend),
+  (0xe31, 0xe31, InCB::Extend), (0xe34, 0xe3a, InCB::Extend),
+  (0xe47, 0xe4e, InCB::Extend), (0xeb1, 0xeb1, InCB::Extend),
+  (0xeb4, 0xebc, InCB::Extend), (0xec8, 0xece, InCB::Extend),
+  (0xf18, 0xf19, InCB::Extend), (0xf35, 0xf35, InCB::Extend),
+  (0xf37, 0xf37, InCB::Extend), (0xf39, 0xf39, InCB::Extend),
+  (0xf71, 0xf7e, InCB::Extend), (0xf80, 0xf84, InCB::Extend),
+  (0xf86, 0xf87, InCB::Extend), (0xf8d, 0xf97, InCB::Extend),
+  (0xf99, 0xfbc, InCB::Extend), (0xfc6, 0xfc6, InCB::Extend),
+  (0x1000, 0x102a, InCB::Consonant), (0x102d, 0x1030, InCB::Extend),
+  (0x1032, 0x1037, InCB::Extend), (0x1039, 0x1039, InCB::Linker),
+  (0x103a, 0x103a, InCB::Extend), (0x103d, 0x103e, InCB::Extend),
+  (0x103f, 0x103f, InCB::Consonant), (0x1050, 0x1055, InCB::Consonant),
+  (0x1058, 0x1059, InCB::Extend), (0x105a, 0x105d, InCB::Consonant),
+  (0x105e, 0x1060, InCB::Extend), (0x1061, 0x1061, InCB::Consonant),
+  (0x1065, 0x1066, InCB::Consonant), (0x106e, 0x1070, InCB::Consonant),
+  (0x1071, 0x1074, InCB::Extend), (0x1075, 0x1081, InCB::Consonant),
+  (0x1082, 0x1082, InCB::Extend), (0x1085, 0x1086, InCB::Extend),
+  (0x108d, 0x108d, InCB::Extend), (0x108e, 0x108e, InCB::Consonant),
+  (0x109d, 0x109d, InCB::Extend), (0x135d, 0x135f, InCB::Extend),
+  (0x1712, 0x1715, InCB::Extend), (0x1732, 0x1734, InCB::Extend),
+  (0x1752, 0x1753, InCB::Extend), (0x1772, 0x1773, InCB::Extend),
+  (0x1780, 0x17b3, InCB::Consonant), (0x17b4, 0x17b5, InCB::Extend),
+  (0x17b7, 0x17bd, InCB::Extend), (0x17c6, 0x17c6, InCB::Extend),
+  (0x17c9, 0x17d1, InCB::Extend), (0x17d2, 0x17d2, InCB::Linker),
+  (0x17d3, 0x17d3, InCB::Extend), (0x17dd, 0x17dd, InCB::Extend),
+  (0x180b, 0x180d, InCB::Extend), (0x180f, 0x180f, InCB::Extend),
+  (0x1885, 0x1886, InCB::Extend), (0x18a9, 0x18a9, InCB::Extend),
+  (0x1920, 0x1922, InCB::Extend), (0x1927, 0x1928, InCB::Extend),
+  (0x1932, 0x1932, InCB::Extend), (0x1939, 0x193b, InCB::Extend),
+  (0x1a17, 0x1a18, InCB::Extend), (0x1a1b, 0x1a1b, InCB::Extend),
+  (0x1a20, 0x1a54, InCB::Consonant), (0x1a56, 0x1a56, InCB::Extend),
+  (0x1a58, 0x1a5e, InCB::Extend), (0x1a60, 0x1a60, InCB::Linker),
+  (0x1a62, 0x1a62, InCB::Extend), (0x1a65, 0x1a6c, InCB::Extend),
+  (0x1a73, 0x1a7c, InCB::Extend), (0x1a7f, 0x1a7f, InCB::Extend),
+  (0x1ab0, 0x1add, InCB::Extend), (0x1ae0, 0x1aeb, InCB::Extend),
+  (0x1b00, 0x1b03, InCB::Extend), (0x1b0b, 0x1b0c, InCB::Consonant),
+  (0x1b13, 0x1b33, InCB::Consonant), (0x1b34, 0x1b3d, InCB::Extend),
+  (0x1b42, 0x1b43, InCB::Extend), (0x1b44, 0x1b44, InCB::Linker),
+  (0x1b45, 0x1b4c, InCB::Consonant), (0x1b6b, 0x1b73, InCB::Extend),
+  (0x1b80, 0x1b81, InCB::Extend), (0x1b83, 0x1ba0, InCB::Consonant),
+  (0x1ba2, 0x1ba5, InCB::Extend), (0x1ba8, 0x1baa, InCB::Extend),
+  (0x1bab, 0x1bab, InCB::Linker), (0x1bac, 0x1bad, InCB::Extend),
+  (0x1bae, 0x1baf, InCB::Consonant), (0x1bbb, 0x1bbd, InCB::Consonant),
+  (0x1be6, 0x1be6, InCB::Extend), (0x1be8, 0x1be9, InCB::Extend),
+  (0x1bed, 0x1bed, InCB::Extend), (0x1bef, 0x1bf3, InCB::Extend),
+  (0x1c2c, 0x1c33, InCB::Extend), (0x1c36, 0x1c37, InCB::Extend),
+  (0x1cd0, 0x1cd2, InCB::Extend), (0x1cd4, 0x1ce0, InCB::Extend),
+  (0x1ce2, 0x1ce8, InCB::Extend), (0x1ced, 0x1ced, InCB::Extend),
+  (0x1cf4, 0x1cf4, InCB::Extend), (0x1cf8, 0x1cf9, InCB::Extend),
+  (0x1dc0, 0x1dff, InCB::Extend), (0x200d, 0x200d, InCB::Extend),
+  (0x20d0, 0x20f0, InCB::Extend), (0x2cef, 0x2cf1, InCB::Extend),
+  (0x2d7f, 0x2d7f, InCB::Extend), (0x2de0, 0x2dff, InCB::Extend),
+  (0x302a, 0x302f, InCB::Extend), (0x3099, 0x309a, InCB::Extend),
+  (0xa66f, 0xa672, InCB::Extend), (0xa674, 0xa67d, InCB::Extend),
+  (0xa69e, 0xa69f, InCB::Extend), (0xa6f0, 0xa6f1, InCB::Extend),
+  (0xa802, 0xa802, InCB::Extend), (0xa806, 0xa806, InCB::Extend),
+  (0xa80b, 0xa80b, InCB::Extend), (0xa825, 0xa826, InCB::Extend),
+  (0xa82c, 0xa82c, InCB::Extend), (0xa8c4, 0xa8c5, InCB::Extend),
+  (0xa8e0, 0xa8f1, InCB::Extend), (0xa8ff, 0xa8ff, InCB::Extend),
+  (0xa926, 0xa92d, InCB::Extend), (0xa947, 0xa951, InCB::Extend),
+  (0xa953, 0xa953, InCB::Extend), (0xa980, 0xa982, InCB::Extend),
+  (0xa989, 0xa98b, InCB::Consonant), (0xa98f, 0xa9b2, InCB::Consonant),
+  (0xa9b3, 0xa9b3, InCB::Extend), (0xa9b6, 0xa9b9, InCB::Extend),
+  (0xa9bc, 0xa9bd, InCB::Extend), (0xa9c0, 0xa9c0, InCB::Linker),
+  (0xa9e0, 0xa9e4, InCB::Consonant), (0xa9e5, 0xa9e5, InCB::Extend),
+  (0xa9e7, 0xa9ef, InCB::Consonant), (0xa9fa, 0xa9fe, InCB::Consonant),
+  (0xaa29, 0xaa2e, InCB::Extend), (0xaa31, 0xaa32, InCB::Extend),
+  (0xaa35, 0xaa36, InCB::Extend), (0xaa43, 0xaa43, InCB::Extend),
+  (0xaa4c, 0xaa4c, InCB::Extend), (0xaa60, 0xaa6f, InCB::Consonant),
+  (0xaa71, 0xaa73, InCB::Consonant), (0xaa7a, 0xaa7a, InCB::Consonant),
+  (0xaa7c, 0xaa7c, InCB::Extend), (0xaa7e, 0xaa7f, InCB::Consonant),
+  (0xaab0, 0xaab0, InCB::Extend), (0xaab2, 0xaab4, InCB::Extend),
+  (0xaab7, 0xaab8, InCB::Extend), (0xaabe, 0xaabf, InCB::Extend),
+  (0xaac1, 0xaac1, InCB::Extend), (0xaae0, 0xaaea, InCB::Consonant),
+  (0xaaec, 0xaaed, InCB::Extend), (0xaaf6, 0xaaf6, InCB::Linker),
+  (0xabc0, 0xabda, InCB::Consonant), (0xabe5, 0xabe5, InCB::Extend),
+  (0xabe8, 0xabe8, InCB::Extend), (0xabed, 0xabed, InCB::Extend),
+  (0xfb1e, 0xfb1e, InCB::Extend), (0xfe00, 0xfe0f, InCB::Extend),
+  (0xfe20, 0xfe2f, InCB::Extend), (0xff9e, 0xff9f, InCB::Extend),
+  (0x101fd, 0x101fd, InCB::Extend), (0x102e0, 0x102e0, InCB::Extend),
+  (0x10376, 0x1037a, InCB::Extend), (0x10a00, 0x10a00, InCB::Consonant),
+  (0x10a01, 0x10a03, InCB::Extend), (0x10a05, 0x10a06, InCB::Extend),
+  (0x10a0c, 0x10a0f, InCB::Extend), (0x10a10, 0x10a13, InCB::Consonant),
+  (0x10a15, 0x10a17, InCB::Consonant), (0x10a19, 0x10a35, InCB::Consonant),
+  (0x10a38, 0x10a3a, InCB::Extend), (0x10a3f, 0x10a3f, InCB::Linker),
+  (0x10ae5, 0x10ae6, InCB::Extend), (0x10d24, 0x10d27, InCB::Extend),
+  (0x10d69, 0x10d6d, InCB::Extend), (0x10eab, 0x10eac, InCB::Extend),
+  (0x10efa, 0x10eff, InCB::Extend), (0x10f46, 0x10f50, InCB::Extend),
+  (0x10f82, 0x10f85, InCB::Extend), (0x11001, 0x11001, InCB::Extend),
+  (0x11038, 0x11046, InCB::Extend), (0x11070, 0x11070, InCB::Extend),
+  (0x11073, 0x11074, InCB::Extend), (0x1107f, 0x11081, InCB::Extend),
+  (0x110b3, 0x110b6, InCB::Extend), (0x110b9, 0x110ba, InCB::Extend),
+  (0x110c2, 0x110c2, InCB::Extend), (0x11100, 0x11102, InCB::Extend),
+  (0x11103, 0x11126, InCB::Consonant), (0x11127, 0x1112b, InCB::Extend),
+  (0x1112d, 0x11132, InCB::Extend), (0x11133, 0x11133, InCB::Linker),
+  (0x11134, 0x11134, InCB::Extend), (0x11144, 0x11144, InCB::Consonant),
+  (0x11147, 0x11147, InCB::Consonant), (0x11173, 0x11173, InCB::Extend),
+  (0x11180, 0x11181, InCB::Extend), (0x111b6, 0x111be, InCB::Extend),
+  (0x111c0, 0x111c0, InCB::Extend), (0x111c9, 0x111cc, InCB::Extend),
+  (0x111cf, 0x111cf, InCB::Extend), (0x1122f, 0x11231, InCB::Extend),
+  (0x11234, 0x11237, InCB::Extend), (0x1123e, 0x1123e, InCB::Extend),
+  (0x11241, 0x11241, InCB::Extend), (0x112df, 0x112df, InCB::Extend),
+  (0x112e3, 0x112ea, InCB::Extend), (0x11300, 0x11301, InCB::Extend),
+  (0x1133b, 0x1133c, InCB::Extend), (0x1133e, 0x1133e, InCB::Extend),
+  (0x11340, 0x11340, InCB::Extend), (0x1134d, 0x1134d, InCB::Extend),
+  (0x11357, 0x11357, InCB::Extend), (0x11366, 0x1136c, InCB::Extend),
+  (0x11370, 0x11374, InCB::Extend), (0x11380, 0x11389, InCB::Consonant),
+  (0x1138b, 0x1138b, InCB::Consonant), (0x1138e, 0x1138e, InCB::Consonant),
+  (0x11390, 0x113b5, InCB::Consonant), (0x113b8, 0x113b8, InCB::Extend),
+  (0x113bb, 0x113c0, InCB::Extend), (0x113c2, 0x113c2, InCB::Extend),
+  (0x113c5, 0x113c5, InCB::Extend), (0x113c7, 0x113c9, InCB::Extend),
+  (0x113ce, 0x113cf, InCB::Extend), (0x113d0, 0x113d0, InCB::Linker),
+  (0x113d2, 0x113d2, InCB::Extend), (0x113e1, 0x113e2, InCB::Extend),
+  (0x11438, 0x1143f, InCB::Extend), (0x11442, 0x11444, InCB::Extend),
+  (0x11446, 0x11446, InCB::Extend), (0x1145e, 0x1145e, InCB::Extend),
+  (0x114b0, 0x114b0, InCB::Extend), (0x114b3, 0x114b8, InCB::Extend),
+  (0x114ba, 0x114ba, InCB::Extend), (0x114bd, 0x114bd, InCB::Extend),
+  (0x114bf, 0x114c0, InCB::Extend), (0x114c2, 0x114c3, InCB::Extend),
+  (0x115af, 0x115af, InCB::Extend), (0x115b2, 0x115b5, InCB::Extend),
+  (0x115bc, 0x115bd, InCB::Extend), (0x115bf, 0x115c0, InCB::Extend),
+  (0x115dc, 0x115dd, InCB::Extend), (0x11633, 0x1163a, InCB::Extend),
+  (0x1163d, 0x1163d, InCB::Extend), (0x1163f, 0x11640, InCB::Extend),
+  (0x116ab, 0x116ab, InCB::Extend), (0x116ad, 0x116ad, InCB::Extend),
+  (0x116b0, 0x116b7, InCB::Extend), (0x1171d, 0x1171d, InCB::Extend),
+  (0x1171f, 0x1171f, InCB::Extend), (0x11722, 0x11725, InCB::Extend),
+  (0x11727, 0x1172b, InCB::Extend), (0x1182f, 0x11837, InCB::Extend),
+  (0x11839, 0x1183a, InCB::Extend), (0x11900, 0x11906, InCB::Consonant),
+  (0x11909, 0x11909, InCB::Consonant), (0x1190c, 0x11913, InCB::Consonant),
+  (0x11915, 0x11916, InCB::Consonant), (0x11918, 0x1192f, InCB::Consonant),
+  (0x11930, 0x11930, InCB::Extend), (0x1193b, 0x1193d, InCB::Extend),
+  (0x1193e, 0x1193e, InCB::Linker), (0x11943, 0x11943, InCB::Extend),
+  (0x119d4, 0x119d7, InCB::Extend), (0x119da, 0x119db, InCB::Extend),
+  (0x119e0, 0x119e0, InCB::Extend), (0x11a00, 0x11a00, InCB::Consonant),
+  (0x11a01, 0x11a0a, InCB::Extend), (0x11a0b, 0x11a32, InCB::Consonant),
+  (0x11a33, 0x11a38, InCB::Extend), (0x11a3b, 0x11a3e, InCB::Extend),
+  (0x11a47, 0x11a47, InCB::Linker), (0x11a50, 0x11a50, InCB::Consonant),
+  (0x11a51, 0x11a56, InCB::Extend), (0x11a59, 0x11a5b, InCB::Extend),
+  (0x11a5c, 0x11a83, InCB::Consonant), (0x11a8a, 0x11a96, InCB::Extend),
+  (0x11a98, 0x11a98, InCB::Extend), (0x11a99, 0x11a99, InCB::Linker),
+  (0x11b60, 0x11b60, InCB::Extend), (0x11b62, 0x11b64, InCB::Extend),
+  (0x11b66, 0x11b66, InCB::Extend), (0x11c30, 0x11c36, InCB::Extend),
+  (0x11c38, 0x11c3d, InCB::Extend), (0x11c3f, 0x11c3f, InCB::Extend),
+  (0x11c92, 0x11ca7, InCB::Extend), (0x11caa, 0x11cb0, InCB::Extend),
+  (0x11cb2, 0x11cb3, InCB::Extend), (0x11cb5, 0x11cb6, InCB::Extend),
+  (0x11d31, 0x11d36, InCB::Extend), (0x11d3a, 0x11d3a, InCB::Extend),
+  (0x11d3c, 0x11d3d, InCB::Extend), (0x11d3f, 0x11d45, InCB::Extend),
+  (0x11d47, 0x11d47, InCB::Extend), (0x11d90, 0x11d91, InCB::Extend),
+  (0x11d95, 0x11d95, InCB::Extend), (0x11d97, 0x11d97, InCB::Extend),
+  (0x11ef3, 0x11ef4, InCB::Extend), (0x11f00, 0x11f01, InCB::Extend),
+  (0x11f04, 0x11f10, InCB::Consonant), (0x11f12, 0x11f33, InCB::Consonant),
+  (0x11f36, 0x11f3a, InCB::Extend), (0x11f40, 0x11f41, InCB::Extend),
+  (0x11f42, 0x11f42, InCB::Linker), (0x11f5a, 0x11f5a, InCB::Extend),
+  (0x13440, 0x13440, InCB::Extend), (0x13447, 0x13455, InCB::Extend),
+  (0x1611e, 0x16129, InCB::Extend), (0x1612d, 0x1612f, InCB::Extend),
+  (0x16af0, 0x16af4, InCB::Extend), (0x16b30, 0x16b36, InCB::Extend),
+  (0x16f4f, 0x16f4f, InCB::Extend), (0x16f8f, 0x16f92, InCB::Extend),
+  (0x16fe4, 0x16fe4, InCB::Extend), (0x16ff0, 0x16ff1, InCB::Extend),
+  (0x1bc9d, 0x1bc9e, InCB::Extend), (0x1cf00, 0x1cf2d, InCB::Extend),
+  (0x1cf30, 0x1cf46, InCB::Extend), (0x1d165, 0x1d169, InCB::Extend),
+  (0x1d16d, 0x1d172, InCB::Extend), (0x1d17b, 0x1d182, InCB::Extend),
+  (0x1d185, 0x1d18b, InCB::Extend), (0x1d1aa, 0x1d1ad, InCB::Extend),
+  (0x1d242, 0x1d244, InCB::Extend), (0x1da00, 0x1da36, InCB::Extend),
+  (0x1da3b, 0x1da6c, InCB::Extend), (0x1da75, 0x1da75, InCB::Extend),
+  (0x1da84, 0x1da84, InCB::Extend), (0x1da9b, 0x1da9f, InCB::Extend),
+  (0x1daa1, 0x1daaf, InCB::Extend), (0x1e000, 0x1e006, InCB::Extend),
+  (0x1e008, 0x1e018, InCB::Extend), (0x1e01b, 0x1e021, InCB::Extend),
+  (0x1e023, 0x1e024, InCB::Extend), (0x1e026, 0x1e02a, InCB::Extend),
+  (0x1e08f, 0x1e08f, InCB::Extend), (0x1e130, 0x1e136, InCB::Extend),
+  (0x1e2ae, 0x1e2ae, InCB::Extend), (0x1e2ec, 0x1e2ef, InCB::Extend),
+  (0x1e4ec, 0x1e4ef, InCB::Extend), (0x1e5ee, 0x1e5ef, InCB::Extend),
+  (0x1e6e3, 0x1e6e3, InCB::Extend), (0x1e6e6, 0x1e6e6, InCB::Extend),
+  (0x1e6ee, 0x1e6ef, InCB::Extend), (0x1e6f5, 0x1e6f5, InCB::Extend),
+  (0x1e8d0, 0x1e8d6, InCB::Extend), (0x1e944, 0x1e94a, InCB::Extend),
+  (0x1f3fb, 0x1f3ff, InCB::Extend), (0xe0020, 0xe007f, InCB::Extend),
+  (0xe0100, 0xe01ef, InCB::Extend)
+];
+
+// Extended_Pictographic code points
+#[rustfmt::skip]
+pub static EXTENDED_PICTOGRAPHIC: [(u32, u32); 156] = [
+  (0xa9, 0xa9), (0xae, 0xae), (0x203c, 0x203c), (0x2049, 0x2049),
+  (0x2122, 0x2122), (0x2139, 0x2139), (0x2194, 0x2199), (0x21a9, 0x21aa),
+  (0x231a, 0x231b), (0x2328, 0x2328), (0x23cf, 0x23cf), (0x23e9, 0x23f3),
+  (0x23f8, 0x23fa), (0x24c2, 0x24c2), (0x25aa, 0x25ab), (0x25b6, 0x25b6),
+  (0x25c0, 0x25c0), (0x25fb, 0x25fe), (0x2600, 0x2604), (0x260e, 0x260e),
+  (0x2611, 0x2611), (0x2614, 0x2615), (0x2618, 0x2618), (0x261d, 0x261d),
+  (0x2620, 0x2620), (0x2622, 0x2623), (0x2626, 0x2626), (0x262a, 0x262a),
+  (0x262e, 0x262f), (0x2638, 0x263a), (0x2640, 0x2640), (0x2642, 0x2642),
+  (0x2648, 0x2653), (0x265f, 0x2660), (0x2663, 0x2663), (0x2665, 0x2666),
+  (0x2668, 0x2668), (0x267b, 0x267b), (0x267e, 0x267f), (0x2692, 0x2697),
+  (0x2699, 0x2699), (0x269b, 0x269c), (0x26a0, 0x26a1), (0x26a7, 0x26a7),
+  (0x26aa, 0x26ab), (0x26b0, 0x26b1), (0x26bd, 0x26be), (0x26c4, 0x26c5),
+  (0x26c8, 0x26c8), (0x26ce, 0x26cf), (0x26d1, 0x26d1), (0x26d3, 0x26d4),
+  (0x26e9, 0x26ea), (0x26f0, 0x26f5), (0x26f7, 0x26fa), (0x26fd, 0x26fd),
+  (0x2702, 0x2702), (0x2705, 0x2705), (0x2708, 0x270d), (0x270f, 0x270f),
+  (0x2712, 0x2712), (0x2714, 0x2714), (0x2716, 0x2716), (0x271d, 0x271d),
+  (0x2721, 0x2721), (0x2728, 0x2728), (0x2733, 0x2734), (0x2744, 0x2744),
+  (0x2747, 0x2747), (0x274c, 0x274c), (0x274e, 0x274e), (0x2753, 0x2755),
+  (0x2757, 0x2757), (0x2763, 0x2764), (0x2795, 0x2797), (0x27a1, 0x27a1),
+  (0x27b0, 0x27b0), (0x27bf, 0x27bf), (0x2934, 0x2935), (0x2b05, 0x2b07),
+  (0x2b1b, 0x2b1c), (0x2b50, 0x2b50), (0x2b55, 0x2b55), (0x3030, 0x3030),
+  (0x303d, 0x303d), (0x3297, 0x3297), (0x3299, 0x3299), (0x1f004, 0x1f004),
+  (0x1f02c, 0x1f02f), (0x1f094, 0x1f09f), (0x1f0af, 0x1f0b0),
+  (0x1f0c0, 0x1f0c0), (0x1f0cf, 0x1f0d0), (0x1f0f6, 0x1f0ff),
+  (0x1f170, 0x1f171), (0x1f17e, 0x1f17f), (0x1f18e, 0x1f18e),
+  (0x1f191, 0x1f19a), (0x1f1ae, 0x1f1e5), (0x1f201, 0x1f20f),
+  (0x1f21a, 0x1f21a), (0x1f22f, 0x1f22f), (0x1f232, 0x1f23a),
+  (0x1f23c, 0x1f23f), (0x1f249, 0x1f25f), (0x1f266, 0x1f321),
+  (0x1f324, 0x1f393), (0x1f396, 0x1f397), (0x1f399, 0x1f39b),
+  (0x1f39e, 0x1f3f0), (0x1f3f3, 0x1f3f5), (0x1f3f7, 0x1f3fa),
+  (0x1f400, 0x1f4fd), (0x1f4ff, 0x1f53d), (0x1f549, 0x1f54e),
+  (0x1f550, 0x1f567), (0x1f56f, 0x1f570), (0x1f573, 0x1f57a),
+  (0x1f587, 0x1f587), (0x1f58a, 0x1f58d), (0x1f590, 0x1f590),
+  (0x1f595, 0x1f596), (0x1f5a4, 0x1f5a5), (0x1f5a8, 0x1f5a8),
+  (0x1f5b1, 0x1f5b2), (0x1f5bc, 0x1f5bc), (0x1f5c2, 0x1f5c4),
+  (0x1f5d1, 0x1f5d3), (0x1f5dc, 0x1f5de), (0x1f5e1, 0x1f5e1),
+  (0x1f5e3, 0x1f5e3), (0x1f5e8, 0x1f5e8), (0x1f5ef, 0x1f5ef),
+  (0x1f5f3, 0x1f5f3), (0x1f5fa, 0x1f64f), (0x1f680, 0x1f6c5),
+  (0x1f6cb, 0x1f6d2), (0x1f6d5, 0x1f6e5), (0x1f6e9, 0x1f6e9),
+  (0x1f6eb, 0x1f6f0), (0x1f6f3, 0x1f6ff), (0x1f7da, 0x1f7ff),
+  (0x1f80c, 0x1f80f), (0x1f848, 0x1f84f), (0x1f85a, 0x1f85f),
+  (0x1f888, 0x1f88f), (0x1f8ae, 0x1f8af), (0x1f8bc, 0x1f8bf),
+  (0x1f8c2, 0x1f8cf), (0x1f8d9, 0x1f8ff), (0x1f90c, 0x1f93a),
+  (0x1f93c, 0x1f945), (0x1f947, 0x1f9ff), (0x1fa58, 0x1fa5f),
+  (0x1fa6e, 0x1faff), (0x1fc00, 0x1fffd)
+];
@@ -0,0 +1,115 @@
+use {
+  super::{LocaleObject, is_posix_locale, territory},
+  crate::{
+    allocation::{borrow::ToOwned, string::String},
+    c_int,
+    support::{locale::errno, string::strtocstr}
+  },
+  allocation::borrow::Cow,
+  core::ffi
+};
+
+/// What a locale is and where its data comes from. The language and the
+/// territory are their English names.
+#[derive(Debug, Clone)]
+pub struct IdentificationObject<'a> {
+  name: Cow<'a, ffi::CStr>,
+  pub title: Cow<'a, ffi::CStr>,
+  pub source: &'static ffi::CStr,
+  pub address: &'static ffi::CStr,
+  pub contact: &'static ffi::CStr,
+  pub email: &'static ffi::CStr,
+  pub tel: &'static ffi::CStr,
+  pub fax: &'static ffi::CStr,
+  pub language: &'static ffi::CStr,
+  pub territory: &'static ffi::CStr,
+  pub audience: &'static ffi::CStr,
+  pub application: &'static ffi::CStr,
+  pub abbreviation: &'static ffi::CStr,
+  pub revision: &'static ffi::CStr,
+  pub date: &'static ffi::CStr
+}
+
+impl<'a> LocaleObject for IdentificationObject<'a> {
+  fn setlocale(
+    &mut self,
+    locale: &ffi::CStr
+  ) -> Result<&ffi::CStr, c_int> {
+    let name = locale.to_str().map_err(|_| errno::ENOENT)?;
+
+    if is_posix_locale(name) {
+      return Ok(self.set_to_posix());
+    }
+
+    let language = territory::get_language(name).ok_or(errno::ENOENT)?;
+
+    *self = IdentificationObject {
+      source: c"Unicode CLDR",
+      territory: c"",
+      revision: c"",
+      date: c"",
+      ..DEFAULT_IDENTIFICATION
+    };
+    if let Some(language) = territory::find_language(&language) {
+      self.language = language.name;
+    }
+    let region = territory::get_region(name);
+    if let Some(territory) =
+      region.as_deref().and_then(territory::find_territory)
+    {
+      self.territory = territory.name;
+    }
+    if let Ok(language) = self.language.to_str() &&
+      !language.is_empty()
+    {
+      let mut title = String::from(language);
+      title.push_str(" locale");
+      if let Ok(territory) = self.territory.to_str() &&
+        !territory.is_empty()
+      {
+        title.push_str(" for ");
+        title.push_str(territory);
+      }
+      self.title = strtocstr(&title);
+    } else {
+      self.title = Cow::Owned(locale.to_owned());
+    }
+    self.name = Cow::Owned(locale.to_owned());
+
+    Ok(self.name.as_ref())
+  }
+
+  fn set_to_posix(&mut self) -> &ffi::CStr {
+    *self = DEFAULT_IDENTIFICATION;
+
+    self.name.as_ref()
+  }
+
+  fn get_name(&self) -> &ffi::CStr {
+    self.name.as_ref()
+  }
+}
+
+impl<'a> Default for IdentificationObject<'a> {
+  fn default() -> Self {
+    DEFAULT_IDENTIFICATION
+  }
+}
+
+pub const DEFAULT_IDENTIFICATION: IdentificationObject = IdentificationObject {
+  name: Cow::Borrowed(c"C"),
+  title: Cow::Borrowed(c"ISO/IEC 14652 i18n FDCC-set"),
+  source: c"ISO/IEC JTC1/SC22/WG20 - internationalization",
+  address: c"",
+  contact: c"",
+  email: c"",
+  tel: c"",
+  fax: c"",
+  language: c"",
+  territory: c"ISO",
+  audience: c"",
+  application: c"",
+  abbreviation: c"",
+  revision: c"1.0",
+  date: c"1997-12-20"
+};
@@ -0,0 +1,273 @@
+mod parser;
+
+use {
+  super::{LocaleObject, is_posix_locale},
+  crate::{
+    allocation::{borrow::Cow, ffi::CString, string::String, vec::Vec},
+    c_char,
+    c_int,
+    std::errno,
+    support::{environment, mapping::FileMapping}
+  },
+  core::ffi,
+  smallvec::SmallVec
+};
+
+// Copies may go through other source files, but not forever
+const MAX_COPY_DEPTH: usize = 8;
+
+/// A value of a keyword in a localedef source file.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+  String(String),
+  Number(i32),
+  Char(char),
+  /// A symbolic name that is not a character, such as a collating symbol
+  Symbol(String),
+  /// The ".." or "..." between the two ends of a range of characters
+  Range,
+  Word(String)
+}
+
+#[derive(Debug)]
+pub struct Statement {
+  /// The keyword the line starts with, empty for lines that start with a
+  /// character, as in the collation order
+  pub keyword: String,
+  pub values: Vec<Value>
+}
+
+/// The definition of one category, from "LC_NUMERIC" to "END LC_NUMERIC".
+#[derive(Debug)]
+pub struct Section {
+  pub category: String,
+  pub statements: Vec<Statement>
+}
+
+impl Section {
+  /// Returns the values of the last line with `keyword`.
+  pub fn get(
+    &self,
+    keyword: &str
+  ) -> Option<&[Value]> {
+    self
+      .statements
+      .iter()
+      .rev()
+      .find(|statement| statement.keyword == keyword)
+      .map(|statement| statement.values.as_slice())
+  }
+
+  pub fn string(
+    &self,
+    keyword: &str
+  ) -> Option<&str> {
+    match self.get(keyword)?.first()? {
+      | Value::String(s) => Some(s),
+      | _ => None
+    }
+  }
+
+  pub fn strings(
+    &self,
+    keyword: &str
+  ) -> Vec<&str> {
+    self
+      .get(keyword)
+      .unwrap_or_default()
+      .iter()
+      .filter_map(|value| match value {
+        | Value::String(s) => Some(s.as_str()),
+        | _ => None
+      })
+      .collect()
+  }
+
+  /// Returns a number that fits a `c_char`, with -1 standing for `CHAR_MAX`
+  /// as in the POSIX locale.
+  pub fn char_number(
+    &self,
+    keyword: &str
+  ) -> Option<c_char> {
+    match self.get(keyword)?.first()? {
+      | Value::Number(-1) => Some(c_char::MAX),
+      | Value::Number(n) => c_char::try_from(*n).ok(),
+      | _ => None
+    }
+  }
+
+  /// Returns a grouping such as "3;2" as it goes into an lconv, with a -1
+  /// ending the grouping and an empty one for no grouping at all.
+  pub fn grouping(
+    &self,
+    keyword: &str
+  ) -> Option<SmallVec<[u8; 3]>> {
+    let values = self.get(keyword)?;
+
+    let mut grouping = SmallVec::new();
+    for value in values {
+      match value {
+        | Value::Number(-1) if grouping.is_empty() => return Some(grouping),
+        | Value::Number(-1) => grouping.push(c_char::MAX as u8),
+        | Value::Number(n @ 1..=126) => grouping.push(*n as u8),
+        | _ => return None
+      }
+    }
+    if !grouping.is_empty() {
+      grouping.push(b'\0');
+    }
+
+    Some(grouping)
+  }
+
+  /// Returns the name of the locale the section copies.
+  pub fn copy(&self) -> Option<&str> {
+    self.string("copy")
+  }
+}
+
+/// A POSIX localedef source file, such as the ones in glibc's
+/// localedata/locales.
+#[derive(Debug)]
+pub struct Source {
+  sections: Vec<Section>,
+  /// How many copies led to this source
+  depth: usize
+}
+
+impl Source {
+  pub fn parse(text: &str) -> Self {
+    Self { sections: parser::Parser::new().parse(text), depth: 0 }
+  }
+
+  pub fn section(
+    &self,
+    category: &str
+  ) -> Option<&Section> {
+    self.sections.iter().find(|section| section.category == category)
+  }
+}
+
+// The names a file may have for a locale such as "en_XA.UTF-8@euro", most
+// specific first. Source files do not depend on the codeset, so they are
+// usually named without one.
+fn get_file_names(name: &str) -> Vec<String> {
+  let (base, modifier) = match name.split_once('@') {
+    | Some((base, modifier)) => (base, Some(modifier)),
+    | None => (name, None)
+  };
+  let language = base.split_once('.').map_or(base, |(language, _)| language);
+
+  let mut names = Vec::new();
+  names.push(String::from(name));
+  if let Some(modifier) = modifier {
+    let mut without_codeset = String::from(language);
+    without_codeset.push('@');
+    without_codeset.push_str(modifier);
+    names.push(without_codeset);
+  }
+  names.push(String::from(language));
+  names.dedup();
+
+  names
+}
+
+fn find(
+  name: &str,
+  depth: usize
+) -> Option<Source> {
+  // The names are file names, not paths
+  if is_posix_locale(name) ||
+    name.is_empty() ||
+    name.starts_with('.') ||
+    name.contains('/')
+  {
+    return None;
+  }
+
+  // Privileged programs only use the built-in data
+  let locpath = environment::secure_getenv("LOCPATH")?.to_str().ok()?;
+  for directory in locpath.split(':').filter(|d| !d.is_empty()) {
+    for file_name in get_file_names(name) {
+      let mut path = String::from(directory);
+      path.push('/');
+      path.push_str(&file_name);
+      let Ok(path) = CString::new(path) else {
+        continue;
+      };
+
+      if let Ok(mapping) = FileMapping::open(&path) &&
+        let Ok(text) = core::str::from_utf8(mapping.as_bytes())
+      {
+        let mut source = Source::parse(text);
+        source.depth = depth;
+
+        return Some(source);
+      }
+    }
+  }
+
+  None
+}
+
+/// Finds the source file of a locale in the directories of LOCPATH.
+pub fn find_source(name: &str) -> Option<Source> {
+  find(name, 0)
+}
+
+// glibc's locales copy their character classes and collation from shared
+// sources, which are the Unicode defaults of the root locale here
+fn get_copy_name(
+  copy: &str,
+  locale: &ffi::CStr
+) -> Result<CString, c_int> {
+  let copy = match copy {
+    | "i18n" | "i18n_ctype" | "iso14651_t1" | "iso14651_t1_common" => "und",
+    | _ => copy
+  };
+
+  // A copied locale without a codeset takes the one of the locale
+  let mut name = String::from(copy);
+  let locale = locale.to_str().map_err(|_| errno::ENOENT)?;
+  if !is_posix_locale(copy) &&
+    !copy.contains('.') &&
+    let Some((_, codeset)) = locale.split_once('.')
+  {
+    name.push('.');
+    name.push_str(codeset.split('@').next().unwrap_or(""));
+  }
+
+  CString::new(name).map_err(|_| errno::ENOENT)
+}
+
+/// Loads what a section builds on into `object`: the locale it copies, from
+/// LOCPATH or the built-in data, or else the POSIX locale.
+pub fn load_base<T: LocaleObject>(
+  object: &mut T,
+  locale: &ffi::CStr,
+  source: &Source,
+  section: Option<&Section>
+) -> Result<(), c_int> {
+  let Some(copy) = section.and_then(Section::copy) else {
+    object.set_to_posix();
+    return Ok(());
+  };
+
+  let name = get_copy_name(copy, locale)?;
+  let copied = match name.to_str() {
+    | Ok(name) if source.depth < MAX_COPY_DEPTH => find(name, source.depth + 1),
+    | _ => return Err(errno::ENOENT)
+  };
+
+  match copied {
+    | Some(copied) => object.load_source(&name, &copied)?,
+    | None => object.setlocale(&name)?
+  };
+
+  Ok(())
+}
+
+/// Turns a string of a source file into the C string of a category object.
+pub fn to_cstr(s: &str) -> Result<Cow<'static, ffi::CStr>, c_int> {
+  Ok(Cow::Owned(CString::new(s).map_err(|_| errno::ENOENT)?))
+}
@@ -0,0 +1,274 @@
+use {
+  super::{Section, Statement, Value},
+  crate::allocation::{string::String, vec::Vec}
+};
+
+/// Returns the character of a symbolic name, either a "<U00E4>" code point
+/// or one of the names of the POSIX portable character set.
+fn get_symbol_char(name: &str) -> Option<char> {
+  if let Some(hex) = name.strip_prefix('U') &&
+    (4..=8).contains(&hex.len())
+  {
+    return u32::from_str_radix(hex, 16).ok().and_then(char::from_u32);
+  }
+
+  // Letters and digits are their own names
+  if name.len() == 1 && name.as_bytes()[0].is_ascii_alphanumeric() {
+    return name.chars().next();
+  }
+
+  Some(match name {
+    | "NUL" => '\0',
+    | "alert" => '\x07',
+    | "backspace" => '\x08',
+    | "tab" => '\t',
+    | "newline" => '\n',
+    | "vertical-tab" => '\x0b',
+    | "form-feed" => '\x0c',
+    | "carriage-return" => '\r',
+    | "space" => ' ',
+    | "exclamation-mark" => '!',
+    | "quotation-mark" => '"',
+    | "number-sign" => '#',
+    | "dollar-sign" => '$',
+    | "percent-sign" => '%',
+    | "ampersand" => '&',
+    | "apostrophe" => '\'',
+    | "left-parenthesis" => '(',
+    | "right-parenthesis" => ')',
+    | "asterisk" => '*',
+    | "plus-sign" => '+',
+    | "comma" => ',',
+    | "hyphen" | "hyphen-minus" => '-',
+    | "period" | "full-stop" => '.',
+    | "slash" | "solidus" => '/',
+    | "zero" => '0',
+    | "one" => '1',
+    | "two" => '2',
+    | "three" => '3',
+    | "four" => '4',
+    | "five" => '5',
+    | "six" => '6',
+    | "seven" => '7',
+    | "eight" => '8',
+    | "nine" => '9',
+    | "colon" => ':',
+    | "semicolon" => ';',
+    | "less-than-sign" => '<',
+    | "equals-sign" => '=',
+    | "greater-than-sign" => '>',
+    | "question-mark" => '?',
+    | "commercial-at" => '@',
+    | "left-square-bracket" => '[',
+    | "backslash" | "reverse-solidus" => '\\',
+    | "right-square-bracket" => ']',
+    | "circumflex" | "circumflex-accent" => '^',
+    | "underscore" | "low-line" => '_',
+    | "grave-accent" => '`',
+    | "left-brace" | "left-curly-bracket" => '{',
+    | "vertical-line" => '|',
+    | "right-brace" | "right-curly-bracket" => '}',
+    | "tilde" => '~',
+    | _ => return None
+  })
+}
+
+fn to_symbol(name: &str) -> Value {
+  match get_symbol_char(name) {
+    | Some(c) => Value::Char(c),
+    | None => Value::Symbol(String::from(name))
+  }
+}
+
+fn to_word(word: &str) -> Value {
+  if word.len() >= 2 && word.bytes().all(|b| b == b'.') {
+    return Value::Range;
+  }
+
+  match word.parse() {
+    | Ok(number) => Value::Number(number),
+    | Err(_) => Value::Word(String::from(word))
+  }
+}
+
+pub struct Parser {
+  comment_char: char,
+  escape_char: char
+}
+
+impl Parser {
+  pub fn new() -> Self {
+    Self { comment_char: '#', escape_char: '\\' }
+  }
+
+  /// Reads the byte that an escape such as "/d228", "/xe4" or "/344" stands
+  /// for, or the escaped character itself.
+  fn read_escape(
+    &self,
+    chars: &mut core::iter::Peekable<core::str::Chars>,
+    bytes: &mut Vec<u8>
+  ) {
+    let Some(c) = chars.next() else {
+      return;
+    };
+
+    let (radix, len) = match c {
+      | 'd' => (10, 3),
+      | 'x' => (16, 2),
+      | '0'..='7' => (8, 2),
+      | _ => {
+        let mut buf = [0; 4];
+        bytes.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+        return;
+      }
+    };
+
+    let mut value = if radix == 8 { c as u32 - '0' as u32 } else { 0 };
+    for _ in 0..len {
+      match chars.peek().and_then(|c| c.to_digit(radix)) {
+        | Some(digit) => {
+          value = value * radix + digit;
+          chars.next();
+        },
+        | None => break
+      }
+    }
+
+    bytes.push(value as u8);
+  }
+
+  /// Splits a line into its values. Semicolons, commas and parentheses only
+  /// separate the values, as every keyword knows the shape of its operands.
+  fn tokenize(
+    &self,
+    line: &str
+  ) -> Option<Vec<Value>> {
+    let mut values = Vec::new();
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+      match c {
+        | _ if c.is_whitespace() => {},
+        | ';' | ',' | '(' | ')' => {},
+        | '"' => {
+          let mut bytes = Vec::new();
+          loop {
+            match chars.next()? {
+              | '"' => break,
+              | c if c == self.escape_char => {
+                self.read_escape(&mut chars, &mut bytes)
+              },
+              | '<' => {
+                let name: String =
+                  chars.by_ref().take_while(|&c| c != '>').collect();
+                let c = get_symbol_char(&name)?;
+                let mut buf = [0; 4];
+                bytes.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+              },
+              | c => {
+                let mut buf = [0; 4];
+                bytes.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+              }
+            }
+          }
+          values.push(Value::String(String::from_utf8(bytes).ok()?));
+        },
+        | '<' => {
+          let name: String = chars.by_ref().take_while(|&c| c != '>').collect();
+          values.push(to_symbol(&name));
+        },
+        | _ => {
+          let mut word = String::from(c);
+          while let Some(&c) = chars.peek() {
+            if c.is_whitespace() ||
+              matches!(c, ';' | ',' | '(' | ')' | '<' | '"')
+            {
+              break;
+            }
+            word.push(c);
+            chars.next();
+          }
+          values.push(to_word(&word));
+        }
+      }
+    }
+
+    Some(values)
+  }
+
+  /// Parses the sections of a source file. Lines that can not be read are
+  /// left out, as are the transliteration tables.
+  pub fn parse(
+    &mut self,
+    text: &str
+  ) -> Vec<Section> {
+    let mut sections = Vec::new();
+    let mut current: Option<Section> = None;
+    let mut in_translit = false;
+    let mut pending = String::new();
+
+    for line in text.lines() {
+      // A line ending in the escape character goes on in the next one
+      if let Some(start) = line.strip_suffix(self.escape_char) {
+        pending.push_str(start);
+        continue;
+      }
+      pending.push_str(line);
+      let line = core::mem::take(&mut pending);
+
+      let trimmed = line.trim_start();
+      if trimmed.is_empty() || trimmed.starts_with(self.comment_char) {
+        continue;
+      }
+      let Some(mut values) = self.tokenize(trimmed) else {
+        continue;
+      };
+
+      let keyword = match values.first() {
+        | Some(Value::Word(word)) => {
+          let keyword = word.clone();
+          values.remove(0);
+          keyword
+        },
+        | _ => String::new()
+      };
+
+      match (keyword.as_str(), &mut current) {
+        | ("comment_char", None) | ("escape_char", None) => {
+          let Some(Value::Word(word)) = values.first() else {
+            continue;
+          };
+          let Some(c) = word.chars().next() else {
+            continue;
+          };
+          if keyword == "comment_char" {
+            self.comment_char = c;
+          } else {
+            self.escape_char = c;
+          }
+        },
+        | (category, None) if category.starts_with("LC_") => {
+          current = Some(Section {
+            category: String::from(category),
+            statements: Vec::new()
+          });
+        },
+        | ("END", Some(section)) => {
+          if let Some(Value::Word(category)) = values.first() &&
+            *category == section.category
+          {
+            sections.extend(current.take());
+          }
+        },
+        | ("translit_start", Some(_)) => in_translit = true,
+        | ("translit_end", Some(_)) => in_translit = false,
+        | (_, Some(section)) if !in_translit => {
+          section.statements.push(Statement { keyword, values })
+        },
+        | _ => {}
+      }
+    }
+
+    sections
+  }
+}
@@ -0,0 +1,59 @@
+use {
+  super::{LocaleObject, is_posix_locale, territory},
+  crate::{allocation::borrow::ToOwned, c_int, support::locale::errno},
+  allocation::borrow::Cow,
+  core::ffi
+};
+
+pub const MEASUREMENT_METRIC: u8 = 1;
+pub const MEASUREMENT_US: u8 = 2;
+
+#[derive(Debug, Clone)]
+pub struct MeasurementObject<'a> {
+  name: Cow<'a, ffi::CStr>,
+  /// [`MEASUREMENT_METRIC`] or [`MEASUREMENT_US`], as in glibc
+  pub measurement: u8
+}
+
+impl<'a> LocaleObject for MeasurementObject<'a> {
+  fn setlocale(
+    &mut self,
+    locale: &ffi::CStr
+  ) -> Result<&ffi::CStr, c_int> {
+    let name = locale.to_str().map_err(|_| errno::ENOENT)?;
+
+    if is_posix_locale(name) {
+      return Ok(self.set_to_posix());
+    }
+
+    territory::get_language(name).ok_or(errno::ENOENT)?;
+
+    let us = territory::get_region(name)
+      .is_some_and(|region| territory::is_us_system_territory(&region));
+    self.measurement = if us { MEASUREMENT_US } else { MEASUREMENT_METRIC };
+    self.name = Cow::Owned(locale.to_owned());
+
+    Ok(self.name.as_ref())
+  }
+
+  fn set_to_posix(&mut self) -> &ffi::CStr {
+    *self = DEFAULT_MEASUREMENT;
+
+    self.name.as_ref()
+  }
+
+  fn get_name(&self) -> &ffi::CStr {
+    self.name.as_ref()
+  }
+}
+
+impl<'a> Default for MeasurementObject<'a> {
+  fn default() -> Self {
+    DEFAULT_MEASUREMENT
+  }
+}
+
+pub const DEFAULT_MEASUREMENT: MeasurementObject = MeasurementObject {
+  name: Cow::Borrowed(c"C"),
+  measurement: MEASUREMENT_METRIC
+};
@@ -1,223 +0,0 @@
-pub const MISC_MESSAGES: [&'static str; 3] = [
-  "Yeah nah, dunno what happened",
-  "Dunno what that signal is, mate",
-  "Real-time signal, ripper"
-];
-
-pub const STRERROR: [&'static str; 134] = [
-  "Sweet as, she'll be right",
-  "Nah yeah, not allowed cobber",
-  "No such file or directory, it's gone walkabout",
-  "No such process, it's carked it",
-  "System call got the arse",
-  "I/O's gone bung",
-  "No such device or address, it's vanished",
-  "Argument list too long, fair go mate",
-  "Exec format's rooted",
-  "Bad file descriptor, proper dodgy",
-  "No child processes, no ankle biters",
-  "Resource temporarily unavailable, having a smoko",
-  "Can't allocate memory, out of scratchings",
-  "Permission denied, get stuffed",
-  "Bad address, you're dreaming",
-  "Block device required, need a proper brick",
-  "Device or resource busy, flat out like a lizard drinking",
-  "File already exists, no worries",
-  "Invalid cross-device link, that's crook",
-  "No such device, it's gone to buggery",
-  "Not a directory, you're off your rocker",
-  "Is a directory, woop woop",
-  "Invalid argument, pulling your leg",
-  "Too many open files in system, chockers",
-  "Too many open files, fair dinkum too many",
-  "Inappropriate ioctl for device, that's not the go",
-  "Text file busy, someone's using it",
-  "File too big, massive as",
-  "No space left on device, chock-a-block full",
-  "Illegal seek, don't be a drongo",
-  "Read-only file system, can't touch this",
-  "Too many links, more links than a sausage sizzle",
-  "Broken pipe, she's snapped",
-  "Numerical argument out of domain, way off",
-  "Numerical result out of range, bugger me",
-  "Resource deadlock avoided, dodged a bullet",
-  "File name too long, what are you, a poet?",
-  "No locks available, someone's hogging them",
-  "Function not implemented, haven't got around to it",
-  "Directory not empty, still got stuff in it",
-  "Too many levels of symbolic links, going around in circles",
-  "Unknown error 41, beats me",
-  "No message of desired type, not what we're after",
-  "Identifier removed, gone to God",
-  "Channel number out of range, too big mate",
-  "Level 2 not synchronized, out of whack",
-  "Level 3 halted, pulled up stumps",
-  "Level 3 reset, back to square one",
-  "Link number out of range, too bloody high",
-  "Protocol driver not attached, it's loose",
-  "No CSI structure available, bugger all there",
-  "Level 2 halted, stopped dead",
-  "Invalid exchange, that's a fizzer",
-  "Invalid request descriptor, no good",
-  "Exchange full, chockers mate",
-  "No anode, nothing there",
-  "Invalid request code, spewing",
-  "Invalid slot, dud one",
-  "Unknown error 58, your guess is as good as mine",
-  "Bad font file format, rooted",
-  "Device not a stream, not flowing",
-  "No data available, bugger all",
-  "Timer expired, time's up chuck",
-  "Out of streams resources, dry as a nun's nasty",
-  "Machine is not on the network, off the grid",
-  "Package not installed, haven't installed it yet",
-  "Object is remote, way out woop woop",
-  "Link has been severed, cut clean off",
-  "Advertise error, promotion's gone bung",
-  "Srmount error, she'll be right (not)",
-  "Communication error on send, message got lost",
-  "Protocol error, someone's not playing by the rules",
-  "Multihop attempted, took the scenic route",
-  "RFS specific error, proper rooted",
-  "Bad message, that's rubbish",
-  "Value too large for defined data type, too big for its boots",
-  "Name not unique on network, someone's nicked it",
-  "File descriptor in bad state, proper mess",
-  "Remote address changed, did a runner",
-  "Cannot access a needed shared library, can't get to the good stuff",
-  "Accessing a corrupted shared library, it's munted",
-  ".lib section in a.out corrupted, proper rooted",
-  "Attempting to link in too many shared libraries, greedy guts",
-  "Cannot exec a shared library directly, no can do",
-  "Invalid or incomplete multibyte or wide character, gibberish mate",
-  "Interrupted system call should be restarted, have another crack",
-  "Streams pipe error, buggered pipe",
-  "Too many users, chockers with blokes",
-  "Socket operation on non-socket, trying to shear a pig",
-  "Destination address required, where to mate?",
-  "Message too long, waffling on a bit",
-  "Protocol wrong type for socket, square peg round hole",
-  "Protocol not available, fresh out",
-  "Protocol not supported, we don't do that here",
-  "Socket type not supported, not on your nelly",
-  "Operation not supported, yeah nah",
-  "Protocol family not supported, wrong mob",
-  "Address family not supported by protocol, doesn't fit",
-  "Address already in use, someone's parked there",
-  "Cannot assign requested address, can't give you that one",
-  "Network is down, carked it",
-  "Network is unreachable, can't get there from here",
-  "Network dropped connection on reset, dumped ya",
-  "Software caused connection abort, computer says no",
-  "Connection reset by peer, they pulled the pin",
-  "No buffer space available, chock-a-block",
-  "Transport endpoint is already connected, already hooked up",
-  "Transport endpoint is not connected, not plugged in",
-  "Cannot send after transport endpoint shutdown, too late mate",
-  "Too many references: cannot splice, tangled up",
-  "Connection timed out, took too long, gave up",
-  "Connection refused, not having a bar of it",
-  "Host is down, carked it",
-  "No route to host, no way through",
-  "Operation already in progress, already on it",
-  "Operation now in progress, cracking on with it",
-  "Stale file handle, older than dirt",
-  "Structure needs cleaning, bit of a mess",
-  "Not a XENIX named type file, not one of those",
-  "No XENIX semaphores available, none left mate",
-  "Is a named type file, one of those special ones",
-  "Remote I/O error, something's dodgy over there",
-  "Disk quota exceeded, eaten all your pie",
-  "No medium found, where'd it go?",
-  "Wrong medium type, not the right flavour",
-  "Operation canceled, pulled the plug",
-  "Required key not available, can't find the bloody thing",
-  "Key has expired, past its use-by date",
-  "Key has been revoked, you're nicked",
-  "Key was rejected by service, not good enough mate",
-  "Owner died, carked it",
-  "State not recoverable, proper rooted",
-  "Operation not possible due to RF-kill, wireless is cactus",
-  "Memory page has hardware error, RAM's gone bung"
-];
-
-pub const STRSIGNAL: [&'static str; 32] = [
-  "Dunno what signal 0 is, mate",
-  "Hang up the blower",
-  "Interrupt, cut it out",
-  "Quit, I'm out",
-  "Illegal instruction, you're under arrest",
-  "Trace/breakpoint trap, gotcha",
-  "Aborted, pulled the pin",
-  "Bus error, the wheels fell off",
-  "Floating point exception, bob's your uncle (not)",
-  "Killed, stone dead",
-  "User defined signal 1, custom ripper",
-  "Segmentation fault, proper crash",
-  "User defined signal 2, another custom one",
-  "Broken pipe, she snapped",
-  "Alarm clock, rise and shine",
-  "Terminated, sacked",
-  "Stack fault, piled up wrong",
-  "Child exited, ankle biter's gone",
-  "Continued, back in the game",
-  "Stopped (signal), pulled up",
-  "Stopped, having a smoko",
-  "Stopped (tty input), waiting for ya",
-  "Stopped (tty output), can't print",
-  "Urgent I/O condition, bloody urgent",
-  "CPU time limit exceeded, working too hard",
-  "File size limit exceeded, too big for its boots",
-  "Virtual timer expired, time's up",
-  "Profiling timer expired, times up for that too",
-  "Window changed, moved the goalposts",
-  "I/O possible, good to go",
-  "Power failure, lights out",
-  "Bad system call, drongo move"
-];
-
-pub const REGERROR: [&'static str; 14] = [
-  r"Sweet as",
-  r"No match, close but no cigar",
-  r"Invalid regular expression, that's not cricket",
-  r"Invalid collation character, dunno that one",
-  r"Invalid character class name, made that up",
-  r"Trailing backslash, lost your way",
-  r"Invalid back reference, remembering wrong",
-  r"Unmatched [, [^, [:, [., or [=, brackets are crook",
-  r"Unmatched ( or \(, parentheses rooted",
-  r"Unmatched \{, braces bung",
-  r"Invalid content of \{\}, rubbish inside",
-  r"Invalid range end, doesn't finish right",
-  r"Memory exhausted, out of puff",
-  r"Invalid preceding regular expression, what came before was dodgy"
-];
-
-pub const HSTRERROR: [&'static str; 5] = [
-  "Resolver Error 0 (no drama)",
-  "Dunno that host, never heard of it",
-  "Host name lookup failure, can't find it anywhere",
-  "Unknown server error, server's having a bad day",
-  "No address associated with name, it's not listed"
-];
-
-pub const GAI_STRERROR: [&'static str; 15] = [
-  "Sweet as",
-  "Bad value for ai_flags, that's dodgy",
-  "Name or service not known, beats me",
-  "Temporary failure in name resolution, having a moment",
-  "Non-recoverable failure in name resolution, proper rooted",
-  "ai_family not supported, we don't do that",
-  "ai_socktype not supported, not that kind",
-  "Servname not supported for ai_socktype, wrong combo",
-  "Memory allocation failure, out of scratchings",
-  "System error, computer's having a spack",
-  "Result too large for supplied buffer, too big for the ute",
-  "No address associated with hostname", // EAI_NODATA
-  "Request canceled, pulled the plug",   // EAI_CANCELED
-  "Request not canceled, still going",   // EAI_NOTCANCELED
-  "Parameter string not correctly encoded, gibberish"  // EAI_IDN_ENCODE
-];
-
-pub const YESEXPR: &'static str = "^[+1yYyYyeeahnah]";
-pub const NOEXPR: &'static str = "^[-0nNnahyeahnah]";
@@ -1,220 +0,0 @@
-pub const MISC_MESSAGES: [&'static str; 3] =
-  ["Unknown error", "Unknown signal", "Real-time signal"];
-
-pub const STRERROR: [&'static str; 134] = [
-  "Success",
-  "Operation not permitted",
-  "No such file or directory",
-  "No such process",
-  "Interrupted system call",
-  "Input/output error",
-  "No such device or address",
-  "Argument list too long",
-  "Exec format error",
-  "Bad file descriptor",
-  "No child processes",
-  "Resource temporarily unavailable",
-  "Cannot allocate memory",
-  "Permission denied",
-  "Bad address",
-  "Block device required",
-  "Device or resource busy",
-  "File exists",
-  "Invalid cross-device link",
-  "No such device",
-  "Not a directory",
-  "Is a directory",
-  "Invalid argument",
-  "Too many open files in system",
-  "Too many open files",
-  "Inappropriate ioctl for device",
-  "Text file busy",
-  "File too large",
-  "No space left on device",
-  "Illegal seek",
-  "Read-only file system",
-  "Too many links",
-  "Broken pipe",
-  "Numerical argument out of domain",
-  "Numerical result out of range",
-  "Resource deadlock avoided",
-  "File name too long",
-  "No locks available",
-  "Function not implemented",
-  "Directory not empty",
-  "Too many levels of symbolic links",
-  "Unknown error 41",
-  "No message of desired type",
-  "Identifier removed",
-  "Channel number out of range",
-  "Level 2 not synchronised",
-  "Level 3 halted",
-  "Level 3 reset",
-  "Link number out of range",
-  "Protocol driver not attached",
-  "No CSI structure available",
-  "Level 2 halted",
-  "Invalid exchange",
-  "Invalid request descriptor",
-  "Exchange full",
-  "No anode",
-  "Invalid request code",
-  "Invalid slot",
-  "Unknown error 58",
-  "Bad font file format",
-  "Device not a stream",
-  "No data available",
-  "Timer expired",
-  "Out of streams resources",
-  "Machine is not on the network",
-  "Package not installed",
-  "Object is remote",
-  "Link has been severed",
-  "Advertise error",
-  "Srmount error",
-  "Communication error on send",
-  "Protocol error",
-  "Multihop attempted",
-  "RFS specific error",
-  "Bad message",
-  "Value too large for defined data type",
-  "Name not unique on network",
-  "File descriptor in bad state",
-  "Remote address changed",
-  "Can not access a needed shared library",
-  "Accessing a corrupted shared library",
-  ".lib section in a.out corrupted",
-  "Attempting to link in too many shared libraries",
-  "Cannot exec a shared library directly",
-  "Invalid or incomplete multibyte or wide character",
-  "Interrupted system call should be restarted",
-  "Streams pipe error",
-  "Too many users",
-  "Socket operation on non-socket",
-  "Destination address required",
-  "Message too long",
-  "Protocol wrong type for socket",
-  "Protocol not available",
-  "Protocol not supported",
-  "Socket type not supported",
-  "Operation not supported",
-  "Protocol family not supported",
-  "Address family not supported by protocol",
-  "Address already in use",
-  "Cannot assign requested address",
-  "Network is down",
-  "Network is unreachable",
-  "Network dropped connection on reset",
-  "Software caused connection abort",
-  "Connection reset by peer",
-  "No buffer space available",
-  "Transport endpoint is already connected",
-  "Transport endpoint is not connected",
-  "Cannot send after transport endpoint shutdown",
-  "Too many references: cannot splice",
-  "Connection timed out",
-  "Connection refused",
-  "Host is down",
-  "No route to host",
-  "Operation already in progress",
-  "Operation now in progress",
-  "Stale file handle",
-  "Structure needs cleaning",
-  "Not a XENIX named type file",
-  "No XENIX semaphores available",
-  "Is a named type file",
-  "Remote I/O error",
-  "Disk quota exceeded",
-  "No medium found",
-  "Wrong medium type",
-  "Operation cancelled",
-  "Required key not available",
-  "Key has expired",
-  "Key has been revoked",
-  "Key was rejected by service",
-  "Owner died",
-  "State not recoverable",
-  "Operation not possible due to RF-kill",
-  "Memory page has hardware error"
-];
-
-pub const STRSIGNAL: [&'static str; 32] = [
-  "Unknown signal 0",
-  "Hangup",
-  "Interrupt",
-  "Quit",
-  "Illegal instruction",
-  "Trace/breakpoint trap",
-  "Aborted",
-  "Bus error",
-  "Floating point exception",
-  "Killed",
-  "User defined signal 1",
-  "Segmentation fault",
-  "User defined signal 2",
-  "Broken pipe",
-  "Alarm clock",
-  "Terminated",
-  "Stack fault",
-  "Child exited",
-  "Continued",
-  "Stopped (signal)",
-  "Stopped",
-  "Stopped (tty input)",
-  "Stopped (tty output)",
-  "Urgent I/O condition",
-  "CPU time limit exceeded",
-  "File size limit exceeded",
-  "Virtual timer expired",
-  "Profiling timer expired",
-  "Window changed",
-  "I/O possible",
-  "Power failure",
-  "Bad system call"
-];
-
-pub const REGERROR: [&'static str; 14] = [
-  r"Success",
-  r"No match",
-  r"Invalid regular expression",
-  r"Invalid collation character",
-  r"Invalid character class name",
-  r"Trailing backslash",
-  r"Invalid back reference",
-  r"Unmatched [, [^, [:, [., or [=",
-  r"Unmatched ( or \(",
-  r"Unmatched \{",
-  r"Invalid content of \{\}",
-  r"Invalid range end",
-  r"Memory exhausted",
-  r"Invalid preceding regular expression"
-];
-
-pub const HSTRERROR: [&'static str; 5] = [
-  "Resolver Error 0 (no error)",
-  "Unknown host",
-  "Host name lookup failure",
-  "Unknown server error",
-  "No address associated with name"
-];
-
-pub const GAI_STRERROR: [&'static str; 15] = [
-  "Success",
-  "Bad value for ai_flags",
-  "Name or service not known",
-  "Temporary failure in name resolution",
-  "Non-recoverable failure in name resolution",
-  "ai_family not supported",
-  "ai_socktype not supported",
-  "Servname not supported for ai_socktype",
-  "Memory allocation failure",
-  "System error",
-  "Result too large for supplied buffer",
-  "No address associated with hostname", // EAI_NODATA
-  "Request canceled",                    // EAI_CANCELED
-  "Request not canceled",                // EAI_NOTCANCELED
-  "Parameter string not correctly encoded"  // EAI_IDN_ENCODE
-];
-
-pub const YESEXPR: &'static str = "^[+1yY]";
-pub const NOEXPR: &'static str = "^[-0nN]";
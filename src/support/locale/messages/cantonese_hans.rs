@@ -1,220 +0,0 @@
-pub const MISC_MESSAGES: [&'static str; 3] =
-  ["未知错误", "未知信号", "实时信号"];
-
-pub const STRERROR: [&'static str; 134] = [
-  "成功",
-  "唔允许操作",
-  "冇呢个文件或者目录",
-  "冇呢个进程",
-  "系统调用俾人打断",
-  "输入/输出错误",
-  "冇呢个设备或者地址",
-  "参数列表太长",
-  "执行格式错误",
-  "文件描述符错误",
-  "冇子进程",
-  "资源暂时唔可用",
-  "分配唔到内存",
-  "权限唔够",
-  "地址错误",
-  "需要块设备",
-  "设备或者资源忙",
-  "文件已经存在",
-  "跨设备链接无效",
-  "冇呢个设备",
-  "唔系目录",
-  "系目录",
-  "参数无效",
-  "系统入面打开文件太多",
-  "打开文件太多",
-  "设备控制命令唔啱",
-  "文本文件忙",
-  "文件太大",
-  "设备空间满咗",
-  "非法寻道",
-  "只读文件系统",
-  "链接太多",
-  "管道断裂",
-  "数值参数超出范围",
-  "数值结果超出范围",
-  "避免资源死锁",
-  "文件名太长",
-  "锁唔可用",
-  "功能未实现",
-  "目录唔系空",
-  "符号链接层级太多",
-  "未知错误 41",
-  "冇想要嘅消息类型",
-  "标识符俾人删除",
-  "通道号超出范围",
-  "第二层唔同步",
-  "第三层停止",
-  "第三层重置",
-  "链接号超出范围",
-  "协议驱动未连接",
-  "冇CSI结构",
-  "第二层停止",
-  "无效交换",
-  "无效请求描述符",
-  "交换满咗",
-  "冇阳极",
-  "无效请求码",
-  "无效插槽",
-  "未知错误 58",
-  "字体文件格式错误",
-  "设备唔系流",
-  "数据唔可用",
-  "定时器到期",
-  "流资源耗尽",
-  "机器唔系喺网络度",
-  "包未安装",
-  "对象喺远程",
-  "链接俾人切断",
-  "广告错误",
-  "服务器挂载错误",
-  "发送通信错误",
-  "协议错误",
-  "尝试多跳",
-  "RFS特定错误",
-  "消息错误",
-  "数值对定义嘅数据类型嚟讲太大",
-  "网络上面名字唔唯一",
-  "文件描述符状态错误",
-  "远程地址改变",
-  "无法访问需要嘅共享库",
-  "访问损坏嘅共享库",
-  "a.out入面嘅.lib段损坏",
-  "尝试链接太多共享库",
-  "无法直接执行共享库",
-  "无效或者唔完整嘅多字节或者宽字符",
-  "俾人打断嘅系统调用要重新启动",
-  "流管道错误",
-  "用户太多",
-  "非套接字上面嘅套接字操作",
-  "需要目标地址",
-  "消息太长",
-  "协议类型唔啱套接字",
-  "协议唔可用",
-  "协议唔支持",
-  "套接字类型唔支持",
-  "操作唔支持",
-  "协议族唔支持",
-  "地址族对协议嚟讲唔支持",
-  "地址已经喺用",
-  "无法分配请求嘅地址",
-  "网络死咗",
-  "网络唔可达",
-  "网络俾人重置连接断开",
-  "软件引起连接异常终止",
-  "对端重置连接",
-  "缓冲区空间唔可用",
-  "传输端点已经连接",
-  "传输端点未连接",
-  "传输端点关闭之后无法发送",
-  "引用太多：无法拼接",
-  "连接超时",
-  "连接俾人拒绝",
-  "主机死咗",
-  "冇到主机嘅路由",
-  "操作已经喺进行紧",
-  "操作而家喺进行紧",
-  "文件句柄过期",
-  "结构需要清理",
-  "唔系XENIX命名类型文件",
-  "冇XENIX信号量可用",
-  "系命名类型文件",
-  "远程I/O错误",
-  "磁盘配额超出",
-  "冇揾到介质",
-  "介质类型错误",
-  "操作俾人取消",
-  "需要嘅密钥唔可用",
-  "密钥过期",
-  "密钥俾人吊销",
-  "密钥俾服务拒绝",
-  "拥有者死咗",
-  "状态无法恢复",
-  "因为RF-kill操作唔可能",
-  "内存页面有硬件错误"
-];
-
-pub const STRSIGNAL: [&'static str; 32] = [
-  "未知信号 0",
-  "挂机",
-  "中断",
-  "退出",
-  "非法指令",
-  "跟踪/断点陷阱",
-  "异常终止",
-  "总线错误",
-  "浮点异常",
-  "杀死",
-  "用户定义信号 1",
-  "段错误",
-  "用户定义信号 2",
-  "管道断裂",
-  "闹钟",
-  "终止",
-  "栈错误",
-  "子进程退出",
-  "继续",
-  "停止（信号）",
-  "停止",
-  "停止（tty输入）",
-  "停止（tty输出）",
-  "紧急I/O条件",
-  "CPU时间限制超出",
-  "文件大小限制超出",
-  "虚拟定时器到期",
-  "性能分析定时器到期",
-  "窗口改变",
-  "I/O可能",
-  "断电",
-  "错误系统调用"
-];
-
-pub const REGERROR: [&'static str; 14] = [
-  r"成功",
-  r"冇匹配",
-  r"无效正则表达式",
-  r"无效对照字符",
-  r"无效字符类名",
-  r"尾部反斜杠",
-  r"无效反向引用",
-  r"[、[^、[[.、[[= 未匹配",
-  r"( 或者 \( 未匹配",
-  r"\{ 未匹配",
-  r"\{\} 内容无效",
-  r"无效范围结尾",
-  r"内存耗尽",
-  r"前面正则表达式无效"
-];
-
-pub const HSTRERROR: [&'static str; 5] = [
-  "解析器错误 0（冇错误）",
-  "未知主机",
-  "主机名查找失败",
-  "未知服务器错误",
-  "冇同名字关联嘅地址"
-];
-
-pub const GAI_STRERROR: [&'static str; 15] = [
-  "成功",
-  "ai_flags 值错误",
-  "名字或者服务唔知",
-  "名字解析暂时失败",
-  "名字解析无法恢复嘅失败",
-  "ai_family 唔支持",
-  "ai_socktype 唔支持",
-  "对ai_socktype嚟讲Servname唔支持",
-  "内存分配失败",
-  "系统错误",
-  "结果对提供嘅缓冲区嚟讲太大",
-  "冇同主机名关联嘅地址", // EAI_NODATA
-  "请求俾人取消",         // EAI_CANCELED
-  "请求未俾人取消",       // EAI_NOTCANCELED
-  "参数字符串编码唔正确"  // EAI_IDN_ENCODE
-];
-
-pub const YESEXPR: &'static str = "^[+1yY係]";
-pub const NOEXPR: &'static str = "^[-0nN唔]";
@@ -1,220 +0,0 @@
-pub const MISC_MESSAGES: [&'static str; 3] =
-  ["未知錯誤", "未知信號", "實時信號"];
-
-pub const STRERROR: [&'static str; 134] = [
-  "成功",
-  "唔允許操作",
-  "冇呢個文件或者目錄",
-  "冇呢個進程",
-  "系統調用俾人打斷",
-  "輸入/輸出錯誤",
-  "冇呢個設備或者地址",
-  "參數列表太長",
-  "執行格式錯誤",
-  "文件描述符錯誤",
-  "冇子進程",
-  "資源暫時唔可用",
-  "分配唔到記憶體",
-  "權限唔夠",
-  "地址錯誤",
-  "需要塊設備",
-  "設備或者資源忙",
-  "文件已經存在",
-  "跨設備鏈接無效",
-  "冇呢個設備",
-  "唔係目錄",
-  "係目錄",
-  "參數無效",
-  "系統入面打開文件太多",
-  "打開文件太多",
-  "設備控制命令唔啱",
-  "文本文件忙",
-  "文件太大",
-  "設備空間滿咗",
-  "非法尋道",
-  "唯讀文件系統",
-  "鏈接太多",
-  "管道斷裂",
-  "數值參數超出範圍",
-  "數值結果超出範圍",
-  "避免資源死鎖",
-  "文件名太長",
-  "鎖唔可用",
-  "功能未實現",
-  "目錄唔係空",
-  "符號鏈接層級太多",
-  "未知錯誤 41",
-  "冇想要嘅消息類型",
-  "標識符俾人刪除",
-  "通道號超出範圍",
-  "第二層唔同步",
-  "第三層停止",
-  "第三層重置",
-  "鏈接號超出範圍",
-  "協議驅動未連接",
-  "冇CSI結構",
-  "第二層停止",
-  "無效交換",
-  "無效請求描述符",
-  "交換滿咗",
-  "冇陽極",
-  "無效請求碼",
-  "無效插槽",
-  "未知錯誤 58",
-  "字體文件格式錯誤",
-  "設備唔係流",
-  "數據唔可用",
-  "定時器到期",
-  "流資源耗盡",
-  "機器唔係喺網絡度",
-  "包未安裝",
-  "對象喺遠程",
-  "鏈接俾人切斷",
-  "廣告錯誤",
-  "伺服器掛載錯誤",
-  "發送通信錯誤",
-  "協議錯誤",
-  "嘗試多跳",
-  "RFS特定錯誤",
-  "消息錯誤",
-  "數值對定義嘅數據類型嚟講太大",
-  "網絡上面名字唔唯一",
-  "文件描述符狀態錯誤",
-  "遠程地址改變",
-  "無法訪問需要嘅共享庫",
-  "訪問損壞嘅共享庫",
-  "a.out入面嘅.lib段損壞",
-  "嘗試鏈接太多共享庫",
-  "無法直接執行共享庫",
-  "無效或者唔完整嘅多字節或者寬字符",
-  "俾人打斷嘅系統調用要重新啟動",
-  "流管道錯誤",
-  "用戶太多",
-  "非套接字上面嘅套接字操作",
-  "需要目標地址",
-  "消息太長",
-  "協議類型唔啱套接字",
-  "協議唔可用",
-  "協議唔支援",
-  "套接字類型唔支援",
-  "操作唔支援",
-  "協議族唔支援",
-  "地址族對協議嚟講唔支援",
-  "地址已經喺用",
-  "無法分配請求嘅地址",
-  "網絡死咗",
-  "網絡唔可達",
-  "網絡俾人重置連接斷開",
-  "軟件引起連接異常終止",
-  "對端重置連接",
-  "緩衝區空間唔可用",
-  "傳輸端點已經連接",
-  "傳輸端點未連接",
-  "傳輸端點關閉之後無法發送",
-  "引用太多：無法拼接",
-  "連接超時",
-  "連接俾人拒絕",
-  "主機死咗",
-  "冇到主機嘅路由",
-  "操作已經喺進行緊",
-  "操作而家喺進行緊",
-  "文件句柄過期",
-  "結構需要清理",
-  "唔係XENIX命名類型文件",
-  "冇XENIX信號量可用",
-  "係命名類型文件",
-  "遠程I/O錯誤",
-  "磁盤配額超出",
-  "冇揾到介質",
-  "介質類型錯誤",
-  "操作俾人取消",
-  "需要嘅密鑰唔可用",
-  "密鑰過期",
-  "密鑰俾人吊銷",
-  "密鑰俾服務拒絕",
-  "擁有者死咗",
-  "狀態無法恢復",
-  "因為RF-kill操作唔可能",
-  "記憶體頁面有硬件錯誤"
-];
-
-pub const STRSIGNAL: [&'static str; 32] = [
-  "未知信號 0",
-  "掛機",
-  "中斷",
-  "退出",
-  "非法指令",
-  "跟蹤/斷點陷阱",
-  "異常終止",
-  "總線錯誤",
-  "浮點異常",
-  "殺死",
-  "用戶定義信號 1",
-  "段錯誤",
-  "用戶定義信號 2",
-  "管道斷裂",
-  "鬧鐘",
-  "終止",
-  "棧錯誤",
-  "子進程退出",
-  "繼續",
-  "停止（信號）",
-  "停止",
-  "停止（tty輸入）",
-  "停止（tty輸出）",
-  "緊急I/O條件",
-  "CPU時間限制超出",
-  "文件大小限制超出",
-  "虛擬定時器到期",
-  "性能分析定時器到期",
-  "窗口改變",
-  "I/O可能",
-  "斷電",
-  "錯誤系統調用"
-];
-
-pub const REGERROR: [&'static str; 14] = [
-  r"成功",
-  r"冇匹配",
-  r"無效正則表達式",
-  r"無效對照字符",
-  r"無效字符類名",
-  r"尾部反斜槓",
-  r"無效反向引用",
-  r"[、[^、[[.、[[= 未匹配",
-  r"( 或者 \( 未匹配",
-  r"\{ 未匹配",
-  r"\{\} 內容無效",
-  r"無效範圍結尾",
-  r"記憶體耗盡",
-  r"前面正則表達式無效"
-];
-
-pub const HSTRERROR: [&'static str; 5] = [
-  "解析器錯誤 0（冇錯誤）",
-  "未知主機",
-  "主機名查找失敗",
-  "未知伺服器錯誤",
-  "冇同名字關聯嘅地址"
-];
-
-pub const GAI_STRERROR: [&'static str; 15] = [
-  "成功",
-  "ai_flags 值錯誤",
-  "名字或者服務唔知",
-  "名字解析暫時失敗",
-  "名字解析無法恢復嘅失敗",
-  "ai_family 唔支援",
-  "ai_socktype 唔支援",
-  "對ai_socktype嚟講Servname唔支援",
-  "記憶體分配失敗",
-  "系統錯誤",
-  "結果對提供嘅緩衝區嚟講太大",
-  "冇同主機名關聯嘅地址", // EAI_NODATA
-  "請求俾人取消",         // EAI_CANCELED
-  "請求未俾人取消",       // EAI_NOTCANCELED
-  "參數字符串編碼唔正確"  // EAI_IDN_ENCODE
-];
-
-pub const YESEXPR: &'static str = "^[+1yY係]";
-pub const NOEXPR: &'static str = "^[-0nN唔]";
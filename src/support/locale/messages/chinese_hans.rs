@@ -1,220 +0,0 @@
-pub const MISC_MESSAGES: [&'static str; 3] =
-  ["未知错误", "未知信号", "实时信号"];
-
-pub const STRERROR: [&'static str; 134] = [
-  "成功",
-  "不允许的操作",
-  "没有那个文件或目录",
-  "没有那个进程",
-  "被中断的系统调用",
-  "输入/输出错误",
-  "没有那个设备或地址",
-  "参数列表过长",
-  "可执行文件格式错误",
-  "错误的文件描述符",
-  "没有子进程",
-  "资源暂时不可用",
-  "无法分配内存",
-  "权限不够",
-  "错误的地址",
-  "需要块设备",
-  "设备或资源忙",
-  "文件已存在",
-  "无效的跨设备链接",
-  "没有那个设备",
-  "不是目录",
-  "是一个目录",
-  "无效的参数",
-  "系统中打开的文件过多",
-  "打开的文件过多",
-  "对设备不适当的 ioctl 操作",
-  "文本文件忙",
-  "文件过大",
-  "设备上没有空间",
-  "非法 seek 操作",
-  "只读文件系统",
-  "过多的链接",
-  "断开的管道",
-  "数值参数超出定义域",
-  "数值结果超出值域",
-  "已避免资源死锁",
-  "文件名过长",
-  "没有可用的锁",
-  "函数未实现",
-  "目录非空",
-  "符号链接的层数过多",
-  "未知错误 41",
-  "没有所需类型的消息",
-  "标识符已删除",
-  "通道编号超出范围",
-  "级别 2 尚未同步",
-  "级别 3 已关闭",
-  "级别 3 已重置",
-  "链接数超出范围",
-  "未加载协议驱动程序",
-  "没有可用的 CSI 结构",
-  "级别 2 己关闭",
-  "无效的交换",
-  "无效的请求描述符",
-  "交换满",
-  "没有 anode",
-  "无效的请求码",
-  "无效的 slot",
-  "未知错误 58",
-  "错误的字体文件格式",
-  "设备不是流",
-  "没有可用的数据",
-  "计时器过期",
-  "流资源不足",
-  "机器不在网络中",
-  "包未安装",
-  "对象是远程的",
-  "链接已被切断",
-  "通知错误",
-  "Srmount 错误",
-  "发送时出现通讯错误",
-  "协议错误",
-  "试图多跳",
-  "RFS 特定错误",
-  "错误的消息",
-  "对已定义的数据类型来说值过大",
-  "网络上的名称不是唯一的",
-  "文件描述符处于错误状态",
-  "远程地址已改变",
-  "无法访问必需的共享库",
-  "访问已损坏的共享库",
-  "a.out 中的 .lib 节已毁坏",
-  "试图与过多的共享库相链接",
-  "无法直接执行共享库",
-  "无效或不完整的多字节字符或宽字符",
-  "被中断的系统调用应当重新启动",
-  "流管道错误",
-  "用户过多",
-  "对非套接字进行套接字操作",
-  "需要目标地址",
-  "消息过长",
-  "用于套接字的协议类型错误",
-  "不可用的协议",
-  "不支持的协议",
-  "不支持的套接字类型",
-  "不支持的操作",
-  "不支持的协议族",
-  "协议不支持的地址族",
-  "地址已在使用",
-  "无法分配被请求的地址",
-  "网络已关闭",
-  "网络不可达",
-  "网络连接在重置时断开",
-  "由软件导致的连接中止",
-  "连接被对方重置",
-  "没有可用的缓冲区空间",
-  "传输端点已连接",
-  "传输端点尚未连接",
-  "无法在传输端点关闭以后发送",
-  "过多的引用：无法接合",
-  "连接超时",
-  "连接被拒绝",
-  "主机已关闭",
-  "没有到主机的路由",
-  "操作已经在进行",
-  "操作现在正在进行",
-  "过旧的文件句柄",
-  "结构需要清理",
-  "不是 XENIX 命名类型文件",
-  "没有可用的 XENIX 信号量",
-  "是一个命名类型文件",
-  "远程 I/O 错误",
-  "超出磁盘配额",
-  "找不到介质",
-  "错误的介质类型",
-  "操作已取消",
-  "需要的密钥不存在",
-  "密钥已过期",
-  "密钥已被撤销",
-  "密钥被服务拒绝",
-  "拥有者已结束",
-  "状态无法恢复",
-  "由于 RF-kill 而无法操作",
-  "内存页有硬件错误"
-];
-
-pub const STRSIGNAL: [&'static str; 32] = [
-  "未知信号 0",
-  "挂断",
-  "中断",
-  "退出",
-  "非法指令",
-  "追踪或断点陷阱",
-  "已中止",
-  "总线错误",
-  "浮点异常",
-  "已杀死",
-  "用户自定义信号 1",
-  "段错误",
-  "用户自定义信号 2",
-  "断开的管道",
-  "闹钟",
-  "已终止",
-  "栈错误",
-  "子进程已退出",
-  "已继续",
-  "已停止（信号）",
-  "已停止",
-  "已停止（tty 输入）",
-  "已停止（tty 输出）",
-  "紧急 I/O 事件",
-  "CPU 时间超出限制",
-  "文件大小超出限制",
-  "虚拟定时器过期",
-  "性能分析定时器过期",
-  "窗口已改变",
-  "可能需要 I/O",
-  "电源故障",
-  "错误的系统调用"
-];
-
-pub const REGERROR: [&'static str; 14] = [
-  r"成功",
-  r"没有匹配",
-  r"无效的正则表达式",
-  r"无效的定序字符",
-  r"无效的字符类名",
-  r"末尾有反斜杠",
-  r"无效的后向引用",
-  r"未匹配的 [、[^、[:、[. 或 [=",
-  r"不匹配的 ( 或 \(",
-  r"不匹配的 \{",
-  r"无效的 \{\} 的内容",
-  r"无效的范围端点",
-  r"内存耗尽",
-  r"前面的正则表达式无效"
-];
-
-pub const HSTRERROR: [&'static str; 5] = [
-  "解析器错误 0（无错误）",
-  "未知的主机",
-  "查找主机名失败",
-  "未知的服务器错误",
-  "没有与名字关联的地址"
-];
-
-pub const GAI_STRERROR: [&'static str; 15] = [
-  "成功",
-  "错误的 ai_flags 值",
-  "名称或服务未知",
-  "域名解析出现暂时性错误",
-  "域名解析出现无法恢复的错误",
-  "不支持的 ai_family",
-  "不支持 ai_socktype",
-  "ai_socktype 不支持 servname",
-  "内存分配失败",
-  "系统错误",
-  "结果太大，超出所提供的缓冲区",
-  "没有与主机名关联的地址", // EAI_NODATA
-  "请求已取消",             // EAI_CANCELED
-  "请求未取消",             // EAI_NOTCANCELED
-  "参数字符串编码不正确"    // EAI_IDN_ENCODE
-];
-
-pub const YESEXPR: &'static str = "^[+1yYｙＹ是]";
-pub const NOEXPR: &'static str = "^[-0nNｎＮ不否]";
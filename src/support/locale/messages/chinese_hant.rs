@@ -1,220 +0,0 @@
-pub const MISC_MESSAGES: [&'static str; 3] =
-  ["未知的錯誤", "未知信號", "即時信號"];
-
-pub const STRERROR: [&'static str; 134] = [
-  "成功",
-  "此項操作並不被允許",
-  "沒有此一檔案或目錄",
-  "沒有此一程序",
-  "中斷的系統呼叫",
-  "輸入/輸出錯誤",
-  "沒有此一裝置或位址",
-  "引數列項目過長",
-  "可執行檔格式錯誤",
-  "錯誤的檔案敘述項",
-  "沒有子程序存在",
-  "資源暫時無法取得",
-  "無法配置記憶體",
-  "拒絕不符權限的操作",
-  "錯誤的位址",
-  "必須是區塊裝置 (Block device)",
-  "裝置或系統資源忙碌中",
-  "檔案已存在",
-  "不適用的裝置間連結",
-  "沒有此一裝置",
-  "並不是一個目錄",
-  "是個目錄",
-  "不適用的引數",
-  "系統中太多的開啟檔案",
-  "開啟太多檔案",
-  "不希望的裝置輸出入控制 (ioctl)",
-  "文字檔忙碌中",
-  "檔案太大",
-  "裝置上已無多餘空間",
-  "不合法的搜尋",
-  "唯讀的檔案系統",
-  "太多連結",
-  "管線損壞",
-  "數值參數的值超出範圍",
-  "數值運算結果超出範圍",
-  "避開資源鎖定",
-  "檔名太長",
-  "無法鎖定資料",
-  "功能沒有實作",
-  "目錄不是空的",
-  "太多層級的符號連結檔",
-  "未知的錯誤 41",
-  "沒有符合需求格式的訊息",
-  "識別符號移除",
-  "通道號碼超出範圍",
-  "層級 2 沒有同步",
-  "層級 3 停止",
-  "層級 3 重設",
-  "連結數目超過範圍",
-  "協定的驅動程式未連接",
-  "沒有可用的 CSI 結構了",
-  "層級 2 停止",
-  "不適用的交換",
-  "不適用的請求敘述項",
-  "交換已滿",
-  "沒有 anode",
-  "不適用的請求碼",
-  "不適用的 slot",
-  "未知的錯誤 58",
-  "錯誤的字型檔格式",
-  "裝置不是資料流",
-  "沒有可用的資料",
-  "計時器已逾時",
-  "所有資料流的資源都已用盡",
-  "機器不在網路中",
-  "套件並未安裝",
-  "目標檔案位於遠端",
-  "連結已有服務",
-  "通知錯誤",
-  "Srmount 錯誤",
-  "在傳送時通訊錯誤",
-  "協定錯誤",
-  "嘗試 Multihop",
-  "RFS 特定錯誤",
-  "錯誤的訊息",
-  "有定義的資料型別值太大",
-  "網路上的名稱不是唯一的",
-  "檔案敘述項處於錯誤狀態",
-  "遠端位址改變了",
-  "無法存取所需的分享函式庫",
-  "存取一個毀掉的分享函式庫",
-  "a.out 中 .lib 區段毀掉了",
-  "嘗試去連結太多的分享資料庫",
-  "不能直接執行一個分享函式庫",
-  "不適用或不完整的多位元組字元或寬字元",
-  "中斷的系統呼叫必須重新啟動",
-  "資料流管線錯誤",
-  "太多使用者",
-  "Socket 在非 socket 上運作",
-  "需要目標位址",
-  "訊息太長",
-  "通訊端的協定錯誤型態",
-  "協定不存在",
-  "協定不支援",
-  "通訊端型別不支援",
-  "此項操作並不被支援",
-  "協定群組不支援",
-  "位址族群不被協定所支援",
-  "位址已經有在使用",
-  "無法指定所需的位址",
-  "網路停擺",
-  "無法接觸網路",
-  "在重設時網路連線漏失",
-  "軟體造成連線中斷",
-  "連線被對方重設",
-  "沒有可用的緩衝空間了",
-  "傳輸的兩端已經完成連線了",
-  "傳輸的兩端尚未連線",
-  "無法在輸送端點關閉後傳送",
-  "太多的參考指標: 無法連接",
-  "連線超過時間",
-  "連線被拒絕",
-  "遠端系統已停機了",
-  "找不到通往該主機的路由路徑",
-  "此項操作已在處理中",
-  "操作正在處理",
-  "過舊的檔案控柄",
-  "結構需要清理",
-  "並非 XENIX 命名格式的檔案",
-  "沒有可用的 XENIX 信號標誌了",
-  "是個具名的型態檔案",
-  "遠端輸出入錯誤",
-  "磁碟配額已滿",
-  "找不到媒體",
-  "錯誤的媒體型態",
-  "操作已被取消",
-  "必要鍵值無法使用",
-  "鍵值已過期",
-  "鍵值已取消",
-  "鍵值被服務所拒絕",
-  "擁有者已消逝",
-  "狀態無法回復",
-  "由於 RF-kill 而無法操作",
-  "記憶體分頁有硬體錯誤"
-];
-
-pub const STRSIGNAL: [&'static str; 32] = [
-  "未知信號 0",
-  "掛斷",
-  "中斷",
-  "退出",
-  "無效指令",
-  "追蹤 / 斷點陷阱",
-  "中止",
-  "匯流排錯誤",
-  "浮點數例外",
-  "強制結束",
-  "使用者定義信號 1",
-  "程式記憶體區段錯誤",
-  "使用者定義信號 2",
-  "管線損壞",
-  "鬧鐘",
-  "終止",
-  "堆疊錯誤",
-  "子程序退出",
-  "繼續",
-  "停止（信號）",
-  "停止",
-  "停止（tty 輸入）",
-  "停止（tty 輸出）",
-  "緊急 I/O 條件",
-  "到達 CPU 時間上限",
-  "到達檔案大小上限",
-  "虛擬計時器過期",
-  "分析計時器過期",
-  "已變更視窗",
-  "可能 I/O",
-  "電源錯誤",
-  "無效系統呼叫"
-];
-
-pub const REGERROR: [&'static str; 14] = [
-  r"成功",
-  r"無符合項目",
-  r"正規表示式無效",
-  r"定序字元無效",
-  r"字元類型名稱無效",
-  r"末尾反斜線",
-  r"向後參考無效",
-  r"不對稱的 [、[^、[:、[. 或 [=",
-  r"不對稱的 ( 或 \(",
-  r"不對稱的 \{",
-  r"\{\} 中內容無效",
-  r"結束範圍無效",
-  r"記憶體用盡",
-  r"前置正規表示式無效"
-];
-
-pub const HSTRERROR: [&'static str; 5] = [
-  "解讀錯誤碼 0 (沒有錯誤)",
-  "未知的遠端位址",
-  "遠端系統名稱搜尋失敗",
-  "未知的伺服器錯誤",
-  "沒有此名稱所對應的位址"
-];
-
-pub const GAI_STRERROR: [&'static str; 15] = [
-  "成功",
-  "ai_flags 的值無效",
-  "名稱或服務未知",
-  "解析名稱時發生暫時性錯誤",
-  "解析名稱時發生無法復原錯誤",
-  "不支援 ai_family",
-  "不支援 ai_socktype",
-  "ai_socktype 不支援伺服器名稱 (Servname)",
-  "分配記憶體失敗",
-  "系統錯誤",
-  "結果過大，無法置入緩衝區",
-  "沒有位址關聯至此主機名稱", // EAI_NODATA
-  "已取消請求",               // EAI_CANCELED
-  "未取消請求",               // EAI_NOTCANCELED
-  "未正確編碼參數字串"        // EAI_IDN_ENCODE
-];
-
-pub const YESEXPR: &'static str = "^[+1yYｙＹ是]";
-pub const NOEXPR: &'static str = "^[-0nNｎＮ不否]";
@@ -1,220 +0,0 @@
-pub const MISC_MESSAGES: [&'static str; 3] =
-  ["Άγνωστο σφάλμα", "Άγνωστο σήμα", "Σήμα πραγματικού-χρόνου"];
-
-pub const STRERROR: [&'static str; 134] = [
-  "Επιτυχία",
-  "Η λειτουργία δεν επιτρέπεται",
-  "Δεν υπάρχει τέτοιο αρχείο ή κατάλογος",
-  "Δεν υπάρχει τέτοια διεργασία",
-  "Διακοπείσα κλήση συστήματος",
-  "Σφάλμα εισόδου/εξόδου",
-  "Δεν υπάρχει τέτοια συσκευή ή διεύθυνση",
-  "Ο κατάλογος των παραμέτρων είναι πολύ μακρύς",
-  "Σφάλμα στη διαμόρφωση του εκτελέσιμου",
-  "Εσφαλμένος περιγραφέας αρχείου",
-  "Καμιά θυγατρική διεργασία",
-  "Ο πόρος είναι προσωρινά μη διαθέσιμος",
-  "Δεν είναι δυνατό να δεσμευτεί μνήμη",
-  "Άρνηση πρόσβασης",
-  "Εσφαλμένη διεύθυνση",
-  "Απαιτείται συσκευή μπλοκ",
-  "Συσκευή ή πόροι είναι απασχολημένοι",
-  "Το αρχείο υπάρχει",
-  "Μη έγκυρος σύνδεσμος μεταξύ συσκευών",
-  "Δεν υπάρχει τέτοια συσκευή",
-  "Δεν είναι κατάλογος",
-  "Είναι κατάλογος",
-  "Μη έγκυρη παράμετρος",
-  "Υπερβολικα πολλά ανοικτά αρχεία στο σύστημα",
-  "Υπερβολικά πολλά ανοικτά αρχεία",
-  "Ακατάλληλο ioctl για συσκευή",
-  "Αρχείο κειμένου σε χρήση",
-  "Πολύ μεγάλο αρχείο",
-  "Δεν έμεινε καθόλου χώρος στη συσκευή",
-  "Παράνομη αναζήτηση",
-  "Αναγνώσιμο-μόνο σύστημα αρχείων",
-  "Υπερβολικά πολλοί σύνδεσμοι",
-  "Διακοπείσα σωλήνωση",
-  "Αριθμητική παράμετρος έξω από τον τομέα",
-  "Το αριθμητικό αποτέλεσμα είναι έξω από το πεδίο",
-  "Αποφεύχθηκε αδιέξοδο σφάλμα πόρων",
-  "Πολύ μεγάλο όνομα αρχείου",
-  "Δεν υπάρχουν διαθέσιμα κλειδώματα",
-  "Η λειτουργία δεν έχει υλοποιηθεί",
-  "Ο κατάλογος δεν είναι κενός",
-  "Υπερβολικά πολλά επίπεδα συμβολικών συνδέσμων",
-  "Άγνωστο σφάλμα 41",
-  "Κανένα μήνυμα επιθυμητού τύπου",
-  "Προσδιοριστής αφαιρέθηκε",
-  "Ο αριθμός των καναλιών είναι έξω από τα όρια",
-  "Επίπεδο 2 δεν συγχρονίστηκε",
-  "Επίπεδο 3 σταμάτησε",
-  "Επίπεδο 3 επαναφέρθηκε",
-  "Ο αριθμός σύνδεσμου είναι έξω από το όριο",
-  "Ο οδηγός πρωτοκόλλου δεν έχει προσκολληθεί",
-  "Δεν είναι διαθέσιμη η CSI δομή(structure)",
-  "Επίπεδο 2 σταμάτησε",
-  "Μη έγκυρη ανταλλαγή",
-  "Μη έγκυρος περιγραφέας αίτησης",
-  "Ανταλλαγή πλήρης",
-  "Κανένα anode",
-  "Μη έγκυρος κώδικας αίτησης",
-  "Μη έγκυρη οπή",
-  "Άγνωστο σφάλμα 58",
-  "Εσφαλμένη διαμόρφωση αρχείου γραμματοσειράς",
-  "Η συσκευή δεν είναι ροής",
-  "Δεν υπάρχουν διαθέσιμα δεδομένα",
-  "Ο χρονομετρητής έληξε",
-  "Δεν απομείναν πόροι ροής(streams)",
-  "Το μηχάνημα δεν είναι στο δίκτυο",
-  "Το πακέτο δεν εγκαταστάθηκε",
-  "Το αντικείμενο είναι απομακρυσμένο",
-  "Ο σύνδεσμος έσπασε",
-  "Σφάλμα διαφήμισης",
-  "Σφάλμα srmount",
-  "Σφάλμα επικοινωνίας κατά την αποστολή",
-  "Σφάλμα πρωτοκόλλου",
-  "Προσπάθεια multihop",
-  "Συγκεκριμένο με RFS σφάλμα",
-  "Εσφαλμένο μήνυμα",
-  "Η τιμή είναι πολύ μεγάλη για καθορισμένο τύπο δεδομένων",
-  "Το όνομα δεν είναι μοναδικό στο δίκτυο",
-  "Ο περιγραφέας αρχείου σε κακή κατάσταση",
-  "Η απομακρυσμένη διεύθυνση άλλαξε",
-  "Δεν είναι δυνατή η προσπέλαση μιας αναγκαίας διαμοιραζόμενης βιβλιοθήκης",
-  "Προσπελαύνεται μια κατεστραμμένη διαμοιραζόμενη βιβλιοθήκη",
-  "Το τμήμα .lib στο a.out είναι κατεστραμμένο",
-  "Προσπάθεια σύνδεσης σε πάρα πολλές διαμοιραζόμενες βιβλιοθήκες",
-  "Δεν είναι δυνατή η άμεση εκτέλεση διαμοιραζόμενης βιβλιοθήκης",
-  "Μη έγκυρο ή ασυμπλήρωτο multibyte ή πλατύς χαρακτήρας",
-  "Η διακοπείσα κλήση συστήματος θα πρέπει να επανακινηθεί",
-  "Σφάλμα σωλήνωσης ροής",
-  "Υπερβολικά πολλοί χρήστες",
-  "Λειτουργία υποδοχής σε μη-υποδοχή",
-  "Απαιτείται διεύθυνση προορισμού",
-  "Υπερβολικά μεγάλο μήνυμα",
-  "Λάθος τύπος πρωτοκόλου για την υποδοχή(socket)",
-  "Δεν είναι διαθέσιμο το πρωτόκολλο",
-  "Το πρωτόκολλο δεν υποστηρίζεται",
-  "Ο τύπος της υποδοχής δεν υποστηρίζεται",
-  "Η λειτουργία δεν υποστηρίζεται",
-  "Η οικογένεια πρωτοκόλλου δεν υποστηρίζεται",
-  "Δεν υποστηρίζετε από το πρωτόκολλο η οικογένεια διευθύνσεων",
-  "Η διεύθυνση υποδοχής είναι ήδη σε χρήση",
-  "Δεν είναι δυνατή η εκχώρηση της ζητηθήσας διεύθυνσης",
-  "Το δίκτυο δε λειτουργεί",
-  "Το δίκτυο δεν είναι προσπελάσιμο",
-  "Το δίκτυο έριξε την σύνδεση κατά την επαναφορά",
-  "Το λογισμικό προκάλεσε ακύρωση σύνδεσης",
-  "Η σύνδεση έκλεισε από το ταίρι",
-  "Δεν υπάρχει διαθέσιμος χώρος ενταμίευσης",
-  "Η άκρη μεταφοράς είναι ήδη συνδεδεμένη",
-  "Η άκρη μεταφοράς δεν είναι συνδεδεμένη",
-  "Δεν είναι δυνατή η αποστολή μετά το κλείσιμο της μιας άκρης επικοινωνίας",
-  "Υπερβολικά πολλοί παραπομπείς: αδυναμία ματίσματος",
-  "Λήξη σύνδεσης",
-  "Απόρριψη σύνδεσης",
-  "Το σύστημα δε λειτουργεί",
-  "Δεν υπάρχει διαδρομή στο σύστημα",
-  "Η λειτουργία εκτελείται ήδη",
-  "Η λειτουργία βρίσκεται τώρα σε εκτέλεση",
-  "Stale file handle",
-  "Η δομή χρειάζεται καθάρισμα",
-  "Δεν είναι XENIX τύπος επώνυμου αρχείου",
-  "Δεν είναι διαθέσιμοι οι XENIX σημαφόροι",
-  "Είναι ένα επώνυμο είδος αρχείου",
-  "Απομακρυσμένο σφάλμα εισόδου/εξόδου",
-  "Ξεπεράστηκε το όριο χρήσης δίσκου",
-  "Δεν βρέθηκε μέσο",
-  "Εσφαλμένο είδος μέσου",
-  "Η λειτουργία ακυρώθηκε",
-  "Required key not available",
-  "Key has expired",
-  "Key has been revoked",
-  "Key was rejected by service",
-  "Owner died",
-  "State not recoverable",
-  "Operation not possible due to RF-kill",
-  "Memory page has hardware error"
-];
-
-pub const STRSIGNAL: [&'static str; 32] = [
-  "Άγνωστο σήμα 0",
-  "Κλείσιμο",
-  "Διακοπή",
-  "Έξοδος",
-  "Ακατάλληλη εντολή",
-  "Παγίδα Trace/breakpoint",
-  "Ακυρώθηκε",
-  "Σφάλμα στον δίαυλο(bus)",
-  "Εξαίρεση κινητής υποδιαστολής",
-  "Σκοτώθηκε",
-  "Καθοριζόμενο από τον χρήστη σήμα 1",
-  "Σφάλμα κατάτμησης (segmentation fault)",
-  "Καθοριζόμενο από τον χρήστη σήμα 2",
-  "Διακοπείσα σωλήνωση",
-  "Ξυπνητήρι",
-  "Τερματίστηκε",
-  "Σφάλμα στοίβας",
-  "Η θυγατρική διεργασία τερματίστηκε",
-  "Συνεχίζεται",
-  "Σταμάτησε (σήμα)",
-  "Σταμάτησε",
-  "Σταμάτησε (είσοδος tty)",
-  "Σταμάτησε (έξοδος tty)",
-  "Επείγουσα κατάσταση εισόδου/εξόδου",
-  "Ξεπεράστηκε το όριο χρόνου της CPU",
-  "Ξεπεράστηκε το όριο μεγέθους αρχείου",
-  "Ο εικονικός χρονομετρητής έληξε",
-  "Ο χρονομετρητής βελτιστοποίησης έληξε",
-  "Το παράθυρο άλλαξε",
-  "Δυνατή η είσοδος/έξοδος",
-  "Αποτυχία τροφοδοσίας",
-  "Εσφαλμένη κλήση συστήματος"
-];
-
-pub const REGERROR: [&'static str; 14] = [
-  r"Επιτυχία",
-  r"Κανένα ταίριασμα",
-  r"Μη έγκυρη κανονική έκφραση",
-  r"Μη έγκυρος χαρακτήρας παραβολής",
-  r"Μη έγκυρος χαρακτήρας ονόματος κλάσης",
-  r"Ακολουθεί ανάστροφη κάθετος",
-  r"Μη έγκυρη πισω-παραπομπή",
-  r"Unmatched [, [^, [:, [., or [=",
-  r"Αταίριαστο ( ή \(",
-  r"Αταίριαστο \{",
-  r"Μη έγκυρο περιεχόμενο των \{\}",
-  r"Μη έγκυρο τέλος πεδίου",
-  r"Η μνήμη εξαντλήθηκε",
-  r"Μη έγκυρη προπορευόμενη κανονική έκφραση"
-];
-
-pub const HSTRERROR: [&'static str; 5] = [
-  "Σφάλμα αναλυτή διευθύνσεων 0 (κανένα σφάλμα)",
-  "Άγνωστο όνομα συστήματος",
-  "Αποτυχία αναζήτησης ονόματος συστήματος",
-  "Άγνωστο σφάλμα διακομιστή",
-  "Καμιά διεύθυνση δεν συνδέεται με το όνομα"
-];
-
-pub const GAI_STRERROR: [&'static str; 15] = [
-  "Επιτυχία",
-  "Ακατάλληλη τιμή στο ai_flags",
-  "Το όνομα ή η υπηρεσία δεν είναι γνωστά",
-  "Προσωρινή αποτυχία κατά την ανάλυση ονόματος",
-  "Μη-αποκαταστήσιμο σφάλμα κατά την ανάλυση ονόματος",
-  "Το ai_family δεν υποστηρίζεται",
-  "Το ai_socktype δεν υποστηρίζεται",
-  "Το servname δεν υποστηρίζεται από το ai_socktype",
-  "Αποτυχία δέσμευσης μνήμης",
-  "Σφάλμα συστήματος",
-  "Result too large for supplied buffer",
-  "Καμιά διεύθυνση δε συνδέεται με το όνομα συστήματος", // EAI_NODATA
-  "Request canceled",                                    // EAI_CANCELED
-  "Request not canceled",                                // EAI_NOTCANCELED
-  "Parameter string not correctly encoded"               // EAI_IDN_ENCODE
-];
-
-pub const YESEXPR: &'static str = "^[+1yYνΝ]";
-pub const NOEXPR: &'static str = "^[-0nNοΟόΌ]";
@@ -1,220 +0,0 @@
-pub const MISC_MESSAGES: [&'static str; 3] =
-  ["毋知个錯誤", "毋知个訊號", "即時訊號"];
-
-pub const STRERROR: [&'static str; 134] = [
-  "成功",
-  "操作毋允許",
-  "無這隻檔案還係目錄",
-  "無這隻行程",
-  "系統呼叫分人插斷",
-  "輸入/輸出錯誤",
-  "無這隻設備還係地址",
-  "參數列忒長",
-  "執行格式錯誤",
-  "檔案描述符錯誤",
-  "無子行程",
-  "資源暫時毋使得用",
-  "分配毋著記憶體",
-  "權限毋夠",
-  "地址錯誤",
-  "需要區塊設備",
-  "設備還係資源佇該忙",
-  "檔案已經佇該",
-  "跨設備連結無效",
-  "無這隻設備",
-  "毋係目錄",
-  "係目錄",
-  "參數無效",
-  "系統肚開開來个檔案忒多",
-  "開開來个檔案忒多",
-  "設備控制命令毋著",
-  "文字檔案佇該忙",
-  "檔案忒大",
-  "設備空間滿吔",
-  "非法尋道",
-  "唯讀檔案系統",
-  "連結忒多",
-  "管線斷吔",
-  "數值參數超出範圍",
-  "數值結果超出範圍",
-  "避免資源死鎖",
-  "檔案名忒長",
-  "鎖毋使得用",
-  "功能還未實現",
-  "目錄毋係空",
-  "符號連結層級忒多",
-  "毋知个錯誤 41",
-  "無想要个訊息類型",
-  "識別符分人刣忒",
-  "通道號超出範圍",
-  "第二層毋同步",
-  "第三層停止",
-  "第三層重設",
-  "連結號超出範圍",
-  "協定驅動程式還未連接",
-  "無CSI結構",
-  "第二層停止",
-  "無效交換",
-  "無效請求描述符",
-  "交換滿吔",
-  "無陽極",
-  "無效請求碼",
-  "無效插槽",
-  "毋知个錯誤 58",
-  "字型檔案格式錯誤",
-  "設備毋係串流",
-  "資料毋使得用",
-  "定時器到期",
-  "串流資源耗盡",
-  "機器毋係佇網路肚",
-  "套件還未安裝",
-  "物件佇遠端",
-  "連結分人切斷",
-  "廣告錯誤",
-  "伺服器掛載錯誤",
-  "傳送通訊錯誤",
-  "協定錯誤",
-  "試做多跳",
-  "RFS特定錯誤",
-  "訊息錯誤",
-  "數值對定義个資料類型來講忒大",
-  "網路項背名毋唯一",
-  "檔案描述符狀態錯誤",
-  "遠端地址改變",
-  "無法度存取需要个共享函式庫",
-  "存取壞忒个共享函式庫",
-  "a.out肚个.lib區段壞忒",
-  "試做連結忒多个共享函式庫",
-  "無法度直接執行共享函式庫",
-  "無效還係毋完整个多位元組還係寬字元",
-  "分人插斷个系統呼叫愛重新啟動",
-  "串流管線錯誤",
-  "使用者忒多",
-  "無佇通訊端項背个通訊端操作",
-  "需要目標地址",
-  "訊息忒長",
-  "協定類型毋著通訊端",
-  "協定毋使得用",
-  "協定毋支援",
-  "通訊端類型毋支援",
-  "操作毋支援",
-  "協定族毋支援",
-  "地址族對協定來講毋支援",
-  "地址已經佇該用",
-  "無法度分配請求个地址",
-  "網路死吔",
-  "網路毋會到",
-  "網路分人重設連接斷忒",
-  "軟體引起連接異常終止",
-  "對方重設連接",
-  "緩衝區空間毋使得用",
-  "傳輸端點已經連接",
-  "傳輸端點還未連接",
-  "傳輸端點關忒以後無法度傳送",
-  "引用忒多：無法度接合",
-  "連接超時",
-  "連接分人拒絕",
-  "主機死吔",
-  "無到主機个路由",
-  "操作已經佇該進行",
-  "操作這下佇該進行",
-  "檔案控制碼過期",
-  "結構需要清理",
-  "毋係XENIX命名類型檔案",
-  "無XENIX號誌量會使得用",
-  "係命名類型檔案",
-  "遠端I/O錯誤",
-  "磁碟配額超出",
-  "無揣著媒體",
-  "媒體類型錯誤",
-  "操作分人取消",
-  "需要个密鑰毋使得用",
-  "密鑰過期",
-  "密鑰分人吊銷",
-  "密鑰分服務拒絕",
-  "擁有者死吔",
-  "狀態無法度恢復",
-  "因為RF-kill操作毋可能",
-  "記憶體分頁有硬體錯誤"
-];
-
-pub const STRSIGNAL: [&'static str; 32] = [
-  "毋知个訊號 0",
-  "掛電話",
-  "插斷",
-  "退出",
-  "非法指令",
-  "追蹤/斷點陷阱",
-  "異常終止",
-  "匯流排錯誤",
-  "浮點數異常",
-  "搣殺",
-  "使用者定義訊號 1",
-  "段錯誤",
-  "使用者定義訊號 2",
-  "管線斷忒",
-  "鬧鐘仔",
-  "終止",
-  "堆疊錯誤",
-  "子行程退出",
-  "繼續",
-  "停止（訊號）",
-  "停止",
-  "停止（tty輸入）",
-  "停止（tty輸出）",
-  "緊急I/O條件",
-  "CPU時間限制超出",
-  "檔案大小限制超出",
-  "虛擬定時器到期",
-  "效能分析定時器到期",
-  "視窗改變",
-  "I/O會使得",
-  "停電",
-  "錯誤系統呼叫"
-];
-
-pub const REGERROR: [&'static str; 14] = [
-  r"成功",
-  r"無匹配",
-  r"無效正規表示式",
-  r"無效對照字元",
-  r"無效字元類名",
-  r"後背反斜線",
-  r"無效反向引用",
-  r"[、[^、[[.、[[= 無匹配",
-  r"( 還係 \( 無匹配",
-  r"\{ 無匹配",
-  r"\{\} 內容無效",
-  r"無效範圍結尾",
-  r"記憶體耗盡",
-  r"頭擺正規表示式無效"
-];
-
-pub const HSTRERROR: [&'static str; 5] = [
-  "解析器錯誤 0（無錯誤）",
-  "毋知个主機",
-  "主機名揣無",
-  "毋知个伺服器錯誤",
-  "無共名連做伙个地址"
-];
-
-pub const GAI_STRERROR: [&'static str; 15] = [
-  "成功",
-  "ai_flags 值錯誤",
-  "名還係服務毋知",
-  "名解析暫時失敗",
-  "名解析無法度恢復个失敗",
-  "ai_family 毋支援",
-  "ai_socktype 毋支援",
-  "對ai_socktype來講Servname毋支援",
-  "記憶體分配失敗",
-  "系統錯誤",
-  "結果對提供个緩衝區來講忒大",
-  "無共主機名連做伙个地址", // EAI_NODATA
-  "請求分人取消",           // EAI_CANCELED
-  "請求還未分人取消",       // EAI_NOTCANCELED
-  "參數字串編碼毋正確"      // EAI_IDN_ENCODE
-];
-
-pub const YESEXPR: &'static str = "^[+1yY係]";
-pub const NOEXPR: &'static str = "^[-0nN毋]";
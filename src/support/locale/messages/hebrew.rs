@@ -1,220 +0,0 @@
-pub const MISC_MESSAGES: [&'static str; 3] =
-  ["שגיאה לא ידועה", "אות לא ידוע", "אות בזמן אמת"];
-
-pub const STRERROR: [&'static str; 134] = [
-  "הצלחה",
-  "פעולה אסורה",
-  "אין קובץ או ספרייה כזה",
-  "אין תהליך כזה",
-  "שיחת מערכת הופסקה",
-  "שגיאת קלט/פלט",
-  "אין התקן או כתובת כזה",
-  "רשימת ארגומנטים ארוכה מדי",
-  "שגיאת פורמט הרצה",
-  "מזהה קובץ גרוע",
-  "אין תהליכי צאצא",
-  "משאב זמנית לא זמין",
-  "לא ניתן להקצות זיכרון",
-  "הרשאה נדחתה",
-  "כתובת גרועה",
-  "נדרש התקן בלוק",
-  "התקן או משאב תפוס",
-  "הקובץ קיים",
-  "קישור בין התקנים לא חוקי",
-  "אין התקן כזה",
-  "אינו ספרייה",
-  "הוא ספרייה",
-  "ארגומנט לא חוקי",
-  "יותר מדי קבצים פתוחים במערכת",
-  "יותר מדי קבצים פתוחים",
-  "ioctl לא מתאים להתקן",
-  "קובץ טקסט תפוס",
-  "הקובץ גדול מדי",
-  "אין מקום פנוי בהתקן",
-  "חיפוש לא חוקי",
-  "מערכת קבצים לקריאה בלבד",
-  "יותר מדי קישורים",
-  "צינור שבור",
-  "ארגומנט מספרי מחוץ לתחום",
-  "תוצאה מספרית מחוץ לטווח",
-  "מנע קיפאון של משאבים",
-  "שם קובץ ארוך מדי",
-  "אין מנעולים זמינים",
-  "פונקציה לא מיושמת",
-  "הספרייה אינה ריקה",
-  "יותר מדי רמות של קישורים סימבוליים",
-  "שגיאה לא ידועה 41",
-  "אין הודעה מהסוג הרצוי",
-  "מזהה הוסר",
-  "מספר ערוץ מחוץ לטווח",
-  "רמה 2 לא מסונכרנת",
-  "רמה 3 נעצרה",
-  "רמה 3 אופסה",
-  "מספר קישור מחוץ לטווח",
-  "מנהל פרוטוקול לא מחובר",
-  "אין מבנה CSI זמין",
-  "רמה 2 נעצרה",
-  "החלפה לא חוקית",
-  "מזהה בקשה לא חוקי",
-  "החלפה מלאה",
-  "אין אנוד",
-  "קוד בקשה לא חוקי",
-  "חריץ לא חוקי",
-  "שגיאה לא ידועה 58",
-  "פורמט קובץ גופן גרוע",
-  "ההתקן אינו זרם",
-  "אין נתונים זמינים",
-  "טיימר פג תוקף",
-  "אין משאבי זרמים",
-  "המכונה אינה ברשת",
-  "חבילה לא מותקנת",
-  "האובייקט מרוחק",
-  "הקישור נותק",
-  "שגיאת פרסום",
-  "שגיאת Srmount",
-  "שגיאת תקשורת בשליחה",
-  "שגיאת פרוטוקול",
-  "ניסיון מרובה-קפיצות",
-  "שגיאה ספציפית ל-RFS",
-  "הודעה גרועה",
-  "ערך גדול מדי עבור סוג הנתונים המוגדר",
-  "שם לא ייחודי ברשת",
-  "מזהה קובץ במצב גרוע",
-  "כתובת מרוחקת השתנתה",
-  "לא ניתן לגשת לספרייה משותפת נדרשת",
-  "גישה לספרייה משותפת פגומה",
-  "מקטע .lib בקובץ a.out פגום",
-  "ניסיון לקשר יותר מדי ספריות משותפות",
-  "לא ניתן להריץ ספרייה משותפת ישירות",
-  "תו מרובה-בתים או תו רחב לא חוקי או לא שלם",
-  "שיחת מערכת שהופסקה צריכה להתחיל מחדש",
-  "שגיאת צינור זרמים",
-  "יותר מדי משתמשים",
-  "פעולת socket על non-socket",
-  "נדרשת כתובת יעד",
-  "הודעה ארוכה מדי",
-  "סוג פרוטוקול שגוי עבור socket",
-  "פרוטוקול לא זמין",
-  "פרוטוקול לא נתמך",
-  "סוג socket לא נתמך",
-  "פעולה לא נתמכת",
-  "משפחת פרוטוקולים לא נתמכת",
-  "משפחת כתובות לא נתמכת על ידי הפרוטוקול",
-  "כתובת כבר בשימוש",
-  "לא ניתן להקצות כתובת מבוקשת",
-  "הרשת מושבתת",
-  "הרשת אינה נגישה",
-  "הרשת ניתקה חיבור באיפוס",
-  "תוכנה גרמה לניתוק החיבור",
-  "החיבור אופס על ידי העמית",
-  "אין מקום בחוצץ זמין",
-  "נקודת קצה תחבורה כבר מחוברת",
-  "נקודת קצה תחבורה אינה מחוברת",
-  "לא ניתן לשלוח לאחר סגירת נקודת קצה תחבורה",
-  "יותר מדי הפניות: לא ניתן לחבר",
-  "פג תוקף החיבור",
-  "החיבור נדחה",
-  "המארח מושבת",
-  "אין מסלול למארח",
-  "הפעולה כבר בתהליך",
-  "הפעולה עכשיו בתהליך",
-  "מזהה קובץ פג תוקף",
-  "המבנה צריך ניקוי",
-  "אינו קובץ מסוג XENIX בעל שם",
-  "אין סמפורי XENIX זמינים",
-  "הוא קובץ מסוג בעל שם",
-  "שגיאת קלט/פלט מרוחקת",
-  "חריגה ממכסת דיסק",
-  "לא נמצא מדיה",
-  "סוג מדיה שגוי",
-  "הפעולה בוטלה",
-  "מפתח נדרש לא זמין",
-  "פג תוקף המפתח",
-  "המפתח בוטל",
-  "המפתח נדחה על ידי השירות",
-  "הבעלים מת",
-  "מצב לא ניתן לשחזור",
-  "הפעולה אינה אפשרית עקב RF-kill",
-  "דף זיכרון עם שגיאת חומרה"
-];
-
-pub const STRSIGNAL: [&'static str; 32] = [
-  "אות לא ידוע 0",
-  "ניתוק",
-  "הפסקה",
-  "יציאה",
-  "הוראה לא חוקית",
-  "מלכודת מעקב/נקודת עצירה",
-  "הופסק",
-  "שגיאת אוטובוס",
-  "חריגת נקודה צפה",
-  "הומת",
-  "אות מוגדר משתמש 1",
-  "שגיאת סגמנטציה",
-  "אות מוגדר משתמש 2",
-  "צינור שבור",
-  "שעון מעורר",
-  "הופסק",
-  "שגיאת מחסנית",
-  "צאצא הסתיים",
-  "המשך",
-  "נעצר (אות)",
-  "נעצר",
-  "נעצר (קלט tty)",
-  "נעצר (פלט tty)",
-  "מצב קלט/פלט דחוף",
-  "חריגה ממגבלת זמן מעבד",
-  "חריגה ממגבלת גודל קובץ",
-  "טיימר וירטואלי פג",
-  "טיימר פרופילינג פג",
-  "חלון השתנה",
-  "קלט/פלט אפשרי",
-  "כשל חשמל",
-  "שיחת מערכת גרועה"
-];
-
-pub const REGERROR: [&'static str; 14] = [
-  r"הצלחה",
-  r"אין התאמה",
-  r"ביטוי רגולרי לא חוקי",
-  r"תו קולציה לא חוקי",
-  r"שם מחלקת תוים לא חוקי",
-  r"לוכסן אחורי בסוף",
-  r"הפניה אחורית לא חוקית",
-  r"[, [^, [:, [., או [= לא סגור",
-  r"( או \( לא סגור",
-  r"{ לא סגור",
-  r"תוכן {} לא חוקי",
-  r"סוף טווח לא חוקי",
-  r"הזיכרון אזל",
-  r"ביטוי רגולרי קודם לא חוקי"
-];
-
-pub const HSTRERROR: [&'static str; 5] = [
-  "שגיאת מפענח 0 (אין שגיאה)",
-  "מארח לא ידוע",
-  "כישלון בחיפוש שם מארח",
-  "שגיאת שרת לא ידועה",
-  "אין כתובת המשויכת לשם"
-];
-
-pub const GAI_STRERROR: [&'static str; 15] = [
-  "הצלחה",
-  "ערך גרוע עבור ai_flags",
-  "שם או שירות לא ידוע",
-  "כישלון זמני בפיענוח שם",
-  "כישלון בלתי ניתן לתיקון בפיענוח שם",
-  "ai_family לא נתמך",
-  "ai_socktype לא נתמך",
-  "Servname לא נתמך עבור ai_socktype",
-  "כישלון בהקצאת זיכרון",
-  "שגיאת מערכת",
-  "תוצאה גדולה מדי עבור החוצץ שסופק",
-  "אין כתובת המשויכת לשם מארח",  // EAI_NODATA
-  "בקשה בוטלה",                  // EAI_CANCELED
-  "בקשה לא בוטלה",               // EAI_NOTCANCELED
-  "מחרוזת פרמטר לא מקודדת נכון"  // EAI_IDN_ENCODE
-];
-
-pub const YESEXPR: &'static str = "^[+1yYכ]";
-pub const NOEXPR: &'static str = "^[-0nNל]";
@@ -1,220 +0,0 @@
-pub const MISC_MESSAGES: [&'static str; 3] =
-  ["毋知影个錯誤", "毋知影个訊號", "即時訊號"];
-
-pub const STRERROR: [&'static str; 134] = [
-  "成功",
-  "操作無允許",
-  "無這个檔案抑是目錄",
-  "無這个行程",
-  "系統呼叫予人插斷",
-  "輸入/輸出錯誤",
-  "無這个裝置抑是地址",
-  "參數列傷長",
-  "執行格式錯誤",
-  "檔案描述符錯誤",
-  "無子行程",
-  "資源暫時無法度用",
-  "分配袂著記憶體",
-  "權限無夠",
-  "地址錯誤",
-  "需要區塊裝置",
-  "裝置抑是資源佇咧忙",
-  "檔案已經佇咧",
-  "跨裝置連結無效",
-  "無這个裝置",
-  "毋是目錄",
-  "是目錄",
-  "參數無效",
-  "系統內底開開來个檔案傷濟",
-  "開開來个檔案傷濟",
-  "裝置控制命令毋著",
-  "文字檔案佇咧忙",
-  "檔案傷大",
-  "裝置空間滿矣",
-  "非法尋道",
-  "唯讀檔案系統",
-  "連結傷濟",
-  "管線斷去",
-  "數值參數超出範圍",
-  "數值結果超出範圍",
-  "避免資源死鎖",
-  "檔案名傷長",
-  "鎖無法度用",
-  "功能猶未實現",
-  "目錄毋是空",
-  "符號連結層級傷濟",
-  "毋知影个錯誤 41",
-  "無欲愛个訊息類型",
-  "識別符予人刣掉",
-  "通道號超出範圍",
-  "第二層無同步",
-  "第三層停止",
-  "第三層重設",
-  "連結號超出範圍",
-  "協定驅動程式猶未連接",
-  "無CSI結構",
-  "第二層停止",
-  "無效交換",
-  "無效請求描述符",
-  "交換滿矣",
-  "無陽極",
-  "無效請求碼",
-  "無效插槽",
-  "毋知影个錯誤 58",
-  "字型檔案格式錯誤",
-  "裝置毋是串流",
-  "資料無法度用",
-  "定時器到期",
-  "串流資源耗盡",
-  "機器毋是佇網路內底",
-  "套件猶未安裝",
-  "物件佇遠端",
-  "連結予人切斷",
-  "廣告錯誤",
-  "伺服器掛載錯誤",
-  "傳送通訊錯誤",
-  "協定錯誤",
-  "欲試多跳",
-  "RFS特定錯誤",
-  "訊息錯誤",
-  "數值對定義个資料類型來講傷大",
-  "網路頂懸名毋唯一",
-  "檔案描述符狀態錯誤",
-  "遠端地址改變",
-  "無法度存取需要个共享函式庫",
-  "存取損壞个共享函式庫",
-  "a.out內底个.lib區段損壞",
-  "欲試連結傷濟个共享函式庫",
-  "無法度直接執行共享函式庫",
-  "無效抑是毋完整个多位元組抑是寬字元",
-  "予人插斷个系統呼叫愛重新啟動",
-  "串流管線錯誤",
-  "用者傷濟",
-  "無佇通訊端頂懸个通訊端操作",
-  "需要目標地址",
-  "訊息傷長",
-  "協定類型毋著通訊端",
-  "協定無法度用",
-  "協定無支援",
-  "通訊端類型無支援",
-  "操作無支援",
-  "協定族無支援",
-  "地址族對協定來講無支援",
-  "地址已經佇咧用",
-  "無法度分配請求个地址",
-  "網路死矣",
-  "網路毋會到",
-  "網路予人重設連接斷去",
-  "軟體引起連接異常終止",
-  "對方重設連接",
-  "緩衝區空間無法度用",
-  "傳輸端點已經連接",
-  "傳輸端點猶未連接",
-  "傳輸端點關掉以後無法度傳送",
-  "引用傷濟：無法度接合",
-  "連接超時",
-  "連接予人拒絕",
-  "主機死矣",
-  "無到主機个路由",
-  "操作已經佇咧進行",
-  "操作這馬佇咧進行",
-  "檔案控制碼過期",
-  "結構需要清理",
-  "毋是XENIX命名類型檔案",
-  "無XENIX號誌量會使得用",
-  "是命名類型檔案",
-  "遠端I/O錯誤",
-  "磁碟配額超出",
-  "無揣著媒體",
-  "媒體類型錯誤",
-  "操作予人取消",
-  "需要个密鑰無法度用",
-  "密鑰過期",
-  "密鑰予人吊銷",
-  "密鑰予服務拒絕",
-  "擁有者死矣",
-  "狀態無法度恢復",
-  "因為RF-kill操作無可能",
-  "記憶體分頁有硬體錯誤"
-];
-
-pub const STRSIGNAL: [&'static str; 32] = [
-  "毋知影个訊號 0",
-  "掛電話",
-  "插斷",
-  "退出",
-  "非法指令",
-  "追蹤/斷點陷阱",
-  "異常終止",
-  "匯流排錯誤",
-  "浮點數異常",
-  "拍殺",
-  "用者定義訊號 1",
-  "段錯誤",
-  "用者定義訊號 2",
-  "管線斷去",
-  "鬧鐘",
-  "終止",
-  "堆疊錯誤",
-  "子行程退出",
-  "繼續",
-  "停止（訊號）",
-  "停止",
-  "停止（tty輸入）",
-  "停止（tty輸出）",
-  "緊急I/O條件",
-  "CPU時間限制超出",
-  "檔案大小限制超出",
-  "虛擬定時器到期",
-  "效能分析定時器到期",
-  "視窗改變",
-  "I/O會使得",
-  "停電",
-  "錯誤系統呼叫"
-];
-
-pub const REGERROR: [&'static str; 14] = [
-  r"成功",
-  r"無匹配",
-  r"無效正規表示式",
-  r"無效對照字元",
-  r"無效字元類名",
-  r"後壁反斜線",
-  r"無效反向引用",
-  r"[、[^、[[.、[[= 無匹配",
-  r"( 抑是 \( 無匹配",
-  r"\{ 無匹配",
-  r"\{\} 內容無效",
-  r"無效範圍結尾",
-  r"記憶體耗盡",
-  r"頭前正規表示式無效"
-];
-
-pub const HSTRERROR: [&'static str; 5] = [
-  "解析器錯誤 0（無錯誤）",
-  "毋知影个主機",
-  "主機名揣無",
-  "毋知影个伺服器錯誤",
-  "無共名牽做伙个地址"
-];
-
-pub const GAI_STRERROR: [&'static str; 15] = [
-  "成功",
-  "ai_flags 值錯誤",
-  "名抑是服務毋知影",
-  "名解析暫時失敗",
-  "名解析無法度恢復个失敗",
-  "ai_family 無支援",
-  "ai_socktype 無支援",
-  "對ai_socktype來講Servname無支援",
-  "記憶體分配失敗",
-  "系統錯誤",
-  "結果對提供个緩衝區來講傷大",
-  "無共主機名牽做伙个地址", // EAI_NODATA
-  "請求予人取消",           // EAI_CANCELED
-  "請求猶未予人取消",       // EAI_NOTCANCELED
-  "參數字串編碼毋正確"      // EAI_IDN_ENCODE
-];
-
-pub const YESEXPR: &'static str = "^[+1yY是]";
-pub const NOEXPR: &'static str = "^[-0nN毋]";
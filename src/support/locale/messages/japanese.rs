@@ -1,220 +0,0 @@
-pub const MISC_MESSAGES: [&'static str; 3] =
-  ["不明なエラーです", "不明なシグナル", "リアルタイムシグナル"];
-
-pub const STRERROR: [&'static str; 134] = [
-  "成功です",
-  "許可されていない操作です",
-  "そのようなファイルやディレクトリはありません",
-  "そのようなプロセスはありません",
-  "システムコール割り込み",
-  "入力/出力エラーです",
-  "そのようなデバイスやアドレスはありません",
-  "引数リストが長すぎます",
-  "実行形式エラー",
-  "不正なファイル記述子です",
-  "子プロセスがありません",
-  "リソースが一時的に利用できません",
-  "メモリを確保できません",
-  "許可がありません",
-  "不正なアドレスです",
-  "ブロックデバイスが必要です",
-  "デバイスもしくはリソースがビジー状態です",
-  "ファイルが存在します",
-  "無効なクロスデバイスリンクです",
-  "そのようなデバイスはありません",
-  "ディレクトリではありません",
-  "ディレクトリです",
-  "無効な引数です",
-  "システム中のファイルを開きすぎです",
-  "ファイルを開きすぎです",
-  "デバイスに対する不適切なioctlです",
-  "テキストファイルがビジー状態です",
-  "ファイルが大きすぎます",
-  "デバイスに空き領域がありません",
-  "不正なシークです",
-  "読み込み専用ファイルシステムです",
-  "リンクが多すぎます",
-  "Broken pipe",
-  "数値の引数はドメイン外です",
-  "計算結果は範囲外の値です",
-  "リソースのデッドロック回避",
-  "ファイル名が長すぎます",
-  "ロックが利用できません",
-  "関数は実装されていません",
-  "ディレクトリは空ではありません",
-  "シンボリックリンクの階層が多すぎます",
-  "不明なエラーです41",
-  "要求した形式のメッセージはありません",
-  "識別子を除去しました",
-  "チャンネル番号が範囲外です",
-  "レベル2は同期していません",
-  "レベル3停止",
-  "レベル3はリセットしました",
-  "リンク数が範囲外です",
-  "プロトコルドライバがアタッチされていません",
-  "CSI 構造が利用できません",
-  "レベル2停止",
-  "無効な交換です",
-  "無効なリクエスト記述子です",
-  "Exchange full",
-  "アノードがありません",
-  "無効なリクエストコードです",
-  "無効なスロットです",
-  "不明なエラーです58",
-  "不正なフォントファイル形式です",
-  "デバイスはストリーム型ではありません",
-  "利用可能なデータがありません",
-  "タイマが破棄されました",
-  "ストリームリソース外です",
-  "マシンはネットワークにつながっていません",
-  "パッケージはインストールされていません",
-  "オブジェクトはリモートにあります",
-  "リンクが切れています",
-  "Advertiseエラー",
-  "Srmount エラー",
-  "送信中の通信エラー",
-  "プロトコルエラー",
-  "多重ホップが企てられました",
-  "RFS特定エラー",
-  "不正なメッセージです",
-  "定義されたデータ型に対して値が大きすぎます",
-  "名前がネットワーク上で重複しています",
-  "ファイル記述子が不正の状態にあります",
-  "遠隔アドレスが変更されました",
-  "必要な共有ライブラリにアクセスできません",
-  "壊れた共有ライブラリにアクセスしています",
-  "a.out 中の .lib セクションが壊れています",
-  "あまりに多過ぎる共有ライブラリへリンクしようとしています",
-  "共有ライブラリは直接実行できません",
-  "無効または不完全なマルチバイトまたはワイド文字です",
-  "割り込まれたシステムコールは再スタートさせるべきです",
-  "ストリームパイプエラー",
-  "ユーザが多すぎます",
-  "ソケットでないものにソケット操作をしています",
-  "送信先アドレスが必要です",
-  "メッセージが長すぎます",
-  "ソケットに対し間違ったプロトコルの形式です",
-  "プロトコルは利用できません",
-  "プロトコルはサポートされていません",
-  "ソケット形式はサポートしていません",
-  "サポートされていない操作です",
-  "プロトコルファミリはサポートしていません",
-  "アドレスファミリはプロトコルによってサポートされていません",
-  "アドレスは既に使用中です",
-  "要求アドレスに割り当てられません",
-  "ネットワークが落ちています",
-  "ネットワークに届きません",
-  "リセット中ネットワークの接続が切れました",
-  "ソフトウェアが接続を中断しました",
-  "接続が相手からリセットされました",
-  "利用可能な空きバッファがありません",
-  "通信端点が既に接続されています",
-  "通信端点が接続されていません",
-  "通信端点のシャットダウン後は送信できません",
-  "参照が多すぎます: 接続できません",
-  "接続がタイムアウトしました",
-  "接続を拒否されました",
-  "ホストが落ちています",
-  "ホストへの経路がありません",
-  "操作はすでに処理中です",
-  "現在処理中の操作です",
-  "古いファイルハンドルです",
-  "構造体を内容消去する必要があります",
-  "XENIX の名前付きファイルではありません",
-  "XENIX セマフォが利用できません",
-  "名前付きファイルです",
-  "遠隔I/Oエラーです",
-  "ディスク使用量制限を超過しました",
-  "メディアが見つかりません",
-  "不正なメディア形式です",
-  "操作は中断されました",
-  "要求されたキーが利用できません",
-  "キーが期限切れです",
-  "キーが破棄されています",
-  "キーがサービスによって拒否されました",
-  "所有者が無くなりました",
-  "状態の復帰が出来ません",
-  "RF-kill のため操作は不可能です",
-  "Memory page has hardware error"
-];
-
-pub const STRSIGNAL: [&'static str; 32] = [
-  "不明なシグナル 0",
-  "Hangup",
-  "割り込み",
-  "終了",
-  "Illegal instruction",
-  "Trace/breakpoint trap",
-  "中止",
-  "バスエラー",
-  "浮動小数点例外",
-  "強制終了",
-  "ユーザー定義シグナル1",
-  "Segmentation fault",
-  "ユーザー定義シグナル2",
-  "Broken pipe",
-  "Alarm clock",
-  "Terminated",
-  "スタックエラー",
-  "子プロセス終了",
-  "継続",
-  "停止 (シグナル)",
-  "停止",
-  "停止 (tty 入力)",
-  "停止 (tty 出力)",
-  "緊急 I/O 状態",
-  "CPU時間制限を超過しました",
-  "ファイルサイズ制限を超過しました",
-  "仮想タイマーが終了しました",
-  "プロファイリングタイマーが終了しました",
-  "Window が変更されました",
-  "I/O 可能",
-  "電源エラー",
-  "間違ったシステムコール"
-];
-
-pub const REGERROR: [&'static str; 14] = [
-  r"成功です",
-  r"一致しません",
-  r"無効な正規表現です",
-  r"無効な照合文字です",
-  r"無効な文字クラス名です",
-  r"終端のバックスラッシュ",
-  r"無効な前方参照です",
-  r"Unmatched [, [^, [:, [., or [=",
-  r"( または \( が不一致です",
-  r"\{ が不一致です",
-  r"\{\} の中身が無効です",
-  r"無効な範囲終了です",
-  r"メモリを使い果たしました",
-  r"無効な前方正規表現です"
-];
-
-pub const HSTRERROR: [&'static str; 5] = [
-  "リゾルバエラー 0 (エラーはありません)",
-  "不明なホストです",
-  "ホスト名の解決に失敗しました",
-  "不明なサーバーエラーです",
-  "名前に対応するアドレスがありません"
-];
-
-pub const GAI_STRERROR: [&'static str; 15] = [
-  "成功です",
-  "ai_flags に対する誤った値です",
-  "名前またはサービスが不明です",
-  "名前解決に一時的に失敗しました",
-  "名前解決で回復できない失敗が発生しました",
-  "ai_family はサポートされていません",
-  "ai_socktype はサポートされていません",
-  "ai_socktype に対して Servname がサポートされていません",
-  "メモリ配置に失敗しました",
-  "システムエラー",
-  "Result too large for supplied buffer",
-  "ホスト名にアドレスが割り当てられていません", // EAI_NODATA
-  "要求がキャンセルされました",                 // EAI_CANCELED
-  "要求がキャンセルされませんでした",           // EAI_NOTCANCELED
-  "パラメーター文字列が正しくエンコードされていません"  // EAI_IDN_ENCODE
-];
-
-pub const YESEXPR: &'static str = "^([+1yYｙＹ]|はい|ハイ)";
-pub const NOEXPR: &'static str = "^([-0nNｎＮ]|いいえ|イイエ)";
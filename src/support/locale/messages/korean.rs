@@ -1,220 +0,0 @@
-pub const MISC_MESSAGES: [&'static str; 3] =
-  ["알 수 없는 오류", "알 수 없는 시그널 번", "실제 시각 시그널 번"];
-
-pub const STRERROR: [&'static str; 134] = [
-  "성공",
-  "명령을 허용하지 않음",
-  "그런 파일이나 디렉터리가 없습니다",
-  "그런 프로세스가 없음",
-  "중단된 시스템 콜",
-  "입력/출력 오류",
-  "그런 장치 혹은 주소가 없음",
-  "인수 명단이 너무 김",
-  "Exec 형식 오류",
-  "파일 디스크립터가 잘못됨",
-  "하위 프로세스가 없음",
-  "자원이 일시적으로 사용 불가능함",
-  "메모리를 할당할 수 없습니다",
-  "허가 거부",
-  "주소가 잘못됨",
-  "블럭 장치가 필요함",
-  "장치나 자원이 동작 중",
-  "파일이 있습니다",
-  "부적절한 장치간 연결",
-  "그런 장치가 없음",
-  "디렉터리가 아닙니다",
-  "디렉터리입니다",
-  "부적절한 인수",
-  "시스템에 파일을 너무 많이 열었음",
-  "열린 파일이 너무 많음",
-  "장치에 대해 부적절한 ioctl",
-  "실행 파일 사용 중",
-  "파일이 너무 큽니다",
-  "장치에 남은 공간이 없음",
-  "탐색이 잘못됨",
-  "읽기전용 파일 시스템",
-  "연결이 너무 많음",
-  "파이프가 깨어짐",
-  "영역을 벗어난 수치 인수",
-  "범위를 벗어난 수치 결과",
-  "자원 교착상태를 피했습니다",
-  "파일 이름이 너무 깁니다",
-  "사용가능한 잠금장치가 없음",
-  "함수를 구현하지 않았습니다",
-  "디렉터리가 비어있지 않음",
-  "기호 연결의 단계가 너무 많음",
-  "알 수 없는 오류41",
-  "적당한 형을 가진 메시지가 없음",
-  "식별자 제거",
-  "범위를 벗어난 채널 번호",
-  "등급 2가 동기화되지 않음",
-  "등급 3 멎었음",
-  "등급 3 리셋",
-  "범위를 벗어난 링크 번호",
-  "규약 구동기에 연결되지 않음",
-  "사용 가능한 CSI 구조가 없음",
-  "등급 2 멎었음",
-  "부적절한 교환",
-  "요청 디스크립터가 잘못됨",
-  "교환이 가득 참",
-  "anode가 없습니다",
-  "부적절한 요청 코드",
-  "부적절한 슬롯",
-  "알 수 없는 오류58",
-  "글꼴 파일 형식이 틀렸습니다",
-  "장치가 스트림이 아님",
-  "사용 가능한 자료가 없음",
-  "타이머 만료",
-  "스트림 자원 부족",
-  "컴퓨터가 네트워크에 붙어 있지 않습니다",
-  "패키지를 설치하지 않았습니다",
-  "원격 개체입니다",
-  "링크가 손상되었습니다",
-  "선전 오류",
-  "srmount 오류",
-  "전송 중 통신 오류",
-  "규약 오류",
-  "여러개의 hop이 시도됨",
-  "RFS 관련 오류",
-  "메시지가 잘못됨",
-  "정의한 자료형으로 쓰기엔 너무 큰 값",
-  "이름이 네트워크 상에서 단일하지 않습니다",
-  "파일 디스크립터가 잘못된 상태에 있습니다",
-  "원격 주소가 바뀌었습니다",
-  "필요한 공유 라이브러리에 접근할 수 없습니다",
-  "손상된 공유 라이브러리에 접근합니다",
-  "a.out의 .lib 섹션이 손상되었습니다",
-  "너무 많은 동적 라이브러리와 링크하려고 시도합니다",
-  "공유 라이브러리를 직접 실행할 수 없습니다",
-  "부적절하거나 불완전한 다중바이트 또는 광역 문자",
-  "중단된 시스템 콜을 다시 시작해야 합니다",
-  "스트림 파이프 오류",
-  "사용자가 너무 많음",
-  "비소켓상의 소켓 동작",
-  "목적지 주소가 필요함",
-  "메시지가 너무 깁니다",
-  "소켓에 대한 규약이 잘못됨",
-  "규약 사용 불가능",
-  "규약을 지원하지 않음",
-  "소켓 타입을 지원하지 않습니다",
-  "명령을 지원하지 않음",
-  "규약군을 지원하지 않음",
-  "주소군을 규약에서 지원하지 않음",
-  "주소가 이미 사용 중입니다",
-  "요청한 주소를 배정할 수 없습니다",
-  "네트워크가 죽었습니다",
-  "네트워크가 접근 불가능합니다",
-  "네트웍이 reset때문에 연결을 끊었습니다",
-  "소프트웨어가 연결 중단을 초래했습니다",
-  "상대편이 연결을 끊음",
-  "사용 가능한 버퍼 공간이 없음",
-  "전송 종료지점이 이미 연결되어 있습니다",
-  "전송 종료지점이 연결되어 있지 않습니다",
-  "전송 종료지점이 지난 후에 보낼 수 없습니다",
-  "참조가 너무 많음: 연결할 수 없습니다",
-  "연결 시간 초과",
-  "연결이 거부됨",
-  "호스트가 죽었습니다",
-  "호스트로 갈 루트가 없음",
-  "이미 진행 중인 명령",
-  "지금 진행 중인 명령",
-  "끊어진 파일 핸들",
-  "구조에 청소가 필요합니다",
-  "XENIX named 타입 파일이 아닙니다",
-  "사용 가능한 XENIX 세마포어가 없습니다",
-  "named 타입 파일입니다",
-  "원격 입출력 오류",
-  "디스크 할당량이 초과됨",
-  "미디어가 없음",
-  "미디어 종류가 잘못됨",
-  "명령을 취소하였습니다",
-  "필요한 키가 없습니다",
-  "키가 만료되었습니다",
-  "키가 종료되었습니다",
-  "서비스가 키를 거부했습니다",
-  "소유자가 죽었습니다",
-  "상태를 복구할 수 없습니다",
-  "작업이 불가능, RF-kill 때문",
-  "메모리 페이지에 하드웨어 오류"
-];
-
-pub const STRSIGNAL: [&'static str; 32] = [
-  "알 수 없는 시그널 0번",
-  "끊어짐",
-  "인터럽트",
-  "끝내기",
-  "명령어가 잘못됨",
-  "추적/중단점 함정",
-  "중지됨",
-  "버스 오류",
-  "부동 소수점 예외",
-  "죽었음",
-  "사용자 정의 시그널 1",
-  "세그멘테이션 오류",
-  "사용자 정의 시그널 2",
-  "파이프가 깨어짐",
-  "자명종 시계",
-  "종료됨",
-  "스택 오류",
-  "하위 프로세스가 끝났음",
-  "계속됨",
-  "정지됨(시그널)",
-  "정지됨",
-  "정지됨(tty 입력)",
-  "정지됨(tty 출력)",
-  "시급한 입출력 상황",
-  "CPU 시간 제한 초과함",
-  "파일 크기 제한을 초과함",
-  "가상 타이머 만료",
-  "프로파일링 타이머 만료",
-  "윈도우 바뀜",
-  "입출력 가능",
-  "전력이 끊어짐",
-  "시스템 콜이 잘못됨"
-];
-
-pub const REGERROR: [&'static str; 14] = [
-  r"성공",
-  r"맞는 짝 없음",
-  r"부적절한 정규식",
-  r"부적절한 대조 문자",
-  r"부적절한 문자 클래스 이름",
-  r"따라붙는 역슬래쉬",
-  r"부적절한 후방 참조",
-  r"짝이 맞지 않는 [, [^, [:, [. 또는 [=",
-  r"짝이 맞지 않는 ( 또는 \(",
-  r"짝이 맞지 않는 \{",
-  r"\{\}에 부적절한 내용물이 있음",
-  r"부적절한 범위 끝",
-  r"메모리가 바닥남",
-  r"앞선 정규식이 부적절함"
-];
-
-pub const HSTRERROR: [&'static str; 5] = [
-  "리졸버 오류 0 (오류 아님)",
-  "알 수 없는 호스트",
-  "호스트 이름 탐색 실패",
-  "알 수 없는 서버 오류",
-  "이름과 대응하는 주소가 없음"
-];
-
-pub const GAI_STRERROR: [&'static str; 15] = [
-  "성공",
-  "ai_flags의 값이 잘못됨",
-  "이름 혹은 서비스를 알 수 없습니다",
-  "name resolution에서 일시적인 실패",
-  "name resolution에서 복구할 수 없는 실패",
-  "ai_family를 지원하지 않습니다",
-  "ai_socktype을 지원하지 않습니다",
-  "ai_socktype에서는 servname을 지원하지 않습니다",
-  "메모리 할당 실패",
-  "시스템 오류",
-  "결과가 제공된 버퍼에 비해 너무 큽니다",
-  "호스트이름과 대응되는 주소가 없음", // EAI_NODATA
-  "요청을 취소합니다",                 // EAI_CANCELED
-  "요청을 취소하지 않습니다",          // EAI_NOTCANCELED
-  "파라미터 문자열을 올바르게 인코딩하지 않았습니다"  // EAI_IDN_ENCODE
-];
-
-pub const YESEXPR: &'static str = "^[+1yYｙＹ예]";
-pub const NOEXPR: &'static str = "^[-0nNｎＮ아]";
@@ -0,0 +1,37 @@
+use {
+  super::MessagesObject,
+  crate::{
+    allocation::borrow::ToOwned,
+    c_int,
+    support::locale::localedef::{self, Source}
+  },
+  allocation::borrow::Cow,
+  core::ffi
+};
+
+pub fn load(
+  messages: &mut MessagesObject,
+  locale: &ffi::CStr,
+  source: &Source
+) -> Result<(), c_int> {
+  let section = source.section("LC_MESSAGES");
+  localedef::load_base(messages, locale, source, section)?;
+
+  if let Some(section) = section {
+    let answers = [
+      ("yesexpr", &mut messages.yesexpr),
+      ("noexpr", &mut messages.noexpr),
+      ("yesstr", &mut messages.yesstr),
+      ("nostr", &mut messages.nostr)
+    ];
+    for (keyword, field) in answers {
+      if let Some(answer) = section.string(keyword) {
+        *field = localedef::to_cstr(answer)?;
+      }
+    }
+  }
+
+  messages.name = Cow::Owned(locale.to_owned());
+
+  Ok(())
+}
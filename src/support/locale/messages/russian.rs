@@ -1,220 +0,0 @@
-pub const MISC_MESSAGES: [&'static str; 3] =
-  ["Неизвестная ошибка", "Неизвестный сигнал", "Сигнал реального времени"];
-
-pub const STRERROR: [&'static str; 134] = [
-  "Выполнено",
-  "Операция не позволена",
-  "Нет такого файла или каталога",
-  "Нет такого процесса",
-  "Прерван системный вызов",
-  "Ошибка ввода/вывода",
-  "Нет такого устройства или адреса",
-  "Слишком длинный список аргументов",
-  "Ошибка формата выполняемого файла",
-  "Неправильный дескриптор файла",
-  "Нет дочерних процессов",
-  "Ресурс временно недоступен",
-  "Невозможно выделить память",
-  "Отказано в доступе",
-  "Неправильный адрес",
-  "Требуется блочное устройство",
-  "Устройство или ресурс занято",
-  "Файл существует",
-  "Неверная ссылка между устройствами",
-  "Нет такого устройства",
-  "Это не каталог",
-  "Это каталог",
-  "Недопустимый аргумент",
-  "Слишком много открытых файлов в системе",
-  "Слишком много открытых файлов",
-  "Неприменимый к данному устройству ioctl",
-  "Текстовый файл занят",
-  "Файл слишком велик",
-  "На устройстве не осталось свободного места",
-  "Недопустимая операция смещения",
-  "Файловая система доступна только для чтения",
-  "Слишком много ссылок",
-  "Обрыв канала",
-  "Числовой аргумент вне области определения",
-  "Числовой результат вне представимого диапазона",
-  "Предотвращена тупиковая ситуация при обращении к ресурсу",
-  "Слишком длинное имя файла",
-  "Нет доступных блокировок",
-  "Функция не реализована",
-  "Каталог не пуст",
-  "Слишком много уровней символьных ссылок",
-  "Неизвестная ошибка 41",
-  "Нет сообщения желаемого типа",
-  "Идентификатор был удален",
-  "Номер канала вне диапазона",
-  "Уровень 2 не синхронизирован",
-  "Уровень 3 остановлен",
-  "Уровень 3 сброшен",
-  "Число ссылок вне допустимого диапазона",
-  "Драйвер протокола не подсоединен",
-  "Нет доступной CSI-структуры",
-  "Уровень 2 остановлен",
-  "Некорректный обмен",
-  "Неверный дескриптор запроса",
-  "Обмен полный",
-  "Нет anode",
-  "Неверный код запроса",
-  "Некорректный слот",
-  "Неизвестная ошибка 58",
-  "Неверный формат файла со шрифтом",
-  "Устройство не является потоком",
-  "Нет доступных данных",
-  "Таймер истек",
-  "Недостаточно потоковых ресурсов",
-  "Машина не в сети",
-  "Пакет не установлен",
-  "Это удаленный объект",
-  "Соединение было разорвано",
-  "Ошибка объявления",
-  "Ошибка Srmount",
-  "Ошибка связи при отправке",
-  "Ошибка протокола",
-  "Попытка Multihop",
-  "Специфичная для RFS ошибка",
-  "Неправильное сообщение",
-  "Значение слишком велико для такого типа данных",
-  "Имя не уникально в сети",
-  "Дескриптор файла в плохом состоянии",
-  "Удаленный адрес был изменен",
-  "Невозможно получить доступ к нужной разделяемой библиотеке",
-  "Обращение к поврежденной разделяемой библиотеке",
-  "Секция .lib в a.out повреждена",
-  "Попытка подключить слишком много разделяемый библиотек",
-  "Невозможно непосредственно выполнить разделяемую библиотеку",
-  "Неверный или неполный мультибайтный или широкий символ",
-  "Прерванный системный вызов следует перезапустить",
-  "Ошибка потоков канала",
-  "Слишком много пользователей",
-  "Операция для сокета применена к не-сокету",
-  "Требуется указать адрес назначения",
-  "Сообщение слишком длинное",
-  "Неподдерживаемый тип протокола для сокета",
-  "Протокол недоступен",
-  "Протокол не поддерживается",
-  "Неподдерживаемый тип сокета",
-  "Неподдерживаемая операция",
-  "Неподдерживаемое семейство протоколов",
-  "Семейство адресов не поддерживается протоколом",
-  "Адрес уже используется",
-  "Невозможно назначить запрошенный адрес",
-  "Сеть не работает",
-  "Сеть недоступна",
-  "Сетевое соединение было разорвано",
-  "Программа вызвала разрыв соединения",
-  "Соединение разорвано другой стороной",
-  "Недостаточно буферного пространства",
-  "Конечная точка передачи уже подсоединена",
-  "Конечная точка передачи не подсоединена",
-  "Невозможно отправить данные после выключения конечной точки передачи",
-  "Слишком много ссылок: невозможно объединить",
-  "Время ожидания соединения истекло",
-  "В соединении отказано",
-  "Узел выключен",
-  "Нет маршрута до узла",
-  "Операция уже выполняется",
-  "Операция выполняется в данный момент",
-  "Устаревший дескриптор файла",
-  "Структуру необходимо почистить",
-  "Не является файлом именованного типа XENIX",
-  "Семафоры XENIX недоступны",
-  "Является файлом именованного типа",
-  "Ошибка удаленного ввода/вывода",
-  "Превышена дисковая квота",
-  "Носитель не найден",
-  "Неправильный тип носителя",
-  "Операция отменена",
-  "Требуемый ключ недоступен",
-  "Время жизни ключа истекло",
-  "Ключ был отозван",
-  "Ключ был отвергнут службой",
-  "Владелец умер",
-  "Состояние нельзя восстановить",
-  "Операция не позволяется из-за RF-kill",
-  "В странице памяти аппаратная ошибка"
-];
-
-pub const STRSIGNAL: [&'static str; 32] = [
-  "Неизвестный сигнал 0",
-  "Обрыв терминальной линии",
-  "Прерывание",
-  "Выход",
-  "Недопустимая инструкция",
-  "Ловушка трассировки/останова",
-  "Аварийный останов",
-  "Ошибка шины",
-  "Исключение в операции с плавающей точкой",
-  "Убито",
-  "Определяемый пользователем сигнал 1",
-  "Ошибка сегментирования",
-  "Определяемый пользователем сигнал 2",
-  "Обрыв канала",
-  "Сигнал таймера",
-  "Завершено",
-  "Ошибка стека",
-  "Дочерний процесс завершился",
-  "Продолжено",
-  "Остановлено (сигнал)",
-  "Остановлено",
-  "Остановлено (ввод с терминала)",
-  "Остановлено (вывод на терминал)",
-  "Ситуация срочного ввода/вывода",
-  "Превышен лимит процессорного времени",
-  "Превышен лимит размера файла",
-  "Виртуальный таймер истек",
-  "Профилировочный таймер истек",
-  "Окно изменилось",
-  "Возможен ввод/вывод",
-  "Сбой питания",
-  "Неверный системный вызов"
-];
-
-pub const REGERROR: [&'static str; 14] = [
-  r"Выполнено",
-  r"Нет совпадения",
-  r"Неверное регулярное выражение",
-  r"Неверный знак сортировки",
-  r"Неверное имя класса знаков",
-  r"Обратная косая черта в конце",
-  r"Неверная ссылка назад",
-  r"Непарная [, [^, [:, [. или [=",
-  r"Непарная ( или \(",
-  r"Непарная \{",
-  r"Неверное содержимое в \{\}",
-  r"Неверный конец диапазона",
-  r"Память исчерпана",
-  r"Неверное предшествующее регулярное выражение"
-];
-
-pub const HSTRERROR: [&'static str; 5] = [
-  "Ошибка ресолвера 0 (нет ошибки)",
-  "Неизвестный узел",
-  "Сбой поиска имени узла",
-  "Неизвестная ошибка сервера",
-  "С именем не связано ни одного адреса"
-];
-
-pub const GAI_STRERROR: [&'static str; 15] = [
-  "Выполнено",
-  "Неправильное значение для ai_flags",
-  "Неизвестное имя или служба",
-  "Временный сбой в разрешении имен",
-  "Неисправимая ошибка в разрешении имен",
-  "ai_family не поддерживается",
-  "ai_socktype не поддерживается",
-  "Servname не поддерживается для ai_socktype",
-  "Ошибка выделения памяти",
-  "Системная ошибка",
-  "Результат слишком большой для указанного буфера",
-  "С именем узла не связано ни одного адреса", // EAI_NODATA
-  "Запрос отменен",                            // EAI_CANCELED
-  "Запрос не отменен",                         // EAI_NOTCANCELED
-  "Неправильно закодирована строка параметра"  // EAI_IDN_ENCODE
-];
-
-pub const YESEXPR: &'static str = "^[+1yYДд]";
-pub const NOEXPR: &'static str = "^[-0nNНн]";
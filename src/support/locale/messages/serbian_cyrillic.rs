@@ -1,220 +0,0 @@
-pub const MISC_MESSAGES: [&'static str; 3] =
-  ["Непозната грешка", "Непознати сигнал", "Сигнал у стварном времену"];
-
-pub const STRERROR: [&'static str; 134] = [
-  "Успешно",
-  "Радња није допуштена",
-  "Нема такве датотеке или директоријума",
-  "Нема таквог процеса",
-  "Прекинути системски позив",
-  "Улазно/излазна грешка",
-  "Нема таквог уређаја или адресе",
-  "Списак аргумената је предуг",
-  "Грешка формата извршавања",
-  "Лош описник датотеке",
-  "Нема процеса порода",
-  "Извориште је тренутно недоступно",
-  "Не могу да доделим меморију",
-  "Овлашћење је одбијено",
-  "Лоша адреса",
-  "Зхтеван је блоковни уређај",
-  "Уређај или извориште је заузето",
-  "Датотека постоји",
-  "Неисправна веза унакрсног уређаја",
-  "Нема таквог уређаја",
-  "Није директоријум",
-  "То је фасцикла",
-  "Неисправан аргумент",
-  "Превише отворених датотека у систему",
-  "Превише отворених датотека",
-  "Неодговарајуће „ioctl“ за уређај",
-  "Текстуална датотека је заузета",
-  "Датотека је превелика",
-  "Није преостало простора на уређају",
-  "Неисправно премотавања",
-  "Систем датотека само за читање",
-  "Превише веза",
-  "Оштећена спојка",
-  "Бројевни аргумент је ван домена",
-  "Бројевни резултат је ван опсега",
-  "Смртно закључавање изворишта је избегнуто",
-  "Назив датотеке је предуг",
-  "Закључавање није доступно",
-  "Функција није примењена",
-  "Директоријум није празан",
-  "Превише нивоа симболичких веза",
-  "Непозната грешка 41",
-  "Нема поруке жељене врсте",
-  "Одредник је уклоњен",
-  "Број канала је ван опсега",
-  "Ниво 2 није усаглашен",
-  "Ниво 3 је заустављен",
-  "Ниво 3 је поново постављен",
-  "Број везе је ван опсега",
-  "Управљач протокола није прикачен",
-  "Није доступна „CSI“ структура",
-  "Ниво 2 је заустављен",
-  "Неисправна размена",
-  "Неисправан описник захтева",
-  "Размена је пуна",
-  "Нема аноде",
-  "Неисправан код захтева",
-  "Неисправан утор",
-  "Непозната грешка 58",
-  "Лош формат датотеке словног лика",
-  "Уређај није ток",
-  "Нема доступних података",
-  "Одбројавач је истекао",
-  "Нема више изворишта токова",
-  "Рачунар није на мрежи",
-  "Пакет није инсталиран",
-  "Објекат је удаљени",
-  "Веза је раскинута",
-  "Грешка обавести",
-  "Грешка ср-монтирања",
-  "Грешка комуникације при слању",
-  "Грешка протокола",
-  "Мултискок је покушан",
-  "Грешка „RFS“-у специфична",
-  "Лоша порука",
-  "Вредност је превелика за дефинисану врсту података",
-  "Назив није јединствен на мрежи",
-  "Описник датотеке је у лошем стању",
-  "Удаљена адреса је измењена",
-  "Не могу да приступим потребној дељено библиотеци",
-  "Приступам оштећеној дељеној библиотеци",
-  "одељак „.lib“ у „a.out“ је оштећен",
-  "Покушавам да повежем у превише дељених библиотека",
-  "Не могу непосредно да извршим дељену библиотеку",
-  "Неисправан или непотпун мултибајт или широк знак",
-  "Прекинути позив система треба поново бити покренут",
-  "Грешка спојке токова",
-  "Превише корисника",
-  "Радња прикључнице на не-прикључници",
-  "Адреса одредишта је захтевана",
-  "Порука је предуга",
-  "Погрешна врста протокола за прикључницу",
-  "Протокол није доступан",
-  "Протокол није подржан",
-  "Врста прикључнице није подржана",
-  "Радња није подржана",
-  "Фамилија протокола није подржана",
-  "Фамилија адресе није подржана протоколом",
-  "Адреса је већ у употреби",
-  "Не могу да доделим захтевану адресу",
-  "Мрежа је пала",
-  "Мрежа је недостижна",
-  "Мрежа је одбацила вез на захтев",
-  "Софтвер је изазвао прекид везе",
-  "Везу је поново поставио парњак",
-  "Нема доступног простора међумеморије",
-  "Крајња тачка преноса је већ повезана",
-  "Крајња тачка преноса није повезана",
-  "Не могу да пошаљем након гашења крајње тачке преноса",
-  "Превише упута: не могу да сплетем",
-  "Истекло је време везе",
-  "Веза је одбијена",
-  "Домаћин је пао",
-  "Нема путање до рачунара",
-  "Радња је већ у току",
-  "Радња је сада у току",
-  "Ручка датотеке загушења",
-  "Структури је потребно чишћење",
-  "Није „XENIX“ именована датотека врсте",
-  "Нису доступни „XENIX“ сигнализатори",
-  "Јесте именована датотека врсте",
-  "Удаљена У/И грешка",
-  "Квота диска је премашена",
-  "Нисам пронашао медијум",
-  "Погрешна врста медијума",
-  "Радња је отказана",
-  "Захтеван кључ није доступан",
-  "Кључ је истекао",
-  "Кључ је опозван",
-  "Кључ је одбијен услугом",
-  "Власник је умро (Бог да му душу прости)",
-  "Стање није поправљиво",
-  "Радња није могућа због „RF-kill“",
-  "Страница меморије има хардверску грешку"
-];
-
-pub const STRSIGNAL: [&'static str; 32] = [
-  "Непознати сигнал 0",
-  "Обустави",
-  "Прекини",
-  "Изађи",
-  "Неисправна инструкција",
-  "Замка праћења/тачке прекида",
-  "Прекинуто",
-  "Грешка сабирнице",
-  "Изузетак покретног зареза",
-  "Убијен",
-  "Кориснички одређени сигнал 1",
-  "Неуспех сегментације",
-  "Кориснички одређени сигнал 2",
-  "Оштећена спојка",
-  "Будилник",
-  "Окончано",
-  "Неуспех стека",
-  "Потпроцес је напуштен",
-  "Настављен",
-  "Заустављен (сигнал)",
-  "Заустављен",
-  "Заустављен (улаз конзоле)",
-  "Заустављен (излаз конзоле)",
-  "Хитни У/И услов",
-  "Прекорачено је временско ограничење процесора",
-  "Прекорачено је ограничење величине датотеке",
-  "Виртуелни одбројавач је истекао",
-  "Одбројавач профилисања је истекао",
-  "Прозор је измењен",
-  "У/И је могућ",
-  "Неуспех напајања",
-  "Лош системски позив"
-];
-
-pub const REGERROR: [&'static str; 14] = [
-  r"Успешно",
-  r"Нема поклапања",
-  r"Неисправан регуларни израз",
-  r"Неисправан знак слагања",
-  r"Неисправан назив класе знака",
-  r"Пратећа контра коса црта",
-  r"Неисправна повратна упута",
-  r"Не одговара [, [^, [:, [., или [=",
-  r"Не одговара ( или \(",
-  r"Не одговара \{",
-  r"Неисправан садржај за \{\}",
-  r"Неисправан крај опсега",
-  r"Меморија је потрошена",
-  r"Неисправан регуларан израз који претходи"
-];
-
-pub const HSTRERROR: [&'static str; 5] = [
-  "Грешка решавача 0 (нема грешака)",
-  "Непознат домаћин",
-  "Неуспех претраге назива домаћина",
-  "Непозната грешка сервера",
-  "Ниједна адреса није придружена са називом"
-];
-
-pub const GAI_STRERROR: [&'static str; 15] = [
-  "Успешно",
-  "Неисправна вредност за аи_опције",
-  "Није познат назив или сервер",
-  "Привремени неуспех одређивања назива",
-  "Непоправљива грешка при одређивању назива",
-  "аи_породица није подржана",
-  "аи_врстаприкључнице није подржана",
-  "Назив сервера није подржан за аи_врступрикључнице",
-  "Расподела меморије није успела",
-  "Грешка система",
-  "Резултат је превелик за достављену међумеморију",
-  "Ниједна адреса није придружена називу домаћина", // EAI_NODATA
-  "Захтев је отказан",                              // EAI_CANCELED
-  "Захтев није отказан",                            // EAI_NOTCANCELED
-  "Ниска параметра није исправно кодирана"          // EAI_IDN_ENCODE
-];
-
-pub const YESEXPR: &'static str = "^[+1yYdDДд]";
-pub const NOEXPR: &'static str = "^[-0nNНн]";
@@ -1,220 +0,0 @@
-pub const MISC_MESSAGES: [&'static str; 3] =
-  ["Невідома помилка", "Невідомий сигнал", "Сигнал реального часу"];
-
-pub const STRERROR: [&'static str; 134] = [
-  "Успіх",
-  "Дію заборонено",
-  "Немає такого файла або каталогу",
-  "Немає такого процесу",
-  "Перерваний системний виклик",
-  "Помилка введення-виведення",
-  "Немає такого пристрою чи адреси",
-  "Занадто довгий список аргументів",
-  "Помилка, пов’язана з форматом виконуваних файлів",
-  "Помилковий дескриптор файла",
-  "Немає дочірніх процесів",
-  "Ресурс тимчасово недоступний",
-  "Не вдалося розподілити пам’ять",
-  "Відмовлено у доступі",
-  "Неправильна адреса",
-  "Потрібен блоковий пристрій",
-  "Пристрій або ресурс зайнято",
-  "Файл вже існує",
-  "Некоректне перехресне посилання на пристрій",
-  "Пристрою не знайдено",
-  "Не є каталогом",
-  "Є каталогом",
-  "Некоректний аргумент",
-  "У системі забагато відкритих файлів",
-  "Забагато відкритих файлів",
-  "Неналежна дія з керування введенням-виведенням (ioctl) для пристрою",
-  "Текстовий файл зайнято",
-  "Файл є надто великим",
-  "Недостатньо місця на пристрої",
-  "Некоректне позиціювання",
-  "Файлова система тільки для зчитування",
-  "Забагато посилань",
-  "Канал обірвано",
-  "Числовий аргумент поза доменом",
-  "Числовий результат поза дозволеним діапазоном",
-  "Уникнено взаємне блокування ресурсу",
-  "Файл є занадто великим",
-  "Блокування недоступні",
-  "Функцію не реалізовано",
-  "Каталог непорожній",
-  "Забагато рівнів символічних посилань",
-  "Невідома помилка 41",
-  "Немає повідомлення бажаного типу",
-  "Ідентифікатор вилучено",
-  "Номер каналу лежить поза припустимим діапазоном",
-  "Рівень 2 не синхронізовано",
-  "Рівень 3 перервано",
-  "Рівень 3 скинуто",
-  "Кількість зв’язків поза припустимим діапазоном",
-  "Драйвер протоколу не долучено",
-  "Структура CSI недоступна",
-  "Рівень 2 перервано",
-  "Некоректний обмін",
-  "Некоректний дескриптор запиту",
-  "Повний обмін",
-  "Немає anode",
-  "Некоректний код запиту",
-  "Некоректний слот",
-  "Невідома помилка 58",
-  "Помилковий формат файла шрифту",
-  "Пристрій не є потоковим",
-  "Не виявлено даних",
-  "Відлік на таймері завершено",
-  "Не вистачає ресурсів потоків",
-  "Комп’ютер не перебуває у мережі",
-  "Пакунок не встановлено",
-  "Об’єкт є віддаленим",
-  "Зв’язок розірвано",
-  "Помилка під час оголошення",
-  "Помилка srmount",
-  "Помилка обміну даними під час надсилання",
-  "Помилка протоколу",
-  "Спроба кратного переходу",
-  "Специфічна для RFS помилка",
-  "Помилкове повідомлення",
-  "Значення є надто великим для визначеного типу даних",
-  "Назва не є унікальною у мережі",
-  "Файловий дескриптор у помилковому стані",
-  "Віддалену адресу змінено",
-  "Не вдалося отримати доступ до потрібної бібліотеки спільного використання",
-  "Доступ до пошкодженої бібліотеки спільного використання",
-  "Розділ .lib у a.out пошкоджено",
-  "Спроба компонування надто великої кількості бібліотек спільного використання",
-  "Не можна виконувати бібліотеку спільного використання безпосередньо",
-  "Некоректний або неповний багатобайтовий або широкий символ",
-  "Перерваний системний виклик має бути перезапущено",
-  "Помилка каналу потоків",
-  "Забагато користувачів",
-  "Дія на об'єкті, який не є сокетом",
-  "Слід вказати адресу призначення",
-  "Задовге повідомлення",
-  "Помилковий тип протоколу сокета",
-  "Протокол недоступний",
-  "Протокол не підтримується",
-  "Тип сокета не підтримується",
-  "Дія не підтримується",
-  "Сімейство протоколів не підтримується",
-  "Сімейство адрес не підтримується протоколом",
-  "Адресу вже використано",
-  "Не вдалося призначити вказану адресу",
-  "Мережу вимкнено",
-  "Не вдалося отримати доступ до мережі",
-  "Під час перезапуску з’єднання було розірвано",
-  "З’єднання розірвано програмним забезпеченням",
-  "Вузол розірвав з’єднання",
-  "У буфері не залишилося місця",
-  "Кінцеву точку передавання вже з’єднано",
-  "Кінцеву точку передавання не з’єднано",
-  "Надсилання неможливе після завершення роботи кінцевої точки каналу зв’язку",
-  "Забагато посилань: не вдалося об’єднати",
-  "Перевищено інтервал очікування з'єднання",
-  "У з'єднанні відмовлено",
-  "Вузол вимкнено",
-  "Немає маршруту до вузла",
-  "Дія вже виконується",
-  "Дія зараз виконується",
-  "Застарілий дескриптор файла",
-  "Структура потребує чищення",
-  "Не є файлом іменованих типів XENIX",
-  "Семафори XENIX недоступні",
-  "Є файлом іменованих типів",
-  "Помилка під час віддаленого введення-виведення",
-  "Перевищено квоту диска",
-  "Не знайдено носія",
-  "Помилковий тип носія",
-  "Дію скасовано",
-  "Потрібний ключ недоступний",
-  "Строк дії ключа вичерпано",
-  "Ключ було відкликано",
-  "Служба відмовилася прийняти ключ",
-  "Власник завершив роботу",
-  "Невідновлюваний стан",
-  "Дію не може бути виконано через RF-kill",
-  "На сторінці пам’яті виявлено апаратну помилку"
-];
-
-pub const STRSIGNAL: [&'static str; 32] = [
-  "Невідомий сигнал 0",
-  "Від’єднання",
-  "Перервати",
-  "Вихід",
-  "Помилкова інструкція",
-  "Переривання на точці зупину",
-  "Перервано",
-  "Помилка каналу обміну даними",
-  "Помилка обчислень з рухомою комою",
-  "Вбито",
-  "Визначений користувачем сигнал 1",
-  "Помилка адресування",
-  "Визначений користувачем сигнал 2",
-  "Канал обірвано",
-  "Сигнал таймера",
-  "Припинено",
-  "Помилка роботи зі стеком",
-  "Дочірній процес завершив роботу",
-  "Поновлено",
-  "Зупинено (сигнал)",
-  "Зупинено",
-  "Зупинено (ввід з термінала)",
-  "Зупинено (вивід на термінал)",
-  "Термінова дія з введення-виведення",
-  "Перевищено обмеження часу використання процесора",
-  "Перевищено обмеження на розмір файла",
-  "Збіг час на віртуальному таймері",
-  "Збіг час профілювання",
-  "Зміна вікна",
-  "Можливе введення-виведення",
-  "Відмова живлення",
-  "Помилковий системний виклик"
-];
-
-pub const REGERROR: [&'static str; 14] = [
-  r"Успіх",
-  r"Не знайдено",
-  r"Помилка у формальному виразі",
-  r"Некоректний символ порівняння",
-  r"Некоректна назва класу символів",
-  r"Кінцевий символ похилої риски",
-  r"Некоректне зворотне посилання",
-  r"Вираз без парних [, [^, [:, [. або [=",
-  r"Неврівноважена послідовність ( або \(",
-  r"Неврівноважена послідовність \{",
-  r"Некоректний вміст \{\}",
-  r"Некоректне завершення діапазону",
-  r"Пам’ять вичерпано",
-  r"Помилка у попередньому формальному виразі"
-];
-
-pub const HSTRERROR: [&'static str; 5] = [
-  "Помилка 0 розв’язувача (немає помилки)",
-  "Невідомий вузол",
-  "Помилка під час визначення назви вузла",
-  "Невідома помилка сервера",
-  "З назвою не пов’язано жодної адреси"
-];
-
-pub const GAI_STRERROR: [&'static str; 15] = [
-  "Успіх",
-  "Неправильне значення ai_flags",
-  "Невідома назва чи сервіс",
-  "Тимчасова помилка розв'язання назв",
-  "Критична помилка при розв'язанні назв",
-  "ai_family не підтримується",
-  "ai_socktype не підтримується",
-  "Servname не підтримується для ai_socktype",
-  "Помилка виділення пам'яті",
-  "Системна помилка",
-  "Результат є надто великим для наданого буфера",
-  "З даною назвою вузла не пов'язано жодної адреси", // EAI_NODATA
-  "Запит скасовано",                                 // EAI_CANCELED
-  "Запит не скасовано",                              // EAI_NOTCANCELED
-  "Неправильно закодований рядок параметрів"         // EAI_IDN_ENCODE
-];
-
-pub const YESEXPR: &'static str = "^([+1Yy]|[Тт][Аа][Кк]?)$";
-pub const NOEXPR: &'static str = "^([-0Nn]|[Нн][Іі])$";
@@ -1,220 +0,0 @@
-pub const MISC_MESSAGES: [&'static str; 3] =
-  ["勿晓得个错", "勿晓得个信号", "实时信号"];
-
-pub const STRERROR: [&'static str; 134] = [
-  "成功",
-  "操作勿允许",
-  "呒没搿只文件或者目录",
-  "呒没搿个进程",
-  "系统调用拨打断脱",
-  "输入/输出错",
-  "呒没搿个设备或者地址",
-  "参数表忒长",
-  "执行格式错",
-  "文件描述符坏脱",
-  "呒没子进程",
-  "资源暂时勿可用",
-  "内存分配勿来",
-  "权限勿够",
-  "地址错",
-  "需要块设备",
-  "设备或者资源忙",
-  "文件已经存在",
-  "跨设备链接无效",
-  "呒没搿个设备",
-  "勿是目录",
-  "是目录",
-  "参数无效",
-  "系统里向打开文件忒多",
-  "打开文件忒多",
-  "设备浪向个控制命令勿对",
-  "文本文件忙",
-  "文件忒大",
-  "设备浪向空间满脱",
-  "非法寻道",
-  "只读文件系统",
-  "链接忒多",
-  "管道断裂",
-  "数值参数超出范围",
-  "数值结果超出范围",
-  "避免资源死锁",
-  "文件名忒长",
-  "锁勿可用",
-  "功能勿曾实现",
-  "目录勿空",
-  "符号链接层级忒多",
-  "勿晓得个错 41",
-  "呒没想要个消息类型",
-  "标识符拨删除",
-  "通道号超出范围",
-  "二级勿同步",
-  "三级停止",
-  "三级重置",
-  "链接号超出范围",
-  "协议驱动勿曾连接",
-  "呒没CSI结构",
-  "二级停止",
-  "无效交换",
-  "无效请求描述符",
-  "交换满脱",
-  "呒没阳极",
-  "无效请求码",
-  "无效插槽",
-  "勿晓得个错 58",
-  "字体文件格式坏脱",
-  "设备勿是流",
-  "数据勿可用",
-  "定时器到期",
-  "流资源耗尽",
-  "机器勿勒网络浪",
-  "包勿曾安装",
-  "对象勒远程",
-  "链接拨切断",
-  "广告错",
-  "服务器挂载错",
-  "发送通信错",
-  "协议错",
-  "尝试多跳",
-  "RFS特定错",
-  "消息坏脱",
-  "值对定义个数据类型来讲忒大",
-  "网络浪向名字勿唯一",
-  "文件描述符状态坏脱",
-  "远程地址改变",
-  "无法访问需要个共享库",
-  "访问损坏个共享库",
-  "a.out里向个.lib段损坏",
-  "尝试链接忒多个共享库",
-  "无法直接执行共享库",
-  "无效或者勿完整个多字节或者宽字符",
-  "打断脱个系统调用需要重新启动",
-  "流管道错",
-  "用户忒多",
-  "非套接字浪向个套接字操作",
-  "需要目标地址",
-  "消息忒长",
-  "协议类型对套接字来讲勿对",
-  "协议勿可用",
-  "协议勿支持",
-  "套接字类型勿支持",
-  "操作勿支持",
-  "协议族勿支持",
-  "地址族对协议来讲勿支持",
-  "地址已经勒用",
-  "无法分配请求个地址",
-  "网络宕脱",
-  "网络勿可达",
-  "网络拨重置连接断开",
-  "软件引起连接异常终止",
-  "对端重置连接",
-  "缓冲区空间勿可用",
-  "传输端点已经连接",
-  "传输端点呒没连接",
-  "传输端点关闭之后无法发送",
-  "引用忒多：无法拼接",
-  "连接超时",
-  "连接拨拒绝",
-  "主机宕脱",
-  "呒没到主机个路由",
-  "操作已经勒进行当中",
-  "操作而家勒进行当中",
-  "文件句柄过期",
-  "结构需要清理",
-  "勿是XENIX命名类型文件",
-  "呒没XENIX信号量可用",
-  "是命名类型文件",
-  "远程I/O错",
-  "磁盘配额超出",
-  "呒没找到介质",
-  "介质类型错",
-  "操作拨取消",
-  "需要个密钥勿可用",
-  "密钥过期",
-  "密钥拨吊销",
-  "密钥拨服务拒绝",
-  "所有者死脱",
-  "状态无法恢复",
-  "因为RF-kill操作勿可能",
-  "内存页面有硬件错"
-];
-
-pub const STRSIGNAL: [&'static str; 32] = [
-  "勿晓得个信号 0",
-  "挂机",
-  "中断",
-  "退出",
-  "非法指令",
-  "跟踪/断点陷阱",
-  "异常终止",
-  "总线错",
-  "浮点异常",
-  "杀死",
-  "用户定义信号 1",
-  "段错",
-  "用户定义信号 2",
-  "管道断裂",
-  "闹钟",
-  "终止",
-  "栈错",
-  "子进程退出",
-  "继续",
-  "停止（信号）",
-  "停止",
-  "停止（tty输入）",
-  "停止（tty输出）",
-  "紧急I/O条件",
-  "CPU时间限制超出",
-  "文件大小限制超出",
-  "虚拟定时器到期",
-  "性能分析定时器到期",
-  "窗口改变",
-  "I/O可能",
-  "断电",
-  "坏系统调用"
-];
-
-pub const REGERROR: [&'static str; 14] = [
-  r"成功",
-  r"呒没匹配",
-  r"无效正则表达式",
-  r"无效对照字符",
-  r"无效字符类名",
-  r"尾部反斜杠",
-  r"无效反向引用",
-  r"【、〖、〔或者［勿匹配",
-  r"( 或者 \( 勿匹配",
-  r"\{ 勿匹配",
-  r"\{\} 内容无效",
-  r"无效范围结尾",
-  r"内存耗尽",
-  r"前面正则表达式无效"
-];
-
-pub const HSTRERROR: [&'static str; 5] = [
-  "解析器错 0（呒没错）",
-  "勿晓得个主机",
-  "主机名查找失败",
-  "勿晓得个服务器错",
-  "呒没搭名字关联个地址"
-];
-
-pub const GAI_STRERROR: [&'static str; 15] = [
-  "成功",
-  "ai_flags 值坏脱",
-  "名字或者服务勿晓得",
-  "名字解析暂时失败",
-  "名字解析无法恢复个失败",
-  "ai_family 勿支持",
-  "ai_socktype 勿支持",
-  "对ai_socktype来讲Servname勿支持",
-  "内存分配失败",
-  "系统错",
-  "结果对提供个缓冲区来讲忒大",
-  "呒没搭主机名关联个地址", // EAI_NODATA
-  "请求拨取消",             // EAI_CANCELED
-  "请求呒没拨取消",         // EAI_NOTCANCELED
-  "参数字符串编码勿正确"    // EAI_IDN_ENCODE
-];
-
-pub const YESEXPR: &'static str = "^[+1yY是]";
-pub const NOEXPR: &'static str = "^[-0nN勿]";
@@ -1,27 +1,43 @@
+pub mod address;
 pub mod collate;
 pub mod ctype;
+pub mod identification;
+pub mod localedef;
+pub mod measurement;
 pub mod messages;
 pub mod monetary;
+pub mod name;
 pub mod numeric;
+pub mod paper;
+pub mod telephone;
+pub mod territory;
 pub mod time;
 
+#[cfg(any(feature = "casemap", feature = "collate", feature = "numeric"))]
+use icu_locale::{
+  LanguageIdentifier,
+  LocaleExpander,
+  TransformResult,
+  subtags::Language
+};
 use {
   crate::{
-    allocation::string::String,
+    allocation::{boxed::Box, string::String},
     c_char,
     c_int,
     intptr_t,
     locale_t,
     std::{errno, locale},
-    support::locale::locale::LC_GLOBAL_LOCALE
+    support::{locale::locale::LC_GLOBAL_LOCALE, sync::rcu::Rcu}
   },
-  atomic_refcell::{AtomicRef, AtomicRefCell, AtomicRefMut},
+  atomic_refcell::{AtomicRef, AtomicRefCell},
   core::{
     cell::UnsafeCell,
     ffi,
     fmt::{Error, Write},
     ptr
-  }
+  },
+  once_cell::race::OnceBox
 };
 
 pub trait LocaleObject: Clone + Default {
@@ -31,6 +47,16 @@ pub trait LocaleObject: Clone + Default {
   ) -> Result<&ffi::CStr, c_int>;
   fn set_to_posix(&mut self) -> &ffi::CStr;
   fn get_name(&self) -> &ffi::CStr;
+
+  /// Loads the category from a localedef source file. Categories that
+  /// sources do not define get their built-in data.
+  fn load_source(
+    &mut self,
+    locale: &ffi::CStr,
+    _source: &localedef::Source
+  ) -> Result<&ffi::CStr, c_int> {
+    self.setlocale(locale)
+  }
 }
 
 #[inline]
@@ -41,14 +67,70 @@ pub fn is_posix_locale(name: &str) -> bool {
     name.starts_with("POSIX.")
 }
 
+/// Checks whether CLDR knows the language of a locale name such as
+/// "de_DE.UTF-8". The ICU objects fall back to the root locale for anything
+/// else, which would make every well-formed name look available.
+#[cfg(any(feature = "casemap", feature = "collate", feature = "numeric"))]
+pub fn is_known_language(name: &str) -> bool {
+  let language = name.split(['_', '-', '.', '@']).next().unwrap_or("");
+  let Ok(language) = Language::try_from_str(language) else {
+    return false;
+  };
+  if language.is_unknown() {
+    return false;
+  }
+
+  let mut id = LanguageIdentifier::from(language);
+
+  LocaleExpander::new_extended().maximize(&mut id) == TransformResult::Modified
+}
+
+// Without any ICU data the ISO tables decide which languages are known. The
+// categories whose data come from ICU only accept the POSIX locales, while
+// the others, such as LC_MESSAGES and LC_TIME, still load the name.
+#[cfg(not(any(
+  feature = "casemap",
+  feature = "collate",
+  feature = "numeric"
+)))]
+pub fn is_known_language(name: &str) -> bool {
+  territory::get_language(name)
+    .is_some_and(|language| territory::find_language(&language).is_some())
+}
+
+#[inline]
+pub fn get_modifier(name: &str) -> Option<&str> {
+  name.split_once('@').map(|(_, modifier)| modifier)
+}
+
+#[inline]
+pub fn get_modifier_keywords(
+  name: &str
+) -> impl Iterator<Item = (&str, Option<&str>)> {
+  get_modifier(name)
+    .unwrap_or("")
+    .split([';', ','])
+    .filter(|keyword| !keyword.is_empty())
+    .map(|keyword| match keyword.split_once('=') {
+      | Some((key, value)) => (key, Some(value)),
+      | None => (keyword, None)
+    })
+}
+
 #[inline]
 pub fn canonicalize_locale(s: &str) -> String {
   let s = s.trim();
   let s = &s.replace("_", "-");
   let mut result = String::from(s);
 
-  if result.starts_with("ar") {
-    result.push_str("-u-nu-latn");
+  // Arabic locales take their separators from the Latin numbering system,
+  // unless another one is asked for
+  if result.starts_with("ar") && !result.contains("-nu-") {
+    if result.contains("-u-") {
+      result.push_str("-nu-latn");
+    } else {
+      result.push_str("-u-nu-latn");
+    }
   }
 
   if result.starts_with("pdc") && result.ends_with("US") {
@@ -91,12 +173,29 @@ pub fn get_slot<'a, T: LocaleObject>(
   if let Some(g) = guard { Some(g.clone()) } else { None }
 }
 
+/// Runs `f` on the object in `slot` without cloning it, for hot paths.
 #[inline]
-pub fn get_slot_mut<'a, T: LocaleObject>(
-  slot: &'a AtomicRefCell<Option<T>>
-) -> AtomicRefMut<'a, T> {
-  let opt = slot.borrow_mut();
-  AtomicRefMut::map(opt, |o| o.get_or_insert_with(T::default))
+pub fn with_slot<T: LocaleObject, R>(
+  slot: &AtomicRefCell<Option<T>>,
+  f: impl FnOnce(&T) -> R
+) -> R {
+  match slot.borrow().as_ref() {
+    | Some(object) => f(object),
+    | None => f(&T::default())
+  }
+}
+
+/// Borrows the object in a slot, or the one of the C locale that `default`
+/// selects if the slot was never loaded.
+#[inline]
+pub fn get_slot_ref<'a, T: LocaleObject>(
+  slot: &'a AtomicRefCell<Option<T>>,
+  default: fn(&'static Locale<'static>) -> &'static AtomicRefCell<Option<T>>
+) -> Option<AtomicRef<'a, T>> {
+  AtomicRef::filter_map(slot.borrow(), |o| o.as_ref()).or_else(|| {
+    let default = default(unsafe { &*DEFAULT_LOCALE.inner.get() });
+    AtomicRef::filter_map(default.borrow(), |o| o.as_ref())
+  })
 }
 
 #[inline]
@@ -108,14 +207,44 @@ pub fn get_slot_name<'a, T: LocaleObject>(
   if let Some(g) = guard { g.get_name().as_ptr() } else { c"C".as_ptr() }
 }
 
+/// Loads a category object for `name` if `selected`, without touching any
+/// slot, so that a failure leaves the locale as it was.
 #[inline]
-pub fn set_slot<T: LocaleObject>(
+fn load_object<T: LocaleObject>(
+  selected: bool,
+  name: &ffi::CStr,
+  source: Option<&localedef::Source>
+) -> Result<Option<T>, c_int> {
+  if !selected {
+    return Ok(None);
+  }
+
+  let mut object = T::default();
+  match source {
+    | Some(source) => object.load_source(name, source),
+    | None => object.setlocale(name)
+  }
+  .map_err(|_| errno::ENOENT)?;
+
+  Ok(Some(object))
+}
+
+#[inline]
+fn store_object<T: LocaleObject>(
   slot: &AtomicRefCell<Option<T>>,
-  name: &ffi::CStr
-) -> Result<(), c_int> {
-  let mut guard = slot.borrow_mut();
-  let obj = guard.get_or_insert_with(T::default);
-  obj.setlocale(name).map(|_| ()).map_err(|_| errno::ENOENT)
+  object: Option<T>
+) {
+  if let Some(object) = object {
+    *slot.borrow_mut() = Some(object);
+  }
+}
+
+#[inline]
+fn copy_slot<T: Clone>(
+  dest: &mut AtomicRefCell<T>,
+  src: &AtomicRefCell<T>
+) {
+  *dest.get_mut() = src.borrow().clone();
 }
 
 fn writer_name_to_category<W: Write>(
@@ -132,127 +261,243 @@ fn writer_name_to_category<W: Write>(
 }
 
 pub struct Locale<'a> {
-  lc_all: AtomicRefCell<[c_char; 1024]>,
-  pub localeconv: AtomicRefCell<locale::lconv>,
+  /// The composite name of LC_ALL, made when it is first asked for
+  lc_all: OnceBox<[c_char; 1024]>,
+  /// The localeconv of this locale, made when it is first asked for
+  pub localeconv: OnceBox<locale::lconv>,
+  pub address: AtomicRefCell<Option<address::AddressObject<'a>>>,
   pub collate: AtomicRefCell<Option<collate::CollateObject<'a>>>,
   pub ctype: AtomicRefCell<Option<ctype::CtypeObject<'a>>>,
+  pub identification:
+    AtomicRefCell<Option<identification::IdentificationObject<'a>>>,
+  pub measurement: AtomicRefCell<Option<measurement::MeasurementObject<'a>>>,
   pub messages: AtomicRefCell<Option<messages::MessagesObject<'a>>>,
   pub monetary: AtomicRefCell<Option<monetary::MonetaryObject<'a>>>,
+  pub name: AtomicRefCell<Option<name::NameObject<'a>>>,
   pub numeric: AtomicRefCell<Option<numeric::NumericObject<'a>>>,
+  pub paper: AtomicRefCell<Option<paper::PaperObject<'a>>>,
+  pub telephone: AtomicRefCell<Option<telephone::TelephoneObject<'a>>>,
   pub time: AtomicRefCell<Option<time::TimeObject<'a>>>
 }
 
 impl<'a> Locale<'a> {
   pub fn new() -> Self {
     Self {
-      lc_all: AtomicRefCell::new([0; 1024]),
-      localeconv: AtomicRefCell::new(unsafe { core::mem::zeroed() }),
+      lc_all: OnceBox::new(),
+      localeconv: OnceBox::new(),
+      address: AtomicRefCell::new(Some(address::DEFAULT_ADDRESS)),
       collate: AtomicRefCell::new(Some(collate::DEFAULT_COLLATE)),
       ctype: AtomicRefCell::new(Some(ctype::DEFAULT_CTYPE)),
+      identification: AtomicRefCell::new(Some(
+        identification::DEFAULT_IDENTIFICATION
+      )),
+      measurement: AtomicRefCell::new(Some(measurement::DEFAULT_MEASUREMENT)),
       messages: AtomicRefCell::new(Some(messages::DEFAULT_MESSAGES)),
       monetary: AtomicRefCell::new(Some(monetary::DEFAULT_MONETARY)),
+      name: AtomicRefCell::new(Some(name::DEFAULT_NAME)),
       numeric: AtomicRefCell::new(Some(numeric::DEFAULT_NUMERIC)),
+      paper: AtomicRefCell::new(Some(paper::DEFAULT_PAPER)),
+      telephone: AtomicRefCell::new(Some(telephone::DEFAULT_TELEPHONE)),
       time: AtomicRefCell::new(Some(time::DEFAULT_TIME))
     }
   }
 
   pub fn setlocale(
-    &self,
+    &mut self,
     category: c_int,
     name: &ffi::CStr
   ) -> Result<&Self, c_int> {
-    match category {
-      | locale::LC_ALL => {
-        set_slot(&self.collate, name)?;
-        set_slot(&self.ctype, name)?;
-        set_slot(&self.messages, name)?;
-        set_slot(&self.monetary, name)?;
-        set_slot(&self.numeric, name)?;
-        set_slot(&self.time, name)?;
-        Ok(self)
-      },
-      | locale::LC_COLLATE => {
-        set_slot(&self.collate, name)?;
-        Ok(self)
-      },
-      | locale::LC_CTYPE => {
-        set_slot(&self.ctype, name)?;
-        Ok(self)
-      },
-      | locale::LC_MESSAGES => {
-        set_slot(&self.messages, name)?;
-        Ok(self)
-      },
-      | locale::LC_MONETARY => {
-        set_slot(&self.monetary, name)?;
-        Ok(self)
-      },
-      | locale::LC_NUMERIC => {
-        set_slot(&self.numeric, name)?;
-        Ok(self)
-      },
-      | locale::LC_TIME => {
-        set_slot(&self.time, name)?;
-        Ok(self)
-      },
-      | _ => Err(errno::ENOENT)
+    let mask = match category {
+      | locale::LC_ALL => locale::LC_ALL_MASK,
+      | locale::LC_CTYPE..locale::LC_ALL |
+      locale::LC_PAPER..=locale::LC_IDENTIFICATION => 1 << category,
+      | _ => return Err(errno::ENOENT)
+    };
+
+    self.load(mask, name)?;
+
+    Ok(self)
+  }
+
+  /// Loads `name` into every category in `mask`. Either all of them are
+  /// changed or, if any category cannot load the name, none of them are.
+  /// This is the only check for whether a locale is available.
+  pub fn load(
+    &mut self,
+    mask: c_int,
+    name: &ffi::CStr
+  ) -> Result<(), c_int> {
+    let name_str = name.to_str().map_err(|_| errno::ENOENT)?;
+
+    // A source file in LOCPATH takes the place of the built-in data
+    let source = localedef::find_source(name_str);
+    let source = source.as_ref();
+    if source.is_none() &&
+      !is_posix_locale(name_str) &&
+      !is_known_language(name_str)
+    {
+      return Err(errno::ENOENT);
+    }
+
+    let address =
+      load_object(mask & locale::LC_ADDRESS_MASK != 0, name, source)?;
+    let collate =
+      load_object(mask & locale::LC_COLLATE_MASK != 0, name, source)?;
+    let ctype = load_object(mask & locale::LC_CTYPE_MASK != 0, name, source)?;
+    let identification =
+      load_object(mask & locale::LC_IDENTIFICATION_MASK != 0, name, source)?;
+    let measurement =
+      load_object(mask & locale::LC_MEASUREMENT_MASK != 0, name, source)?;
+    let messages =
+      load_object(mask & locale::LC_MESSAGES_MASK != 0, name, source)?;
+    let monetary =
+      load_object(mask & locale::LC_MONETARY_MASK != 0, name, source)?;
+    let name_object =
+      load_object(mask & locale::LC_NAME_MASK != 0, name, source)?;
+    let numeric =
+      load_object(mask & locale::LC_NUMERIC_MASK != 0, name, source)?;
+    let paper = load_object(mask & locale::LC_PAPER_MASK != 0, name, source)?;
+    let telephone =
+      load_object(mask & locale::LC_TELEPHONE_MASK != 0, name, source)?;
+    let time = load_object(mask & locale::LC_TIME_MASK != 0, name, source)?;
+
+    store_object(&self.address, address);
+    store_object(&self.collate, collate);
+    store_object(&self.ctype, ctype);
+    store_object(&self.identification, identification);
+    store_object(&self.measurement, measurement);
+    store_object(&self.messages, messages);
+    store_object(&self.monetary, monetary);
+    store_object(&self.name, name_object);
+    store_object(&self.numeric, numeric);
+    store_object(&self.paper, paper);
+    store_object(&self.telephone, telephone);
+    store_object(&self.time, time);
+    self.lc_all = OnceBox::new();
+    self.localeconv = OnceBox::new();
+
+    Ok(())
+  }
+
+  /// Copies every category outside of `mask` from `base`, which is how
+  /// newlocale and setlocale keep the categories they are not asked to load.
+  pub fn inherit(
+    &mut self,
+    base: &Locale<'a>,
+    mask: c_int
+  ) {
+    if mask & locale::LC_ADDRESS_MASK == 0 {
+      copy_slot(&mut self.address, &base.address);
+    }
+    if mask & locale::LC_COLLATE_MASK == 0 {
+      copy_slot(&mut self.collate, &base.collate);
     }
+    if mask & locale::LC_CTYPE_MASK == 0 {
+      copy_slot(&mut self.ctype, &base.ctype);
+    }
+    if mask & locale::LC_IDENTIFICATION_MASK == 0 {
+      copy_slot(&mut self.identification, &base.identification);
+    }
+    if mask & locale::LC_MEASUREMENT_MASK == 0 {
+      copy_slot(&mut self.measurement, &base.measurement);
+    }
+    if mask & locale::LC_MESSAGES_MASK == 0 {
+      copy_slot(&mut self.messages, &base.messages);
+    }
+    if mask & locale::LC_MONETARY_MASK == 0 {
+      copy_slot(&mut self.monetary, &base.monetary);
+    }
+    if mask & locale::LC_NAME_MASK == 0 {
+      copy_slot(&mut self.name, &base.name);
+    }
+    if mask & locale::LC_NUMERIC_MASK == 0 {
+      copy_slot(&mut self.numeric, &base.numeric);
+    }
+    if mask & locale::LC_PAPER_MASK == 0 {
+      copy_slot(&mut self.paper, &base.paper);
+    }
+    if mask & locale::LC_TELEPHONE_MASK == 0 {
+      copy_slot(&mut self.telephone, &base.telephone);
+    }
+    if mask & locale::LC_TIME_MASK == 0 {
+      copy_slot(&mut self.time, &base.time);
+    }
+    self.lc_all = OnceBox::new();
+    self.localeconv = OnceBox::new();
   }
 
   pub fn querylocale(
     &self,
     category: c_int
   ) -> *mut c_char {
-    let collate = unsafe { ffi::CStr::from_ptr(get_slot_name(&self.collate)) };
-    let ctype = unsafe { ffi::CStr::from_ptr(get_slot_name(&self.ctype)) };
-    let messages =
-      unsafe { ffi::CStr::from_ptr(get_slot_name(&self.messages)) };
-    let monetary =
-      unsafe { ffi::CStr::from_ptr(get_slot_name(&self.monetary)) };
-    let numeric = unsafe { ffi::CStr::from_ptr(get_slot_name(&self.numeric)) };
-    let time = unsafe { ffi::CStr::from_ptr(get_slot_name(&self.time)) };
+    let get_name = |ptr| unsafe { ffi::CStr::from_ptr(ptr) };
+    let address = get_name(get_slot_name(&self.address));
+    let collate = get_name(get_slot_name(&self.collate));
+    let ctype = get_name(get_slot_name(&self.ctype));
+    let identification = get_name(get_slot_name(&self.identification));
+    let measurement = get_name(get_slot_name(&self.measurement));
+    let messages = get_name(get_slot_name(&self.messages));
+    let monetary = get_name(get_slot_name(&self.monetary));
+    let name = get_name(get_slot_name(&self.name));
+    let numeric = get_name(get_slot_name(&self.numeric));
+    let paper = get_name(get_slot_name(&self.paper));
+    let telephone = get_name(get_slot_name(&self.telephone));
+    let time = get_name(get_slot_name(&self.time));
 
     match category {
       | locale::LC_ALL => {
-        let names = [collate, ctype, monetary, numeric, messages, time];
-        if names.windows(2).all(|w| w[0] == w[1]) {
+        let cats: [(&'static str, &ffi::CStr); 12] = [
+          ("LC_ADDRESS", address),
+          ("LC_COLLATE", collate),
+          ("LC_CTYPE", ctype),
+          ("LC_IDENTIFICATION", identification),
+          ("LC_MEASUREMENT", measurement),
+          ("LC_MESSAGES", messages),
+          ("LC_MONETARY", monetary),
+          ("LC_NAME", name),
+          ("LC_NUMERIC", numeric),
+          ("LC_PAPER", paper),
+          ("LC_TELEPHONE", telephone),
+          ("LC_TIME", time)
+        ];
+        if cats.windows(2).all(|w| w[0].1 == w[1].1) {
           return collate.as_ptr().cast_mut();
         }
 
-        let mut buf = self.lc_all.borrow_mut();
-        buf.fill(0);
+        if let Some(buf) = self.lc_all.get() {
+          return buf.as_ptr().cast_mut();
+        }
 
+        let mut buf = [0; 1024];
         let mut ss = crate::support::string::StringStream::new(&mut buf[..]);
 
-        let cats: [(&'static str, &ffi::CStr, bool); 6] = [
-          ("LC_COLLATE", collate, true),
-          ("LC_CTYPE", ctype, true),
-          ("LC_MESSAGES", messages, true),
-          ("LC_MONETARY", monetary, true),
-          ("LC_NUMERIC", numeric, true),
-          ("LC_TIME", time, false)
-        ];
-
-        for (label, val, with_sep) in cats {
+        for (i, (label, val)) in cats.into_iter().enumerate() {
+          let with_sep = i + 1 < cats.len();
           if writer_name_to_category(&mut ss, label, val, with_sep).is_err() {
             return ptr::null_mut();
           }
         }
 
         let trimmed_size: usize = buf.iter().filter(|&x| *x != 0).count() + 1;
-        let output = &mut buf[..trimmed_size];
-
-        if output[trimmed_size - 1] as u8 != b'\0' {
+        if trimmed_size > buf.len() {
           return ptr::null_mut();
         }
 
-        output.as_mut_ptr().cast()
+        // Another thread may have made the same name first
+        let _ = self.lc_all.set(Box::new(buf));
+        self.lc_all.get().map_or(ptr::null_mut(), |buf| buf.as_ptr().cast_mut())
       },
+      | locale::LC_ADDRESS => address.as_ptr().cast_mut(),
       | locale::LC_COLLATE => collate.as_ptr().cast_mut(),
       | locale::LC_CTYPE => ctype.as_ptr().cast_mut(),
+      | locale::LC_IDENTIFICATION => identification.as_ptr().cast_mut(),
+      | locale::LC_MEASUREMENT => measurement.as_ptr().cast_mut(),
       | locale::LC_MESSAGES => messages.as_ptr().cast_mut(),
       | locale::LC_MONETARY => monetary.as_ptr().cast_mut(),
+      | locale::LC_NAME => name.as_ptr().cast_mut(),
       | locale::LC_NUMERIC => numeric.as_ptr().cast_mut(),
+      | locale::LC_PAPER => paper.as_ptr().cast_mut(),
+      | locale::LC_TELEPHONE => telephone.as_ptr().cast_mut(),
       | locale::LC_TIME => time.as_ptr().cast_mut(),
       | _ => ptr::null_mut()
     }
@@ -264,38 +509,76 @@ pub struct SyncLocale {
 }
 unsafe impl Sync for SyncLocale {}
 
-pub static GLOBAL_LOCALE: SyncLocale = SyncLocale {
+static INITIAL_GLOBAL_LOCALE: SyncLocale = SyncLocale {
   inner: UnsafeCell::new(Locale {
-    lc_all: AtomicRefCell::new([0; 1024]),
-    localeconv: AtomicRefCell::new(unsafe { core::mem::zeroed() }),
+    lc_all: OnceBox::new(),
+    localeconv: OnceBox::new(),
+    address: AtomicRefCell::new(None),
     collate: AtomicRefCell::new(None),
     ctype: AtomicRefCell::new(None),
+    identification: AtomicRefCell::new(None),
+    measurement: AtomicRefCell::new(None),
     messages: AtomicRefCell::new(None),
     monetary: AtomicRefCell::new(None),
+    name: AtomicRefCell::new(None),
     numeric: AtomicRefCell::new(None),
+    paper: AtomicRefCell::new(None),
+    telephone: AtomicRefCell::new(None),
     time: AtomicRefCell::new(None)
   })
 };
 
 pub static DEFAULT_LOCALE: SyncLocale = SyncLocale {
   inner: UnsafeCell::new(Locale {
-    lc_all: AtomicRefCell::new([0; 1024]),
-    localeconv: AtomicRefCell::new(unsafe { core::mem::zeroed() }),
+    lc_all: OnceBox::new(),
+    localeconv: OnceBox::new(),
+    address: AtomicRefCell::new(Some(address::DEFAULT_ADDRESS)),
     collate: AtomicRefCell::new(Some(collate::DEFAULT_COLLATE)),
     ctype: AtomicRefCell::new(Some(ctype::DEFAULT_CTYPE)),
+    identification: AtomicRefCell::new(Some(
+      identification::DEFAULT_IDENTIFICATION
+    )),
+    measurement: AtomicRefCell::new(Some(measurement::DEFAULT_MEASUREMENT)),
     messages: AtomicRefCell::new(Some(messages::DEFAULT_MESSAGES)),
     monetary: AtomicRefCell::new(Some(monetary::DEFAULT_MONETARY)),
+    name: AtomicRefCell::new(Some(name::DEFAULT_NAME)),
     numeric: AtomicRefCell::new(Some(numeric::DEFAULT_NUMERIC)),
+    paper: AtomicRefCell::new(Some(paper::DEFAULT_PAPER)),
+    telephone: AtomicRefCell::new(Some(telephone::DEFAULT_TELEPHONE)),
     time: AtomicRefCell::new(Some(time::DEFAULT_TIME))
   })
 };
 
+/// The global locale. setlocale publishes every change as a new version,
+/// so that the other threads can keep reading the one they started with.
+/// Versions are never freed, so the pointers that setlocale, localeconv and
+/// nl_langinfo return into them stay valid for the life of the process.
+/// A name set again reuses its earlier version, see [`is_same_locale`].
+pub static GLOBAL_LOCALE: Rcu<Locale<'static>> =
+  Rcu::new(unsafe { &*INITIAL_GLOBAL_LOCALE.inner.get() });
+
+/// Checks whether two locales have the same name in every category. Each
+/// name is loaded once and its version kept, so setting the same names
+/// again publishes the earlier version instead of allocating a new one.
+pub fn is_same_locale(
+  lhs: &Locale,
+  rhs: &Locale
+) -> bool {
+  let (lhs, rhs) =
+    (lhs.querylocale(locale::LC_ALL), rhs.querylocale(locale::LC_ALL));
+  if lhs.is_null() || rhs.is_null() {
+    return false;
+  }
+
+  unsafe { ffi::CStr::from_ptr(lhs) == ffi::CStr::from_ptr(rhs) }
+}
+
 #[inline(always)]
 pub fn get_real_locale(locale: locale_t<'static>) -> &'static Locale<'static> {
   unsafe {
     match locale as intptr_t {
       | 0 => &*DEFAULT_LOCALE.inner.get(),
-      | -1 => &*GLOBAL_LOCALE.inner.get(),
+      | -1 => GLOBAL_LOCALE.read(),
       | _ => &*locale
     }
   }
@@ -0,0 +1,377 @@
+use {
+  super::{MonetaryObject, static_data},
+  crate::{
+    allocation::{
+      borrow::ToOwned,
+      string::{String, ToString}
+    },
+    c_char,
+    c_int,
+    support::{
+      locale::{
+        canonicalize_locale,
+        errno,
+        get_modifier_keywords,
+        numeric::{
+          get_decimal_point,
+          get_thousands_sep,
+          icu::{apply_numbering_system, get_posix_grouping}
+        }
+      },
+      string::strtocstr
+    }
+  },
+  allocation::borrow::Cow,
+  core::ffi,
+  icu_decimal::{DecimalFormatter, input::Decimal},
+  icu_experimental::dimension::provider::currency::essentials::{
+    Baked,
+    CurrencyEssentialsV1,
+    PatternSelection,
+    PlaceholderValue
+  },
+  icu_locale::{Locale, extensions::unicode::key},
+  icu_pattern::{DoublePlaceholderKey, PatternItem},
+  icu_provider::{
+    DataIdentifierBorrowed,
+    DataLocale,
+    DataProvider,
+    DataRequest,
+    DataResponse
+  },
+  smallvec::SmallVec,
+  tinystr::*
+};
+
+#[inline]
+fn is_bidi_control(c: char) -> bool {
+  matches!(
+    c,
+    '\u{061C}' |
+      '\u{200E}' |
+      '\u{200F}' |
+      '\u{202A}' |
+      '\u{202B}' |
+      '\u{202C}' |
+      '\u{202D}' |
+      '\u{202E}' |
+      '\u{2066}' |
+      '\u{2067}' |
+      '\u{2068}' |
+      '\u{2069}'
+  )
+}
+
+/// Returns the region of a locale name such as "de_CH.UTF-8", which is the
+/// first subtag of two capital letters or three digits. The region selects
+/// the currency and its digits from the tables in static_data.rs.
+pub fn extract_region(locale: &str) -> Option<String> {
+  let core = locale.split(['.', '@']).next().unwrap_or(locale);
+  for part in core.split(['-', '_']) {
+    if part.len() == 2 && part.chars().all(|c| c.is_uppercase()) {
+      return Some(part.to_string());
+    }
+    if part.len() == 3 && part.chars().all(|c| c.is_numeric()) {
+      return Some(part.to_string());
+    }
+  }
+  None
+}
+
+/// The parts of a currency pattern that matter to POSIX.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Part {
+  Currency,
+  Number,
+  Sign,
+  Space,
+  Parenthesis
+}
+
+/// Where a subpattern puts the currency symbol, the space and the sign, in
+/// the terms of the cs_precedes, sep_by_space and sign_posn fields.
+#[derive(Debug, Clone, Copy)]
+struct Layout {
+  cs_precedes: c_char,
+  sep_by_space: c_char,
+  sign_posn: c_char
+}
+
+/// Lays out a subpattern written as in CLDR, with "¤" for the currency
+/// symbol, "#" for the number and "-" for the sign.
+fn get_layout(pattern: &str) -> Option<Layout> {
+  let mut parts = SmallVec::<[Part; 8]>::new();
+  for c in pattern.chars() {
+    let part = match c {
+      | '¤' => Part::Currency,
+      | '#' => Part::Number,
+      | '-' => Part::Sign,
+      | '(' | ')' => Part::Parenthesis,
+      | c if c.is_whitespace() => Part::Space,
+      | _ => continue
+    };
+    if parts.last() != Some(&part) {
+      parts.push(part);
+    }
+  }
+
+  let position = |part| parts.iter().position(|&p| p == part);
+  let currency = position(Part::Currency)?;
+  let number = position(Part::Number)?;
+  let spaced =
+    |a: usize, b: usize| parts[a.min(b)..a.max(b)].contains(&Part::Space);
+
+  let cs_precedes = currency < number;
+  let (first, last) = (currency.min(number), currency.max(number));
+
+  // Parentheses take the place of the sign
+  if position(Part::Parenthesis).is_some() {
+    return Some(Layout {
+      cs_precedes: cs_precedes as c_char,
+      sep_by_space: spaced(currency, number) as c_char,
+      sign_posn: 0
+    });
+  }
+  let sign = position(Part::Sign)?;
+
+  let sign_posn = if sign < first {
+    1
+  } else if sign > last {
+    2
+  } else if cs_precedes {
+    4
+  } else {
+    3
+  };
+
+  // 1 is a space before the value, which a sign between the symbol and the
+  // value joins, and 2 a space between the sign and what it is next to
+  let sep_by_space = if first < sign && sign < last {
+    if spaced(sign, currency) {
+      2
+    } else if spaced(sign, number) {
+      1
+    } else {
+      0
+    }
+  } else if spaced(currency, number) {
+    1
+  } else if spaced(sign, if sign < first { first } else { last }) {
+    2
+  } else {
+    0
+  };
+
+  Some(Layout { cs_precedes: cs_precedes as c_char, sep_by_space, sign_posn })
+}
+
+/// Returns the positive subpattern of the standard currency format for a
+/// currency, in the notation of [`get_layout`], and the currency symbol.
+fn get_currency_pattern(
+  locale: &Locale,
+  currency: TinyAsciiStr<3>
+) -> Result<(String, String), c_int> {
+  let locale = DataLocale::from(locale);
+  let response: DataResponse<CurrencyEssentialsV1> = Baked
+    .load(DataRequest {
+      id: DataIdentifierBorrowed::for_locale(&locale),
+      ..Default::default()
+    })
+    .map_err(|_| errno::ENOENT)?;
+  let essentials = response.payload.get();
+
+  let config = essentials
+    .pattern_config_map
+    .get_copied(&currency.to_unvalidated())
+    .unwrap_or(essentials.default_pattern_config);
+
+  let symbol = match config.short_placeholder_value {
+    | Some(PlaceholderValue::Index(index)) => {
+      essentials.placeholders.get(index.into()).ok_or(errno::ENOENT)?
+    },
+    | Some(PlaceholderValue::ISO) | None => currency.as_str()
+  };
+
+  let pattern = match config.short_pattern_selection {
+    | PatternSelection::Standard => essentials.standard_pattern.as_ref(),
+    | PatternSelection::StandardAlphaNextToNumber => {
+      essentials.standard_alpha_next_to_number_pattern.as_ref()
+    },
+  }
+  .ok_or(errno::ENOENT)?;
+
+  let mut result = String::new();
+  for item in pattern.iter() {
+    match item {
+      | PatternItem::Placeholder(DoublePlaceholderKey::Place0) => {
+        result.push('#')
+      },
+      | PatternItem::Placeholder(DoublePlaceholderKey::Place1) => {
+        result.push('¤')
+      },
+      | PatternItem::Literal(literal) => result.push_str(literal)
+    }
+  }
+
+  Ok((result, symbol.chars().filter(|&c| !is_bidi_control(c)).collect()))
+}
+
+/// Checks for the accounting currency format, asked for with "-u-cf-account"
+/// or "@cf=account".
+fn is_accounting(
+  locale: &Locale,
+  name: &str
+) -> bool {
+  let cf = locale
+    .extensions
+    .unicode
+    .keywords
+    .get(&key!("cf"))
+    .and_then(|value| value.as_single_subtag())
+    .map(|subtag| subtag.as_str());
+
+  cf == Some("account") ||
+    get_modifier_keywords(name).any(|(key, value)| {
+      key.eq_ignore_ascii_case("cf") && value == Some("account")
+    })
+}
+
+/// Returns the currency asked for with "@currency=EUR", "@euro" or
+/// "-u-cu-eur", which takes the place of the currency of the region.
+fn get_currency_override(
+  locale: &Locale,
+  name: &str
+) -> Result<Option<TinyAsciiStr<3>>, c_int> {
+  let mut currency = locale
+    .extensions
+    .unicode
+    .keywords
+    .get(&key!("cu"))
+    .and_then(|value| value.as_single_subtag())
+    .map(|subtag| subtag.as_str());
+
+  for (key, value) in get_modifier_keywords(name) {
+    match (key.to_ascii_lowercase().as_str(), value) {
+      | ("currency" | "cu", Some(value)) => currency = Some(value),
+      | ("euro", None) => currency = Some("EUR"),
+      | _ => {}
+    }
+  }
+
+  let Some(currency) = currency else {
+    return Ok(None);
+  };
+  let currency = TinyAsciiStr::<3>::try_from_str(currency)
+    .ok()
+    .filter(|currency| currency.is_ascii_alphabetic())
+    .ok_or(errno::ENOENT)?;
+
+  Ok(Some(currency.to_ascii_uppercase()))
+}
+
+fn construct_iso4217_currency_symbol(s: &str) -> SmallVec<[u8; 5]> {
+  let sb = s.as_bytes();
+  let mut result: SmallVec<[u8; 5]> = SmallVec::new();
+  result.extend_from_slice(&[sb[0], sb[1], sb[2], b' ', b'\0']);
+  result
+}
+
+/// Fills in a monetary object from the ICU data of a locale.
+pub fn load(
+  monetary: &mut MonetaryObject,
+  locale: &ffi::CStr,
+  lang: &str
+) -> Result<(), c_int> {
+  let icu_locale_name = canonicalize_locale(lang);
+
+  let mut icu_locale = Locale::try_from_str(&icu_locale_name.replace("_", "-"))
+    .map_err(|_| errno::ENOENT)?;
+
+  let name = locale.to_str().map_err(|_| errno::ENOENT)?;
+  apply_numbering_system(&mut icu_locale, lang, name)?;
+
+  let mon_grouping = get_posix_grouping(&icu_locale)?;
+
+  let formatter =
+    DecimalFormatter::try_new(icu_locale.clone().into(), Default::default())
+      .map_err(|_| errno::ENOENT)?;
+
+  let mut frac = Decimal::from(1234);
+  frac.multiply_pow10(-2);
+  let s_frac = formatter.format(&frac);
+  let s_frac = s_frac.to_string();
+
+  let big = Decimal::from(1234567890123u128);
+  let s_int = formatter.format(&big);
+  let s_int = s_int.to_string();
+
+  let mon_decimal_point = get_decimal_point(&s_frac).ok_or(errno::ENOENT)?;
+  // Locales that never group digits have neither a separator nor a grouping
+  let mon_thousands_sep = get_thousands_sep(&s_int).unwrap_or_default();
+
+  let region = extract_region(lang);
+
+  let (currency_code, frac_digits) =
+    match get_currency_override(&icu_locale, name)? {
+      | Some(currency) => {
+        (currency, static_data::get_currency_frac_digits(&currency))
+      },
+      | None => {
+        let currency =
+          static_data::get_iso4217_currency_from_region(region.clone())
+            .ok_or(errno::ENOENT)?;
+        let currency = TinyAsciiStr::<3>::try_from_str(currency)
+          .map_err(|_| errno::ENOENT)?;
+
+        (currency, static_data::get_frac_digits(lang))
+      }
+    };
+
+  let int_curr_symbol = construct_iso4217_currency_symbol(&currency_code);
+
+  let (positive, currency_symbol) =
+    get_currency_pattern(&icu_locale, currency_code)?;
+
+  // Without an explicit negative subpattern CLDR puts the minus sign in
+  // front of the positive one, and a plus sign would go to the same place
+  let mut implicit = String::from("-");
+  implicit.push_str(&positive);
+
+  let language = lang.split(['_', '-']).next().unwrap_or(lang);
+  let region = region.unwrap_or_default();
+  let negative = if is_accounting(&icu_locale, name) {
+    static_data::get_accounting_currency_pattern(language, &region)
+  } else {
+    None
+  }
+  .or_else(|| static_data::get_negative_currency_pattern(language, &region))
+  .unwrap_or(&implicit);
+
+  let p = get_layout(&implicit).ok_or(errno::ENOENT)?;
+  let n = get_layout(negative).ok_or(errno::ENOENT)?;
+
+  monetary.name = Cow::Owned(locale.to_owned());
+  monetary.mon_decimal_point = strtocstr(&mon_decimal_point);
+  monetary.mon_thousands_sep = strtocstr(&mon_thousands_sep);
+  monetary.mon_grouping = mon_grouping;
+  monetary.positive_sign = Cow::Borrowed(c"");
+  monetary.negative_sign = Cow::Borrowed(c"-");
+  monetary.frac_digits = frac_digits;
+  monetary.int_frac_digits = frac_digits;
+  monetary.currency_symbol = strtocstr(&currency_symbol);
+  monetary.int_curr_symbol = int_curr_symbol;
+  monetary.p_sign_posn = p.sign_posn;
+  monetary.n_sign_posn = n.sign_posn;
+  monetary.p_cs_precedes = p.cs_precedes;
+  monetary.n_cs_precedes = n.cs_precedes;
+  monetary.p_sep_by_space = p.sep_by_space;
+  monetary.n_sep_by_space = n.sep_by_space;
+  monetary.int_p_sign_posn = p.sign_posn;
+  monetary.int_n_sign_posn = n.sign_posn;
+  monetary.int_p_cs_precedes = p.cs_precedes;
+  monetary.int_n_cs_precedes = n.cs_precedes;
+  monetary.int_p_sep_by_space = p.sep_by_space;
+  monetary.int_n_sep_by_space = n.sep_by_space;
+
+  Ok(())
+}
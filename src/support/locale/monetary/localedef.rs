@@ -0,0 +1,73 @@
+use {
+  super::MonetaryObject,
+  crate::{
+    allocation::borrow::ToOwned,
+    c_int,
+    std::errno,
+    support::locale::localedef::{self, Source}
+  },
+  allocation::borrow::Cow,
+  core::ffi
+};
+
+pub fn load(
+  monetary: &mut MonetaryObject,
+  locale: &ffi::CStr,
+  source: &Source
+) -> Result<(), c_int> {
+  let section = source.section("LC_MONETARY");
+  localedef::load_base(monetary, locale, source, section)?;
+
+  if let Some(section) = section {
+    let strings = [
+      ("currency_symbol", &mut monetary.currency_symbol),
+      ("mon_decimal_point", &mut monetary.mon_decimal_point),
+      ("mon_thousands_sep", &mut monetary.mon_thousands_sep),
+      ("positive_sign", &mut monetary.positive_sign),
+      ("negative_sign", &mut monetary.negative_sign)
+    ];
+    for (keyword, field) in strings {
+      if let Some(s) = section.string(keyword) {
+        *field = localedef::to_cstr(s)?;
+      }
+    }
+
+    let numbers = [
+      ("int_frac_digits", &mut monetary.int_frac_digits),
+      ("frac_digits", &mut monetary.frac_digits),
+      ("p_cs_precedes", &mut monetary.p_cs_precedes),
+      ("p_sep_by_space", &mut monetary.p_sep_by_space),
+      ("n_cs_precedes", &mut monetary.n_cs_precedes),
+      ("n_sep_by_space", &mut monetary.n_sep_by_space),
+      ("p_sign_posn", &mut monetary.p_sign_posn),
+      ("n_sign_posn", &mut monetary.n_sign_posn),
+      ("int_p_cs_precedes", &mut monetary.int_p_cs_precedes),
+      ("int_p_sep_by_space", &mut monetary.int_p_sep_by_space),
+      ("int_n_cs_precedes", &mut monetary.int_n_cs_precedes),
+      ("int_n_sep_by_space", &mut monetary.int_n_sep_by_space),
+      ("int_p_sign_posn", &mut monetary.int_p_sign_posn),
+      ("int_n_sign_posn", &mut monetary.int_n_sign_posn)
+    ];
+    for (keyword, field) in numbers {
+      if let Some(n) = section.char_number(keyword) {
+        *field = n;
+      }
+    }
+
+    if let Some(mon_grouping) = section.grouping("mon_grouping") {
+      monetary.mon_grouping = mon_grouping;
+    }
+    if let Some(int_curr_symbol) = section.string("int_curr_symbol") {
+      if int_curr_symbol.contains('\0') {
+        return Err(errno::ENOENT);
+      }
+      monetary.int_curr_symbol.clear();
+      monetary.int_curr_symbol.extend_from_slice(int_curr_symbol.as_bytes());
+      monetary.int_curr_symbol.push(b'\0');
+    }
+  }
+
+  monetary.name = Cow::Owned(locale.to_owned());
+
+  Ok(())
+}
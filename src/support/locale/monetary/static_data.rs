@@ -1,5 +1,11 @@
+// ICU4X has no data for the currency of a region, the digits of a currency,
+// or the negative and accounting currency patterns, so these are static
+// approximations kept by hand. They only know the regions and locales listed
+// here, and do not follow CLDR updates on their own.
+
 use crate::{allocation::string::String, c_char};
 
+/// Returns the currency of a region, as the glibc locales give it.
 pub fn get_iso4217_currency_from_region(
   region: Option<String>
 ) -> Option<&'static str> {
@@ -166,6 +172,10 @@ pub fn get_iso4217_currency_from_region(
   })
 }
 
+/// Returns the number of fractional digits of the currency of a locale, as
+/// the glibc locales give it for its region. IS, JP, KR, IR, AF, VN and ER
+/// use 0, the Arab states listed below, BT and AL use 3, and every other
+/// region 2.
 pub fn get_frac_digits(locale: &str) -> c_char {
   // https://lh.2xlibre.net/values/frac_digits/
   const ZERO_FRAC: &[&str] = &["IS", "JP", "KR", "IR", "AF", "VN", "ER"];
@@ -174,7 +184,7 @@ pub fn get_frac_digits(locale: &str) -> c_char {
     "SD", "SS", "SY", "TN", "YE", "BT", "AL"
   ];
 
-  let region = super::extract_region(locale).unwrap_or_default();
+  let region = super::icu::extract_region(locale).unwrap_or_default();
   if ZERO_FRAC.contains(&region.as_str()) {
     0
   } else if THREE_FRAC.contains(&region.as_str()) {
@@ -183,3 +193,64 @@ pub fn get_frac_digits(locale: &str) -> c_char {
     2
   }
 }
+
+/// Returns the minor units of an ISO 4217 currency, for currencies asked for
+/// by name rather than taken from the region.
+pub fn get_currency_frac_digits(currency: &str) -> c_char {
+  match currency {
+    | "BIF" | "CLP" | "DJF" | "GNF" | "ISK" | "JPY" | "KMF" | "KRW" |
+    "PYG" | "RWF" | "UGX" | "UYI" | "VND" | "VUV" | "XAF" | "XOF" | "XPF" => 0,
+    | "BHD" | "IQD" | "JOD" | "KWD" | "LYD" | "OMR" | "TND" => 3,
+    | "CLF" | "UYW" => 4,
+    | _ => 2
+  }
+}
+
+// Currency patterns are written as in CLDR, with "#" standing for the whole
+// number. Bidi marks are left out, they do not change the layout.
+
+/// Returns the explicit negative subpattern of the CLDR standard currency
+/// format of a locale. The ICU4X data only has the positive subpattern, which
+/// is enough for the others: their negative one is the minus sign followed by
+/// the positive one. The table is copied from CLDR and covers de_CH, en_CH,
+/// it_CH, es_CL, es_EC, es_VE, es_PY and every nl locale.
+pub fn get_negative_currency_pattern(
+  language: &str,
+  region: &str
+) -> Option<&'static str> {
+  Some(match (language, region) {
+    | ("de" | "en" | "it", "CH") | ("es", "CL" | "EC" | "VE") => "¤-#",
+    | ("es", "PY") | ("nl", _) => "¤\u{a0}-#",
+    | _ => return None
+  })
+}
+
+/// Returns the negative subpattern of the CLDR accounting currency format of
+/// a locale, for the locales that put negative amounts in parentheses. The
+/// table is copied from CLDR and covers the languages and regions matched
+/// below; every other locale keeps its standard negative subpattern.
+pub fn get_accounting_currency_pattern(
+  language: &str,
+  region: &str
+) -> Option<&'static str> {
+  Some(match (language, region) {
+    | ("en", "AT" | "CH" | "DE" | "DK" | "FI" | "SE") => return None,
+    | ("en", "SI") => "(#\u{a0}¤)",
+    | ("zh", "SG") => return None,
+    | ("ar", "DZ" | "LY" | "MA" | "TN") |
+    ("es", "DO") |
+    (
+      "cy" | "en" | "ga" | "ja" | "ko" | "ms" | "th" | "tr" | "ur" | "yue" |
+      "zh",
+      _
+    ) => "(¤#)",
+    | ("es", "AR" | "UY") | ("nl", _) => "(¤\u{a0}#)",
+    | ("pt", "BR") => return None,
+    | (
+      "bg" | "ca" | "et" | "eu" | "fr" | "pl" | "pt" | "ro" | "sk" | "sl" |
+      "sq" | "sr",
+      _
+    ) => "(#\u{a0}¤)",
+    | _ => return None
+  })
+}
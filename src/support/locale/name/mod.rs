@@ -0,0 +1,74 @@
+use {
+  super::{LocaleObject, is_posix_locale, territory},
+  crate::{allocation::borrow::ToOwned, c_int, support::locale::errno},
+  allocation::borrow::Cow,
+  core::ffi
+};
+
+/// The name format uses the escapes of ISO/IEC 14652, such as "%g" for the
+/// given name and "%f" for the family names.
+#[derive(Debug, Clone)]
+pub struct NameObject<'a> {
+  name: Cow<'a, ffi::CStr>,
+  pub name_fmt: &'static ffi::CStr,
+  pub name_gen: &'static ffi::CStr,
+  pub name_mr: &'static ffi::CStr,
+  pub name_mrs: &'static ffi::CStr,
+  pub name_miss: &'static ffi::CStr,
+  pub name_ms: &'static ffi::CStr
+}
+
+impl<'a> LocaleObject for NameObject<'a> {
+  fn setlocale(
+    &mut self,
+    locale: &ffi::CStr
+  ) -> Result<&ffi::CStr, c_int> {
+    let name = locale.to_str().map_err(|_| errno::ENOENT)?;
+
+    if is_posix_locale(name) {
+      return Ok(self.set_to_posix());
+    }
+
+    let language = territory::get_language(name).ok_or(errno::ENOENT)?;
+
+    // CLDR has no name formats, so they come from the glibc locales, and the
+    // salutations stay those of the POSIX locale
+    *self = DEFAULT_NAME;
+    let region = territory::get_region(name);
+    if let Some(formats) = region
+      .as_deref()
+      .and_then(|region| territory::find_formats(&language, region))
+    {
+      self.name_fmt = formats.name_fmt;
+    }
+    self.name = Cow::Owned(locale.to_owned());
+
+    Ok(self.name.as_ref())
+  }
+
+  fn set_to_posix(&mut self) -> &ffi::CStr {
+    *self = DEFAULT_NAME;
+
+    self.name.as_ref()
+  }
+
+  fn get_name(&self) -> &ffi::CStr {
+    self.name.as_ref()
+  }
+}
+
+impl<'a> Default for NameObject<'a> {
+  fn default() -> Self {
+    DEFAULT_NAME
+  }
+}
+
+pub const DEFAULT_NAME: NameObject = NameObject {
+  name: Cow::Borrowed(c"C"),
+  name_fmt: c"%p%t%g%t%m%t%f",
+  name_gen: c"",
+  name_mr: c"",
+  name_mrs: c"",
+  name_miss: c"",
+  name_ms: c""
+};
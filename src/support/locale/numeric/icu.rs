@@ -0,0 +1,165 @@
+use {
+  super::{NumericObject, encode_digits, get_decimal_point, get_thousands_sep},
+  crate::{
+    allocation::borrow::ToOwned,
+    c_int,
+    support::{
+      locale::{canonicalize_locale, errno, get_modifier_keywords},
+      string::strtocstr
+    }
+  },
+  allocation::borrow::Cow,
+  core::ffi,
+  icu_decimal::{
+    DecimalFormatter,
+    input::Decimal,
+    provider::{Baked, DecimalDigitsV1, DecimalSymbolsV1}
+  },
+  icu_locale::{
+    Locale,
+    extensions::unicode::{Value, key},
+    locale
+  },
+  icu_provider::{
+    DataIdentifierBorrowed,
+    DataLocale,
+    DataMarkerAttributes,
+    DataProvider,
+    DataRequest,
+    DataResponse
+  },
+  smallvec::SmallVec
+};
+
+/// Returns the POSIX grouping of a locale from the primary and secondary
+/// group sizes of its CLDR decimal pattern, such as "\x03\x02" for the
+/// "#,##,##0" of Indian locales. Locales that never group get an empty one.
+pub fn get_posix_grouping(locale: &Locale) -> Result<SmallVec<[u8; 3]>, c_int> {
+  let locale = DataLocale::from(locale);
+  let response: DataResponse<DecimalSymbolsV1> = Baked
+    .load(DataRequest {
+      id: DataIdentifierBorrowed::for_locale(&locale),
+      ..Default::default()
+    })
+    .map_err(|_| errno::ENOENT)?;
+  let sizes = response.payload.get().grouping_sizes;
+
+  let mut grouping = SmallVec::new();
+  if sizes.primary == 0 {
+    return Ok(grouping);
+  }
+
+  // A secondary size of 0 repeats the primary one
+  let secondary =
+    if sizes.secondary == 0 { sizes.primary } else { sizes.secondary };
+  grouping.extend_from_slice(&[sizes.primary, secondary, b'\0']);
+
+  Ok(grouping)
+}
+
+/// Applies the numbering system asked for with "@numbers=arab" or
+/// "-u-nu-arab" to the locale used for the separators, and returns it.
+pub fn apply_numbering_system(
+  locale: &mut Locale,
+  lang: &str,
+  name: &str
+) -> Result<Option<Value>, c_int> {
+  let mut numbering_system =
+    Locale::try_from_str(&lang.replace("_", "-")).ok().and_then(|locale| {
+      locale.extensions.unicode.keywords.get(&key!("nu")).cloned()
+    });
+
+  for (keyword, value) in get_modifier_keywords(name) {
+    if keyword.eq_ignore_ascii_case("numbers") ||
+      keyword.eq_ignore_ascii_case("nu")
+    {
+      let value = value.ok_or(errno::ENOENT)?;
+      let value = Value::try_from_str(value).map_err(|_| errno::ENOENT)?;
+      numbering_system = Some(value);
+    }
+  }
+
+  if let Some(value) = &numbering_system {
+    locale.extensions.unicode.keywords.set(key!("nu"), value.clone());
+  }
+
+  Ok(numbering_system)
+}
+
+/// Returns the digits of a numbering system, or of the default numbering
+/// system of the locale, such as the Arabic-Indic digits of "ar-EG".
+pub fn get_digits(
+  locale: &Locale,
+  numbering_system: Option<&Value>
+) -> Result<[char; 10], c_int> {
+  let data_locale = DataLocale::from(locale);
+  let symbols: DataResponse<DecimalSymbolsV1> = Baked
+    .load(DataRequest {
+      id: DataIdentifierBorrowed::for_locale(&data_locale),
+      ..Default::default()
+    })
+    .map_err(|_| errno::ENOENT)?;
+
+  let numbering_system = match numbering_system {
+    | Some(value) => value.as_single_subtag().ok_or(errno::ENOENT)?.as_str(),
+    | None => symbols.payload.get().numsys()
+  };
+  let attributes = DataMarkerAttributes::try_from_str(numbering_system)
+    .map_err(|_| errno::ENOENT)?;
+
+  // Digits are stored for the root locale only
+  let digits: DataResponse<DecimalDigitsV1> = Baked
+    .load(DataRequest {
+      id: DataIdentifierBorrowed::for_marker_attributes_and_locale(
+        attributes,
+        &locale!("und").into()
+      ),
+      ..Default::default()
+    })
+    .map_err(|_| errno::ENOENT)?;
+
+  Ok(*digits.payload.get())
+}
+
+/// Fills in a numeric object from the ICU data of a locale.
+pub fn load(
+  numeric: &mut NumericObject,
+  locale: &ffi::CStr,
+  lang: &str
+) -> Result<(), c_int> {
+  let icu_locale_name = canonicalize_locale(lang);
+
+  let mut icu_locale = Locale::try_from_str(&icu_locale_name.replace("_", "-"))
+    .map_err(|_| errno::ENOENT)?;
+
+  let name = locale.to_str().map_err(|_| errno::ENOENT)?;
+  let numbering_system = apply_numbering_system(&mut icu_locale, lang, name)?;
+  let digits = get_digits(&icu_locale, numbering_system.as_ref())?;
+
+  let grouping = get_posix_grouping(&icu_locale)?;
+
+  let formatter =
+    DecimalFormatter::try_new(icu_locale.into(), Default::default())
+      .map_err(|_| errno::ENOENT)?;
+
+  let mut frac = Decimal::from(1234);
+  frac.multiply_pow10(-2);
+  let s_frac = formatter.format(&frac);
+  let s_frac = s_frac.to_string();
+
+  let big = Decimal::from(1234567890123u128);
+  let s_int = formatter.format(&big);
+  let s_int = s_int.to_string();
+
+  let decimal_point = get_decimal_point(&s_frac).ok_or(errno::ENOENT)?;
+  // Locales that never group digits have neither a separator nor a grouping
+  let thousands_sep = get_thousands_sep(&s_int).unwrap_or_default();
+
+  numeric.name = Cow::Owned(locale.to_owned());
+  numeric.decimal_point = strtocstr(&decimal_point);
+  numeric.thousands_sep = strtocstr(&thousands_sep);
+  numeric.grouping = grouping;
+  numeric.digits = encode_digits(digits);
+
+  Ok(())
+}
@@ -0,0 +1,35 @@
+use {
+  super::NumericObject,
+  crate::{
+    allocation::borrow::ToOwned,
+    c_int,
+    support::locale::localedef::{self, Source}
+  },
+  allocation::borrow::Cow,
+  core::ffi
+};
+
+pub fn load(
+  numeric: &mut NumericObject,
+  locale: &ffi::CStr,
+  source: &Source
+) -> Result<(), c_int> {
+  let section = source.section("LC_NUMERIC");
+  localedef::load_base(numeric, locale, source, section)?;
+
+  if let Some(section) = section {
+    if let Some(decimal_point) = section.string("decimal_point") {
+      numeric.decimal_point = localedef::to_cstr(decimal_point)?;
+    }
+    if let Some(thousands_sep) = section.string("thousands_sep") {
+      numeric.thousands_sep = localedef::to_cstr(thousands_sep)?;
+    }
+    if let Some(grouping) = section.grouping("grouping") {
+      numeric.grouping = grouping;
+    }
+  }
+
+  numeric.name = Cow::Owned(locale.to_owned());
+
+  Ok(())
+}
@@ -1,104 +1,15 @@
+#[cfg(feature = "numeric")]
+pub mod icu;
+mod localedef;
+
 use {
-  super::{LocaleObject, canonicalize_locale, is_posix_locale},
-  crate::{
-    allocation::{borrow::ToOwned, collections::BTreeMap, string::String},
-    c_int,
-    support::{locale::errno, string::strtocstr}
-  },
+  super::{LocaleObject, is_posix_locale, localedef::Source},
+  crate::{allocation::string::String, c_int, support::locale::errno},
   allocation::borrow::Cow,
   core::ffi,
-  icu_decimal::{DecimalFormatter, input::Decimal, options},
-  icu_locale::Locale,
   smallvec::SmallVec
 };
 
-pub fn get_grouping_strategy_for_locale(
-  locale: &Locale
-) -> options::GroupingStrategy {
-  // https://lh.2xlibre.net/values/grouping/
-
-  if let Some(region) = locale.id.region {
-    match region.as_str() {
-      | "CN" | "HK" | "PH" | "SG" | "FR" | "TW" | "MT" | "NP" | "MA" | "JP" => {
-        return options::GroupingStrategy::Min2;
-      },
-      | "PT" | "RS" | "SL" | "CU" | "NK" => {
-        return options::GroupingStrategy::Never;
-      },
-      | _ => ()
-    }
-  }
-
-  match locale.id.language.as_str() {
-    | "ar" | "az" | "ckb" | "fa" | "pl" | "ja" => {
-      options::GroupingStrategy::Min2
-    },
-    | "el" | "gl" => options::GroupingStrategy::Never,
-    | _ => options::GroupingStrategy::Auto
-  }
-}
-
-pub fn get_posix_grouping<'a>(
-  formatter: &DecimalFormatter
-) -> Option<SmallVec<[u8; 3]>> {
-  let mut buffer = SmallVec::<[u8; 3]>::new();
-  let mut cur: usize = 0;
-
-  let fmt = |n: u128| {
-    let d = Decimal::from(n);
-    let f = formatter.format(&d);
-    f.to_string()
-  };
-
-  let probe = fmt(123456789012345u128);
-  let sep = {
-    let mut counts = BTreeMap::<char, usize>::new();
-
-    for ch in probe.chars() {
-      if !ch.is_numeric() {
-        *counts.entry(ch).or_default() += 1;
-      }
-    }
-
-    counts.into_iter().max_by_key(|&(_, c)| c).map(|(ch, _)| ch)
-  };
-
-  let Some(sep) = sep else { return None };
-
-  for ch in probe.chars().rev() {
-    if ch == sep {
-      buffer.push(cur as u8);
-      cur = 0;
-    } else if ch.is_numeric() {
-      cur += 1;
-    }
-  }
-  if cur > 0 {
-    buffer.push(cur as u8)
-  }
-
-  let primary = buffer[0];
-  let secondary = buffer.get(1).copied();
-
-  let big = fmt(12345).contains(sep);
-  let small = fmt(1234).contains(sep);
-  let is_min2 = big && !small;
-
-  let mut result: SmallVec<[u8; 3]> = SmallVec::new();
-
-  if is_min2 && secondary == Some(primary) {
-    result.push(primary);
-  } else if let Some(s) = secondary {
-    result.push(primary);
-    result.push(s);
-  } else {
-    result.push(primary);
-  }
-  result.push(b'\0');
-
-  Some(result)
-}
-
 pub fn get_thousands_sep(s: &str) -> Option<String> {
   for ch in s.chars() {
     if !ch.is_numeric() && !ch.is_whitespace() {
@@ -137,7 +48,19 @@ pub struct NumericObject<'a> {
   name: Cow<'a, ffi::CStr>,
   pub decimal_point: Cow<'a, ffi::CStr>,
   pub thousands_sep: Cow<'a, ffi::CStr>,
-  pub grouping: SmallVec<[u8; 3]>
+  pub grouping: SmallVec<[u8; 3]>,
+  /// The digits of the numbering system of the locale, for the alternative
+  /// digits of the printf "I" flag, as NUL-terminated UTF-8
+  pub digits: [[u8; 5]; 10]
+}
+
+/// Encodes the digits of a numbering system for [`NumericObject::digits`].
+pub fn encode_digits(digits: [char; 10]) -> [[u8; 5]; 10] {
+  digits.map(|digit| {
+    let mut encoded = [0; 5];
+    digit.encode_utf8(&mut encoded);
+    encoded
+  })
 }
 
 impl<'a> NumericObject<'a> {
@@ -150,6 +73,54 @@ impl<'a> NumericObject<'a> {
   pub fn get_thousands_sep(&self) -> Option<char> {
     self.thousands_sep.to_str().ok()?.chars().next()
   }
+
+  /// Returns the digit of the locale for a value from 0 to 9.
+  #[inline]
+  pub fn get_digit(
+    &self,
+    value: u32
+  ) -> Option<char> {
+    let digit = self.digits.get(value as usize)?;
+
+    ffi::CStr::from_bytes_until_nul(digit).ok()?.to_str().ok()?.chars().next()
+  }
+
+  /// Returns the value of a digit, either an ASCII one or one of the locale.
+  #[inline]
+  pub fn get_digit_value(
+    &self,
+    c: char
+  ) -> Option<u32> {
+    c.to_digit(10)
+      .or_else(|| (0..10).find(|&value| self.get_digit(value) == Some(c)))
+  }
+
+  /// Writes the ASCII digits of `s` as the digits of the locale, leaving
+  /// everything else as it is.
+  pub fn to_native_digits(
+    &self,
+    s: &str
+  ) -> String {
+    s.chars()
+      .map(|c| c.to_digit(10).and_then(|d| self.get_digit(d)).unwrap_or(c))
+      .collect()
+  }
+
+  /// Writes the digits of the locale in `s` as ASCII digits, leaving
+  /// everything else as it is.
+  pub fn to_ascii_digits(
+    &self,
+    s: &str
+  ) -> String {
+    s.chars()
+      .map(|c| {
+        self
+          .get_digit_value(c)
+          .and_then(|d| char::from_digit(d, 10))
+          .unwrap_or(c)
+      })
+      .collect()
+  }
 }
 
 impl<'a> LocaleObject for NumericObject<'a> {
@@ -169,37 +140,16 @@ impl<'a> LocaleObject for NumericObject<'a> {
       return Err(errno::ENOENT);
     }
 
-    let icu_locale_name = canonicalize_locale(lang);
-
-    let icu_locale = Locale::try_from_str(&icu_locale_name.replace("_", "-"))
-      .map_err(|_| errno::ENOENT)?;
-
-    let mut options: options::DecimalFormatterOptions = Default::default();
-    options.grouping_strategy =
-      Some(get_grouping_strategy_for_locale(&icu_locale));
+    // Without the ICU number data only the POSIX locales are available
+    #[cfg(not(feature = "numeric"))]
+    return Err(errno::ENOENT);
 
-    let formatter = DecimalFormatter::try_new(icu_locale.into(), options)
-      .map_err(|_| errno::ENOENT)?;
+    #[cfg(feature = "numeric")]
+    {
+      icu::load(self, locale, lang)?;
 
-    let mut frac = Decimal::from(1234);
-    frac.multiply_pow10(-2);
-    let s_frac = formatter.format(&frac);
-    let s_frac = s_frac.to_string();
-
-    let big = Decimal::from(1234567890123u128);
-    let s_int = formatter.format(&big);
-    let s_int = s_int.to_string();
-
-    let decimal_point = get_decimal_point(&s_frac).ok_or(errno::ENOENT)?;
-    let thousands_sep = get_thousands_sep(&s_int).ok_or(errno::ENOENT)?;
-    let grouping = get_posix_grouping(&formatter).ok_or(errno::ENOENT)?;
-
-    self.name = Cow::Owned(locale.to_owned());
-    self.decimal_point = strtocstr(&decimal_point);
-    self.thousands_sep = strtocstr(&thousands_sep);
-    self.grouping = grouping.into();
-
-    Ok(self.name.as_ref())
+      Ok(self.name.as_ref())
+    }
   }
 
   fn set_to_posix(&mut self) -> &ffi::CStr {
@@ -211,6 +161,16 @@ impl<'a> LocaleObject for NumericObject<'a> {
   fn get_name(&self) -> &ffi::CStr {
     self.name.as_ref()
   }
+
+  fn load_source(
+    &mut self,
+    locale: &ffi::CStr,
+    source: &Source
+  ) -> Result<&ffi::CStr, c_int> {
+    localedef::load(self, locale, source)?;
+
+    Ok(self.name.as_ref())
+  }
 }
 
 impl<'a> Default for NumericObject<'a> {
@@ -223,5 +183,17 @@ pub const DEFAULT_NUMERIC: NumericObject = NumericObject {
   name: Cow::Borrowed(c"C"),
   decimal_point: Cow::Borrowed(c"."),
   thousands_sep: Cow::Borrowed(c""),
-  grouping: SmallVec::new_const()
+  grouping: SmallVec::new_const(),
+  digits: [
+    *b"0\0\0\0\0",
+    *b"1\0\0\0\0",
+    *b"2\0\0\0\0",
+    *b"3\0\0\0\0",
+    *b"4\0\0\0\0",
+    *b"5\0\0\0\0",
+    *b"6\0\0\0\0",
+    *b"7\0\0\0\0",
+    *b"8\0\0\0\0",
+    *b"9\0\0\0\0"
+  ]
 };
@@ -0,0 +1,57 @@
+use {
+  super::{LocaleObject, is_posix_locale, territory},
+  crate::{allocation::borrow::ToOwned, c_int, support::locale::errno},
+  allocation::borrow::Cow,
+  core::ffi
+};
+
+#[derive(Debug, Clone)]
+pub struct PaperObject<'a> {
+  name: Cow<'a, ffi::CStr>,
+  /// The height of the paper in millimetres
+  pub height: u32,
+  /// The width of the paper in millimetres
+  pub width: u32
+}
+
+impl<'a> LocaleObject for PaperObject<'a> {
+  fn setlocale(
+    &mut self,
+    locale: &ffi::CStr
+  ) -> Result<&ffi::CStr, c_int> {
+    let name = locale.to_str().map_err(|_| errno::ENOENT)?;
+
+    if is_posix_locale(name) {
+      return Ok(self.set_to_posix());
+    }
+
+    territory::get_language(name).ok_or(errno::ENOENT)?;
+
+    let letter = territory::get_region(name)
+      .is_some_and(|region| territory::is_letter_territory(&region));
+    // US Letter is 8.5 by 11 inches, which glibc rounds down
+    (self.height, self.width) = if letter { (279, 216) } else { (297, 210) };
+    self.name = Cow::Owned(locale.to_owned());
+
+    Ok(self.name.as_ref())
+  }
+
+  fn set_to_posix(&mut self) -> &ffi::CStr {
+    *self = DEFAULT_PAPER;
+
+    self.name.as_ref()
+  }
+
+  fn get_name(&self) -> &ffi::CStr {
+    self.name.as_ref()
+  }
+}
+
+impl<'a> Default for PaperObject<'a> {
+  fn default() -> Self {
+    DEFAULT_PAPER
+  }
+}
+
+pub const DEFAULT_PAPER: PaperObject =
+  PaperObject { name: Cow::Borrowed(c"C"), height: 297, width: 210 };
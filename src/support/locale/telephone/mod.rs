@@ -0,0 +1,79 @@
+use {
+  super::{LocaleObject, is_posix_locale, territory},
+  crate::{allocation::borrow::ToOwned, c_int, support::locale::errno},
+  allocation::borrow::Cow,
+  core::ffi
+};
+
+/// The telephone formats use the escapes of ISO/IEC 14652, such as "%c" for
+/// the country calling code, "%a" for the area code and "%l" for the local
+/// number.
+#[derive(Debug, Clone)]
+pub struct TelephoneObject<'a> {
+  name: Cow<'a, ffi::CStr>,
+  pub tel_int_fmt: &'static ffi::CStr,
+  pub tel_dom_fmt: &'static ffi::CStr,
+  pub int_select: &'static ffi::CStr,
+  /// The country calling code, without a leading "+"
+  pub int_prefix: &'static ffi::CStr
+}
+
+impl<'a> LocaleObject for TelephoneObject<'a> {
+  fn setlocale(
+    &mut self,
+    locale: &ffi::CStr
+  ) -> Result<&ffi::CStr, c_int> {
+    let name = locale.to_str().map_err(|_| errno::ENOENT)?;
+
+    if is_posix_locale(name) {
+      return Ok(self.set_to_posix());
+    }
+
+    let language = territory::get_language(name).ok_or(errno::ENOENT)?;
+
+    // CLDR has no dialling formats or international call prefixes, so the
+    // formats come from the glibc locales and the prefix stays that of the
+    // POSIX locale
+    *self = DEFAULT_TELEPHONE;
+    let region = territory::get_region(name);
+    if let Some(territory) =
+      region.as_deref().and_then(territory::find_territory)
+    {
+      self.int_prefix = territory.tel;
+    }
+    if let Some(formats) = region
+      .as_deref()
+      .and_then(|region| territory::find_formats(&language, region))
+    {
+      self.tel_int_fmt = formats.tel_int_fmt;
+      self.tel_dom_fmt = formats.tel_dom_fmt;
+    }
+    self.name = Cow::Owned(locale.to_owned());
+
+    Ok(self.name.as_ref())
+  }
+
+  fn set_to_posix(&mut self) -> &ffi::CStr {
+    *self = DEFAULT_TELEPHONE;
+
+    self.name.as_ref()
+  }
+
+  fn get_name(&self) -> &ffi::CStr {
+    self.name.as_ref()
+  }
+}
+
+impl<'a> Default for TelephoneObject<'a> {
+  fn default() -> Self {
+    DEFAULT_TELEPHONE
+  }
+}
+
+pub const DEFAULT_TELEPHONE: TelephoneObject = TelephoneObject {
+  name: Cow::Borrowed(c"C"),
+  tel_int_fmt: c"+%c %a %l",
+  tel_dom_fmt: c"",
+  int_select: c"",
+  int_prefix: c""
+};
@@ -0,0 +1,139 @@
+#[cfg(any(feature = "casemap", feature = "collate", feature = "numeric"))]
+use icu_locale::{Locale, LocaleExpander};
+use {
+  crate::allocation::string::String,
+  core::{cmp::Ordering, ffi}
+};
+
+mod static_data;
+
+pub use static_data::{is_letter_territory, is_us_system_territory};
+
+#[derive(Debug)]
+pub struct Territory {
+  pub ab2: &'static ffi::CStr,
+  pub ab3: &'static ffi::CStr,
+  pub num: u16,
+  pub tel: &'static ffi::CStr,
+  pub name: &'static ffi::CStr
+}
+
+#[derive(Debug)]
+pub struct Language {
+  pub ab: &'static ffi::CStr,
+  pub term: &'static ffi::CStr,
+  pub lib: &'static ffi::CStr,
+  pub name: &'static ffi::CStr
+}
+
+#[derive(Debug)]
+pub struct Formats {
+  pub locale: &'static ffi::CStr,
+  pub name_fmt: &'static ffi::CStr,
+  pub postal_fmt: &'static ffi::CStr,
+  pub tel_int_fmt: &'static ffi::CStr,
+  pub tel_dom_fmt: &'static ffi::CStr
+}
+
+fn compare(
+  code: &ffi::CStr,
+  s: &str
+) -> Ordering {
+  code.to_bytes().cmp(s.as_bytes())
+}
+
+/// Returns the language subtag of a locale name such as "de_DE.UTF-8" or
+/// "sr-Latn-RS", in lowercase.
+pub fn get_language(name: &str) -> Option<String> {
+  let language = name.split(['_', '-', '.', '@']).next().unwrap_or("");
+  if !(2..=3).contains(&language.len()) ||
+    !language.bytes().all(|b| b.is_ascii_alphabetic())
+  {
+    return None;
+  }
+
+  Some(language.to_ascii_lowercase())
+}
+
+/// Returns the region subtag of a locale name, in uppercase. Names without
+/// one, such as "de.UTF-8", get the likely region of their language.
+pub fn get_region(name: &str) -> Option<String> {
+  let base = name.split(['.', '@']).next().unwrap_or("");
+
+  for subtag in base.split(['_', '-']).skip(1) {
+    // Extensions such as -u-nu-latn come after the region
+    if subtag.len() == 1 {
+      break;
+    }
+    if subtag.len() == 2 && subtag.bytes().all(|b| b.is_ascii_alphabetic()) {
+      return Some(subtag.to_ascii_uppercase());
+    }
+    if subtag.len() == 3 && subtag.bytes().all(|b| b.is_ascii_digit()) {
+      return Some(String::from(subtag));
+    }
+  }
+
+  get_likely_region(base)
+}
+
+#[cfg(any(feature = "casemap", feature = "collate", feature = "numeric"))]
+fn get_likely_region(base: &str) -> Option<String> {
+  let mut id = Locale::try_from_str(&base.replace('_', "-")).ok()?.id;
+  LocaleExpander::new_extended().maximize(&mut id);
+
+  id.region.map(|region| String::from(region.as_str()))
+}
+
+// Without any ICU data there are no likely regions
+#[cfg(not(any(
+  feature = "casemap",
+  feature = "collate",
+  feature = "numeric"
+)))]
+fn get_likely_region(_: &str) -> Option<String> {
+  None
+}
+
+/// Finds a territory by its two-letter code. Other regions, such as the
+/// "419" of Latin America, have no country codes.
+pub fn find_territory(region: &str) -> Option<&'static Territory> {
+  static_data::TERRITORIES
+    .binary_search_by(|t| compare(t.ab2, region))
+    .ok()
+    .map(|i| &static_data::TERRITORIES[i])
+}
+
+/// Finds a language by its two-letter or either of its three-letter codes.
+pub fn find_language(language: &str) -> Option<&'static Language> {
+  if language.len() == 2 {
+    return static_data::LANGUAGES
+      .binary_search_by(|l| compare(l.ab, language))
+      .ok()
+      .map(|i| &static_data::LANGUAGES[i]);
+  }
+
+  static_data::LANGUAGES.iter().find(|l| {
+    compare(l.term, language).is_eq() || compare(l.lib, language).is_eq()
+  })
+}
+
+/// Finds the name, postal and telephone formats of a language and region,
+/// such as "de" and "AT". Languages without formats for the region take
+/// those of another locale of the region, as the postal and telephone
+/// formats belong to the region.
+pub fn find_formats(
+  language: &str,
+  region: &str
+) -> Option<&'static Formats> {
+  let mut locale = String::from(language);
+  locale.push('_');
+  locale.push_str(region);
+
+  match static_data::FORMATS.binary_search_by(|f| compare(f.locale, &locale)) {
+    | Ok(i) => Some(&static_data::FORMATS[i]),
+    | Err(_) => static_data::FORMATS.iter().find(|f| {
+      f.locale.to_bytes().split(|&b| b == b'_').nth(1) ==
+        Some(region.as_bytes())
+    })
+  }
+}
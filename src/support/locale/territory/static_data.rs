@@ -0,0 +1,786 @@
+use super::{Formats, Language, Territory};
+
+// The ISO 3166 codes and English short names of the territories, with the
+// country calling codes of the telephoneCodeData of CLDR. Sorted by the
+// two-letter code.
+#[rustfmt::skip]
+pub static TERRITORIES: [Territory; 249] = [
+  Territory { ab2: c"AD", ab3: c"AND", num: 20, tel: c"376", name: c"Andorra" },
+  Territory { ab2: c"AE", ab3: c"ARE", num: 784, tel: c"971", name: c"United Arab Emirates" },
+  Territory { ab2: c"AF", ab3: c"AFG", num: 4, tel: c"93", name: c"Afghanistan" },
+  Territory { ab2: c"AG", ab3: c"ATG", num: 28, tel: c"1", name: c"Antigua and Barbuda" },
+  Territory { ab2: c"AI", ab3: c"AIA", num: 660, tel: c"1", name: c"Anguilla" },
+  Territory { ab2: c"AL", ab3: c"ALB", num: 8, tel: c"355", name: c"Albania" },
+  Territory { ab2: c"AM", ab3: c"ARM", num: 51, tel: c"374", name: c"Armenia" },
+  Territory { ab2: c"AO", ab3: c"AGO", num: 24, tel: c"244", name: c"Angola" },
+  Territory { ab2: c"AQ", ab3: c"ATA", num: 10, tel: c"672", name: c"Antarctica" },
+  Territory { ab2: c"AR", ab3: c"ARG", num: 32, tel: c"54", name: c"Argentina" },
+  Territory { ab2: c"AS", ab3: c"ASM", num: 16, tel: c"1", name: c"American Samoa" },
+  Territory { ab2: c"AT", ab3: c"AUT", num: 40, tel: c"43", name: c"Austria" },
+  Territory { ab2: c"AU", ab3: c"AUS", num: 36, tel: c"61", name: c"Australia" },
+  Territory { ab2: c"AW", ab3: c"ABW", num: 533, tel: c"297", name: c"Aruba" },
+  Territory { ab2: c"AX", ab3: c"ALA", num: 248, tel: c"358", name: c"Åland Islands" },
+  Territory { ab2: c"AZ", ab3: c"AZE", num: 31, tel: c"994", name: c"Azerbaijan" },
+  Territory { ab2: c"BA", ab3: c"BIH", num: 70, tel: c"387", name: c"Bosnia and Herzegovina" },
+  Territory { ab2: c"BB", ab3: c"BRB", num: 52, tel: c"1", name: c"Barbados" },
+  Territory { ab2: c"BD", ab3: c"BGD", num: 50, tel: c"880", name: c"Bangladesh" },
+  Territory { ab2: c"BE", ab3: c"BEL", num: 56, tel: c"32", name: c"Belgium" },
+  Territory { ab2: c"BF", ab3: c"BFA", num: 854, tel: c"226", name: c"Burkina Faso" },
+  Territory { ab2: c"BG", ab3: c"BGR", num: 100, tel: c"359", name: c"Bulgaria" },
+  Territory { ab2: c"BH", ab3: c"BHR", num: 48, tel: c"973", name: c"Bahrain" },
+  Territory { ab2: c"BI", ab3: c"BDI", num: 108, tel: c"257", name: c"Burundi" },
+  Territory { ab2: c"BJ", ab3: c"BEN", num: 204, tel: c"229", name: c"Benin" },
+  Territory { ab2: c"BL", ab3: c"BLM", num: 652, tel: c"590", name: c"Saint Barthélemy" },
+  Territory { ab2: c"BM", ab3: c"BMU", num: 60, tel: c"1", name: c"Bermuda" },
+  Territory { ab2: c"BN", ab3: c"BRN", num: 96, tel: c"673", name: c"Brunei Darussalam" },
+  Territory { ab2: c"BO", ab3: c"BOL", num: 68, tel: c"591", name: c"Bolivia" },
+  Territory { ab2: c"BQ", ab3: c"BES", num: 535, tel: c"599", name: c"Bonaire, Sint Eustatius and Saba" },
+  Territory { ab2: c"BR", ab3: c"BRA", num: 76, tel: c"55", name: c"Brazil" },
+  Territory { ab2: c"BS", ab3: c"BHS", num: 44, tel: c"1", name: c"Bahamas" },
+  Territory { ab2: c"BT", ab3: c"BTN", num: 64, tel: c"975", name: c"Bhutan" },
+  Territory { ab2: c"BV", ab3: c"BVT", num: 74, tel: c"47", name: c"Bouvet Island" },
+  Territory { ab2: c"BW", ab3: c"BWA", num: 72, tel: c"267", name: c"Botswana" },
+  Territory { ab2: c"BY", ab3: c"BLR", num: 112, tel: c"375", name: c"Belarus" },
+  Territory { ab2: c"BZ", ab3: c"BLZ", num: 84, tel: c"501", name: c"Belize" },
+  Territory { ab2: c"CA", ab3: c"CAN", num: 124, tel: c"1", name: c"Canada" },
+  Territory { ab2: c"CC", ab3: c"CCK", num: 166, tel: c"61", name: c"Cocos (Keeling) Islands" },
+  Territory { ab2: c"CD", ab3: c"COD", num: 180, tel: c"243", name: c"Congo, The Democratic Republic of the" },
+  Territory { ab2: c"CF", ab3: c"CAF", num: 140, tel: c"236", name: c"Central African Republic" },
+  Territory { ab2: c"CG", ab3: c"COG", num: 178, tel: c"242", name: c"Congo" },
+  Territory { ab2: c"CH", ab3: c"CHE", num: 756, tel: c"41", name: c"Switzerland" },
+  Territory { ab2: c"CI", ab3: c"CIV", num: 384, tel: c"225", name: c"Côte d'Ivoire" },
+  Territory { ab2: c"CK", ab3: c"COK", num: 184, tel: c"682", name: c"Cook Islands" },
+  Territory { ab2: c"CL", ab3: c"CHL", num: 152, tel: c"56", name: c"Chile" },
+  Territory { ab2: c"CM", ab3: c"CMR", num: 120, tel: c"237", name: c"Cameroon" },
+  Territory { ab2: c"CN", ab3: c"CHN", num: 156, tel: c"86", name: c"China" },
+  Territory { ab2: c"CO", ab3: c"COL", num: 170, tel: c"57", name: c"Colombia" },
+  Territory { ab2: c"CR", ab3: c"CRI", num: 188, tel: c"506", name: c"Costa Rica" },
+  Territory { ab2: c"CU", ab3: c"CUB", num: 192, tel: c"53", name: c"Cuba" },
+  Territory { ab2: c"CV", ab3: c"CPV", num: 132, tel: c"238", name: c"Cabo Verde" },
+  Territory { ab2: c"CW", ab3: c"CUW", num: 531, tel: c"599", name: c"Curaçao" },
+  Territory { ab2: c"CX", ab3: c"CXR", num: 162, tel: c"61", name: c"Christmas Island" },
+  Territory { ab2: c"CY", ab3: c"CYP", num: 196, tel: c"357", name: c"Cyprus" },
+  Territory { ab2: c"CZ", ab3: c"CZE", num: 203, tel: c"420", name: c"Czechia" },
+  Territory { ab2: c"DE", ab3: c"DEU", num: 276, tel: c"49", name: c"Germany" },
+  Territory { ab2: c"DJ", ab3: c"DJI", num: 262, tel: c"253", name: c"Djibouti" },
+  Territory { ab2: c"DK", ab3: c"DNK", num: 208, tel: c"45", name: c"Denmark" },
+  Territory { ab2: c"DM", ab3: c"DMA", num: 212, tel: c"1", name: c"Dominica" },
+  Territory { ab2: c"DO", ab3: c"DOM", num: 214, tel: c"1", name: c"Dominican Republic" },
+  Territory { ab2: c"DZ", ab3: c"DZA", num: 12, tel: c"213", name: c"Algeria" },
+  Territory { ab2: c"EC", ab3: c"ECU", num: 218, tel: c"593", name: c"Ecuador" },
+  Territory { ab2: c"EE", ab3: c"EST", num: 233, tel: c"372", name: c"Estonia" },
+  Territory { ab2: c"EG", ab3: c"EGY", num: 818, tel: c"20", name: c"Egypt" },
+  Territory { ab2: c"EH", ab3: c"ESH", num: 732, tel: c"212", name: c"Western Sahara" },
+  Territory { ab2: c"ER", ab3: c"ERI", num: 232, tel: c"291", name: c"Eritrea" },
+  Territory { ab2: c"ES", ab3: c"ESP", num: 724, tel: c"34", name: c"Spain" },
+  Territory { ab2: c"ET", ab3: c"ETH", num: 231, tel: c"251", name: c"Ethiopia" },
+  Territory { ab2: c"FI", ab3: c"FIN", num: 246, tel: c"358", name: c"Finland" },
+  Territory { ab2: c"FJ", ab3: c"FJI", num: 242, tel: c"679", name: c"Fiji" },
+  Territory { ab2: c"FK", ab3: c"FLK", num: 238, tel: c"500", name: c"Falkland Islands (Malvinas)" },
+  Territory { ab2: c"FM", ab3: c"FSM", num: 583, tel: c"691", name: c"Micronesia, Federated States of" },
+  Territory { ab2: c"FO", ab3: c"FRO", num: 234, tel: c"298", name: c"Faroe Islands" },
+  Territory { ab2: c"FR", ab3: c"FRA", num: 250, tel: c"33", name: c"France" },
+  Territory { ab2: c"GA", ab3: c"GAB", num: 266, tel: c"241", name: c"Gabon" },
+  Territory { ab2: c"GB", ab3: c"GBR", num: 826, tel: c"44", name: c"United Kingdom" },
+  Territory { ab2: c"GD", ab3: c"GRD", num: 308, tel: c"1", name: c"Grenada" },
+  Territory { ab2: c"GE", ab3: c"GEO", num: 268, tel: c"995", name: c"Georgia" },
+  Territory { ab2: c"GF", ab3: c"GUF", num: 254, tel: c"594", name: c"French Guiana" },
+  Territory { ab2: c"GG", ab3: c"GGY", num: 831, tel: c"44", name: c"Guernsey" },
+  Territory { ab2: c"GH", ab3: c"GHA", num: 288, tel: c"233", name: c"Ghana" },
+  Territory { ab2: c"GI", ab3: c"GIB", num: 292, tel: c"350", name: c"Gibraltar" },
+  Territory { ab2: c"GL", ab3: c"GRL", num: 304, tel: c"299", name: c"Greenland" },
+  Territory { ab2: c"GM", ab3: c"GMB", num: 270, tel: c"220", name: c"Gambia" },
+  Territory { ab2: c"GN", ab3: c"GIN", num: 324, tel: c"224", name: c"Guinea" },
+  Territory { ab2: c"GP", ab3: c"GLP", num: 312, tel: c"590", name: c"Guadeloupe" },
+  Territory { ab2: c"GQ", ab3: c"GNQ", num: 226, tel: c"240", name: c"Equatorial Guinea" },
+  Territory { ab2: c"GR", ab3: c"GRC", num: 300, tel: c"30", name: c"Greece" },
+  Territory { ab2: c"GS", ab3: c"SGS", num: 239, tel: c"500", name: c"South Georgia and the South Sandwich Islands" },
+  Territory { ab2: c"GT", ab3: c"GTM", num: 320, tel: c"502", name: c"Guatemala" },
+  Territory { ab2: c"GU", ab3: c"GUM", num: 316, tel: c"1", name: c"Guam" },
+  Territory { ab2: c"GW", ab3: c"GNB", num: 624, tel: c"245", name: c"Guinea-Bissau" },
+  Territory { ab2: c"GY", ab3: c"GUY", num: 328, tel: c"592", name: c"Guyana" },
+  Territory { ab2: c"HK", ab3: c"HKG", num: 344, tel: c"852", name: c"Hong Kong" },
+  Territory { ab2: c"HM", ab3: c"HMD", num: 334, tel: c"672", name: c"Heard Island and McDonald Islands" },
+  Territory { ab2: c"HN", ab3: c"HND", num: 340, tel: c"504", name: c"Honduras" },
+  Territory { ab2: c"HR", ab3: c"HRV", num: 191, tel: c"385", name: c"Croatia" },
+  Territory { ab2: c"HT", ab3: c"HTI", num: 332, tel: c"509", name: c"Haiti" },
+  Territory { ab2: c"HU", ab3: c"HUN", num: 348, tel: c"36", name: c"Hungary" },
+  Territory { ab2: c"ID", ab3: c"IDN", num: 360, tel: c"62", name: c"Indonesia" },
+  Territory { ab2: c"IE", ab3: c"IRL", num: 372, tel: c"353", name: c"Ireland" },
+  Territory { ab2: c"IL", ab3: c"ISR", num: 376, tel: c"972", name: c"Israel" },
+  Territory { ab2: c"IM", ab3: c"IMN", num: 833, tel: c"44", name: c"Isle of Man" },
+  Territory { ab2: c"IN", ab3: c"IND", num: 356, tel: c"91", name: c"India" },
+  Territory { ab2: c"IO", ab3: c"IOT", num: 86, tel: c"246", name: c"British Indian Ocean Territory" },
+  Territory { ab2: c"IQ", ab3: c"IRQ", num: 368, tel: c"964", name: c"Iraq" },
+  Territory { ab2: c"IR", ab3: c"IRN", num: 364, tel: c"98", name: c"Iran" },
+  Territory { ab2: c"IS", ab3: c"ISL", num: 352, tel: c"354", name: c"Iceland" },
+  Territory { ab2: c"IT", ab3: c"ITA", num: 380, tel: c"39", name: c"Italy" },
+  Territory { ab2: c"JE", ab3: c"JEY", num: 832, tel: c"44", name: c"Jersey" },
+  Territory { ab2: c"JM", ab3: c"JAM", num: 388, tel: c"1", name: c"Jamaica" },
+  Territory { ab2: c"JO", ab3: c"JOR", num: 400, tel: c"962", name: c"Jordan" },
+  Territory { ab2: c"JP", ab3: c"JPN", num: 392, tel: c"81", name: c"Japan" },
+  Territory { ab2: c"KE", ab3: c"KEN", num: 404, tel: c"254", name: c"Kenya" },
+  Territory { ab2: c"KG", ab3: c"KGZ", num: 417, tel: c"996", name: c"Kyrgyzstan" },
+  Territory { ab2: c"KH", ab3: c"KHM", num: 116, tel: c"855", name: c"Cambodia" },
+  Territory { ab2: c"KI", ab3: c"KIR", num: 296, tel: c"686", name: c"Kiribati" },
+  Territory { ab2: c"KM", ab3: c"COM", num: 174, tel: c"269", name: c"Comoros" },
+  Territory { ab2: c"KN", ab3: c"KNA", num: 659, tel: c"1", name: c"Saint Kitts and Nevis" },
+  Territory { ab2: c"KP", ab3: c"PRK", num: 408, tel: c"850", name: c"North Korea" },
+  Territory { ab2: c"KR", ab3: c"KOR", num: 410, tel: c"82", name: c"South Korea" },
+  Territory { ab2: c"KW", ab3: c"KWT", num: 414, tel: c"965", name: c"Kuwait" },
+  Territory { ab2: c"KY", ab3: c"CYM", num: 136, tel: c"1", name: c"Cayman Islands" },
+  Territory { ab2: c"KZ", ab3: c"KAZ", num: 398, tel: c"7", name: c"Kazakhstan" },
+  Territory { ab2: c"LA", ab3: c"LAO", num: 418, tel: c"856", name: c"Laos" },
+  Territory { ab2: c"LB", ab3: c"LBN", num: 422, tel: c"961", name: c"Lebanon" },
+  Territory { ab2: c"LC", ab3: c"LCA", num: 662, tel: c"1", name: c"Saint Lucia" },
+  Territory { ab2: c"LI", ab3: c"LIE", num: 438, tel: c"423", name: c"Liechtenstein" },
+  Territory { ab2: c"LK", ab3: c"LKA", num: 144, tel: c"94", name: c"Sri Lanka" },
+  Territory { ab2: c"LR", ab3: c"LBR", num: 430, tel: c"231", name: c"Liberia" },
+  Territory { ab2: c"LS", ab3: c"LSO", num: 426, tel: c"266", name: c"Lesotho" },
+  Territory { ab2: c"LT", ab3: c"LTU", num: 440, tel: c"370", name: c"Lithuania" },
+  Territory { ab2: c"LU", ab3: c"LUX", num: 442, tel: c"352", name: c"Luxembourg" },
+  Territory { ab2: c"LV", ab3: c"LVA", num: 428, tel: c"371", name: c"Latvia" },
+  Territory { ab2: c"LY", ab3: c"LBY", num: 434, tel: c"218", name: c"Libya" },
+  Territory { ab2: c"MA", ab3: c"MAR", num: 504, tel: c"212", name: c"Morocco" },
+  Territory { ab2: c"MC", ab3: c"MCO", num: 492, tel: c"377", name: c"Monaco" },
+  Territory { ab2: c"MD", ab3: c"MDA", num: 498, tel: c"373", name: c"Moldova" },
+  Territory { ab2: c"ME", ab3: c"MNE", num: 499, tel: c"382", name: c"Montenegro" },
+  Territory { ab2: c"MF", ab3: c"MAF", num: 663, tel: c"590", name: c"Saint Martin (French part)" },
+  Territory { ab2: c"MG", ab3: c"MDG", num: 450, tel: c"261", name: c"Madagascar" },
+  Territory { ab2: c"MH", ab3: c"MHL", num: 584, tel: c"692", name: c"Marshall Islands" },
+  Territory { ab2: c"MK", ab3: c"MKD", num: 807, tel: c"389", name: c"North Macedonia" },
+  Territory { ab2: c"ML", ab3: c"MLI", num: 466, tel: c"223", name: c"Mali" },
+  Territory { ab2: c"MM", ab3: c"MMR", num: 104, tel: c"95", name: c"Myanmar" },
+  Territory { ab2: c"MN", ab3: c"MNG", num: 496, tel: c"976", name: c"Mongolia" },
+  Territory { ab2: c"MO", ab3: c"MAC", num: 446, tel: c"853", name: c"Macao" },
+  Territory { ab2: c"MP", ab3: c"MNP", num: 580, tel: c"1", name: c"Northern Mariana Islands" },
+  Territory { ab2: c"MQ", ab3: c"MTQ", num: 474, tel: c"596", name: c"Martinique" },
+  Territory { ab2: c"MR", ab3: c"MRT", num: 478, tel: c"222", name: c"Mauritania" },
+  Territory { ab2: c"MS", ab3: c"MSR", num: 500, tel: c"1", name: c"Montserrat" },
+  Territory { ab2: c"MT", ab3: c"MLT", num: 470, tel: c"356", name: c"Malta" },
+  Territory { ab2: c"MU", ab3: c"MUS", num: 480, tel: c"230", name: c"Mauritius" },
+  Territory { ab2: c"MV", ab3: c"MDV", num: 462, tel: c"960", name: c"Maldives" },
+  Territory { ab2: c"MW", ab3: c"MWI", num: 454, tel: c"265", name: c"Malawi" },
+  Territory { ab2: c"MX", ab3: c"MEX", num: 484, tel: c"52", name: c"Mexico" },
+  Territory { ab2: c"MY", ab3: c"MYS", num: 458, tel: c"60", name: c"Malaysia" },
+  Territory { ab2: c"MZ", ab3: c"MOZ", num: 508, tel: c"258", name: c"Mozambique" },
+  Territory { ab2: c"NA", ab3: c"NAM", num: 516, tel: c"264", name: c"Namibia" },
+  Territory { ab2: c"NC", ab3: c"NCL", num: 540, tel: c"687", name: c"New Caledonia" },
+  Territory { ab2: c"NE", ab3: c"NER", num: 562, tel: c"227", name: c"Niger" },
+  Territory { ab2: c"NF", ab3: c"NFK", num: 574, tel: c"672", name: c"Norfolk Island" },
+  Territory { ab2: c"NG", ab3: c"NGA", num: 566, tel: c"234", name: c"Nigeria" },
+  Territory { ab2: c"NI", ab3: c"NIC", num: 558, tel: c"505", name: c"Nicaragua" },
+  Territory { ab2: c"NL", ab3: c"NLD", num: 528, tel: c"31", name: c"Netherlands" },
+  Territory { ab2: c"NO", ab3: c"NOR", num: 578, tel: c"47", name: c"Norway" },
+  Territory { ab2: c"NP", ab3: c"NPL", num: 524, tel: c"977", name: c"Nepal" },
+  Territory { ab2: c"NR", ab3: c"NRU", num: 520, tel: c"674", name: c"Nauru" },
+  Territory { ab2: c"NU", ab3: c"NIU", num: 570, tel: c"683", name: c"Niue" },
+  Territory { ab2: c"NZ", ab3: c"NZL", num: 554, tel: c"64", name: c"New Zealand" },
+  Territory { ab2: c"OM", ab3: c"OMN", num: 512, tel: c"968", name: c"Oman" },
+  Territory { ab2: c"PA", ab3: c"PAN", num: 591, tel: c"507", name: c"Panama" },
+  Territory { ab2: c"PE", ab3: c"PER", num: 604, tel: c"51", name: c"Peru" },
+  Territory { ab2: c"PF", ab3: c"PYF", num: 258, tel: c"689", name: c"French Polynesia" },
+  Territory { ab2: c"PG", ab3: c"PNG", num: 598, tel: c"675", name: c"Papua New Guinea" },
+  Territory { ab2: c"PH", ab3: c"PHL", num: 608, tel: c"63", name: c"Philippines" },
+  Territory { ab2: c"PK", ab3: c"PAK", num: 586, tel: c"92", name: c"Pakistan" },
+  Territory { ab2: c"PL", ab3: c"POL", num: 616, tel: c"48", name: c"Poland" },
+  Territory { ab2: c"PM", ab3: c"SPM", num: 666, tel: c"508", name: c"Saint Pierre and Miquelon" },
+  Territory { ab2: c"PN", ab3: c"PCN", num: 612, tel: c"64", name: c"Pitcairn" },
+  Territory { ab2: c"PR", ab3: c"PRI", num: 630, tel: c"1", name: c"Puerto Rico" },
+  Territory { ab2: c"PS", ab3: c"PSE", num: 275, tel: c"970", name: c"Palestine, State of" },
+  Territory { ab2: c"PT", ab3: c"PRT", num: 620, tel: c"351", name: c"Portugal" },
+  Territory { ab2: c"PW", ab3: c"PLW", num: 585, tel: c"680", name: c"Palau" },
+  Territory { ab2: c"PY", ab3: c"PRY", num: 600, tel: c"595", name: c"Paraguay" },
+  Territory { ab2: c"QA", ab3: c"QAT", num: 634, tel: c"974", name: c"Qatar" },
+  Territory { ab2: c"RE", ab3: c"REU", num: 638, tel: c"262", name: c"Réunion" },
+  Territory { ab2: c"RO", ab3: c"ROU", num: 642, tel: c"40", name: c"Romania" },
+  Territory { ab2: c"RS", ab3: c"SRB", num: 688, tel: c"381", name: c"Serbia" },
+  Territory { ab2: c"RU", ab3: c"RUS", num: 643, tel: c"7", name: c"Russian Federation" },
+  Territory { ab2: c"RW", ab3: c"RWA", num: 646, tel: c"250", name: c"Rwanda" },
+  Territory { ab2: c"SA", ab3: c"SAU", num: 682, tel: c"966", name: c"Saudi Arabia" },
+  Territory { ab2: c"SB", ab3: c"SLB", num: 90, tel: c"677", name: c"Solomon Islands" },
+  Territory { ab2: c"SC", ab3: c"SYC", num: 690, tel: c"248", name: c"Seychelles" },
+  Territory { ab2: c"SD", ab3: c"SDN", num: 729, tel: c"249", name: c"Sudan" },
+  Territory { ab2: c"SE", ab3: c"SWE", num: 752, tel: c"46", name: c"Sweden" },
+  Territory { ab2: c"SG", ab3: c"SGP", num: 702, tel: c"65", name: c"Singapore" },
+  Territory { ab2: c"SH", ab3: c"SHN", num: 654, tel: c"290", name: c"Saint Helena, Ascension and Tristan da Cunha" },
+  Territory { ab2: c"SI", ab3: c"SVN", num: 705, tel: c"386", name: c"Slovenia" },
+  Territory { ab2: c"SJ", ab3: c"SJM", num: 744, tel: c"47", name: c"Svalbard and Jan Mayen" },
+  Territory { ab2: c"SK", ab3: c"SVK", num: 703, tel: c"421", name: c"Slovakia" },
+  Territory { ab2: c"SL", ab3: c"SLE", num: 694, tel: c"232", name: c"Sierra Leone" },
+  Territory { ab2: c"SM", ab3: c"SMR", num: 674, tel: c"378", name: c"San Marino" },
+  Territory { ab2: c"SN", ab3: c"SEN", num: 686, tel: c"221", name: c"Senegal" },
+  Territory { ab2: c"SO", ab3: c"SOM", num: 706, tel: c"252", name: c"Somalia" },
+  Territory { ab2: c"SR", ab3: c"SUR", num: 740, tel: c"597", name: c"Suriname" },
+  Territory { ab2: c"SS", ab3: c"SSD", num: 728, tel: c"211", name: c"South Sudan" },
+  Territory { ab2: c"ST", ab3: c"STP", num: 678, tel: c"239", name: c"Sao Tome and Principe" },
+  Territory { ab2: c"SV", ab3: c"SLV", num: 222, tel: c"503", name: c"El Salvador" },
+  Territory { ab2: c"SX", ab3: c"SXM", num: 534, tel: c"1", name: c"Sint Maarten (Dutch part)" },
+  Territory { ab2: c"SY", ab3: c"SYR", num: 760, tel: c"963", name: c"Syria" },
+  Territory { ab2: c"SZ", ab3: c"SWZ", num: 748, tel: c"268", name: c"Eswatini" },
+  Territory { ab2: c"TC", ab3: c"TCA", num: 796, tel: c"1", name: c"Turks and Caicos Islands" },
+  Territory { ab2: c"TD", ab3: c"TCD", num: 148, tel: c"235", name: c"Chad" },
+  Territory { ab2: c"TF", ab3: c"ATF", num: 260, tel: c"262", name: c"French Southern Territories" },
+  Territory { ab2: c"TG", ab3: c"TGO", num: 768, tel: c"228", name: c"Togo" },
+  Territory { ab2: c"TH", ab3: c"THA", num: 764, tel: c"66", name: c"Thailand" },
+  Territory { ab2: c"TJ", ab3: c"TJK", num: 762, tel: c"992", name: c"Tajikistan" },
+  Territory { ab2: c"TK", ab3: c"TKL", num: 772, tel: c"690", name: c"Tokelau" },
+  Territory { ab2: c"TL", ab3: c"TLS", num: 626, tel: c"670", name: c"Timor-Leste" },
+  Territory { ab2: c"TM", ab3: c"TKM", num: 795, tel: c"993", name: c"Turkmenistan" },
+  Territory { ab2: c"TN", ab3: c"TUN", num: 788, tel: c"216", name: c"Tunisia" },
+  Territory { ab2: c"TO", ab3: c"TON", num: 776, tel: c"676", name: c"Tonga" },
+  Territory { ab2: c"TR", ab3: c"TUR", num: 792, tel: c"90", name: c"Türkiye" },
+  Territory { ab2: c"TT", ab3: c"TTO", num: 780, tel: c"1", name: c"Trinidad and Tobago" },
+  Territory { ab2: c"TV", ab3: c"TUV", num: 798, tel: c"688", name: c"Tuvalu" },
+  Territory { ab2: c"TW", ab3: c"TWN", num: 158, tel: c"886", name: c"Taiwan" },
+  Territory { ab2: c"TZ", ab3: c"TZA", num: 834, tel: c"255", name: c"Tanzania" },
+  Territory { ab2: c"UA", ab3: c"UKR", num: 804, tel: c"380", name: c"Ukraine" },
+  Territory { ab2: c"UG", ab3: c"UGA", num: 800, tel: c"256", name: c"Uganda" },
+  Territory { ab2: c"UM", ab3: c"UMI", num: 581, tel: c"1", name: c"United States Minor Outlying Islands" },
+  Territory { ab2: c"US", ab3: c"USA", num: 840, tel: c"1", name: c"United States" },
+  Territory { ab2: c"UY", ab3: c"URY", num: 858, tel: c"598", name: c"Uruguay" },
+  Territory { ab2: c"UZ", ab3: c"UZB", num: 860, tel: c"998", name: c"Uzbekistan" },
+  Territory { ab2: c"VA", ab3: c"VAT", num: 336, tel: c"39", name: c"Holy See (Vatican City State)" },
+  Territory { ab2: c"VC", ab3: c"VCT", num: 670, tel: c"1", name: c"Saint Vincent and the Grenadines" },
+  Territory { ab2: c"VE", ab3: c"VEN", num: 862, tel: c"58", name: c"Venezuela" },
+  Territory { ab2: c"VG", ab3: c"VGB", num: 92, tel: c"1", name: c"Virgin Islands, British" },
+  Territory { ab2: c"VI", ab3: c"VIR", num: 850, tel: c"1", name: c"Virgin Islands, U.S." },
+  Territory { ab2: c"VN", ab3: c"VNM", num: 704, tel: c"84", name: c"Vietnam" },
+  Territory { ab2: c"VU", ab3: c"VUT", num: 548, tel: c"678", name: c"Vanuatu" },
+  Territory { ab2: c"WF", ab3: c"WLF", num: 876, tel: c"681", name: c"Wallis and Futuna" },
+  Territory { ab2: c"WS", ab3: c"WSM", num: 882, tel: c"685", name: c"Samoa" },
+  Territory { ab2: c"YE", ab3: c"YEM", num: 887, tel: c"967", name: c"Yemen" },
+  Territory { ab2: c"YT", ab3: c"MYT", num: 175, tel: c"262", name: c"Mayotte" },
+  Territory { ab2: c"ZA", ab3: c"ZAF", num: 710, tel: c"27", name: c"South Africa" },
+  Territory { ab2: c"ZM", ab3: c"ZMB", num: 894, tel: c"260", name: c"Zambia" },
+  Territory { ab2: c"ZW", ab3: c"ZWE", num: 716, tel: c"263", name: c"Zimbabwe" },
+];
+
+// The ISO 639 codes and English names of the languages that have a
+// two-letter code. Sorted by the two-letter code.
+#[rustfmt::skip]
+pub static LANGUAGES: [Language; 184] = [
+  Language { ab: c"aa", term: c"aar", lib: c"aar", name: c"Afar" },
+  Language { ab: c"ab", term: c"abk", lib: c"abk", name: c"Abkhazian" },
+  Language { ab: c"ae", term: c"ave", lib: c"ave", name: c"Avestan" },
+  Language { ab: c"af", term: c"afr", lib: c"afr", name: c"Afrikaans" },
+  Language { ab: c"ak", term: c"aka", lib: c"aka", name: c"Akan" },
+  Language { ab: c"am", term: c"amh", lib: c"amh", name: c"Amharic" },
+  Language { ab: c"an", term: c"arg", lib: c"arg", name: c"Aragonese" },
+  Language { ab: c"ar", term: c"ara", lib: c"ara", name: c"Arabic" },
+  Language { ab: c"as", term: c"asm", lib: c"asm", name: c"Assamese" },
+  Language { ab: c"av", term: c"ava", lib: c"ava", name: c"Avaric" },
+  Language { ab: c"ay", term: c"aym", lib: c"aym", name: c"Aymara" },
+  Language { ab: c"az", term: c"aze", lib: c"aze", name: c"Azerbaijani" },
+  Language { ab: c"ba", term: c"bak", lib: c"bak", name: c"Bashkir" },
+  Language { ab: c"be", term: c"bel", lib: c"bel", name: c"Belarusian" },
+  Language { ab: c"bg", term: c"bul", lib: c"bul", name: c"Bulgarian" },
+  Language { ab: c"bh", term: c"bih", lib: c"bih", name: c"Bihari languages" },
+  Language { ab: c"bi", term: c"bis", lib: c"bis", name: c"Bislama" },
+  Language { ab: c"bm", term: c"bam", lib: c"bam", name: c"Bambara" },
+  Language { ab: c"bn", term: c"ben", lib: c"ben", name: c"Bengali" },
+  Language { ab: c"bo", term: c"bod", lib: c"tib", name: c"Tibetan" },
+  Language { ab: c"br", term: c"bre", lib: c"bre", name: c"Breton" },
+  Language { ab: c"bs", term: c"bos", lib: c"bos", name: c"Bosnian" },
+  Language { ab: c"ca", term: c"cat", lib: c"cat", name: c"Catalan" },
+  Language { ab: c"ce", term: c"che", lib: c"che", name: c"Chechen" },
+  Language { ab: c"ch", term: c"cha", lib: c"cha", name: c"Chamorro" },
+  Language { ab: c"co", term: c"cos", lib: c"cos", name: c"Corsican" },
+  Language { ab: c"cr", term: c"cre", lib: c"cre", name: c"Cree" },
+  Language { ab: c"cs", term: c"ces", lib: c"cze", name: c"Czech" },
+  Language { ab: c"cu", term: c"chu", lib: c"chu", name: c"Church Slavic" },
+  Language { ab: c"cv", term: c"chv", lib: c"chv", name: c"Chuvash" },
+  Language { ab: c"cy", term: c"cym", lib: c"wel", name: c"Welsh" },
+  Language { ab: c"da", term: c"dan", lib: c"dan", name: c"Danish" },
+  Language { ab: c"de", term: c"deu", lib: c"ger", name: c"German" },
+  Language { ab: c"dv", term: c"div", lib: c"div", name: c"Divehi" },
+  Language { ab: c"dz", term: c"dzo", lib: c"dzo", name: c"Dzongkha" },
+  Language { ab: c"ee", term: c"ewe", lib: c"ewe", name: c"Ewe" },
+  Language { ab: c"el", term: c"ell", lib: c"gre", name: c"Greek" },
+  Language { ab: c"en", term: c"eng", lib: c"eng", name: c"English" },
+  Language { ab: c"eo", term: c"epo", lib: c"epo", name: c"Esperanto" },
+  Language { ab: c"es", term: c"spa", lib: c"spa", name: c"Spanish" },
+  Language { ab: c"et", term: c"est", lib: c"est", name: c"Estonian" },
+  Language { ab: c"eu", term: c"eus", lib: c"baq", name: c"Basque" },
+  Language { ab: c"fa", term: c"fas", lib: c"per", name: c"Persian" },
+  Language { ab: c"ff", term: c"ful", lib: c"ful", name: c"Fulah" },
+  Language { ab: c"fi", term: c"fin", lib: c"fin", name: c"Finnish" },
+  Language { ab: c"fj", term: c"fij", lib: c"fij", name: c"Fijian" },
+  Language { ab: c"fo", term: c"fao", lib: c"fao", name: c"Faroese" },
+  Language { ab: c"fr", term: c"fra", lib: c"fre", name: c"French" },
+  Language { ab: c"fy", term: c"fry", lib: c"fry", name: c"Western Frisian" },
+  Language { ab: c"ga", term: c"gle", lib: c"gle", name: c"Irish" },
+  Language { ab: c"gd", term: c"gla", lib: c"gla", name: c"Gaelic" },
+  Language { ab: c"gl", term: c"glg", lib: c"glg", name: c"Galician" },
+  Language { ab: c"gn", term: c"grn", lib: c"grn", name: c"Guarani" },
+  Language { ab: c"gu", term: c"guj", lib: c"guj", name: c"Gujarati" },
+  Language { ab: c"gv", term: c"glv", lib: c"glv", name: c"Manx" },
+  Language { ab: c"ha", term: c"hau", lib: c"hau", name: c"Hausa" },
+  Language { ab: c"he", term: c"heb", lib: c"heb", name: c"Hebrew" },
+  Language { ab: c"hi", term: c"hin", lib: c"hin", name: c"Hindi" },
+  Language { ab: c"ho", term: c"hmo", lib: c"hmo", name: c"Hiri Motu" },
+  Language { ab: c"hr", term: c"hrv", lib: c"hrv", name: c"Croatian" },
+  Language { ab: c"ht", term: c"hat", lib: c"hat", name: c"Haitian" },
+  Language { ab: c"hu", term: c"hun", lib: c"hun", name: c"Hungarian" },
+  Language { ab: c"hy", term: c"hye", lib: c"arm", name: c"Armenian" },
+  Language { ab: c"hz", term: c"her", lib: c"her", name: c"Herero" },
+  Language { ab: c"ia", term: c"ina", lib: c"ina", name: c"Interlingua" },
+  Language { ab: c"id", term: c"ind", lib: c"ind", name: c"Indonesian" },
+  Language { ab: c"ie", term: c"ile", lib: c"ile", name: c"Interlingue" },
+  Language { ab: c"ig", term: c"ibo", lib: c"ibo", name: c"Igbo" },
+  Language { ab: c"ii", term: c"iii", lib: c"iii", name: c"Sichuan Yi" },
+  Language { ab: c"ik", term: c"ipk", lib: c"ipk", name: c"Inupiaq" },
+  Language { ab: c"io", term: c"ido", lib: c"ido", name: c"Ido" },
+  Language { ab: c"is", term: c"isl", lib: c"ice", name: c"Icelandic" },
+  Language { ab: c"it", term: c"ita", lib: c"ita", name: c"Italian" },
+  Language { ab: c"iu", term: c"iku", lib: c"iku", name: c"Inuktitut" },
+  Language { ab: c"ja", term: c"jpn", lib: c"jpn", name: c"Japanese" },
+  Language { ab: c"jv", term: c"jav", lib: c"jav", name: c"Javanese" },
+  Language { ab: c"ka", term: c"kat", lib: c"geo", name: c"Georgian" },
+  Language { ab: c"kg", term: c"kon", lib: c"kon", name: c"Kongo" },
+  Language { ab: c"ki", term: c"kik", lib: c"kik", name: c"Kikuyu" },
+  Language { ab: c"kj", term: c"kua", lib: c"kua", name: c"Kuanyama" },
+  Language { ab: c"kk", term: c"kaz", lib: c"kaz", name: c"Kazakh" },
+  Language { ab: c"kl", term: c"kal", lib: c"kal", name: c"Kalaallisut" },
+  Language { ab: c"km", term: c"khm", lib: c"khm", name: c"Central Khmer" },
+  Language { ab: c"kn", term: c"kan", lib: c"kan", name: c"Kannada" },
+  Language { ab: c"ko", term: c"kor", lib: c"kor", name: c"Korean" },
+  Language { ab: c"kr", term: c"kau", lib: c"kau", name: c"Kanuri" },
+  Language { ab: c"ks", term: c"kas", lib: c"kas", name: c"Kashmiri" },
+  Language { ab: c"ku", term: c"kur", lib: c"kur", name: c"Kurdish" },
+  Language { ab: c"kv", term: c"kom", lib: c"kom", name: c"Komi" },
+  Language { ab: c"kw", term: c"cor", lib: c"cor", name: c"Cornish" },
+  Language { ab: c"ky", term: c"kir", lib: c"kir", name: c"Kirghiz" },
+  Language { ab: c"la", term: c"lat", lib: c"lat", name: c"Latin" },
+  Language { ab: c"lb", term: c"ltz", lib: c"ltz", name: c"Luxembourgish" },
+  Language { ab: c"lg", term: c"lug", lib: c"lug", name: c"Ganda" },
+  Language { ab: c"li", term: c"lim", lib: c"lim", name: c"Limburgan" },
+  Language { ab: c"ln", term: c"lin", lib: c"lin", name: c"Lingala" },
+  Language { ab: c"lo", term: c"lao", lib: c"lao", name: c"Lao" },
+  Language { ab: c"lt", term: c"lit", lib: c"lit", name: c"Lithuanian" },
+  Language { ab: c"lu", term: c"lub", lib: c"lub", name: c"Luba-Katanga" },
+  Language { ab: c"lv", term: c"lav", lib: c"lav", name: c"Latvian" },
+  Language { ab: c"mg", term: c"mlg", lib: c"mlg", name: c"Malagasy" },
+  Language { ab: c"mh", term: c"mah", lib: c"mah", name: c"Marshallese" },
+  Language { ab: c"mi", term: c"mri", lib: c"mao", name: c"Maori" },
+  Language { ab: c"mk", term: c"mkd", lib: c"mac", name: c"Macedonian" },
+  Language { ab: c"ml", term: c"mal", lib: c"mal", name: c"Malayalam" },
+  Language { ab: c"mn", term: c"mon", lib: c"mon", name: c"Mongolian" },
+  Language { ab: c"mr", term: c"mar", lib: c"mar", name: c"Marathi" },
+  Language { ab: c"ms", term: c"msa", lib: c"may", name: c"Malay" },
+  Language { ab: c"mt", term: c"mlt", lib: c"mlt", name: c"Maltese" },
+  Language { ab: c"my", term: c"mya", lib: c"bur", name: c"Burmese" },
+  Language { ab: c"na", term: c"nau", lib: c"nau", name: c"Nauru" },
+  Language { ab: c"nb", term: c"nob", lib: c"nob", name: c"Norwegian Bokmål" },
+  Language { ab: c"nd", term: c"nde", lib: c"nde", name: c"North Ndebele" },
+  Language { ab: c"ne", term: c"nep", lib: c"nep", name: c"Nepali" },
+  Language { ab: c"ng", term: c"ndo", lib: c"ndo", name: c"Ndonga" },
+  Language { ab: c"nl", term: c"nld", lib: c"dut", name: c"Dutch" },
+  Language { ab: c"nn", term: c"nno", lib: c"nno", name: c"Norwegian Nynorsk" },
+  Language { ab: c"no", term: c"nor", lib: c"nor", name: c"Norwegian" },
+  Language { ab: c"nr", term: c"nbl", lib: c"nbl", name: c"South Ndebele" },
+  Language { ab: c"nv", term: c"nav", lib: c"nav", name: c"Navajo" },
+  Language { ab: c"ny", term: c"nya", lib: c"nya", name: c"Chichewa" },
+  Language { ab: c"oc", term: c"oci", lib: c"oci", name: c"Occitan" },
+  Language { ab: c"oj", term: c"oji", lib: c"oji", name: c"Ojibwa" },
+  Language { ab: c"om", term: c"orm", lib: c"orm", name: c"Oromo" },
+  Language { ab: c"or", term: c"ori", lib: c"ori", name: c"Oriya" },
+  Language { ab: c"os", term: c"oss", lib: c"oss", name: c"Ossetian" },
+  Language { ab: c"pa", term: c"pan", lib: c"pan", name: c"Panjabi" },
+  Language { ab: c"pi", term: c"pli", lib: c"pli", name: c"Pali" },
+  Language { ab: c"pl", term: c"pol", lib: c"pol", name: c"Polish" },
+  Language { ab: c"ps", term: c"pus", lib: c"pus", name: c"Pushto" },
+  Language { ab: c"pt", term: c"por", lib: c"por", name: c"Portuguese" },
+  Language { ab: c"qu", term: c"que", lib: c"que", name: c"Quechua" },
+  Language { ab: c"rm", term: c"roh", lib: c"roh", name: c"Romansh" },
+  Language { ab: c"rn", term: c"run", lib: c"run", name: c"Rundi" },
+  Language { ab: c"ro", term: c"ron", lib: c"rum", name: c"Romanian" },
+  Language { ab: c"ru", term: c"rus", lib: c"rus", name: c"Russian" },
+  Language { ab: c"rw", term: c"kin", lib: c"kin", name: c"Kinyarwanda" },
+  Language { ab: c"sa", term: c"san", lib: c"san", name: c"Sanskrit" },
+  Language { ab: c"sc", term: c"srd", lib: c"srd", name: c"Sardinian" },
+  Language { ab: c"sd", term: c"snd", lib: c"snd", name: c"Sindhi" },
+  Language { ab: c"se", term: c"sme", lib: c"sme", name: c"Northern Sami" },
+  Language { ab: c"sg", term: c"sag", lib: c"sag", name: c"Sango" },
+  Language { ab: c"si", term: c"sin", lib: c"sin", name: c"Sinhala" },
+  Language { ab: c"sk", term: c"slk", lib: c"slo", name: c"Slovak" },
+  Language { ab: c"sl", term: c"slv", lib: c"slv", name: c"Slovenian" },
+  Language { ab: c"sm", term: c"smo", lib: c"smo", name: c"Samoan" },
+  Language { ab: c"sn", term: c"sna", lib: c"sna", name: c"Shona" },
+  Language { ab: c"so", term: c"som", lib: c"som", name: c"Somali" },
+  Language { ab: c"sq", term: c"sqi", lib: c"alb", name: c"Albanian" },
+  Language { ab: c"sr", term: c"srp", lib: c"srp", name: c"Serbian" },
+  Language { ab: c"ss", term: c"ssw", lib: c"ssw", name: c"Swati" },
+  Language { ab: c"st", term: c"sot", lib: c"sot", name: c"Southern Sotho" },
+  Language { ab: c"su", term: c"sun", lib: c"sun", name: c"Sundanese" },
+  Language { ab: c"sv", term: c"swe", lib: c"swe", name: c"Swedish" },
+  Language { ab: c"sw", term: c"swa", lib: c"swa", name: c"Swahili" },
+  Language { ab: c"ta", term: c"tam", lib: c"tam", name: c"Tamil" },
+  Language { ab: c"te", term: c"tel", lib: c"tel", name: c"Telugu" },
+  Language { ab: c"tg", term: c"tgk", lib: c"tgk", name: c"Tajik" },
+  Language { ab: c"th", term: c"tha", lib: c"tha", name: c"Thai" },
+  Language { ab: c"ti", term: c"tir", lib: c"tir", name: c"Tigrinya" },
+  Language { ab: c"tk", term: c"tuk", lib: c"tuk", name: c"Turkmen" },
+  Language { ab: c"tl", term: c"tgl", lib: c"tgl", name: c"Tagalog" },
+  Language { ab: c"tn", term: c"tsn", lib: c"tsn", name: c"Tswana" },
+  Language { ab: c"to", term: c"ton", lib: c"ton", name: c"Tongan" },
+  Language { ab: c"tr", term: c"tur", lib: c"tur", name: c"Turkish" },
+  Language { ab: c"ts", term: c"tso", lib: c"tso", name: c"Tsonga" },
+  Language { ab: c"tt", term: c"tat", lib: c"tat", name: c"Tatar" },
+  Language { ab: c"tw", term: c"twi", lib: c"twi", name: c"Twi" },
+  Language { ab: c"ty", term: c"tah", lib: c"tah", name: c"Tahitian" },
+  Language { ab: c"ug", term: c"uig", lib: c"uig", name: c"Uighur" },
+  Language { ab: c"uk", term: c"ukr", lib: c"ukr", name: c"Ukrainian" },
+  Language { ab: c"ur", term: c"urd", lib: c"urd", name: c"Urdu" },
+  Language { ab: c"uz", term: c"uzb", lib: c"uzb", name: c"Uzbek" },
+  Language { ab: c"ve", term: c"ven", lib: c"ven", name: c"Venda" },
+  Language { ab: c"vi", term: c"vie", lib: c"vie", name: c"Vietnamese" },
+  Language { ab: c"vo", term: c"vol", lib: c"vol", name: c"Volapük" },
+  Language { ab: c"wa", term: c"wln", lib: c"wln", name: c"Walloon" },
+  Language { ab: c"wo", term: c"wol", lib: c"wol", name: c"Wolof" },
+  Language { ab: c"xh", term: c"xho", lib: c"xho", name: c"Xhosa" },
+  Language { ab: c"yi", term: c"yid", lib: c"yid", name: c"Yiddish" },
+  Language { ab: c"yo", term: c"yor", lib: c"yor", name: c"Yoruba" },
+  Language { ab: c"za", term: c"zha", lib: c"zha", name: c"Zhuang" },
+  Language { ab: c"zh", term: c"zho", lib: c"chi", name: c"Chinese" },
+  Language { ab: c"zu", term: c"zul", lib: c"zul", name: c"Zulu" },
+];
+
+// The name, postal and telephone formats of the glibc locales, with the
+// escapes of ISO/IEC 14652. Sorted by the locale name.
+#[rustfmt::skip]
+pub static FORMATS: [Formats; 305] = [
+  Formats { locale: c"aa_DJ", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"%a-%l" },
+  Formats { locale: c"aa_ER", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"%a-%l" },
+  Formats { locale: c"aa_ET", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"%c-%a-%l", tel_dom_fmt: c"%a-%l" },
+  Formats { locale: c"ab_GE", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%d%N%f%N%d%N%b%N%s %h 5e %r%N%C%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"af_ZA", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"(%A) %l" },
+  Formats { locale: c"agr_PE", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"ak_GH", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%a%N%f%N%d%N%b%N%h %s %e %r%N%T, %S %z%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"%a %l" },
+  Formats { locale: c"am_ET", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"%c-%a-%l", tel_dom_fmt: c"%a-%l" },
+  Formats { locale: c"an_ES", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%a%N%f%N%d%N%b%N%s %h %e %r%N%z %T, %S%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"anp_IN", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"ar_AE", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"ar_BH", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"ar_DZ", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"ar_EG", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"ar_IN", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"ar_IQ", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"ar_JO", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"ar_KW", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"ar_LB", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"ar_LY", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"ar_MA", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"ar_OM", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"ar_QA", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"ar_SA", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"ar_SD", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"ar_SS", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"ar_SY", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"ar_TN", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"ar_YE", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"as_IN", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"ast_ES", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"ayc_PE", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"az_AZ", name_fmt: c"???", postal_fmt: c"???", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"az_IR", name_fmt: c"%d%t%s%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"\u{202a}+%c %a %l\u{202c}", tel_dom_fmt: c"\u{202a}%A‒%l\u{202c}" },
+  Formats { locale: c"be_BY", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"bem_ZM", name_fmt: c"%p%t%d1%g%t%M%f", postal_fmt: c"%a%N%f%N%e %h%N%b %s%N%z%N%T%N%c%N", tel_int_fmt: c"+%c %A %l", tel_dom_fmt: c"%A %l" },
+  Formats { locale: c"ber_DZ", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"ber_MA", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"bg_BG", name_fmt: c"%s%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%sN%h, %e, %r%N%z %T%N%c%N", tel_int_fmt: c"(+%c %a) %l", tel_dom_fmt: c"(0%a) %l" },
+  Formats { locale: c"bhb_IN", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"bho_IN", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"bho_NP", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%f%N%h%s%N%T", tel_int_fmt: c"+%c %a%t%l", tel_dom_fmt: c"" },
+  Formats { locale: c"bi_VU", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%a%b%s%c", tel_int_fmt: c"+%c %l", tel_dom_fmt: c"" },
+  Formats { locale: c"bn_BD", name_fmt: c"%p%t%f%t%m%t%g", postal_fmt: c"%a%N%f%N%d%N%b%N%h %s %e %r%N%T %z%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"%a %l" },
+  Formats { locale: c"bn_IN", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"bo_CN", name_fmt: c" ", postal_fmt: c"%c%N%T%N%s %h %e %r%N%b%N%d%N%f%N%a%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"0%a %l" },
+  Formats { locale: c"bo_IN", name_fmt: c" ", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"br_FR", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"%a %l" },
+  Formats { locale: c"brx_IN", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"bs_BA", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%a%N%f%N%d%N%b%N%h %s %e %r%N%T %z%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"byn_ER", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"%a-%l" },
+  Formats { locale: c"ca_AD", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"ca_ES", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"ca_FR", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"%a %l" },
+  Formats { locale: c"ca_IT", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"ce_RU", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"chr_US", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%a%N%f%N%d%N%b%N%h %s %e %r%N%T, %S %z%N%c%N", tel_int_fmt: c"+%c (%a) %l", tel_dom_fmt: c"(%a) %l" },
+  Formats { locale: c"ckb_IQ", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c %a%t%l", tel_dom_fmt: c"" },
+  Formats { locale: c"cmn_TW", name_fmt: c"%f%t%g%t%d", postal_fmt: c"%c%N%T%N%s %h %e %r%N%b%N%d%N%f%N%a%N", tel_int_fmt: c"+%c-%a-%l", tel_dom_fmt: c"%A-%l" },
+  Formats { locale: c"crh_UA", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"cs_CZ", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"(0%a) %l" },
+  Formats { locale: c"csb_PL", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"cv_RU", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"cy_GB", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%d%N%f%N%d%N%b%N%s %h 5e %r%N%C%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"%A %l" },
+  Formats { locale: c"da_DK", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"de_AT", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"%a %l" },
+  Formats { locale: c"de_BE", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"de_CH", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"de_DE", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"%A %l" },
+  Formats { locale: c"de_IT", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"de_LI", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"de_LU", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"doi_IN", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"dsb_DE", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"%A %l" },
+  Formats { locale: c"dv_MV", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"%a %l" },
+  Formats { locale: c"dz_BT", name_fmt: c"%p%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%r%t%e%t%b%N%h%t%s%N%T%N%S%N%z%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"el_CY", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"el_GR", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"en_AG", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"en_AU", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"en_BW", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"en_CA", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"en_DK", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"en_GB", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"%A %l" },
+  Formats { locale: c"en_HK", name_fmt: c"%p%t%g%t%m%t%f", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"en_IE", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"en_IL", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"en_IN", name_fmt: c"%p%t%g%t%m%t%f", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"en_NG", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"(%A) %l" },
+  Formats { locale: c"en_NZ", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"en_PH", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"en_SC", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%h%s%N%T", tel_int_fmt: c"+%c %l", tel_dom_fmt: c"" },
+  Formats { locale: c"en_SG", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"en_US", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%a%N%f%N%d%N%b%N%h %s %e %r%N%T, %S %z%N%c%N", tel_int_fmt: c"+%c (%a) %l", tel_dom_fmt: c"(%a) %l" },
+  Formats { locale: c"en_ZA", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"(%A) %l" },
+  Formats { locale: c"en_ZM", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%a%N%f%N%e %h%N%b %s%N%z%N%T%N%c%N", tel_int_fmt: c"+%c %A %l", tel_dom_fmt: c"%A %l" },
+  Formats { locale: c"en_ZW", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"es_AR", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"es_BO", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"es_CL", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"es_CO", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"es_CR", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"%l" },
+  Formats { locale: c"es_CU", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"es_DO", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"es_EC", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"es_ES", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"es_GT", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"es_HN", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"es_MX", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"es_NI", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"es_PA", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"es_PE", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"es_PR", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"es_PY", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"es_SV", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"es_US", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%a%N%f%N%d%N%b%N%h %s %e %r%N%T, %S %z%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"es_UY", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"es_VE", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"et_EE", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%a%N%f%N%d%N%b%N%s%t%h%t%e%t%r%N%C-%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"eu_ES", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"eu_FR", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"%a %l" },
+  Formats { locale: c"fa_IR", name_fmt: c"%d%t%s%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"\u{202a}+%c %a %l\u{202c}", tel_dom_fmt: c"\u{202a}%A‒%l\u{202c}" },
+  Formats { locale: c"ff_SN", name_fmt: c"%p%t%g%m%t%f", postal_fmt: c"%a%N%f%N%d%N%b%N%h %s %e %r%N%T, %S %z%N%c%N", tel_int_fmt: c"%c %a %l", tel_dom_fmt: c"%a %l" },
+  Formats { locale: c"fi_FI", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%d%N%b%N%a%N%s %h%t%e%t%r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"(%A) %l" },
+  Formats { locale: c"fil_PH", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%a%N%f%N%d%N%b%N%h %s %e %r%N%T %z%N%c%N", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"fo_FO", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"fr_BE", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"fr_CA", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"fr_CH", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"fr_FR", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"%a %l" },
+  Formats { locale: c"fr_LU", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"fur_IT", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"fy_DE", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"%A %l" },
+  Formats { locale: c"fy_NL", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"ga_IE", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"gd_GB", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%S%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"%A %l" },
+  Formats { locale: c"gez_ER", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"%a-%l" },
+  Formats { locale: c"gez_ET", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"%c-%a-%l", tel_dom_fmt: c"%a-%l" },
+  Formats { locale: c"gl_ES", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"gu_IN", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"gv_GB", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"ha_NG", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"(%A) %l" },
+  Formats { locale: c"hak_TW", name_fmt: c"%f%t%g%t%d", postal_fmt: c"%c%N%T%N%s %h %e %r%N%b%N%d%N%f%N%a%N", tel_int_fmt: c"+%c-%a-%l", tel_dom_fmt: c"%A-%l" },
+  Formats { locale: c"he_IL", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"hi_IN", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"hif_FJ", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%a%N%h%t%s%N%N%T%c", tel_int_fmt: c"+%c %l", tel_dom_fmt: c"" },
+  Formats { locale: c"hne_IN", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"hr_HR", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%d%N%a%N%s %h%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"%A %l" },
+  Formats { locale: c"hsb_DE", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"%A %l" },
+  Formats { locale: c"ht_HT", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %R%N%%Z %t%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"%a %l" },
+  Formats { locale: c"hu_HU", name_fmt: c"%d%t%f%t%m%t%g", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"hy_AM", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%d%N%f%N%d%N%b%N%s %h 5e %r%N%C%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"ia_FR", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s%t%h%t%e%t%r%N%z%t%T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"%a %l" },
+  Formats { locale: c"id_ID", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"ig_NG", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"(%A) %l" },
+  Formats { locale: c"ik_CA", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"is_IS", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"it_CH", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"it_IT", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"iu_CA", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%a%N%f%N%d%N%b%N%h %s %e %r%N%T %z%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"ja_JP", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"ka_GE", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%d%N%f%N%d%N%b%N%s %h 5e %r%N%C%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"kab_DZ", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"kk_KZ", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"(%A) %l" },
+  Formats { locale: c"kl_GL", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"km_KH", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%r%t%e%t%b%N%h%t%s%N%T%N%S%N%z%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"(%A)%l" },
+  Formats { locale: c"kn_IN", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"ko_KR", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"(%A) %l" },
+  Formats { locale: c"kok_IN", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"ks_IN", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"ku_TR", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"kw_GB", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"ky_KG", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"lb_LU", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"%A %l" },
+  Formats { locale: c"lg_UG", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"%a %l" },
+  Formats { locale: c"li_BE", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"li_NL", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"lij_IT", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"ln_CD", name_fmt: c"%d%t%g%t%m%t%f%m", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a%t%l", tel_dom_fmt: c"%a %l" },
+  Formats { locale: c"lo_LA", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%r%t%e%t%b%N%h%t%s%N%T%N%S%N%z%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"(%A)%l" },
+  Formats { locale: c"lt_LT", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"lv_LV", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"lzh_TW", name_fmt: c"%f%d%t%g", postal_fmt: c"%c%N%T%N%s %h %e %r%N%b%N%d%N%f%N%a%N", tel_int_fmt: c"+%c-%a-%l", tel_dom_fmt: c"%A-%l" },
+  Formats { locale: c"mag_IN", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"mai_IN", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"mai_NP", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%f%N%h%s%N%T", tel_int_fmt: c"+%c %a%t%l", tel_dom_fmt: c"" },
+  Formats { locale: c"mfe_MU", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%h%s%N%T", tel_int_fmt: c"+%c %l", tel_dom_fmt: c"" },
+  Formats { locale: c"mg_MG", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"%a %l" },
+  Formats { locale: c"mhr_RU", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"mi_NZ", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"miq_NI", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"mjw_IN", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"mk_MK", name_fmt: c"%g%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"%A %l" },
+  Formats { locale: c"ml_IN", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"mn_MN", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"(+%c %a) %l", tel_dom_fmt: c"(0%a) %l" },
+  Formats { locale: c"mni_IN", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"mnw_MM", name_fmt: c"%p%t%g%t%m%t%f", postal_fmt: c"%a%N%d%N%f%N%b%N%h%t%r%t%e%t%s%N%T%t%z%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"%a %l" },
+  Formats { locale: c"mr_IN", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"ms_MY", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"mt_MT", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"my_MM", name_fmt: c"%p%t%g%t%m%t%f", postal_fmt: c"%a%N%d%N%f%N%b%N%h%t%r%t%e%t%s%N%T%t%z%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"%a %l" },
+  Formats { locale: c"nan_TW", name_fmt: c"%f%t%g%t%d", postal_fmt: c"%c%N%T%N%s %h %e %r%N%b%N%d%N%f%N%a%N", tel_int_fmt: c"+%c-%a-%l", tel_dom_fmt: c"%A-%l" },
+  Formats { locale: c"nb_NO", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %l", tel_dom_fmt: c"%l" },
+  Formats { locale: c"nds_DE", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"%A %l" },
+  Formats { locale: c"nds_NL", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"ne_NP", name_fmt: c"%p%t%g%t%m%t%f", postal_fmt: c"%f%N%h%s%N%T", tel_int_fmt: c"+%c %a%t%l", tel_dom_fmt: c"" },
+  Formats { locale: c"nhn_MX", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"niu_NU", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"niu_NZ", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"nl_AW", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"nl_BE", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"nl_NL", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"nn_NO", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %l", tel_dom_fmt: c"%l" },
+  Formats { locale: c"nr_ZA", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"(%A) %l" },
+  Formats { locale: c"nso_ZA", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"(%A) %l" },
+  Formats { locale: c"oc_FR", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%d%N%f%N%d%N%b%N%s %h 5e %r%N%C%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"%a %l" },
+  Formats { locale: c"om_ET", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"%c-%a-%l", tel_dom_fmt: c"%a-%l" },
+  Formats { locale: c"om_KE", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"%c-%a-%l", tel_dom_fmt: c"%a-%l" },
+  Formats { locale: c"or_IN", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"os_RU", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"pa_IN", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"pa_PK", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%a%N%f%N%d%N%b%N%h %s %e %r%N%T %z%N%c%N", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"pap_AW", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%d%N%f%N%d%N%b%N%s %h 5e %r%N%C%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"pap_CW", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%d%N%f%N%d%N%b%N%s %h 5e %r%N%C%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"pl_PL", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"ps_AF", name_fmt: c"%s%t%p%t%g%t%m%t%f", postal_fmt: c"%a%N%f%N%d%N%b%N%s %h %e %r%N%C-%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"%A‒%l" },
+  Formats { locale: c"pt_BR", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"pt_PT", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %l", tel_dom_fmt: c"%l" },
+  Formats { locale: c"quz_PE", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"raj_IN", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"rif_MA", name_fmt: c"%g%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c%l", tel_dom_fmt: c"0%l" },
+  Formats { locale: c"ro_RO", name_fmt: c"%d%t%s%t%f%t%g%t%m", postal_fmt: c"%f%N%a%d%N%s%t%h%N%b%t%e%t%r%N%z%t%T%N%S%t%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"ru_RU", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"ru_UA", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"rw_RW", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"%a%l" },
+  Formats { locale: c"sa_IN", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"sah_RU", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"sat_IN", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"sc_IT", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"sd_IN", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"se_NO", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %l", tel_dom_fmt: c"%l" },
+  Formats { locale: c"sgs_LT", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"shn_MM", name_fmt: c"%p%t%g%t%m%t%f", postal_fmt: c"%a%N%d%N%f%N%b%N%h%t%r%t%e%t%s%N%T%t%z%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"%a %l" },
+  Formats { locale: c"shs_CA", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"si_LK", name_fmt: c"%g%t%m%t%f%t%s", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c (%a) %l", tel_dom_fmt: c"(%a) %l" },
+  Formats { locale: c"sid_ET", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"%c-%a-%l", tel_dom_fmt: c"%a-%l" },
+  Formats { locale: c"sk_SK", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"sl_SI", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"sm_WS", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%a%N%f%N%d%N%b%N%h %s %e %r%N%T, %S %z%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"so_DJ", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"%a-%l" },
+  Formats { locale: c"so_ET", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"%c-%a-%l", tel_dom_fmt: c"%a-%l" },
+  Formats { locale: c"so_KE", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"%c-%a-%l", tel_dom_fmt: c"%a-%l" },
+  Formats { locale: c"so_SO", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"%c-%a-%l", tel_dom_fmt: c"%a-%l" },
+  Formats { locale: c"sq_AL", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"sq_MK", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"%A %l" },
+  Formats { locale: c"sr_ME", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"%A %l" },
+  Formats { locale: c"sr_RS", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"%A %l" },
+  Formats { locale: c"ss_ZA", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"(%A) %l" },
+  Formats { locale: c"st_ZA", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"(%A) %l" },
+  Formats { locale: c"sv_FI", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%d%N%b%N%a%N%s %h%t%e%t%r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"(%A) %l" },
+  Formats { locale: c"sv_SE", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c-%a-%l", tel_dom_fmt: c"0%a-%l" },
+  Formats { locale: c"sw_KE", name_fmt: c"%p%t%g%m%t%f", postal_fmt: c"%a%N%f%N%d%N%b%N%h %s %e %r%N%T, %S %z%N%c%N", tel_int_fmt: c"%c %a %l", tel_dom_fmt: c"%A %l" },
+  Formats { locale: c"sw_TZ", name_fmt: c"%p%t%g%m%t%f", postal_fmt: c"%a%N%f%N%d%N%b%N%h %s %e %r%N%T, %S %z%N%c%N", tel_int_fmt: c"%c %a %l", tel_dom_fmt: c"%A %l" },
+  Formats { locale: c"szl_PL", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"ta_IN", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"ta_LK", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c (%a) %l", tel_dom_fmt: c"(%a) %l" },
+  Formats { locale: c"tcy_IN", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"te_IN", name_fmt: c"%p%t%f%t%g%t%m", postal_fmt: c"%a%N%d%N%f%N%r%t%e%N%h%t%b%N%s%t%N%T%t%z%N%S%N%c", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"tg_TJ", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%a%N%f%N%d%N%b%N%h %s %e %r%N%T %z%N%c%N", tel_int_fmt: c"+%c %a%t%l", tel_dom_fmt: c"" },
+  Formats { locale: c"th_TH", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%r%t%e%t%b%N%h%t%s%N%T%N%S%N%z%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"0-%a%l" },
+  Formats { locale: c"the_NP", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c %a%t%l", tel_dom_fmt: c"" },
+  Formats { locale: c"ti_ER", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"%a-%l" },
+  Formats { locale: c"ti_ET", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"%c-%a-%l", tel_dom_fmt: c"%a-%l" },
+  Formats { locale: c"tig_ER", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"%a-%l" },
+  Formats { locale: c"tk_TM", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"tl_PH", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%a%N%f%N%d%N%b%N%h %s %e %r%N%T %z%N%c%N", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"tn_ZA", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"(%A) %l" },
+  Formats { locale: c"to_TO", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%a%b%s%c", tel_int_fmt: c"+%c %l", tel_dom_fmt: c"" },
+  Formats { locale: c"tpi_PG", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%a%s%z%C", tel_int_fmt: c"+%c %l", tel_dom_fmt: c"" },
+  Formats { locale: c"tr_CY", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"tr_TR", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"ts_ZA", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"(%A) %l" },
+  Formats { locale: c"tt_RU", name_fmt: c"???", postal_fmt: c"???", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"ug_CN", name_fmt: c"%f%t%g%t%d", postal_fmt: c"%c%N%T%N%s %h %e %r%N%b%N%d%N%f%N%a%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"0%a %l" },
+  Formats { locale: c"uk_UA", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%a%t%f%t%d%N%s%t%h%t, %b%t%e%t%r%N%T%t%S%N%z%N%c", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"(%A) %l" },
+  Formats { locale: c"unm_US", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%a%N%f%N%d%N%b%N%h %s %e %r%N%T, %S %z%N%c%N", tel_int_fmt: c"+%c (%a) %l", tel_dom_fmt: c"(%a) %l" },
+  Formats { locale: c"ur_IN", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"ur_PK", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%a%N%f%N%d%N%b%N%h %s %e %r%N%T %z%N%c%N", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"uz_UZ", name_fmt: c"%d%t%f%t%g%t%m", postal_fmt: c"%C%N%T%N%s %h%N%f%N%b%N%d%N%e %r%N%a%N%z", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"ve_ZA", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"(%A) %l" },
+  Formats { locale: c"vi_VN", name_fmt: c"%p%t%f%t%m%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"wa_BE", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"wae_CH", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"wal_ET", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"%c-%a-%l", tel_dom_fmt: c"%a-%l" },
+  Formats { locale: c"wo_SN", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"%a %l" },
+  Formats { locale: c"xh_ZA", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"(%A) %l" },
+  Formats { locale: c"yi_US", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%d%N%f%N%d%N%b%N%s %h 5e %r%N%C%z %T%N%c%N", tel_int_fmt: c"+%c (%a) %l", tel_dom_fmt: c"(%a) %l" },
+  Formats { locale: c"yo_NG", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"(%A) %l" },
+  Formats { locale: c"yue_HK", name_fmt: c"%f%t%g%t%d", postal_fmt: c"%c%T%s%h%N%b%e%r%N%f%N%d%N%a", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"yuw_PG", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"" },
+  Formats { locale: c"zh_CN", name_fmt: c"%f%t%g%t%d", postal_fmt: c"%c%N%T%N%s %h %e %r%N%b%N%d%N%f%N%a%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"0%a %l" },
+  Formats { locale: c"zh_HK", name_fmt: c"%f%t%d1", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"zh_SG", name_fmt: c"%p%t%f%t%g", postal_fmt: c"%z%c%T%s%b%e%r", tel_int_fmt: c"+%c ;%a ;%l", tel_dom_fmt: c"" },
+  Formats { locale: c"zh_TW", name_fmt: c"%f%t%g%t%d", postal_fmt: c"%c%N%T%N%s %h %e %r%N%b%N%d%N%f%N%a%N", tel_int_fmt: c"+%c-%a-%l", tel_dom_fmt: c"%A-%l" },
+  Formats { locale: c"zu_ZA", name_fmt: c"%d%t%g%t%m%t%f", postal_fmt: c"%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", tel_int_fmt: c"+%c %a %l", tel_dom_fmt: c"(%A) %l" },
+];
+
+/// Returns whether a territory uses US Letter paper rather than A4, after
+/// the paperSize of CLDR.
+pub fn is_letter_territory(region: &str) -> bool {
+  matches!(
+    region,
+    "BZ" |
+      "CA" |
+      "CL" |
+      "CO" |
+      "CR" |
+      "GT" |
+      "MX" |
+      "NI" |
+      "PA" |
+      "PH" |
+      "PR" |
+      "SV" |
+      "US" |
+      "VE"
+  )
+}
+
+/// Returns whether a territory uses US customary units rather than the
+/// metric system, after the measurementSystem of CLDR.
+pub fn is_us_system_territory(region: &str) -> bool {
+  matches!(region, "LR" | "MM" | "US")
+}
@@ -0,0 +1,65 @@
+use {
+  super::TimeObject,
+  crate::{
+    allocation::borrow::ToOwned,
+    c_int,
+    std::errno,
+    support::locale::localedef::{self, Section, Source}
+  },
+  allocation::borrow::Cow,
+  core::ffi
+};
+
+// A list of names has to name every day or month
+fn load_names<const N: usize>(
+  section: &Section,
+  keyword: &str,
+  names: &mut [Cow<'_, ffi::CStr>; N]
+) -> Result<(), c_int> {
+  if section.get(keyword).is_none() {
+    return Ok(());
+  }
+
+  let strings = section.strings(keyword);
+  if strings.len() != N {
+    return Err(errno::ENOENT);
+  }
+  for (name, s) in names.iter_mut().zip(strings) {
+    *name = localedef::to_cstr(s)?;
+  }
+
+  Ok(())
+}
+
+pub fn load(
+  time: &mut TimeObject,
+  locale: &ffi::CStr,
+  source: &Source
+) -> Result<(), c_int> {
+  let section = source.section("LC_TIME");
+  localedef::load_base(time, locale, source, section)?;
+
+  if let Some(section) = section {
+    load_names(section, "abday", &mut time.abday)?;
+    load_names(section, "day", &mut time.day)?;
+    load_names(section, "abmon", &mut time.abmon)?;
+    load_names(section, "mon", &mut time.mon)?;
+    load_names(section, "am_pm", &mut time.am_pm)?;
+
+    let formats = [
+      ("d_t_fmt", &mut time.d_t_fmt),
+      ("d_fmt", &mut time.d_fmt),
+      ("t_fmt", &mut time.t_fmt),
+      ("t_fmt_ampm", &mut time.t_fmt_ampm)
+    ];
+    for (keyword, field) in formats {
+      if let Some(format) = section.string(keyword) {
+        *field = localedef::to_cstr(format)?;
+      }
+    }
+  }
+
+  time.name = Cow::Owned(locale.to_owned());
+
+  Ok(())
+}
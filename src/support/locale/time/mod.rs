@@ -1,13 +1,26 @@
+mod localedef;
+
 use {
-  super::{LocaleObject, is_posix_locale},
+  super::{LocaleObject, is_posix_locale, localedef::Source},
   crate::{allocation::borrow::ToOwned, c_int, support::locale::errno},
   allocation::borrow::Cow,
   core::ffi
 };
 
+/// The names and formats of dates and times. Only localedef sources have
+/// them, the built-in locales use the ones of the POSIX locale.
 #[derive(Debug, Clone)]
 pub struct TimeObject<'a> {
-  name: Cow<'a, ffi::CStr>
+  name: Cow<'a, ffi::CStr>,
+  pub abday: [Cow<'a, ffi::CStr>; 7],
+  pub day: [Cow<'a, ffi::CStr>; 7],
+  pub abmon: [Cow<'a, ffi::CStr>; 12],
+  pub mon: [Cow<'a, ffi::CStr>; 12],
+  pub am_pm: [Cow<'a, ffi::CStr>; 2],
+  pub d_t_fmt: Cow<'a, ffi::CStr>,
+  pub d_fmt: Cow<'a, ffi::CStr>,
+  pub t_fmt: Cow<'a, ffi::CStr>,
+  pub t_fmt_ampm: Cow<'a, ffi::CStr>
 }
 
 impl<'a> LocaleObject for TimeObject<'a> {
@@ -41,6 +54,16 @@ impl<'a> LocaleObject for TimeObject<'a> {
   fn get_name(&self) -> &ffi::CStr {
     self.name.as_ref()
   }
+
+  fn load_source(
+    &mut self,
+    locale: &ffi::CStr,
+    source: &Source
+  ) -> Result<&ffi::CStr, c_int> {
+    localedef::load(self, locale, source)?;
+
+    Ok(self.name.as_ref())
+  }
 }
 
 impl<'a> Default for TimeObject<'a> {
@@ -49,4 +72,57 @@ impl<'a> Default for TimeObject<'a> {
   }
 }
 
-pub const DEFAULT_TIME: TimeObject = TimeObject { name: Cow::Borrowed(c"C") };
+pub const DEFAULT_TIME: TimeObject = TimeObject {
+  name: Cow::Borrowed(c"C"),
+  abday: [
+    Cow::Borrowed(c"Sun"),
+    Cow::Borrowed(c"Mon"),
+    Cow::Borrowed(c"Tue"),
+    Cow::Borrowed(c"Wed"),
+    Cow::Borrowed(c"Thu"),
+    Cow::Borrowed(c"Fri"),
+    Cow::Borrowed(c"Sat")
+  ],
+  day: [
+    Cow::Borrowed(c"Sunday"),
+    Cow::Borrowed(c"Monday"),
+    Cow::Borrowed(c"Tuesday"),
+    Cow::Borrowed(c"Wednesday"),
+    Cow::Borrowed(c"Thursday"),
+    Cow::Borrowed(c"Friday"),
+    Cow::Borrowed(c"Saturday")
+  ],
+  abmon: [
+    Cow::Borrowed(c"Jan"),
+    Cow::Borrowed(c"Feb"),
+    Cow::Borrowed(c"Mar"),
+    Cow::Borrowed(c"Apr"),
+    Cow::Borrowed(c"May"),
+    Cow::Borrowed(c"Jun"),
+    Cow::Borrowed(c"Jul"),
+    Cow::Borrowed(c"Aug"),
+    Cow::Borrowed(c"Sep"),
+    Cow::Borrowed(c"Oct"),
+    Cow::Borrowed(c"Nov"),
+    Cow::Borrowed(c"Dec")
+  ],
+  mon: [
+    Cow::Borrowed(c"January"),
+    Cow::Borrowed(c"February"),
+    Cow::Borrowed(c"March"),
+    Cow::Borrowed(c"April"),
+    Cow::Borrowed(c"May"),
+    Cow::Borrowed(c"June"),
+    Cow::Borrowed(c"July"),
+    Cow::Borrowed(c"August"),
+    Cow::Borrowed(c"September"),
+    Cow::Borrowed(c"October"),
+    Cow::Borrowed(c"November"),
+    Cow::Borrowed(c"December")
+  ],
+  am_pm: [Cow::Borrowed(c"AM"), Cow::Borrowed(c"PM")],
+  d_t_fmt: Cow::Borrowed(c"%a %b %e %H:%M:%S %Y"),
+  d_fmt: Cow::Borrowed(c"%m/%d/%y"),
+  t_fmt: Cow::Borrowed(c"%H:%M:%S"),
+  t_fmt_ampm: Cow::Borrowed(c"%I:%M:%S %p")
+};
@@ -0,0 +1,81 @@
+use {
+  crate::{arch::sys, c_int},
+  core::{ffi, ptr, slice},
+  syscalls::raw_syscall
+};
+
+const AT_FDCWD: isize = -100;
+const O_RDONLY: usize = 0;
+const O_CLOEXEC: usize = 0o2000000;
+const SEEK_END: usize = 2;
+const PROT_READ: usize = 1;
+const MAP_PRIVATE: usize = 2;
+
+// System calls return -4095..-1 for an errno
+#[inline]
+fn check(ret: usize) -> Result<usize, c_int> {
+  if ret > -4096isize as usize {
+    Err(-(ret as isize) as c_int)
+  } else {
+    Ok(ret)
+  }
+}
+
+/// A private read-only mapping of a whole file, unmapped when dropped.
+#[derive(Debug)]
+pub struct FileMapping {
+  addr: *const u8,
+  len: usize
+}
+
+unsafe impl Send for FileMapping {}
+unsafe impl Sync for FileMapping {}
+
+impl FileMapping {
+  pub fn open(path: &ffi::CStr) -> Result<Self, c_int> {
+    let fd = check(unsafe {
+      raw_syscall!(
+        sys::SYS_OPENAT,
+        AT_FDCWD,
+        path.as_ptr(),
+        O_RDONLY | O_CLOEXEC
+      )
+    })?;
+
+    let result =
+      check(unsafe { raw_syscall!(sys::SYS_LSEEK, fd, 0, SEEK_END) }).and_then(
+        |len| {
+          if len == 0 {
+            return Ok(Self { addr: ptr::null(), len });
+          }
+
+          let addr =
+            check(unsafe { sys::mmap(0, len, PROT_READ, MAP_PRIVATE, fd, 0) })?;
+
+          Ok(Self { addr: addr as *const u8, len })
+        }
+      );
+
+    // The mapping stays valid after the descriptor is closed
+    unsafe { raw_syscall!(sys::SYS_CLOSE, fd) };
+
+    result
+  }
+
+  #[inline]
+  pub fn as_bytes(&self) -> &[u8] {
+    if self.addr.is_null() {
+      return &[];
+    }
+
+    unsafe { slice::from_raw_parts(self.addr, self.len) }
+  }
+}
+
+impl Drop for FileMapping {
+  fn drop(&mut self) {
+    if !self.addr.is_null() {
+      unsafe { raw_syscall!(sys::SYS_MUNMAP, self.addr, self.len) };
+    }
+  }
+}
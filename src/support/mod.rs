@@ -1,4 +1,7 @@
 pub mod algorithm;
+pub mod environment;
+pub mod intl;
 pub mod locale;
+pub mod mapping;
 pub mod string;
 pub mod sync;
@@ -1,3 +1,4 @@
 pub mod futex;
+pub mod rcu;
 
 mod critical_section;
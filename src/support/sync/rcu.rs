@@ -0,0 +1,97 @@
+use {
+  crate::allocation::{boxed::Box, vec::Vec},
+  core::{
+    cell::UnsafeCell,
+    hint,
+    iter,
+    ptr,
+    sync::atomic::{
+      AtomicBool,
+      AtomicPtr,
+      Ordering::{Acquire, Release}
+    }
+  }
+};
+
+/// A value that readers see without locks while writers replace it.
+///
+/// A writer makes a new value from the current one and publishes it with one
+/// atomic store. Replaced values are never freed: C hands out pointers into
+/// them, such as the names returned by setlocale, which must stay valid for
+/// as long as the caller keeps them. Readers therefore get a plain reference,
+/// never wait, and may update the value while still holding an older one.
+///
+/// So that memory stays bounded, a writer publishes an earlier value again
+/// instead of its new one when the two are the same, and the cell keeps at
+/// most one value for every distinct state it has been in. Writers are
+/// serialized among themselves, so the function making a new value must not
+/// update the same cell.
+pub struct Rcu<T: 'static> {
+  current: AtomicPtr<T>,
+  /// The value the cell started with, which is not ours to allocate
+  initial: &'static T,
+  /// Every value published since, which only writers touch
+  published: UnsafeCell<Vec<&'static T>>,
+  writer: AtomicBool
+}
+
+unsafe impl<T: Sync> Sync for Rcu<T> {}
+
+impl<T: 'static> Rcu<T> {
+  pub const fn new(initial: &'static T) -> Self {
+    Self {
+      current: AtomicPtr::new(ptr::from_ref(initial).cast_mut()),
+      initial,
+      published: UnsafeCell::new(Vec::new()),
+      writer: AtomicBool::new(false)
+    }
+  }
+
+  #[inline]
+  pub fn read(&self) -> &'static T {
+    unsafe { &*self.current.load(Acquire) }
+  }
+
+  /// Replaces the value with the one `f` makes from the current value, or
+  /// leaves it as it was if `f` fails. When `same` holds for the new value
+  /// and an earlier one, the earlier one is published again.
+  pub fn update<E>(
+    &self,
+    f: impl FnOnce(&T) -> Result<T, E>,
+    same: impl Fn(&T, &T) -> bool
+  ) -> Result<(), E> {
+    while self
+      .writer
+      .compare_exchange_weak(false, true, Acquire, Acquire)
+      .is_err()
+    {
+      hint::spin_loop();
+    }
+
+    let result = f(self.read()).map(|value| self.publish(value, same));
+
+    self.writer.store(false, Release);
+
+    result
+  }
+
+  fn publish(
+    &self,
+    value: T,
+    same: impl Fn(&T, &T) -> bool
+  ) {
+    // We hold the writer lock, so nothing else touches the list
+    let published = unsafe { &mut *self.published.get() };
+
+    let earlier = iter::once(self.initial)
+      .chain(published.iter().copied())
+      .find(|earlier| same(earlier, &value));
+    let value = earlier.unwrap_or_else(|| {
+      let value: &'static T = Box::leak(Box::new(value));
+      published.push(value);
+      value
+    });
+
+    self.current.store(ptr::from_ref(value).cast_mut(), Release);
+  }
+}
@@ -2,7 +2,10 @@
 
 #include <wctype.h>
 
+#include <atomic>
 #include <iostream>
+#include <string>
+#include <thread>
 
 extern "C"
 {
@@ -77,6 +80,10 @@ extern "C"
   int rs_tolower_l(int, strogino_locale_t);
   int rs_toupper(int);
   int rs_toupper_l(int, strogino_locale_t);
+
+  const unsigned short** __rs_ctype_b_loc(void);
+  const int32_t** __rs_ctype_tolower_loc(void);
+  const int32_t** __rs_ctype_toupper_loc(void);
 }
 
 TEST(iswascii, examples)
@@ -314,7 +321,7 @@ TEST(iswpunct, examples)
 
   ASSERT_TRUE(rs_iswpunct(L'.'));
 
-  ASSERT_FALSE(rs_iswpunct_l(L'€', loc));
+  ASSERT_TRUE(rs_iswpunct_l(L'€', loc));
   ASSERT_FALSE(rs_iswpunct(L'A'));
   ASSERT_FALSE(rs_iswpunct(L'\t'));
   ASSERT_FALSE(rs_iswpunct(L' '));
@@ -324,6 +331,23 @@ TEST(iswpunct, examples)
   rs_freelocale(loc);
 }
 
+TEST(ispunct, symbols)
+{
+  ASSERT_NE(rs_setlocale(LC_ALL, "C"), nullptr);
+
+  strogino_locale_t loc = rs_newlocale(LC_CTYPE_MASK, "en_US.UTF-8", 0);
+  ASSERT_NE(nullptr, loc);
+
+  for (char c : std::string("$+<=>^`|~")) {
+    ASSERT_NE(0, rs_ispunct(c)) << c;
+    ASSERT_NE(0, rs_ispunct_l(c, loc)) << c;
+    ASSERT_TRUE(rs_iswpunct(c)) << c;
+    ASSERT_TRUE(rs_iswpunct_l(c, loc)) << c;
+  }
+
+  rs_freelocale(loc);
+}
+
 TEST(iswspace, examples)
 {
   ASSERT_NE(rs_setlocale(LC_ALL, "C"), nullptr);
@@ -450,6 +474,31 @@ TEST(towupper, examples)
   rs_freelocale(loc);
 }
 
+TEST(towupper, turkish)
+{
+  strogino_locale_t loc = rs_newlocale(LC_CTYPE_MASK, "tr_TR.UTF-8", 0);
+  ASSERT_NE(nullptr, loc);
+
+  ASSERT_EQ(L'İ', rs_towupper_l(L'i', loc));
+  ASSERT_EQ(L'I', rs_towupper_l(L'ı', loc));
+  ASSERT_EQ(L'ı', rs_towlower_l(L'I', loc));
+  ASSERT_EQ(L'i', rs_towlower_l(L'İ', loc));
+
+  // The dotted capital I is not a single byte, so toupper leaves 'i' alone
+  ASSERT_EQ('i', rs_toupper_l('i', loc));
+  ASSERT_EQ('i', rs_tolower_l('i', loc));
+
+  rs_freelocale(loc);
+
+  loc = rs_newlocale(LC_CTYPE_MASK, "nl_BE.UTF-8", 0);
+  ASSERT_NE(nullptr, loc);
+
+  ASSERT_EQ(L'I', rs_towupper_l(L'i', loc));
+  ASSERT_EQ(L'i', rs_towlower_l(L'I', loc));
+
+  rs_freelocale(loc);
+}
+
 TEST(towctrans, examples)
 {
   ASSERT_NE(rs_setlocale(LC_ALL, "C"), nullptr);
@@ -460,13 +509,92 @@ TEST(towctrans, examples)
   ASSERT_EQ(WEOF, rs_towctrans(WEOF, rs_wctrans("toupper")));
 }
 
+TEST(towctrans, title_fold)
+{
+  strogino_locale_t loc = rs_newlocale(LC_CTYPE_MASK, "nl_BE.UTF-8", 0);
+  ASSERT_NE(nullptr, loc);
+
+  wctrans_t totitle = rs_wctrans("totitle");
+  wctrans_t tofold = rs_wctrans("tofold");
+
+  // Digraphs have a distinct titlecase form
+  ASSERT_EQ(L'ǅ', rs_towctrans_l(L'ǆ', totitle, loc));
+  ASSERT_EQ(L'ǅ', rs_towctrans_l(L'Ǆ', totitle, loc));
+  ASSERT_EQ(L'A', rs_towctrans_l(L'a', totitle, loc));
+
+  ASSERT_EQ(L'σ', rs_towctrans_l(L'ς', tofold, loc));
+  ASSERT_EQ(L'σ', rs_towctrans_l(L'Σ', tofold, loc));
+  ASSERT_EQ(L'i', rs_towctrans_l(L'I', tofold, loc));
+
+  rs_freelocale(loc);
+
+  loc = rs_newlocale(LC_CTYPE_MASK, "tr_TR.UTF-8", 0);
+  ASSERT_NE(nullptr, loc);
+
+  ASSERT_EQ(L'İ', rs_towctrans_l(L'i', totitle, loc));
+  ASSERT_EQ(L'ı', rs_towctrans_l(L'I', tofold, loc));
+
+  rs_freelocale(loc);
+
+  ASSERT_NE(rs_setlocale(LC_ALL, "C"), nullptr);
+  ASSERT_EQ(wint_t('A'), rs_towctrans(L'a', totitle));
+  ASSERT_EQ(wint_t('a'), rs_towctrans(L'A', tofold));
+  ASSERT_EQ(L'Σ', rs_towctrans(L'Σ', tofold));
+}
+
 TEST(wctrans, example)
 {
   ASSERT_TRUE(rs_wctrans("tolower") != 0);
   ASSERT_TRUE(rs_wctrans("toupper") != 0);
+  ASSERT_TRUE(rs_wctrans("totitle") != 0);
+  ASSERT_TRUE(rs_wctrans("tofold") != 0);
   ASSERT_TRUE(rs_wctrans("monkeys") == 0);
 }
 
+TEST(wctype, extended)
+{
+  strogino_locale_t loc = rs_newlocale(LC_CTYPE_MASK, "ja_JP.UTF-8", 0);
+  ASSERT_NE(nullptr, loc);
+
+  wctype_t hiragana = rs_wctype("hiragana");
+  wctype_t katakana = rs_wctype("katakana");
+  wctype_t jdigit = rs_wctype("jdigit");
+  ASSERT_NE((wctype_t)0, hiragana);
+  ASSERT_NE((wctype_t)0, katakana);
+  ASSERT_NE((wctype_t)0, jdigit);
+  ASSERT_NE(hiragana, katakana);
+
+  ASSERT_TRUE(rs_iswctype_l(L'ひ', hiragana, loc));
+  ASSERT_FALSE(rs_iswctype_l(L'ヒ', hiragana, loc));
+  ASSERT_TRUE(rs_iswctype_l(L'ヒ', katakana, loc));
+  ASSERT_FALSE(rs_iswctype_l(L'日', katakana, loc));
+  // The prolonged sound mark is shared by both kana scripts
+  ASSERT_TRUE(rs_iswctype_l(L'ー', hiragana, loc));
+  ASSERT_TRUE(rs_iswctype_l(L'ー', katakana, loc));
+
+  ASSERT_TRUE(rs_iswctype_l(L'３', jdigit, loc));
+  ASSERT_FALSE(rs_iswctype_l(L'3', jdigit, loc));
+
+  ASSERT_TRUE(rs_iswctype_l(L'日', rs_wctype("ideographic"), loc));
+  ASSERT_FALSE(rs_iswctype_l(L'ひ', rs_wctype("ideographic"), loc));
+  ASSERT_TRUE(rs_iswctype_l(0x301, rs_wctype("combining"), loc));
+  ASSERT_FALSE(rs_iswctype_l(L'a', rs_wctype("combining"), loc));
+  ASSERT_TRUE(rs_iswctype_l(0x1f600, rs_wctype("emoji"), loc));
+  ASSERT_FALSE(rs_iswctype_l(L'a', rs_wctype("emoji"), loc));
+
+  // Script codes work as well as names
+  ASSERT_EQ(rs_wctype("greek"), rs_wctype("Grek"));
+  ASSERT_TRUE(rs_iswctype_l(L'λ', rs_wctype("greek"), loc));
+  ASSERT_FALSE(rs_iswctype_l(L'l', rs_wctype("greek"), loc));
+
+  rs_freelocale(loc);
+
+  // The C locale defines none of them
+  ASSERT_NE(rs_setlocale(LC_ALL, "C"), nullptr);
+  ASSERT_FALSE(rs_iswctype('a', rs_wctype("latin")));
+  ASSERT_FALSE(rs_iswctype(L'ひ', hiragana));
+}
+
 TEST(wctype, classes)
 {
   ASSERT_NE(rs_setlocale(LC_ALL, "C"), nullptr);
@@ -652,3 +780,133 @@ TEST(ctype, unicode_equality)
 
   rs_freelocale(loc);
 }
+
+TEST(__ctype_b_loc, matches_functions)
+{
+  ASSERT_NE(rs_setlocale(LC_ALL, "C"), nullptr);
+
+  const unsigned short* table = *__rs_ctype_b_loc();
+  const int32_t* lower = *__rs_ctype_tolower_loc();
+  const int32_t* upper = *__rs_ctype_toupper_loc();
+
+  for (int ch = EOF; ch <= UCHAR_MAX; ++ch) {
+    SCOPED_TRACE(ch);
+
+    ASSERT_EQ(rs_isalnum(ch) != 0, (table[ch] & _ISalnum) != 0);
+    ASSERT_EQ(rs_isalpha(ch) != 0, (table[ch] & _ISalpha) != 0);
+    ASSERT_EQ(rs_isblank(ch) != 0, (table[ch] & _ISblank) != 0);
+    ASSERT_EQ(rs_iscntrl(ch) != 0, (table[ch] & _IScntrl) != 0);
+    ASSERT_EQ(rs_isdigit(ch) != 0, (table[ch] & _ISdigit) != 0);
+    ASSERT_EQ(rs_isgraph(ch) != 0, (table[ch] & _ISgraph) != 0);
+    ASSERT_EQ(rs_islower(ch) != 0, (table[ch] & _ISlower) != 0);
+    ASSERT_EQ(rs_isprint(ch) != 0, (table[ch] & _ISprint) != 0);
+    ASSERT_EQ(rs_ispunct(ch) != 0, (table[ch] & _ISpunct) != 0);
+    ASSERT_EQ(rs_isspace(ch) != 0, (table[ch] & _ISspace) != 0);
+    ASSERT_EQ(rs_isupper(ch) != 0, (table[ch] & _ISupper) != 0);
+    ASSERT_EQ(rs_isxdigit(ch) != 0, (table[ch] & _ISxdigit) != 0);
+    ASSERT_EQ(rs_tolower(ch), lower[ch]);
+    ASSERT_EQ(rs_toupper(ch), upper[ch]);
+  }
+}
+
+TEST(__ctype_b_loc, signed_char)
+{
+  ASSERT_NE(rs_setlocale(LC_ALL, "C"), nullptr);
+
+  const unsigned short* table = *__rs_ctype_b_loc();
+  const int32_t* lower = *__rs_ctype_tolower_loc();
+
+  for (int ch = -128; ch < 0; ++ch) {
+    SCOPED_TRACE(ch);
+
+    ASSERT_EQ(0, table[ch]);
+    ASSERT_EQ(ch, lower[ch]);
+  }
+}
+
+TEST(__ctype_b_loc, exported)
+{
+  ASSERT_NE(rs_setlocale(LC_ALL, "C"), nullptr);
+
+  // The glibc names are the ones that the ctype.h macros call
+  ASSERT_EQ(__rs_ctype_b_loc(), __ctype_b_loc());
+  ASSERT_EQ(__rs_ctype_tolower_loc(), __ctype_tolower_loc());
+  ASSERT_EQ(__rs_ctype_toupper_loc(), __ctype_toupper_loc());
+}
+
+TEST(__ctype_b_loc, new_thread)
+{
+  ASSERT_NE(rs_setlocale(LC_ALL, "C"), nullptr);
+
+  std::thread([] {
+    const int32_t** upper = __rs_ctype_toupper_loc();
+    ASSERT_NE(nullptr, *upper);
+    ASSERT_EQ('I', (*upper)['i']);
+  }).join();
+}
+
+TEST(__ctype_toupper_loc, uselocale)
+{
+  ASSERT_NE(rs_setlocale(LC_ALL, "C"), nullptr);
+
+  // The pointers stay in place and follow the locale of the thread
+  const int32_t** upper = __rs_ctype_toupper_loc();
+  ASSERT_EQ('I', (*upper)['i']);
+
+  strogino_locale_t loc = rs_newlocale(LC_CTYPE_MASK, "tr_TR.UTF-8", 0);
+  ASSERT_NE(nullptr, loc);
+
+  strogino_locale_t old = rs_uselocale(loc);
+  ASSERT_EQ('i', (*upper)['i']);
+  ASSERT_EQ('I', (*upper)['I']);
+
+  rs_uselocale(old);
+  ASSERT_EQ('I', (*upper)['i']);
+
+  rs_freelocale(loc);
+}
+
+TEST(__ctype_toupper_loc, setlocale)
+{
+  const int32_t** upper = __rs_ctype_toupper_loc();
+
+  ASSERT_NE(rs_setlocale(LC_CTYPE, "tr_TR.UTF-8"), nullptr);
+  ASSERT_EQ('i', (*upper)['i']);
+
+  ASSERT_NE(rs_setlocale(LC_CTYPE, "C"), nullptr);
+  ASSERT_EQ('I', (*upper)['i']);
+}
+
+TEST(__ctype_b_loc, setlocale_other_thread)
+{
+  ASSERT_NE(rs_setlocale(LC_ALL, "C"), nullptr);
+
+  std::atomic<int> step = 0;
+  const unsigned short* table = nullptr;
+
+  std::thread thread([&] {
+    // The thread's pointers are set up for the C locale first
+    EXPECT_EQ('I', (*__ctype_toupper_loc())['i']);
+    step = 1;
+    while (step != 2) {
+      std::this_thread::yield();
+    }
+
+    // Another thread changed the global locale, and the next lookups of the
+    // ctype macros follow it
+    EXPECT_EQ(table, *__ctype_b_loc());
+    EXPECT_NE(0, (*__ctype_b_loc())['a'] & _ISalpha);
+    EXPECT_EQ(0, (*__ctype_b_loc())['1'] & _ISalpha);
+    EXPECT_EQ('i', (*__ctype_toupper_loc())['i']);
+  });
+
+  while (step != 1) {
+    std::this_thread::yield();
+  }
+  ASSERT_NE(rs_setlocale(LC_CTYPE, "tr_TR.UTF-8"), nullptr);
+  table = *__rs_ctype_b_loc();
+  step = 2;
+  thread.join();
+
+  ASSERT_NE(rs_setlocale(LC_ALL, "C"), nullptr);
+}
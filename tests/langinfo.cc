@@ -0,0 +1,243 @@
+#include "common.h"
+#include <clocale>
+#include <cstdint>
+#include <gtest/gtest.h>
+#include <langinfo.h>
+
+extern "C"
+{
+  char* rs_nl_langinfo(nl_item);
+  char* rs_nl_langinfo_l(nl_item, strogino_locale_t);
+}
+
+TEST(nl_langinfo, c_locale)
+{
+  ASSERT_STREQ(rs_setlocale(LC_ALL, "C"), "C");
+  ASSERT_STREQ(rs_nl_langinfo(CODESET), "US-ASCII");
+  ASSERT_STREQ(rs_nl_langinfo(RADIXCHAR), ".");
+  ASSERT_STREQ(rs_nl_langinfo(THOUSEP), "");
+  ASSERT_STREQ(rs_nl_langinfo(_NL_CTYPE_OUTDIGIT0_MB), "0");
+  ASSERT_STREQ(rs_nl_langinfo(_NL_CTYPE_OUTDIGIT9_MB), "9");
+  ASSERT_STREQ(rs_nl_langinfo(-1), "");
+}
+
+TEST(nl_langinfo, c_time_and_messages)
+{
+  ASSERT_STREQ(rs_setlocale(LC_ALL, "C"), "C");
+  ASSERT_STREQ(rs_nl_langinfo(ABDAY_1), "Sun");
+  ASSERT_STREQ(rs_nl_langinfo(DAY_7), "Saturday");
+  ASSERT_STREQ(rs_nl_langinfo(ABMON_12), "Dec");
+  ASSERT_STREQ(rs_nl_langinfo(MON_1), "January");
+  ASSERT_STREQ(rs_nl_langinfo(AM_STR), "AM");
+  ASSERT_STREQ(rs_nl_langinfo(D_T_FMT), "%a %b %e %H:%M:%S %Y");
+  ASSERT_STREQ(rs_nl_langinfo(D_FMT), "%m/%d/%y");
+  ASSERT_STREQ(rs_nl_langinfo(T_FMT_AMPM), "%I:%M:%S %p");
+  ASSERT_STREQ(rs_nl_langinfo(YESEXPR), "^[+1yY]");
+  ASSERT_STREQ(rs_nl_langinfo(NOSTR), "");
+}
+
+TEST(nl_langinfo, latin_digits)
+{
+  strogino_locale_t locale = rs_newlocale(LC_ALL_MASK, "en_US.UTF-8", nullptr);
+  ASSERT_NE(locale, nullptr);
+  ASSERT_STREQ(rs_nl_langinfo_l(CODESET, locale), "UTF-8");
+  ASSERT_STREQ(rs_nl_langinfo_l(RADIXCHAR, locale), ".");
+  ASSERT_STREQ(rs_nl_langinfo_l(THOUSEP, locale), ",");
+  ASSERT_STREQ(rs_nl_langinfo_l(_NL_CTYPE_OUTDIGIT0_MB, locale), "0");
+  ASSERT_STREQ(rs_nl_langinfo_l(_NL_CTYPE_OUTDIGIT0_MB + 5, locale), "5");
+  rs_freelocale(locale);
+}
+
+TEST(nl_langinfo, native_digits)
+{
+  strogino_locale_t locale = rs_newlocale(LC_ALL_MASK, "ar_EG.UTF-8", nullptr);
+  ASSERT_NE(locale, nullptr);
+  ASSERT_STREQ(rs_nl_langinfo_l(_NL_CTYPE_OUTDIGIT0_MB, locale), "٠");
+  ASSERT_STREQ(rs_nl_langinfo_l(_NL_CTYPE_OUTDIGIT9_MB, locale), "٩");
+  ASSERT_STREQ(rs_nl_langinfo_l(RADIXCHAR, locale), ".");
+  ASSERT_STREQ(rs_nl_langinfo_l(THOUSEP, locale), ",");
+  rs_freelocale(locale);
+
+  locale = rs_newlocale(LC_ALL_MASK, "hi_IN.UTF-8", nullptr);
+  ASSERT_NE(locale, nullptr);
+  ASSERT_STREQ(rs_nl_langinfo_l(_NL_CTYPE_OUTDIGIT0_MB, locale), "0");
+  rs_freelocale(locale);
+}
+
+TEST(nl_langinfo, numbering_system)
+{
+  const char* names[] = {
+    "ar_EG-u-nu-arab.UTF-8",
+    "ar_EG.UTF-8@numbers=arab",
+    "ar_EG.UTF-8@nu=arab",
+  };
+  for (const char* name : names)
+  {
+    SCOPED_TRACE(name);
+    strogino_locale_t locale = rs_newlocale(LC_ALL_MASK, name, nullptr);
+    ASSERT_NE(locale, nullptr);
+    ASSERT_STREQ(rs_nl_langinfo_l(_NL_CTYPE_OUTDIGIT0_MB, locale), "٠");
+    ASSERT_STREQ(rs_nl_langinfo_l(RADIXCHAR, locale), "٫");
+    ASSERT_STREQ(rs_nl_langinfo_l(THOUSEP, locale), "٬");
+    ASSERT_STREQ(rs_getlocalename_l(LC_NUMERIC, locale), name);
+    rs_freelocale(locale);
+  }
+
+  strogino_locale_t locale =
+    rs_newlocale(LC_ALL_MASK, "hi_IN.UTF-8@numbers=deva", nullptr);
+  ASSERT_NE(locale, nullptr);
+  ASSERT_STREQ(rs_nl_langinfo_l(_NL_CTYPE_OUTDIGIT0_MB, locale), "०");
+  ASSERT_STREQ(rs_nl_langinfo_l(_NL_CTYPE_OUTDIGIT9_MB, locale), "९");
+  ASSERT_STREQ(rs_nl_langinfo_l(RADIXCHAR, locale), ".");
+  rs_freelocale(locale);
+
+  locale = rs_newlocale(LC_ALL_MASK, "fa_IR.UTF-8@numbers=latn", nullptr);
+  ASSERT_NE(locale, nullptr);
+  ASSERT_STREQ(rs_nl_langinfo_l(_NL_CTYPE_OUTDIGIT0_MB, locale), "0");
+  rs_freelocale(locale);
+}
+
+TEST(nl_langinfo, unknown_numbering_system)
+{
+  rs_errno = 0;
+  ASSERT_EQ(rs_newlocale(LC_NUMERIC_MASK, "en_US.UTF-8@numbers=bogus", nullptr),
+            nullptr);
+  ASSERT_EQ(rs_errno, ENOENT);
+  rs_errno = 0;
+  ASSERT_EQ(rs_newlocale(LC_NUMERIC_MASK, "en_US.UTF-8@numbers", nullptr),
+            nullptr);
+  ASSERT_EQ(rs_errno, ENOENT);
+}
+
+namespace
+{
+  int word(const char* value)
+  {
+    return static_cast<int>(reinterpret_cast<intptr_t>(value));
+  }
+}
+
+TEST(nl_langinfo, c_extra_categories)
+{
+  ASSERT_STREQ(rs_setlocale(LC_ALL, "C"), "C");
+  ASSERT_EQ(word(rs_nl_langinfo(_NL_PAPER_HEIGHT)), 297);
+  ASSERT_EQ(word(rs_nl_langinfo(_NL_PAPER_WIDTH)), 210);
+  ASSERT_EQ(*rs_nl_langinfo(_NL_MEASUREMENT_MEASUREMENT), 1);
+  ASSERT_STREQ(rs_nl_langinfo(_NL_NAME_NAME_FMT), "%p%t%g%t%m%t%f");
+  ASSERT_STREQ(rs_nl_langinfo(_NL_ADDRESS_POSTAL_FMT),
+               "%a%N%f%N%d%N%b%N%s %h %e %r%N%C-%z %T%N%c%N");
+  ASSERT_STREQ(rs_nl_langinfo(_NL_ADDRESS_COUNTRY_AB2), "");
+  ASSERT_EQ(word(rs_nl_langinfo(_NL_ADDRESS_COUNTRY_NUM)), 0);
+  ASSERT_STREQ(rs_nl_langinfo(_NL_TELEPHONE_TEL_INT_FMT), "+%c %a %l");
+  ASSERT_STREQ(rs_nl_langinfo(_NL_TELEPHONE_INT_PREFIX), "");
+  ASSERT_STREQ(rs_nl_langinfo(_NL_IDENTIFICATION_TITLE),
+               "ISO/IEC 14652 i18n FDCC-set");
+  ASSERT_STREQ(rs_nl_langinfo(_NL_IDENTIFICATION_TERRITORY), "ISO");
+}
+
+TEST(nl_langinfo, united_states)
+{
+  strogino_locale_t locale = rs_newlocale(
+    LC_PAPER_MASK | LC_MEASUREMENT_MASK | LC_ADDRESS_MASK |
+      LC_TELEPHONE_MASK | LC_IDENTIFICATION_MASK,
+    "en_US.UTF-8", nullptr);
+  ASSERT_NE(locale, nullptr);
+  ASSERT_EQ(word(rs_nl_langinfo_l(_NL_PAPER_HEIGHT, locale)), 279);
+  ASSERT_EQ(word(rs_nl_langinfo_l(_NL_PAPER_WIDTH, locale)), 216);
+  ASSERT_EQ(*rs_nl_langinfo_l(_NL_MEASUREMENT_MEASUREMENT, locale), 2);
+  ASSERT_STREQ(rs_nl_langinfo_l(_NL_ADDRESS_COUNTRY_AB2, locale), "US");
+  ASSERT_STREQ(rs_nl_langinfo_l(_NL_ADDRESS_COUNTRY_AB3, locale), "USA");
+  ASSERT_EQ(word(rs_nl_langinfo_l(_NL_ADDRESS_COUNTRY_NUM, locale)), 840);
+  ASSERT_STREQ(rs_nl_langinfo_l(_NL_ADDRESS_LANG_AB, locale), "en");
+  ASSERT_STREQ(rs_nl_langinfo_l(_NL_ADDRESS_LANG_TERM, locale), "eng");
+  ASSERT_STREQ(rs_nl_langinfo_l(_NL_TELEPHONE_INT_PREFIX, locale), "1");
+  ASSERT_STREQ(rs_nl_langinfo_l(_NL_IDENTIFICATION_LANGUAGE, locale),
+               "English");
+  ASSERT_STREQ(rs_nl_langinfo_l(_NL_IDENTIFICATION_TERRITORY, locale),
+               "United States");
+  ASSERT_STREQ(rs_nl_langinfo_l(_NL_IDENTIFICATION_TITLE, locale),
+               "English locale for United States");
+  rs_freelocale(locale);
+}
+
+TEST(nl_langinfo, formats)
+{
+  struct Formats
+  {
+    const char* name;
+    const char* name_fmt;
+    const char* postal_fmt;
+    const char* tel_int_fmt;
+    const char* tel_dom_fmt;
+  };
+  const Formats formats[] = {
+    {"en_US.UTF-8", "%d%t%g%t%m%t%f",
+     "%a%N%f%N%d%N%b%N%h %s %e %r%N%T, %S %z%N%c%N", "+%c (%a) %l",
+     "(%a) %l"},
+    {"de_DE.UTF-8", "%d%t%g%t%m%t%f",
+     "%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", "+%c %a %l", "%A %l"},
+    {"ja_JP.UTF-8", "%p%t%f%t%g", "%z%c%T%s%b%e%r", "+%c ;%a ;%l", ""},
+    // No glibc locale has English in Germany, so the formats are those of
+    // German in Germany
+    {"en_DE.UTF-8", "%d%t%g%t%m%t%f",
+     "%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N", "+%c %a %l", "%A %l"},
+    {"es_419.UTF-8", "%p%t%g%t%m%t%f",
+     "%a%N%f%N%d%N%b%N%s %h %e %r%N%C-%z %T%N%c%N", "+%c %a %l", ""},
+  };
+  for (const Formats& f : formats)
+  {
+    SCOPED_TRACE(f.name);
+    strogino_locale_t locale = rs_newlocale(
+      LC_NAME_MASK | LC_ADDRESS_MASK | LC_TELEPHONE_MASK, f.name, nullptr);
+    ASSERT_NE(locale, nullptr);
+    ASSERT_STREQ(rs_nl_langinfo_l(_NL_NAME_NAME_FMT, locale), f.name_fmt);
+    ASSERT_STREQ(rs_nl_langinfo_l(_NL_ADDRESS_POSTAL_FMT, locale),
+                 f.postal_fmt);
+    ASSERT_STREQ(rs_nl_langinfo_l(_NL_TELEPHONE_TEL_INT_FMT, locale),
+                 f.tel_int_fmt);
+    ASSERT_STREQ(rs_nl_langinfo_l(_NL_TELEPHONE_TEL_DOM_FMT, locale),
+                 f.tel_dom_fmt);
+    rs_freelocale(locale);
+  }
+}
+
+TEST(nl_langinfo, territories)
+{
+  struct Territory
+  {
+    const char* name;
+    int height;
+    int measurement;
+    const char* ab3;
+    const char* int_prefix;
+    const char* lang_lib;
+  };
+  const Territory territories[] = {
+    {"de_DE.UTF-8", 297, 1, "DEU", "49", "ger"},
+    {"fr_CA.UTF-8", 279, 1, "CAN", "1", "fre"},
+    {"es_MX.UTF-8", 279, 1, "MEX", "52", "spa"},
+    {"en_GB.UTF-8", 297, 1, "GBR", "44", "eng"},
+    {"my_MM.UTF-8", 297, 2, "MMR", "95", "bur"},
+    {"zh-Hant-TW", 297, 1, "TWN", "886", "chi"},
+    {"sr_RS.UTF-8@latin", 297, 1, "SRB", "381", "srp"},
+    {"ja.UTF-8", 297, 1, "JPN", "81", "jpn"},
+    {"es_419.UTF-8", 297, 1, "", "", "spa"},
+  };
+  for (const Territory& t : territories)
+  {
+    SCOPED_TRACE(t.name);
+    strogino_locale_t locale = rs_newlocale(
+      LC_PAPER_MASK | LC_MEASUREMENT_MASK | LC_ADDRESS_MASK |
+        LC_TELEPHONE_MASK,
+      t.name, nullptr);
+    ASSERT_NE(locale, nullptr);
+    ASSERT_EQ(word(rs_nl_langinfo_l(_NL_PAPER_HEIGHT, locale)), t.height);
+    ASSERT_EQ(*rs_nl_langinfo_l(_NL_MEASUREMENT_MEASUREMENT, locale),
+              t.measurement);
+    ASSERT_STREQ(rs_nl_langinfo_l(_NL_ADDRESS_COUNTRY_AB3, locale), t.ab3);
+    ASSERT_STREQ(rs_nl_langinfo_l(_NL_TELEPHONE_INT_PREFIX, locale),
+                 t.int_prefix);
+    ASSERT_STREQ(rs_nl_langinfo_l(_NL_ADDRESS_LANG_LIB, locale), t.lang_lib);
+    rs_freelocale(locale);
+  }
+}
@@ -0,0 +1,262 @@
+#include "common.h"
+#include <algorithm>
+#include <clocale>
+#include <cstdint>
+#include <cstdlib>
+#include <fstream>
+#include <gtest/gtest.h>
+#include <string>
+#include <sys/stat.h>
+#include <utility>
+#include <vector>
+
+extern "C"
+{
+  char* rs_gettext(const char*);
+  char* rs_dgettext(const char*, const char*);
+  char* rs_dcgettext(const char*, const char*, int);
+  char* rs_ngettext(const char*, const char*, unsigned long);
+  char* rs_dngettext(const char*, const char*, const char*, unsigned long);
+  char* rs_textdomain(const char*);
+  char* rs_bindtextdomain(const char*, const char*);
+  char* rs_bind_textdomain_codeset(const char*, const char*);
+}
+
+namespace
+{
+  typedef std::vector<std::pair<std::string, std::string>> Messages;
+
+  // Writes a GNU .mo catalog, with the header as the empty msgid. Plural
+  // entries separate their forms with NULs, as msgfmt does.
+  void write_mo(
+    const std::string& path,
+    const std::string& header,
+    Messages messages,
+    bool big_endian)
+  {
+    messages.push_back({"", header});
+    std::sort(messages.begin(), messages.end(), [](auto& a, auto& b) {
+      return a.first.compare(0, a.first.find('\0'), b.first, 0,
+                             b.first.find('\0')) < 0;
+    });
+
+    std::string out;
+    auto put = [&](uint32_t v) {
+      for (int i = 0; i < 4; i++) {
+        int shift = big_endian ? 24 - 8 * i : 8 * i;
+        out.push_back(char((v >> shift) & 0xff));
+      }
+    };
+
+    uint32_t n = messages.size();
+    uint32_t originals = 28;
+    uint32_t translations = originals + 8 * n;
+    uint32_t strings = translations + 8 * n;
+
+    put(0x950412de);
+    put(0);
+    put(n);
+    put(originals);
+    put(translations);
+    put(0);
+    put(0);
+
+    std::string data;
+    std::vector<uint32_t> offsets;
+    for (auto& [id, _] : messages) {
+      offsets.push_back(strings + data.size());
+      data += id;
+      data.push_back('\0');
+    }
+    for (auto& [_, text] : messages) {
+      offsets.push_back(strings + data.size());
+      data += text;
+      data.push_back('\0');
+    }
+    for (uint32_t i = 0; i < n; i++) {
+      put(messages[i].first.size());
+      put(offsets[i]);
+    }
+    for (uint32_t i = 0; i < n; i++) {
+      put(messages[i].second.size());
+      put(offsets[n + i]);
+    }
+
+    std::ofstream(path, std::ios::binary) << out << data;
+  }
+
+  std::string make_catalog_dir(const char* language)
+  {
+    static std::string root = [] {
+      char tmpl[] = "/tmp/strogino-libintl-XXXXXX";
+      return std::string(mkdtemp(tmpl));
+    }();
+
+    std::string dir = root + "/" + language;
+    mkdir(dir.c_str(), 0755);
+    mkdir((dir + "/LC_MESSAGES").c_str(), 0755);
+
+    return root;
+  }
+
+  const char* UTF8_HEADER = "Content-Type: text/plain; charset=UTF-8\n"
+                            "Plural-Forms: nplurals=2; plural=(n != 1);\n";
+}
+
+TEST(textdomain, default_domain)
+{
+  ASSERT_STREQ("messages", rs_textdomain(nullptr));
+  ASSERT_STREQ("strogino-test", rs_textdomain("strogino-test"));
+  ASSERT_STREQ("strogino-test", rs_textdomain(nullptr));
+  ASSERT_STREQ("messages", rs_textdomain(""));
+}
+
+TEST(textdomain, keeps_old_names)
+{
+  // The returned names stay valid after the domain changes again
+  const char* first = rs_textdomain("strogino-first");
+  ASSERT_STREQ("strogino-second", rs_textdomain("strogino-second"));
+  ASSERT_STREQ("strogino-first", first);
+  ASSERT_EQ(first, rs_textdomain("strogino-first"));
+  rs_textdomain("");
+  ASSERT_STREQ("strogino-first", first);
+}
+
+TEST(bindtextdomain, default_dirname)
+{
+  ASSERT_STREQ("/usr/share/locale", rs_bindtextdomain("unbound", nullptr));
+  ASSERT_STREQ("/srv/locale", rs_bindtextdomain("unbound", "/srv/locale"));
+  ASSERT_STREQ("/srv/locale", rs_bindtextdomain("unbound", nullptr));
+  ASSERT_EQ(nullptr, rs_bindtextdomain("", "/srv/locale"));
+  ASSERT_EQ(nullptr, rs_bind_textdomain_codeset("unbound", nullptr));
+}
+
+TEST(gettext, little_endian)
+{
+  std::string root = make_catalog_dir("de");
+  write_mo(root + "/de/LC_MESSAGES/little.mo", UTF8_HEADER,
+           {{"File not found", "Datei nicht gefunden"}}, false);
+  rs_bindtextdomain("little", root.c_str());
+
+  ASSERT_STREQ("C", rs_setlocale(LC_ALL, "C"));
+  ASSERT_STREQ("File not found", rs_dgettext("little", "File not found"));
+
+  // de_AT falls back to the catalog of de
+  ASSERT_STREQ("de_AT.UTF-8", rs_setlocale(LC_ALL, "de_AT.UTF-8"));
+  ASSERT_STREQ("Datei nicht gefunden",
+               rs_dgettext("little", "File not found"));
+  ASSERT_STREQ("Unknown", rs_dgettext("little", "Unknown"));
+  ASSERT_STREQ("File not found",
+               rs_dcgettext("little", "File not found", LC_ALL));
+
+  rs_textdomain("little");
+  ASSERT_STREQ("Datei nicht gefunden", rs_gettext("File not found"));
+  rs_textdomain("");
+
+  ASSERT_STREQ("C", rs_setlocale(LC_ALL, "C"));
+}
+
+TEST(gettext, big_endian)
+{
+  std::string root = make_catalog_dir("fr");
+  write_mo(root + "/fr/LC_MESSAGES/big.mo", UTF8_HEADER,
+           {{"Cancel", "Annuler"}, {"Open", "Ouvrir"}}, true);
+  rs_bindtextdomain("big", root.c_str());
+
+  ASSERT_STREQ("fr_FR.UTF-8", rs_setlocale(LC_ALL, "fr_FR.UTF-8"));
+  ASSERT_STREQ("Annuler", rs_dgettext("big", "Cancel"));
+  ASSERT_STREQ("Ouvrir", rs_dgettext("big", "Open"));
+
+  ASSERT_STREQ("C", rs_setlocale(LC_ALL, "C"));
+}
+
+TEST(ngettext, plural_forms)
+{
+  std::string root = make_catalog_dir("pl");
+  std::string plural = std::string("file\0files", 10);
+  std::string forms = std::string("plik\0pliki\0plików", 18);
+  write_mo(root + "/pl/LC_MESSAGES/plural.mo",
+           "Content-Type: text/plain; charset=UTF-8\n"
+           "Plural-Forms: nplurals=3; plural=(n==1 ? 0 : n%10>=2 && "
+           "n%10<=4 && (n%100<10 || n%100>=20) ? 1 : 2);\n",
+           {{plural, forms}}, false);
+  rs_bindtextdomain("plural", root.c_str());
+
+  ASSERT_STREQ("file", rs_dngettext("plural", "file", "files", 1));
+  ASSERT_STREQ("files", rs_dngettext("plural", "file", "files", 5));
+
+  ASSERT_STREQ("pl_PL.UTF-8", rs_setlocale(LC_ALL, "pl_PL.UTF-8"));
+  ASSERT_STREQ("plik", rs_dngettext("plural", "file", "files", 1));
+  ASSERT_STREQ("pliki", rs_dngettext("plural", "file", "files", 2));
+  ASSERT_STREQ("pliki", rs_dngettext("plural", "file", "files", 24));
+  ASSERT_STREQ("plików", rs_dngettext("plural", "file", "files", 5));
+  ASSERT_STREQ("plików", rs_dngettext("plural", "file", "files", 12));
+  ASSERT_STREQ("plików", rs_dngettext("plural", "file", "files", 112));
+  ASSERT_STREQ("pliki", rs_dngettext("plural", "file", "files", 122));
+
+  ASSERT_STREQ("C", rs_setlocale(LC_ALL, "C"));
+}
+
+TEST(ngettext, deep_plural_forms)
+{
+  // Expressions too deep to evaluate fall back to the Germanic rule
+  std::string chain = "n";
+  for (int i = 0; i < 100000; i++) {
+    chain += "+n";
+  }
+  std::string root = make_catalog_dir("cs");
+  std::string plural = std::string("file\0files", 10);
+  std::string forms = std::string("soubor\0soubory\0souborů", 23);
+  write_mo(root + "/cs/LC_MESSAGES/deep.mo",
+           "Content-Type: text/plain; charset=UTF-8\n"
+           "Plural-Forms: nplurals=3; plural=" + chain + ";\n",
+           {{plural, forms}}, false);
+  rs_bindtextdomain("deep", root.c_str());
+
+  ASSERT_STREQ("cs_CZ.UTF-8", rs_setlocale(LC_ALL, "cs_CZ.UTF-8"));
+  ASSERT_STREQ("soubor", rs_dngettext("deep", "file", "files", 1));
+  ASSERT_STREQ("soubory", rs_dngettext("deep", "file", "files", 2));
+
+  ASSERT_STREQ("C", rs_setlocale(LC_ALL, "C"));
+}
+
+TEST(gettext, language_list)
+{
+  std::string root = make_catalog_dir("it");
+  write_mo(root + "/it/LC_MESSAGES/language.mo", UTF8_HEADER,
+           {{"Yes", "Sì"}}, false);
+  rs_bindtextdomain("language", root.c_str());
+
+  ASSERT_STREQ("de_DE.UTF-8", rs_setlocale(LC_ALL, "de_DE.UTF-8"));
+  ASSERT_STREQ("Yes", rs_dgettext("language", "Yes"));
+
+  setenv("LANGUAGE", "eo:it_CH:de", 1);
+  ASSERT_STREQ("Sì", rs_dgettext("language", "Yes"));
+
+  // Entries after C are not used
+  setenv("LANGUAGE", "C:it", 1);
+  ASSERT_STREQ("Yes", rs_dgettext("language", "Yes"));
+
+  // Nor is LANGUAGE in the C locale
+  setenv("LANGUAGE", "it", 1);
+  ASSERT_STREQ("C", rs_setlocale(LC_ALL, "C"));
+  ASSERT_STREQ("Yes", rs_dgettext("language", "Yes"));
+
+  unsetenv("LANGUAGE");
+}
+
+TEST(bind_textdomain_codeset, conversion)
+{
+  std::string root = make_catalog_dir("es");
+  write_mo(root + "/es/LC_MESSAGES/codeset.mo", UTF8_HEADER,
+           {{"Year", "Año"}}, false);
+  rs_bindtextdomain("codeset", root.c_str());
+
+  ASSERT_STREQ("es_ES.UTF-8", rs_setlocale(LC_ALL, "es_ES.UTF-8"));
+  ASSERT_STREQ("Año", rs_dgettext("codeset", "Year"));
+
+  ASSERT_STREQ("US-ASCII", rs_bind_textdomain_codeset("codeset", "US-ASCII"));
+  ASSERT_STREQ("A?o", rs_dgettext("codeset", "Year"));
+
+  ASSERT_STREQ("C", rs_setlocale(LC_ALL, "C"));
+}
@@ -1,12 +1,99 @@
 #include "common.h"
+#include <atomic>
 #include <clocale>
+#include <cstring>
+#include <fstream>
 #include <gtest/gtest.h>
+#include <langinfo.h>
 #include <locale>
+#include <string>
+#include <thread>
+#include <vector>
 
 extern "C"
 {
   struct lconv* rs_localeconv(void);
   struct lconv* rs_localeconv_l(strogino_locale_t);
+  size_t rs_getavailablelocales(const char**, size_t);
+  int rs_strcoll(const char*, const char*);
+  int rs_toupper(int);
+  char* rs_nl_langinfo(nl_item);
+  char* rs_nl_langinfo_l(nl_item, strogino_locale_t);
+  int rs_strcoll_l(const char*, const char*, strogino_locale_t);
+  size_t rs_strxfrm_l(char*, const char*, size_t, strogino_locale_t);
+  int rs_iswalpha_l(wint_t, strogino_locale_t);
+  int rs_iswalnum_l(wint_t, strogino_locale_t);
+  wint_t rs_towupper_l(wint_t, strogino_locale_t);
+}
+
+namespace
+{
+  const char en_XA[] = R"(comment_char %
+escape_char /
+% A company locale with its own currency and dates
+
+LC_CTYPE
+copy "i18n"
+alpha <U0041>..<U005A>;<U0061>..<U007A>;/
+      <U0040>
+toupper (<U0040>,<U00A9>)
+END LC_CTYPE
+
+LC_COLLATE
+order_start forward
+<U002D> IGNORE
+<U0062>
+<U0042> <U0062>
+<U0061>
+UNDEFINED
+<U007A>
+order_end
+END LC_COLLATE
+
+LC_NUMERIC
+decimal_point ","
+thousands_sep "<U0027>"
+grouping 3;2
+END LC_NUMERIC
+
+LC_MONETARY
+copy "en_US"
+int_curr_symbol "XAU "
+currency_symbol "<U00A4>"
+frac_digits 3
+END LC_MONETARY
+
+LC_TIME
+abday "Su";"Mo";"Tu";"We";"Th";"Fr";"Sa"
+d_fmt "%Y-%m-%d"
+t_fmt "%H.%M"
+am_pm "vm";"nm"
+END LC_TIME
+
+LC_MESSAGES
+yesexpr "^[+1jJyY]"
+yesstr "ja"
+END LC_MESSAGES
+)";
+
+  const char en_XB[] = R"(LC_TIME
+copy "en_XA"
+d_t_fmt "%c"
+END LC_TIME
+)";
+
+  std::string make_locpath()
+  {
+    static std::string root = [] {
+      char tmpl[] = "/tmp/strogino-localedef-XXXXXX";
+      std::string root(mkdtemp(tmpl));
+      std::ofstream(root + "/en_XA") << en_XA;
+      std::ofstream(root + "/en_XB") << en_XB;
+      return root;
+    }();
+
+    return root;
+  }
 }
 
 TEST(localeconv, posix)
@@ -63,19 +150,19 @@ TEST(localeconv, netherlands)
   ASSERT_STREQ("€", lconv->currency_symbol);
   ASSERT_EQ(2, lconv->frac_digits);
   ASSERT_EQ(1, lconv->p_cs_precedes);
-  ASSERT_EQ(2, lconv->p_sep_by_space);
+  ASSERT_EQ(1, lconv->p_sep_by_space);
   ASSERT_EQ(1, lconv->p_sign_posn);
   ASSERT_EQ(1, lconv->n_cs_precedes);
   ASSERT_EQ(2, lconv->n_sep_by_space);
-  ASSERT_EQ(1, lconv->n_sign_posn);
+  ASSERT_EQ(4, lconv->n_sign_posn);
   ASSERT_STREQ("EUR ", lconv->int_curr_symbol);
   ASSERT_EQ(2, lconv->int_frac_digits);
   ASSERT_EQ(1, lconv->int_p_cs_precedes);
-  ASSERT_EQ(2, lconv->int_p_sep_by_space);
+  ASSERT_EQ(1, lconv->int_p_sep_by_space);
   ASSERT_EQ(1, lconv->int_p_sign_posn);
   ASSERT_EQ(1, lconv->int_n_cs_precedes);
   ASSERT_EQ(2, lconv->int_n_sep_by_space);
-  ASSERT_EQ(1, lconv->int_n_sign_posn);
+  ASSERT_EQ(4, lconv->int_n_sign_posn);
 
   ASSERT_EQ(lconv, rs_localeconv_l(locale));
 
@@ -103,18 +190,18 @@ TEST(localeconv, united_states)
   ASSERT_EQ(2, lconv->frac_digits);
   ASSERT_EQ(1, lconv->p_cs_precedes);
   ASSERT_EQ(0, lconv->p_sep_by_space);
-  ASSERT_EQ(4, lconv->p_sign_posn);
+  ASSERT_EQ(1, lconv->p_sign_posn);
   ASSERT_EQ(1, lconv->n_cs_precedes);
   ASSERT_EQ(0, lconv->n_sep_by_space);
-  ASSERT_EQ(4, lconv->n_sign_posn);
+  ASSERT_EQ(1, lconv->n_sign_posn);
   ASSERT_STREQ("USD ", lconv->int_curr_symbol);
   ASSERT_EQ(2, lconv->int_frac_digits);
   ASSERT_EQ(1, lconv->int_p_cs_precedes);
   ASSERT_EQ(0, lconv->int_p_sep_by_space);
-  ASSERT_EQ(4, lconv->int_p_sign_posn);
+  ASSERT_EQ(1, lconv->int_p_sign_posn);
   ASSERT_EQ(1, lconv->int_n_cs_precedes);
   ASSERT_EQ(0, lconv->int_n_sep_by_space);
-  ASSERT_EQ(4, lconv->int_n_sign_posn);
+  ASSERT_EQ(1, lconv->int_n_sign_posn);
 
   ASSERT_EQ(lconv, rs_localeconv_l(locale));
 
@@ -132,28 +219,28 @@ TEST(localeconv, japan)
 
   ASSERT_STREQ(".", lconv->decimal_point);
   ASSERT_STREQ(",", lconv->thousands_sep);
-  ASSERT_STREQ("\x03", lconv->grouping);
+  ASSERT_STREQ("\x03\x03", lconv->grouping);
   ASSERT_STREQ(".", lconv->mon_decimal_point);
   ASSERT_STREQ(",", lconv->mon_thousands_sep);
-  ASSERT_STREQ("\x03", lconv->mon_grouping);
+  ASSERT_STREQ("\x03\x03", lconv->mon_grouping);
   ASSERT_STREQ("", lconv->positive_sign);
   ASSERT_STREQ("-", lconv->negative_sign);
-  ASSERT_STREQ("¥", lconv->currency_symbol);
+  ASSERT_STREQ("￥", lconv->currency_symbol);
   ASSERT_EQ(0, lconv->frac_digits);
   ASSERT_EQ(1, lconv->p_cs_precedes);
   ASSERT_EQ(0, lconv->p_sep_by_space);
-  ASSERT_EQ(4, lconv->p_sign_posn);
+  ASSERT_EQ(1, lconv->p_sign_posn);
   ASSERT_EQ(1, lconv->n_cs_precedes);
   ASSERT_EQ(0, lconv->n_sep_by_space);
-  ASSERT_EQ(4, lconv->n_sign_posn);
+  ASSERT_EQ(1, lconv->n_sign_posn);
   ASSERT_STREQ("JPY ", lconv->int_curr_symbol);
   ASSERT_EQ(0, lconv->int_frac_digits);
   ASSERT_EQ(1, lconv->int_p_cs_precedes);
   ASSERT_EQ(0, lconv->int_p_sep_by_space);
-  ASSERT_EQ(4, lconv->int_p_sign_posn);
+  ASSERT_EQ(1, lconv->int_p_sign_posn);
   ASSERT_EQ(1, lconv->int_n_cs_precedes);
   ASSERT_EQ(0, lconv->int_n_sep_by_space);
-  ASSERT_EQ(4, lconv->int_n_sign_posn);
+  ASSERT_EQ(1, lconv->int_n_sign_posn);
 
   ASSERT_EQ(lconv, rs_localeconv_l(locale));
 
@@ -199,6 +286,439 @@ TEST(localeconv, israel)
   rs_freelocale(locale);
 }
 
+TEST(localeconv, india)
+{
+  // Lakh and crore grouping, as in 1,23,45,678
+  for (const char* name : {"hi_IN.UTF-8", "en_IN.UTF-8"}) {
+    strogino_locale_t locale =
+      rs_newlocale(LC_NUMERIC_MASK | LC_MONETARY_MASK, name, nullptr);
+    ASSERT_NE(nullptr, locale);
+
+    struct lconv* lconv = rs_localeconv_l(locale);
+
+    ASSERT_STREQ(",", lconv->thousands_sep);
+    ASSERT_STREQ("\x03\x02", lconv->grouping);
+    ASSERT_STREQ(",", lconv->mon_thousands_sep);
+    ASSERT_STREQ("\x03\x02", lconv->mon_grouping);
+
+    rs_freelocale(locale);
+  }
+}
+
+namespace
+{
+  struct MonetaryLayout
+  {
+    const char* name;
+    const char* currency_symbol;
+    const char* int_curr_symbol;
+    // cs_precedes, sep_by_space and sign_posn
+    char p[3];
+    char n[3];
+  };
+
+  // The currency of every available locale and where its standard currency
+  // format puts the symbol, the space and the sign
+  const MonetaryLayout monetary_layouts[] = {
+    {"ar_AE.UTF-8", "د.إ.", "AED ", {0, 1, 1}, {0, 1, 1}},
+    {"ar_BH.UTF-8", "د.ب.", "BHD ", {0, 1, 1}, {0, 1, 1}},
+    {"ar_DZ.UTF-8", "د.ج.", "DZD ", {0, 1, 1}, {0, 1, 1}},
+    {"ar_EG.UTF-8", "ج.م.", "EGP ", {0, 1, 1}, {0, 1, 1}},
+    {"ar_IQ.UTF-8", "د.ع.", "IQD ", {0, 1, 1}, {0, 1, 1}},
+    {"ar_JO.UTF-8", "د.أ.", "JOD ", {0, 1, 1}, {0, 1, 1}},
+    {"ar_KW.UTF-8", "د.ك.", "KWD ", {0, 1, 1}, {0, 1, 1}},
+    {"ar_LB.UTF-8", "ل.ل.", "LBP ", {0, 1, 1}, {0, 1, 1}},
+    {"ar_LY.UTF-8", "د.ل.", "LYD ", {0, 1, 1}, {0, 1, 1}},
+    {"ar_MA.UTF-8", "د.م.", "MAD ", {0, 1, 1}, {0, 1, 1}},
+    {"ar_OM.UTF-8", "ر.ع.", "OMR ", {0, 1, 1}, {0, 1, 1}},
+    {"ar_QA.UTF-8", "ر.ق.", "QAR ", {0, 1, 1}, {0, 1, 1}},
+    {"ar_SA.UTF-8", "ر.س.", "SAR ", {0, 1, 1}, {0, 1, 1}},
+    {"ar_SD.UTF-8", "ج.س.", "SDG ", {0, 1, 1}, {0, 1, 1}},
+    {"ar_SY.UTF-8", "ل.س.", "SYP ", {0, 1, 1}, {0, 1, 1}},
+    {"ar_TN.UTF-8", "د.ت.", "TND ", {0, 1, 1}, {0, 1, 1}},
+    {"ar_YE.UTF-8", "ر.ي.", "YER ", {0, 1, 1}, {0, 1, 1}},
+    {"az_AZ.UTF-8", "₼", "AZN ", {0, 1, 1}, {0, 1, 1}},
+    {"bg_BG.UTF-8", "лв.", "BGN ", {0, 1, 1}, {0, 1, 1}},
+    {"bn_BD.UTF-8", "৳", "BDT ", {0, 0, 1}, {0, 0, 1}},
+    {"bn_IN.UTF-8", "₹", "INR ", {1, 0, 1}, {1, 0, 1}},
+    {"ca_AD.UTF-8", "€", "EUR ", {0, 1, 1}, {0, 1, 1}},
+    {"ca_ES.UTF-8", "€", "EUR ", {0, 1, 1}, {0, 1, 1}},
+    {"ca_FR.UTF-8", "€", "EUR ", {0, 1, 1}, {0, 1, 1}},
+    {"ca_IT.UTF-8", "€", "EUR ", {0, 1, 1}, {0, 1, 1}},
+    {"cs_CZ.UTF-8", "Kč", "CZK ", {0, 1, 1}, {0, 1, 1}},
+    {"cy_GB.UTF-8", "£", "GBP ", {1, 0, 1}, {1, 0, 1}},
+    {"da_DK.UTF-8", "kr.", "DKK ", {0, 1, 1}, {0, 1, 1}},
+    {"da_GL.UTF-8", "kr.", "DKK ", {0, 1, 1}, {0, 1, 1}},
+    {"de_AT.UTF-8", "€", "EUR ", {1, 1, 1}, {1, 1, 1}},
+    {"de_BE.UTF-8", "€", "EUR ", {0, 1, 1}, {0, 1, 1}},
+    {"de_CH.UTF-8", "CHF", "CHF ", {1, 1, 1}, {1, 0, 4}},
+    {"de_DE.UTF-8", "€", "EUR ", {0, 1, 1}, {0, 1, 1}},
+    {"de_IT.UTF-8", "€", "EUR ", {0, 1, 1}, {0, 1, 1}},
+    {"de_LI.UTF-8", "CHF", "CHF ", {1, 1, 1}, {1, 1, 1}},
+    {"de_LU.UTF-8", "€", "EUR ", {0, 1, 1}, {0, 1, 1}},
+    {"de_US.UTF-8", "$", "USD ", {0, 1, 1}, {0, 1, 1}},
+    {"el_CY.UTF-8", "€", "EUR ", {0, 1, 1}, {0, 1, 1}},
+    {"el_GR.UTF-8", "€", "EUR ", {0, 1, 1}, {0, 1, 1}},
+    {"en_AT.UTF-8", "€", "EUR ", {1, 1, 1}, {1, 1, 1}},
+    {"en_AU.UTF-8", "$", "AUD ", {1, 0, 1}, {1, 0, 1}},
+    {"en_CA.UTF-8", "$", "CAD ", {1, 0, 1}, {1, 0, 1}},
+    {"en_CH.UTF-8", "CHF", "CHF ", {1, 1, 1}, {1, 0, 4}},
+    {"en_DE.UTF-8", "€", "EUR ", {0, 1, 1}, {0, 1, 1}},
+    {"en_DK.UTF-8", "kr.", "DKK ", {0, 1, 1}, {0, 1, 1}},
+    {"en_FI.UTF-8", "€", "EUR ", {0, 1, 1}, {0, 1, 1}},
+    {"en_FK.UTF-8", "£", "FKP ", {1, 0, 1}, {1, 0, 1}},
+    {"en_GB.UTF-8", "£", "GBP ", {1, 0, 1}, {1, 0, 1}},
+    {"en_GI.UTF-8", "£", "GIP ", {1, 0, 1}, {1, 0, 1}},
+    {"en_HK.UTF-8", "HK$", "HKD ", {1, 0, 1}, {1, 0, 1}},
+    {"en_JE.UTF-8", "£", "GBP ", {1, 0, 1}, {1, 0, 1}},
+    {"en_JM.UTF-8", "$", "JMD ", {1, 0, 1}, {1, 0, 1}},
+    {"en_KN.UTF-8", "$", "XCD ", {1, 0, 1}, {1, 0, 1}},
+    {"en_KY.UTF-8", "$", "KYD ", {1, 0, 1}, {1, 0, 1}},
+    {"en_MH.UTF-8", "$", "USD ", {1, 0, 1}, {1, 0, 1}},
+    {"en_MO.UTF-8", "MOP$", "MOP ", {1, 0, 1}, {1, 0, 1}},
+    {"en_MP.UTF-8", "$", "USD ", {1, 0, 1}, {1, 0, 1}},
+    {"en_MT.UTF-8", "€", "EUR ", {1, 0, 1}, {1, 0, 1}},
+    {"en_NZ.UTF-8", "$", "NZD ", {1, 0, 1}, {1, 0, 1}},
+    {"en_PR.UTF-8", "$", "USD ", {1, 0, 1}, {1, 0, 1}},
+    {"en_SB.UTF-8", "$", "SBD ", {1, 0, 1}, {1, 0, 1}},
+    {"en_SE.UTF-8", "kr", "SEK ", {0, 1, 1}, {0, 1, 1}},
+    {"en_SG.UTF-8", "$", "SGD ", {1, 0, 1}, {1, 0, 1}},
+    {"en_SH.UTF-8", "£", "SHP ", {1, 0, 1}, {1, 0, 1}},
+    {"en_SI.UTF-8", "€", "EUR ", {0, 1, 1}, {0, 1, 1}},
+    {"en_UM.UTF-8", "$", "USD ", {1, 0, 1}, {1, 0, 1}},
+    {"en_US.UTF-8", "$", "USD ", {1, 0, 1}, {1, 0, 1}},
+    {"en_VC.UTF-8", "$", "XCD ", {1, 0, 1}, {1, 0, 1}},
+    {"en_VG.UTF-8", "US$", "USD ", {1, 0, 1}, {1, 0, 1}},
+    {"en_VI.UTF-8", "$", "USD ", {1, 0, 1}, {1, 0, 1}},
+    {"en_ZA.UTF-8", "R", "ZAR ", {1, 0, 1}, {1, 0, 1}},
+    {"es_AR.UTF-8", "$", "ARS ", {1, 1, 1}, {1, 1, 1}},
+    {"es_BO.UTF-8", "Bs", "BOB ", {1, 1, 1}, {1, 1, 1}},
+    {"es_CL.UTF-8", "$", "CLP ", {1, 0, 1}, {1, 0, 4}},
+    {"es_CO.UTF-8", "$", "COP ", {1, 1, 1}, {1, 1, 1}},
+    {"es_CR.UTF-8", "₡", "CRC ", {1, 0, 1}, {1, 0, 1}},
+    {"es_CU.UTF-8", "$", "CUP ", {1, 0, 1}, {1, 0, 1}},
+    {"es_DO.UTF-8", "RD$", "DOP ", {1, 0, 1}, {1, 0, 1}},
+    {"es_EC.UTF-8", "$", "USD ", {1, 0, 1}, {1, 0, 4}},
+    {"es_ES.UTF-8", "€", "EUR ", {0, 1, 1}, {0, 1, 1}},
+    {"es_GT.UTF-8", "Q", "GTQ ", {1, 1, 1}, {1, 1, 1}},
+    {"es_HN.UTF-8", "L", "HNL ", {1, 1, 1}, {1, 1, 1}},
+    {"es_MX.UTF-8", "$", "MXN ", {1, 0, 1}, {1, 0, 1}},
+    {"es_NI.UTF-8", "C$", "NIO ", {1, 0, 1}, {1, 0, 1}},
+    {"es_PA.UTF-8", "USD", "USD ", {1, 1, 1}, {1, 1, 1}},
+    {"es_PE.UTF-8", "S/", "PEN ", {1, 1, 1}, {1, 1, 1}},
+    {"es_PR.UTF-8", "$", "USD ", {1, 0, 1}, {1, 0, 1}},
+    {"es_PY.UTF-8", "Gs.", "PYG ", {1, 1, 1}, {1, 2, 4}},
+    {"es_SV.UTF-8", "$", "USD ", {1, 0, 1}, {1, 0, 1}},
+    {"es_US.UTF-8", "$", "USD ", {1, 0, 1}, {1, 0, 1}},
+    {"es_UY.UTF-8", "$", "UYU ", {1, 1, 1}, {1, 1, 1}},
+    {"es_VE.UTF-8", "Bs.", "VEF ", {1, 0, 1}, {1, 0, 4}},
+    {"et_ET.UTF-8", "ETB", "ETB ", {0, 1, 1}, {0, 1, 1}},
+    {"eu_ES.UTF-8", "€", "EUR ", {0, 1, 1}, {0, 1, 1}},
+    {"fa_IR.UTF-8", "ریال", "IRR ", {1, 1, 1}, {1, 1, 1}},
+    {"fi_FI.UTF-8", "€", "EUR ", {0, 1, 1}, {0, 1, 1}},
+    {"fr_BE.UTF-8", "€", "EUR ", {0, 1, 1}, {0, 1, 1}},
+    {"fr_BL.UTF-8", "€", "EUR ", {0, 1, 1}, {0, 1, 1}},
+    {"fr_CA.UTF-8", "$", "CAD ", {0, 1, 1}, {0, 1, 1}},
+    {"fr_CH.UTF-8", "CHF", "CHF ", {0, 1, 1}, {0, 1, 1}},
+    {"fr_DZ.UTF-8", "DA", "DZD ", {0, 1, 1}, {0, 1, 1}},
+    {"fr_FR.UTF-8", "€", "EUR ", {0, 1, 1}, {0, 1, 1}},
+    {"fr_LU.UTF-8", "€", "EUR ", {0, 1, 1}, {0, 1, 1}},
+    {"fr_MA.UTF-8", "MAD", "MAD ", {0, 1, 1}, {0, 1, 1}},
+    {"fr_MC.UTF-8", "€", "EUR ", {0, 1, 1}, {0, 1, 1}},
+    {"fr_MF.UTF-8", "€", "EUR ", {0, 1, 1}, {0, 1, 1}},
+    {"fr_NC.UTF-8", "FCFP", "XPF ", {0, 1, 1}, {0, 1, 1}},
+    {"fr_PM.UTF-8", "€", "EUR ", {0, 1, 1}, {0, 1, 1}},
+    {"fr_RE.UTF-8", "€", "EUR ", {0, 1, 1}, {0, 1, 1}},
+    {"fr_SY.UTF-8", "LS", "SYP ", {0, 1, 1}, {0, 1, 1}},
+    {"fr_TN.UTF-8", "DT", "TND ", {0, 1, 1}, {0, 1, 1}},
+    {"ga_IE.UTF-8", "€", "EUR ", {1, 0, 1}, {1, 0, 1}},
+    {"gl_ES.UTF-8", "€", "EUR ", {0, 1, 1}, {0, 1, 1}},
+    {"hak_CN.UTF-8", "¥", "CNY ", {1, 0, 1}, {1, 0, 1}},
+    {"hak_TW.UTF-8", "$", "TWD ", {1, 0, 1}, {1, 0, 1}},
+    {"he_IL.UTF-8", "₪", "ILS ", {0, 1, 1}, {0, 1, 1}},
+    {"hi_IN.UTF-8", "₹", "INR ", {1, 0, 1}, {1, 0, 1}},
+    {"hr_BA.UTF-8", "KM", "BAM ", {0, 1, 1}, {0, 1, 1}},
+    {"hr_HR.UTF-8", "€", "EUR ", {0, 1, 1}, {0, 1, 1}},
+    {"hu_HU.UTF-8", "Ft", "HUF ", {0, 1, 1}, {0, 1, 1}},
+    {"id_ID.UTF-8", "Rp", "IDR ", {1, 1, 1}, {1, 1, 1}},
+    {"is_IS.UTF-8", "kr.", "ISK ", {0, 1, 1}, {0, 1, 1}},
+    {"it_CH.UTF-8", "CHF", "CHF ", {1, 1, 1}, {1, 0, 4}},
+    {"it_IT.UTF-8", "€", "EUR ", {0, 1, 1}, {0, 1, 1}},
+    {"it_SM.UTF-8", "€", "EUR ", {0, 1, 1}, {0, 1, 1}},
+    {"it_VA.UTF-8", "€", "EUR ", {0, 1, 1}, {0, 1, 1}},
+    {"ja_JP.UTF-8", "￥", "JPY ", {1, 0, 1}, {1, 0, 1}},
+    {"ka_GE.UTF-8", "₾", "GEL ", {0, 1, 1}, {0, 1, 1}},
+    {"kk_KZ.UTF-8", "₸", "KZT ", {0, 1, 1}, {0, 1, 1}},
+    {"ko_KR.UTF-8", "₩", "KRW ", {1, 0, 1}, {1, 0, 1}},
+    {"lt_LT.UTF-8", "€", "EUR ", {0, 1, 1}, {0, 1, 1}},
+    {"lv_LV.UTF-8", "€", "EUR ", {0, 1, 1}, {0, 1, 1}},
+    {"mk_MK.UTF-8", "ден.", "MKD ", {0, 1, 1}, {0, 1, 1}},
+    {"ms_MY.UTF-8", "RM", "MYR ", {1, 1, 1}, {1, 1, 1}},
+    {"mt_MT.UTF-8", "€", "EUR ", {1, 0, 1}, {1, 0, 1}},
+    {"nan_TW.UTF-8", "$", "TWD ", {1, 0, 1}, {1, 0, 1}},
+    {"nb_NO.UTF-8", "kr", "NOK ", {0, 1, 1}, {0, 1, 1}},
+    {"nl_BE.UTF-8", "€", "EUR ", {1, 1, 1}, {1, 2, 4}},
+    {"nl_BQ.UTF-8", "$", "USD ", {1, 1, 1}, {1, 2, 4}},
+    {"nl_CW.UTF-8", "NAf.", "ANG ", {1, 1, 1}, {1, 2, 4}},
+    {"nl_NL.UTF-8", "€", "EUR ", {1, 1, 1}, {1, 2, 4}},
+    {"nl_SR.UTF-8", "$", "SRD ", {1, 1, 1}, {1, 2, 4}},
+    {"nl_SX.UTF-8", "NAf.", "ANG ", {1, 1, 1}, {1, 2, 4}},
+    {"pdc_US.UTF-8", "$", "USD ", {1, 0, 1}, {1, 0, 1}},
+    {"pl_PL.UTF-8", "zł", "PLN ", {0, 1, 1}, {0, 1, 1}},
+    {"pt_AO.UTF-8", "Kz", "AOA ", {0, 1, 1}, {0, 1, 1}},
+    {"pt_BR.UTF-8", "R$", "BRL ", {1, 1, 1}, {1, 1, 1}},
+    {"pt_CH.UTF-8", "CHF", "CHF ", {0, 1, 1}, {0, 1, 1}},
+    {"pt_CV.UTF-8", "​", "CVE ", {0, 1, 1}, {0, 1, 1}},
+    {"pt_GQ.UTF-8", "FCFA", "XAF ", {0, 1, 1}, {0, 1, 1}},
+    {"pt_LU.UTF-8", "€", "EUR ", {0, 1, 1}, {0, 1, 1}},
+    {"pt_MO.UTF-8", "MOP$", "MOP ", {0, 1, 1}, {0, 1, 1}},
+    {"pt_MZ.UTF-8", "MTn", "MZN ", {0, 1, 1}, {0, 1, 1}},
+    {"pt_PT.UTF-8", "€", "EUR ", {0, 1, 1}, {0, 1, 1}},
+    {"pt_ST.UTF-8", "STD", "STD ", {0, 1, 1}, {0, 1, 1}},
+    {"pt_TL.UTF-8", "US$", "USD ", {0, 1, 1}, {0, 1, 1}},
+    {"rm_CH.UTF-8", "CHF", "CHF ", {1, 1, 1}, {1, 1, 1}},
+    {"ro_RO.UTF-8", "RON", "RON ", {0, 1, 1}, {0, 1, 1}},
+    {"ru_BY.UTF-8", "BYR", "BYR ", {0, 1, 1}, {0, 1, 1}},
+    {"ru_KG.UTF-8", "сом", "KGS ", {0, 1, 1}, {0, 1, 1}},
+    {"ru_KZ.UTF-8", "₸", "KZT ", {0, 1, 1}, {0, 1, 1}},
+    {"ru_RU.UTF-8", "₽", "RUB ", {0, 1, 1}, {0, 1, 1}},
+    {"ru_UA.UTF-8", "₴", "UAH ", {0, 1, 1}, {0, 1, 1}},
+    {"sk_SK.UTF-8", "€", "EUR ", {0, 1, 1}, {0, 1, 1}},
+    {"sl_SI.UTF-8", "€", "EUR ", {0, 1, 1}, {0, 1, 1}},
+    {"sq_AL.UTF-8", "Lekë", "ALL ", {0, 1, 1}, {0, 1, 1}},
+    {"sr_BA.UTF-8", "КМ", "BAM ", {0, 1, 1}, {0, 1, 1}},
+    {"sr_BA.UTF-8@latin", "КМ", "BAM ", {0, 1, 1}, {0, 1, 1}},
+    {"sr_ME.UTF-8", "€", "EUR ", {0, 1, 1}, {0, 1, 1}},
+    {"sr_ME.UTF-8@latin", "€", "EUR ", {0, 1, 1}, {0, 1, 1}},
+    {"sr_RS.UTF-8", "RSD", "RSD ", {0, 1, 1}, {0, 1, 1}},
+    {"sr_RS.UTF-8@latin", "RSD", "RSD ", {0, 1, 1}, {0, 1, 1}},
+    {"sr_XK.UTF-8", "€", "EUR ", {0, 1, 1}, {0, 1, 1}},
+    {"sr_XK.UTF-8@latin", "€", "EUR ", {0, 1, 1}, {0, 1, 1}},
+    {"sv_AX.UTF-8", "€", "EUR ", {0, 1, 1}, {0, 1, 1}},
+    {"sv_FI.UTF-8", "€", "EUR ", {0, 1, 1}, {0, 1, 1}},
+    {"sv_SE.UTF-8", "kr", "SEK ", {0, 1, 1}, {0, 1, 1}},
+    {"th_TH.UTF-8", "฿", "THB ", {1, 0, 1}, {1, 0, 1}},
+    {"tr_CY.UTF-8", "€", "EUR ", {1, 0, 1}, {1, 0, 1}},
+    {"tr_TR.UTF-8", "₺", "TRY ", {1, 0, 1}, {1, 0, 1}},
+    {"uk_PL.UTF-8", "PLN", "PLN ", {0, 1, 1}, {0, 1, 1}},
+    {"uk_UA.UTF-8", "₴", "UAH ", {0, 1, 1}, {0, 1, 1}},
+    {"ur_PK.UTF-8", "Rs", "PKR ", {1, 1, 1}, {1, 1, 1}},
+    {"uz_UZ.UTF-8", "soʻm", "UZS ", {0, 1, 1}, {0, 1, 1}},
+    {"vi_VN.UTF-8", "₫", "VND ", {0, 1, 1}, {0, 1, 1}},
+    {"wa_BE.UTF-8", "€", "EUR ", {1, 1, 1}, {1, 1, 1}},
+    {"wae_CH.UTF-8", "CHF", "CHF ", {1, 1, 1}, {1, 1, 1}},
+    {"wuu_CN.UTF-8", "¥", "CNY ", {1, 0, 1}, {1, 0, 1}},
+    {"yue_CN.UTF-8", "CN¥", "CNY ", {1, 0, 1}, {1, 0, 1}},
+    {"yue_HK.UTF-8", "HK$", "HKD ", {1, 0, 1}, {1, 0, 1}},
+    {"yue_MO.UTF-8", "MOP", "MOP ", {1, 1, 1}, {1, 1, 1}},
+    {"zh_CN.UTF-8", "¥", "CNY ", {1, 0, 1}, {1, 0, 1}},
+    {"zh_HK.UTF-8", "HK$", "HKD ", {1, 0, 1}, {1, 0, 1}},
+    {"zh_MO.UTF-8", "MOP$", "MOP ", {1, 0, 1}, {1, 0, 1}},
+    {"zh_SG.UTF-8", "$", "SGD ", {1, 0, 1}, {1, 0, 1}},
+    {"zh_TW.UTF-8", "$", "TWD ", {1, 0, 1}, {1, 0, 1}}
+  };
+}
+
+TEST(localeconv, currency_patterns)
+{
+  for (const MonetaryLayout& layout : monetary_layouts) {
+    SCOPED_TRACE(layout.name);
+
+    strogino_locale_t locale =
+      rs_newlocale(LC_MONETARY_MASK, layout.name, nullptr);
+    ASSERT_NE(nullptr, locale);
+
+    struct lconv* lconv = rs_localeconv_l(locale);
+
+    EXPECT_STREQ(layout.currency_symbol, lconv->currency_symbol);
+    EXPECT_STREQ(layout.int_curr_symbol, lconv->int_curr_symbol);
+    EXPECT_EQ(layout.p[0], lconv->p_cs_precedes);
+    EXPECT_EQ(layout.p[1], lconv->p_sep_by_space);
+    EXPECT_EQ(layout.p[2], lconv->p_sign_posn);
+    EXPECT_EQ(layout.n[0], lconv->n_cs_precedes);
+    EXPECT_EQ(layout.n[1], lconv->n_sep_by_space);
+    EXPECT_EQ(layout.n[2], lconv->n_sign_posn);
+    EXPECT_EQ(layout.p[0], lconv->int_p_cs_precedes);
+    EXPECT_EQ(layout.p[1], lconv->int_p_sep_by_space);
+    EXPECT_EQ(layout.p[2], lconv->int_p_sign_posn);
+    EXPECT_EQ(layout.n[0], lconv->int_n_cs_precedes);
+    EXPECT_EQ(layout.n[1], lconv->int_n_sep_by_space);
+    EXPECT_EQ(layout.n[2], lconv->int_n_sign_posn);
+
+    rs_freelocale(locale);
+  }
+}
+
+TEST(localeconv, accounting)
+{
+  // Negative amounts in parentheses, with either spelling of the keyword
+  const char* names[] = {"en_US.UTF-8@cf=account", "en-US-u-cf-account"};
+  for (const char* name : names) {
+    SCOPED_TRACE(name);
+
+    strogino_locale_t locale = rs_newlocale(LC_MONETARY_MASK, name, nullptr);
+    ASSERT_NE(nullptr, locale);
+
+    struct lconv* lconv = rs_localeconv_l(locale);
+
+    ASSERT_STREQ("$", lconv->currency_symbol);
+    ASSERT_EQ(1, lconv->p_cs_precedes);
+    ASSERT_EQ(0, lconv->p_sep_by_space);
+    ASSERT_EQ(1, lconv->p_sign_posn);
+    ASSERT_EQ(1, lconv->n_cs_precedes);
+    ASSERT_EQ(0, lconv->n_sep_by_space);
+    ASSERT_EQ(0, lconv->n_sign_posn);
+    ASSERT_EQ(0, lconv->int_n_sign_posn);
+
+    rs_freelocale(locale);
+  }
+
+  // After the number
+  strogino_locale_t locale =
+    rs_newlocale(LC_MONETARY_MASK, "fr_FR.UTF-8@cf=account", nullptr);
+  ASSERT_NE(nullptr, locale);
+  struct lconv* lconv = rs_localeconv_l(locale);
+  ASSERT_EQ(0, lconv->n_cs_precedes);
+  ASSERT_EQ(1, lconv->n_sep_by_space);
+  ASSERT_EQ(0, lconv->n_sign_posn);
+  rs_freelocale(locale);
+
+  // Locales without an accounting format keep the minus sign
+  locale = rs_newlocale(LC_MONETARY_MASK, "de_DE.UTF-8@cf=account", nullptr);
+  ASSERT_NE(nullptr, locale);
+  lconv = rs_localeconv_l(locale);
+  ASSERT_EQ(0, lconv->n_cs_precedes);
+  ASSERT_EQ(1, lconv->n_sep_by_space);
+  ASSERT_EQ(1, lconv->n_sign_posn);
+  rs_freelocale(locale);
+}
+
+TEST(localeconv, locale_change)
+{
+  ASSERT_STREQ("C", rs_setlocale(LC_ALL, "C"));
+  struct lconv* lconv = rs_localeconv();
+  ASSERT_STREQ(".", lconv->decimal_point);
+  ASSERT_EQ(lconv, rs_localeconv());
+
+  // The result of the old locale is not updated in place, and stays valid
+  ASSERT_STREQ("de_DE.UTF-8", rs_setlocale(LC_NUMERIC, "de_DE.UTF-8"));
+  struct lconv* changed = rs_localeconv();
+  ASSERT_STREQ(",", changed->decimal_point);
+  ASSERT_STREQ("", changed->currency_symbol);
+  ASSERT_STREQ(".", lconv->decimal_point);
+
+  ASSERT_STREQ("C", rs_setlocale(LC_ALL, "C"));
+}
+
+TEST(localeconv, concurrent)
+{
+  ASSERT_STREQ("de_DE.UTF-8", rs_setlocale(LC_ALL, "de_DE.UTF-8"));
+
+  // Every thread gets the same result, made by whichever came first
+  std::vector<struct lconv*> results(8);
+  std::vector<std::thread> threads;
+  for (auto& result : results)
+  {
+    threads.emplace_back([&result] { result = rs_localeconv(); });
+  }
+  for (auto& thread : threads)
+  {
+    thread.join();
+  }
+
+  for (struct lconv* lconv : results)
+  {
+    ASSERT_EQ(results[0], lconv);
+  }
+  ASSERT_STREQ(",", results[0]->decimal_point);
+  ASSERT_STREQ("€", results[0]->currency_symbol);
+
+  // The readers keep their results while the global locale changes under
+  // them, and read them only afterwards
+  std::atomic<bool> done = false;
+  std::atomic<int> failures = 0;
+  threads.clear();
+  for (int i = 0; i < 4; i++)
+  {
+    threads.emplace_back([&] {
+      while (!done)
+      {
+        struct lconv* lconv = rs_localeconv();
+        std::this_thread::yield();
+        if (strcmp(lconv->decimal_point, ",") != 0 ||
+            strcmp(lconv->currency_symbol, "€") != 0)
+        {
+          failures++;
+        }
+      }
+    });
+  }
+  for (int i = 0; i < 200; i++)
+  {
+    if (rs_setlocale(LC_ALL, i % 2 ? "de_DE.UTF-8" : "fr_FR.UTF-8") == nullptr)
+    {
+      failures++;
+    }
+  }
+  done = true;
+  for (auto& thread : threads)
+  {
+    thread.join();
+  }
+  ASSERT_EQ(0, failures);
+
+  ASSERT_STREQ("C", rs_setlocale(LC_ALL, "C"));
+}
+
+TEST(localeconv, currency_override)
+{
+  strogino_locale_t locale =
+    rs_newlocale(LC_MONETARY_MASK, "de_CH.UTF-8@currency=EUR", nullptr);
+  ASSERT_NE(nullptr, locale);
+  struct lconv* lconv = rs_localeconv_l(locale);
+  ASSERT_STREQ("EUR", lconv->currency_symbol);
+  ASSERT_STREQ("EUR ", lconv->int_curr_symbol);
+  ASSERT_EQ(2, lconv->frac_digits);
+  ASSERT_EQ(2, lconv->int_frac_digits);
+  ASSERT_STREQ("de_CH.UTF-8@currency=EUR",
+               rs_getlocalename_l(LC_MONETARY, locale));
+  rs_freelocale(locale);
+
+  // The legacy euro modifier
+  locale = rs_newlocale(LC_ALL_MASK, "de_DE.UTF-8@euro", nullptr);
+  ASSERT_NE(nullptr, locale);
+  lconv = rs_localeconv_l(locale);
+  ASSERT_STREQ("€", lconv->currency_symbol);
+  ASSERT_STREQ("EUR ", lconv->int_curr_symbol);
+  ASSERT_STREQ("de_DE.UTF-8@euro", rs_getlocalename_l(LC_ALL, locale));
+  rs_freelocale(locale);
+
+  // And the BCP-47 keyword, with the minor units of the currency
+  locale = rs_newlocale(LC_MONETARY_MASK, "en_US-u-cu-jpy.UTF-8", nullptr);
+  ASSERT_NE(nullptr, locale);
+  lconv = rs_localeconv_l(locale);
+  ASSERT_STREQ("¥", lconv->currency_symbol);
+  ASSERT_STREQ("JPY ", lconv->int_curr_symbol);
+  ASSERT_EQ(0, lconv->frac_digits);
+  ASSERT_EQ(0, lconv->int_frac_digits);
+  ASSERT_STREQ("en_US-u-cu-jpy.UTF-8",
+               rs_getlocalename_l(LC_MONETARY, locale));
+  rs_freelocale(locale);
+
+  locale = rs_newlocale(LC_MONETARY_MASK, "ja_JP.UTF-8@currency=usd", nullptr);
+  ASSERT_NE(nullptr, locale);
+  lconv = rs_localeconv_l(locale);
+  ASSERT_STREQ("$", lconv->currency_symbol);
+  ASSERT_STREQ("USD ", lconv->int_curr_symbol);
+  ASSERT_EQ(2, lconv->frac_digits);
+  rs_freelocale(locale);
+
+  rs_errno = 0;
+  ASSERT_EQ(nullptr, rs_newlocale(LC_MONETARY_MASK,
+                                  "en_US.UTF-8@currency=EURO", nullptr));
+  ASSERT_EQ(ENOENT, rs_errno);
+}
+
 TEST(setlocale, good)
 {
   ASSERT_STREQ("C", rs_setlocale(LC_ALL, "C"));
@@ -210,11 +730,31 @@ TEST(setlocale, good)
   ASSERT_STREQ("pdc_US.UTF-8", rs_setlocale(LC_MESSAGES, "pdc_US.utf8"));
   ASSERT_STREQ("de_DE.UTF-8", rs_setlocale(LC_TIME, "de_DE.utf8"));
   ASSERT_STREQ(
-    "LC_COLLATE=sv_SE.UTF-8;LC_CTYPE=POSIX.UTF-8;LC_MESSAGES=pdc_US.UTF-8;LC_"
-    "MONETARY=nl_NL.UTF-8;LC_NUMERIC=en_US.UTF-8;LC_TIME=de_DE.UTF-8",
+    "LC_ADDRESS=en_US.UTF-8;LC_COLLATE=sv_SE.UTF-8;LC_CTYPE=POSIX.UTF-8;"
+    "LC_IDENTIFICATION=en_US.UTF-8;LC_MEASUREMENT=en_US.UTF-8;LC_MESSAGES="
+    "pdc_US.UTF-8;LC_MONETARY=nl_NL.UTF-8;LC_NAME=en_US.UTF-8;LC_NUMERIC=en_"
+    "US.UTF-8;LC_PAPER=en_US.UTF-8;LC_TELEPHONE=en_US.UTF-8;LC_TIME=de_DE.UTF-8",
     rs_setlocale(LC_ALL, NULL));
 }
 
+TEST(setlocale, extra_categories)
+{
+  ASSERT_STREQ("C", rs_setlocale(LC_ALL, "C"));
+  ASSERT_STREQ("en_US.UTF-8", rs_setlocale(LC_PAPER, "en_US.utf8"));
+  ASSERT_STREQ("de_DE.UTF-8", rs_setlocale(LC_MEASUREMENT, "de_DE.UTF-8"));
+  ASSERT_STREQ("fr_FR.UTF-8", rs_setlocale(LC_IDENTIFICATION, "fr_FR.UTF-8"));
+  ASSERT_STREQ("en_US.UTF-8", rs_setlocale(LC_PAPER, NULL));
+  ASSERT_STREQ("C", rs_setlocale(LC_NAME, NULL));
+  ASSERT_STREQ("LC_ADDRESS=C;LC_COLLATE=C;LC_CTYPE=C;LC_IDENTIFICATION=fr_"
+               "FR.UTF-8;LC_MEASUREMENT=de_DE.UTF-8;LC_MESSAGES=C;LC_"
+               "MONETARY=C;LC_NAME=C;LC_NUMERIC=C;LC_PAPER=en_US.UTF-8;LC_"
+               "TELEPHONE=C;LC_TIME=C",
+               rs_setlocale(LC_ALL, NULL));
+  ASSERT_STREQ("C", rs_setlocale(LC_ALL, "C"));
+  ASSERT_STREQ("C", rs_setlocale(LC_ALL, NULL));
+  ASSERT_STREQ(NULL, rs_setlocale(LC_IDENTIFICATION + 1, "C"));
+}
+
 TEST(setlocale, bad)
 {
   ASSERT_STREQ(NULL, rs_setlocale(1337, "C"));
@@ -225,6 +765,135 @@ TEST(setlocale, bad)
   ASSERT_STREQ(NULL, rs_setlocale(LC_ALL, "sr-SR@latin"));
 }
 
+TEST(setlocale, icu_data)
+{
+  ASSERT_STREQ("es_ES.UTF-8", rs_setlocale(LC_ALL, "es_ES.UTF-8"));
+  ASSERT_STREQ("tr_TR.UTF-8", rs_setlocale(LC_ALL, "tr_TR.UTF-8"));
+  ASSERT_STREQ("ar_EG.UTF-8", rs_setlocale(LC_ALL, "ar_EG.UTF-8"));
+  ASSERT_STREQ("hi_IN.UTF-8", rs_setlocale(LC_ALL, "hi_IN.UTF-8"));
+  ASSERT_STREQ("pt_PT.UTF-8", rs_setlocale(LC_ALL, "pt_PT.UTF-8"));
+  ASSERT_STREQ("C", rs_setlocale(LC_ALL, "C"));
+}
+
+TEST(setlocale, messages)
+{
+  ASSERT_STREQ("es_MX.UTF-8", rs_setlocale(LC_MESSAGES, "es_MX.UTF-8"));
+  ASSERT_STREQ("ar_SA.UTF-8", rs_setlocale(LC_MESSAGES, "ar_SA.UTF-8"));
+  ASSERT_STREQ("fa_IR.UTF-8", rs_setlocale(LC_MESSAGES, "fa_IR.UTF-8"));
+  ASSERT_STREQ("hu_HU.UTF-8", rs_setlocale(LC_MESSAGES, "hu_HU.UTF-8"));
+  ASSERT_STREQ("id_ID.UTF-8", rs_setlocale(LC_MESSAGES, "id_ID.UTF-8"));
+  ASSERT_STREQ("ro_RO.UTF-8", rs_setlocale(LC_MESSAGES, "ro_RO.UTF-8"));
+  ASSERT_STREQ("sk_SK.UTF-8", rs_setlocale(LC_MESSAGES, "sk_SK.UTF-8"));
+  ASSERT_STREQ("th_TH.UTF-8", rs_setlocale(LC_MESSAGES, "th_TH.UTF-8"));
+  ASSERT_STREQ("pt_AO.UTF-8", rs_setlocale(LC_MESSAGES, "pt_AO.UTF-8"));
+  ASSERT_STREQ("de_AT.UTF-8", rs_setlocale(LC_MESSAGES, "de_AT.UTF-8"));
+  ASSERT_STREQ(
+    "sr_RS.UTF-8@latin", rs_setlocale(LC_MESSAGES, "sr_RS.UTF-8@latin"));
+  ASSERT_STREQ("en_CAT.UTF-8", rs_setlocale(LC_MESSAGES, "en_CAT.UTF-8"));
+  ASSERT_STREQ("C", rs_setlocale(LC_MESSAGES, "C"));
+}
+
+TEST(setlocale, unknown_language)
+{
+  ASSERT_STREQ(NULL, rs_setlocale(LC_ALL, "xx_YY.UTF-8"));
+  ASSERT_STREQ(NULL, rs_setlocale(LC_MESSAGES, "xx_YY.UTF-8"));
+  ASSERT_STREQ(NULL, rs_setlocale(LC_COLLATE, "qaa_US.UTF-8"));
+}
+
+TEST(setlocale, failure_keeps_locale)
+{
+  ASSERT_STREQ("de_DE.UTF-8", rs_setlocale(LC_ALL, "de_DE.UTF-8"));
+
+  // Every category but LC_MONETARY can load an unknown territory
+  ASSERT_STREQ(NULL, rs_setlocale(LC_ALL, "en_ZZ.UTF-8"));
+  ASSERT_STREQ("de_DE.UTF-8", rs_setlocale(LC_ALL, NULL));
+
+  ASSERT_STREQ("en_ZZ.UTF-8", rs_setlocale(LC_NUMERIC, "en_ZZ.UTF-8"));
+  ASSERT_STREQ("C", rs_setlocale(LC_ALL, "C"));
+}
+
+TEST(newlocale, same_as_setlocale)
+{
+  const char* names[] = { "C",           "POSIX.UTF-8", "es_ES.UTF-8",
+                          "es_ES.utf8",  "de_DE",       "xx_YY.UTF-8",
+                          "en_ZZ.UTF-8", "Unknown",     "sr_RS.UTF-8@latin" };
+
+  for (const char* name : names) {
+    strogino_locale_t locale = rs_newlocale(LC_ALL_MASK, name, nullptr);
+    bool set = rs_setlocale(LC_ALL, name) != nullptr;
+
+    EXPECT_EQ(locale != nullptr, set) << name;
+    rs_freelocale(locale);
+  }
+
+  rs_setlocale(LC_ALL, "C");
+}
+
+TEST(newlocale, mask_only)
+{
+  strogino_locale_t locale =
+    rs_newlocale(LC_NUMERIC_MASK, "en_ZZ.UTF-8", nullptr);
+  ASSERT_NE(nullptr, locale);
+  ASSERT_STREQ("en_ZZ.UTF-8", rs_getlocalename_l(LC_NUMERIC, locale));
+  ASSERT_STREQ("C", rs_getlocalename_l(LC_MONETARY, locale));
+  rs_freelocale(locale);
+
+  locale = rs_newlocale(LC_MONETARY_MASK, "en_ZZ.UTF-8", nullptr);
+  ASSERT_EQ(nullptr, locale);
+  ASSERT_EQ(ENOENT, rs_errno);
+}
+
+TEST(getavailablelocales, all_load)
+{
+  size_t n = rs_getavailablelocales(nullptr, 0);
+  ASSERT_GT(n, 100u);
+
+  std::vector<const char*> names(n);
+  ASSERT_EQ(n, rs_getavailablelocales(names.data(), n));
+
+  std::vector<std::string> list(names.begin(), names.end());
+  EXPECT_THAT(list, testing::Contains("C"));
+  EXPECT_THAT(list, testing::Contains("C.UTF-8"));
+  EXPECT_THAT(list, testing::Contains("es_ES.UTF-8"));
+  EXPECT_THAT(list, testing::Contains("sr_RS.UTF-8@latin"));
+  EXPECT_THAT(list, testing::Not(testing::Contains("es_ES.utf8")));
+
+  for (const char* name : names) {
+    strogino_locale_t locale = rs_newlocale(LC_ALL_MASK, name, nullptr);
+    EXPECT_NE(nullptr, locale) << name;
+    rs_freelocale(locale);
+  }
+}
+
+TEST(getavailablelocales, partial)
+{
+  const char* names[2] = { nullptr, nullptr };
+
+  ASSERT_GT(rs_getavailablelocales(names, 1), 1u);
+  ASSERT_STREQ("C", names[0]);
+  ASSERT_EQ(nullptr, names[1]);
+}
+
+TEST(newlocale, extra_categories)
+{
+  strogino_locale_t base = rs_newlocale(LC_ALL_MASK, "de_DE.UTF-8", nullptr);
+  ASSERT_NE(nullptr, base);
+  strogino_locale_t locale = rs_newlocale(
+    LC_PAPER_MASK | LC_TELEPHONE_MASK, "en_US.UTF-8", base);
+  ASSERT_NE(nullptr, locale);
+
+  ASSERT_STREQ("en_US.UTF-8", rs_getlocalename_l(LC_PAPER, locale));
+  ASSERT_STREQ("en_US.UTF-8", rs_getlocalename_l(LC_TELEPHONE, locale));
+  ASSERT_STREQ("de_DE.UTF-8", rs_getlocalename_l(LC_ADDRESS, locale));
+  ASSERT_STREQ("de_DE.UTF-8", rs_getlocalename_l(LC_MEASUREMENT, locale));
+  ASSERT_STREQ("de_DE.UTF-8", rs_getlocalename_l(LC_NAME, locale));
+  ASSERT_STREQ("de_DE.UTF-8", rs_getlocalename_l(LC_IDENTIFICATION, locale));
+  ASSERT_STREQ("de_DE.UTF-8", rs_getlocalename_l(LC_NUMERIC, locale));
+
+  rs_freelocale(locale);
+  rs_freelocale(base);
+}
+
 TEST(newlocale, zero_mask)
 {
   strogino_locale_t locale = rs_newlocale(0, "Unknown", 0);
@@ -258,6 +927,140 @@ TEST(newlocale, success_belgium)
   rs_freelocale(locale);
 }
 
+TEST(setlocale, concurrent_categories)
+{
+  ASSERT_STREQ("C", rs_setlocale(LC_ALL, "C"));
+
+  // Every thread changes its own category, so none of the changes may be
+  // lost to another thread publishing a locale made from an older version
+  std::vector<std::thread> threads;
+  for (int category : { LC_CTYPE, LC_NUMERIC, LC_TIME, LC_COLLATE, LC_MONETARY,
+                        LC_MESSAGES, LC_PAPER, LC_NAME, LC_ADDRESS,
+                        LC_TELEPHONE, LC_MEASUREMENT, LC_IDENTIFICATION })
+  {
+    threads.emplace_back([category] {
+      for (int i = 0; i < 50; i++)
+      {
+        rs_setlocale(category, i % 2 ? "de_DE.UTF-8" : "C");
+      }
+    });
+  }
+  for (auto& thread : threads)
+  {
+    thread.join();
+  }
+
+  ASSERT_STREQ("de_DE.UTF-8", rs_setlocale(LC_ALL, nullptr));
+  ASSERT_STREQ("C", rs_setlocale(LC_ALL, "C"));
+}
+
+TEST(setlocale, concurrent_readers)
+{
+  ASSERT_STREQ("C", rs_setlocale(LC_ALL, "C"));
+
+  std::atomic<bool> done = false;
+  std::atomic<int> failures = 0;
+
+  std::vector<std::thread> readers;
+  for (int i = 0; i < 4; i++)
+  {
+    readers.emplace_back([&] {
+      while (!done)
+      {
+        int order = rs_strcoll("Müller", "Muff");
+        if (order == 0 || rs_toupper('a') != 'A')
+        {
+          failures++;
+        }
+      }
+    });
+  }
+
+  for (int i = 0; i < 200; i++)
+  {
+    const char* name =
+      i % 2 ? "de_DE.UTF-8@collation=phonebook" : "de_DE.UTF-8";
+    if (rs_setlocale(LC_ALL, name) == nullptr)
+    {
+      failures++;
+    }
+  }
+  done = true;
+  for (auto& reader : readers)
+  {
+    reader.join();
+  }
+
+  ASSERT_EQ(0, failures);
+  ASSERT_STREQ("C", rs_setlocale(LC_ALL, "C"));
+}
+
+TEST(setlocale, pointers_outlive_changes)
+{
+  ASSERT_NE(nullptr, rs_setlocale(LC_ALL, "de_DE.UTF-8"));
+  const char* name = rs_setlocale(LC_ALL, nullptr);
+  const char* codeset = rs_nl_langinfo(CODESET);
+  const char* radix = rs_nl_langinfo(RADIXCHAR);
+
+  // Changing the global locale leaves the strings it returned in place
+  for (const char* other : { "C", "fr_FR.UTF-8", "en_US.UTF-8", "C" })
+  {
+    ASSERT_NE(nullptr, rs_setlocale(LC_ALL, other));
+  }
+  ASSERT_STREQ("de_DE.UTF-8", name);
+  ASSERT_STREQ("UTF-8", codeset);
+  ASSERT_STREQ(",", radix);
+}
+
+TEST(setlocale, concurrent_pointers)
+{
+  ASSERT_NE(nullptr, rs_setlocale(LC_ALL, "de_DE.UTF-8"));
+
+  std::atomic<bool> done = false;
+  std::atomic<int> failures = 0;
+
+  // The readers hold on to the strings while the global locale changes
+  // under them, and read them only afterwards
+  std::vector<std::thread> readers;
+  for (int i = 0; i < 4; i++)
+  {
+    readers.emplace_back([&] {
+      while (!done)
+      {
+        const char* name = rs_setlocale(LC_ALL, nullptr);
+        const char* codeset = rs_nl_langinfo(CODESET);
+        const char* radix = rs_nl_langinfo(RADIXCHAR);
+        std::this_thread::yield();
+        if (strcmp(name, "de_DE.UTF-8") != 0 &&
+            strcmp(name, "fr_FR.UTF-8") != 0)
+        {
+          failures++;
+        }
+        if (strcmp(codeset, "UTF-8") != 0 || strcmp(radix, ",") != 0)
+        {
+          failures++;
+        }
+      }
+    });
+  }
+
+  for (int i = 0; i < 200; i++)
+  {
+    if (rs_setlocale(LC_ALL, i % 2 ? "de_DE.UTF-8" : "fr_FR.UTF-8") == nullptr)
+    {
+      failures++;
+    }
+  }
+  done = true;
+  for (auto& reader : readers)
+  {
+    reader.join();
+  }
+
+  ASSERT_EQ(0, failures);
+  ASSERT_STREQ("C", rs_setlocale(LC_ALL, "C"));
+}
+
 TEST(newlocale, success_netherlands)
 {
   rs_errno = 0;
@@ -296,14 +1099,30 @@ TEST(getlocalename_l, good)
     rs_newlocale(LC_MESSAGES_MASK, "de_DE.UTF-8", locale);
 
   ASSERT_STREQ(
-    "LC_COLLATE=en_US.UTF-8;LC_CTYPE=en_US.UTF-8;LC_MESSAGES=de_DE.UTF-8;LC_"
-    "MONETARY=en_US.UTF-8;LC_NUMERIC=en_US.UTF-8;LC_TIME=en_US.UTF-8",
+    "LC_ADDRESS=en_US.UTF-8;LC_COLLATE=en_US.UTF-8;LC_CTYPE=en_US.UTF-8;"
+    "LC_IDENTIFICATION=en_US.UTF-8;LC_MEASUREMENT=en_US.UTF-8;LC_MESSAGES="
+    "de_DE.UTF-8;LC_MONETARY=en_US.UTF-8;LC_NAME=en_US.UTF-8;LC_NUMERIC=en_"
+    "US.UTF-8;LC_PAPER=en_US.UTF-8;LC_TELEPHONE=en_US.UTF-8;LC_TIME=en_US.UTF-8",
     rs_getlocalename_l(LC_ALL, new_locale));
 
   rs_freelocale(new_locale);
   rs_freelocale(locale);
 }
 
+TEST(getlocalename_l, modifier)
+{
+  strogino_locale_t locale = rs_newlocale(
+    LC_ALL_MASK, "de_DE.UTF-8@collation=phonebook", nullptr);
+  ASSERT_NE(nullptr, locale);
+
+  ASSERT_STREQ("de_DE.UTF-8@collation=phonebook",
+               rs_getlocalename_l(LC_COLLATE, locale));
+  ASSERT_STREQ("de_DE.UTF-8@collation=phonebook",
+               rs_getlocalename_l(LC_ALL, locale));
+
+  rs_freelocale(locale);
+}
+
 TEST(getlocalename_l, bad)
 {
   strogino_locale_t locale = rs_newlocale(LC_CTYPE_MASK, "pdc_US", nullptr);
@@ -313,3 +1132,103 @@ TEST(getlocalename_l, bad)
 
   rs_freelocale(locale);
 }
+
+TEST(localedef, setlocale)
+{
+  setenv("LOCPATH", make_locpath().c_str(), 1);
+  rs_uselocale(STROGINO_LC_GLOBAL_LOCALE);
+
+  ASSERT_STREQ("en_XA.UTF-8", rs_setlocale(LC_ALL, "en_XA.UTF-8"));
+
+  struct lconv* lconv = rs_localeconv();
+  ASSERT_STREQ(",", lconv->decimal_point);
+  ASSERT_STREQ("'", lconv->thousands_sep);
+  ASSERT_STREQ("\3\2", lconv->grouping);
+  ASSERT_STREQ("XAU ", lconv->int_curr_symbol);
+  ASSERT_STREQ("¤", lconv->currency_symbol);
+  ASSERT_STREQ(".", lconv->mon_decimal_point);
+  ASSERT_EQ(3, lconv->frac_digits);
+
+  ASSERT_STREQ("C", rs_setlocale(LC_ALL, "C"));
+  unsetenv("LOCPATH");
+}
+
+TEST(localedef, newlocale)
+{
+  setenv("LOCPATH", make_locpath().c_str(), 1);
+
+  strogino_locale_t locale = rs_newlocale(LC_ALL_MASK, "en_XA.UTF-8", nullptr);
+  ASSERT_NE(nullptr, locale);
+  ASSERT_STREQ("en_XA.UTF-8", rs_getlocalename_l(LC_ALL, locale));
+
+  ASSERT_STREQ("UTF-8", rs_nl_langinfo_l(CODESET, locale));
+  ASSERT_STREQ("Mo", rs_nl_langinfo_l(ABDAY_2, locale));
+  ASSERT_STREQ("Monday", rs_nl_langinfo_l(DAY_2, locale));
+  ASSERT_STREQ("%Y-%m-%d", rs_nl_langinfo_l(D_FMT, locale));
+  ASSERT_STREQ("%H.%M", rs_nl_langinfo_l(T_FMT, locale));
+  ASSERT_STREQ("nm", rs_nl_langinfo_l(PM_STR, locale));
+  ASSERT_STREQ("^[+1jJyY]", rs_nl_langinfo_l(YESEXPR, locale));
+  ASSERT_STREQ("^[-0nN]", rs_nl_langinfo_l(NOEXPR, locale));
+  ASSERT_STREQ("ja", rs_nl_langinfo_l(YESSTR, locale));
+
+  rs_freelocale(locale);
+  unsetenv("LOCPATH");
+}
+
+TEST(localedef, ctype)
+{
+  setenv("LOCPATH", make_locpath().c_str(), 1);
+
+  strogino_locale_t locale =
+    rs_newlocale(LC_CTYPE_MASK, "en_XA.UTF-8", nullptr);
+  ASSERT_NE(nullptr, locale);
+
+  ASSERT_TRUE(rs_iswalpha_l(L'@', locale));
+  ASSERT_TRUE(rs_iswalnum_l(L'@', locale));
+  ASSERT_TRUE(rs_iswalnum_l(L'7', locale));
+  ASSERT_FALSE(rs_iswalpha_l(L'é', locale));
+  ASSERT_EQ(L'©', rs_towupper_l(L'@', locale));
+  ASSERT_EQ(L'É', rs_towupper_l(L'é', locale));
+
+  rs_freelocale(locale);
+  unsetenv("LOCPATH");
+}
+
+TEST(localedef, collate)
+{
+  setenv("LOCPATH", make_locpath().c_str(), 1);
+
+  strogino_locale_t locale =
+    rs_newlocale(LC_COLLATE_MASK, "en_XA.UTF-8", nullptr);
+  ASSERT_NE(nullptr, locale);
+
+  ASSERT_GT(0, rs_strcoll_l("b", "a", locale));
+  ASSERT_GT(0, rs_strcoll_l("a", "c", locale));
+  ASSERT_GT(0, rs_strcoll_l("c", "z", locale));
+  ASSERT_GT(0, rs_strcoll_l("b", "B", locale));
+  ASSERT_GT(0, rs_strcoll_l("Bb", "ba", locale));
+  ASSERT_EQ(0, rs_strcoll_l("a-b", "ab", locale));
+
+  char lhs[64];
+  char rhs[64];
+  ASSERT_GT(sizeof(lhs), rs_strxfrm_l(lhs, "Bb", sizeof(lhs), locale));
+  ASSERT_GT(sizeof(rhs), rs_strxfrm_l(rhs, "ba", sizeof(rhs), locale));
+  ASSERT_GT(0, strcmp(lhs, rhs));
+
+  rs_freelocale(locale);
+  unsetenv("LOCPATH");
+}
+
+TEST(localedef, copy)
+{
+  setenv("LOCPATH", make_locpath().c_str(), 1);
+
+  strogino_locale_t locale = rs_newlocale(LC_TIME_MASK, "en_XB.UTF-8", nullptr);
+  ASSERT_NE(nullptr, locale);
+  ASSERT_STREQ("%Y-%m-%d", rs_nl_langinfo_l(D_FMT, locale));
+  ASSERT_STREQ("%c", rs_nl_langinfo_l(D_T_FMT, locale));
+  rs_freelocale(locale);
+
+  unsetenv("LOCPATH");
+  ASSERT_EQ(nullptr, rs_newlocale(LC_ALL_MASK, "en_XA.UTF-8", nullptr));
+}
@@ -0,0 +1,180 @@
+#include "common.h"
+#include <cerrno>
+#include <clocale>
+#include <cstdint>
+#include <cstdlib>
+#include <fstream>
+#include <gtest/gtest.h>
+#include <nl_types.h>
+#include <string>
+#include <sys/stat.h>
+#include <tuple>
+#include <vector>
+
+typedef void* strogino_nl_catd;
+
+extern "C"
+{
+  strogino_nl_catd rs_catopen(const char*, int);
+  char* rs_catgets(strogino_nl_catd, int, int, const char*);
+  int rs_catclose(strogino_nl_catd);
+}
+
+#define STROGINO_CATD_INVALID ((strogino_nl_catd) - 1L)
+
+namespace
+{
+  typedef std::vector<std::tuple<uint32_t, uint32_t, std::string>> Messages;
+
+  // Writes a catalog in the format of gencat: each message goes into the
+  // slot (set * msg) % size of the first plane where it is free, and the
+  // table is stored in the byte order of the file and then swapped.
+  void write_cat(const std::string& path, const Messages& messages,
+                 bool big_endian)
+  {
+    const uint32_t size = 3;
+    const uint32_t depth = 2;
+    std::vector<uint32_t> table(size * depth * 3);
+    std::string strings;
+
+    for (auto& [set, msg, text] : messages) {
+      uint32_t plane = 0;
+      while (table[(plane * size + set * msg % size) * 3] != 0) {
+        plane++;
+      }
+      uint32_t slot = (plane * size + set * msg % size) * 3;
+      table[slot] = set;
+      table[slot + 1] = msg;
+      table[slot + 2] = strings.size();
+      strings += text;
+      strings.push_back('\0');
+    }
+
+    std::string out;
+    auto put = [&](uint32_t v, bool big) {
+      for (int i = 0; i < 4; i++) {
+        int shift = big ? 24 - 8 * i : 8 * i;
+        out.push_back(char((v >> shift) & 0xff));
+      }
+    };
+
+    put(0x960408de, big_endian);
+    put(size, big_endian);
+    put(depth, big_endian);
+    for (uint32_t v : table) {
+      put(v, big_endian);
+    }
+    for (uint32_t v : table) {
+      put(v, !big_endian);
+    }
+
+    std::ofstream(path, std::ios::binary) << out << strings;
+  }
+
+  std::string make_catalog_dir(const char* language)
+  {
+    static std::string root = [] {
+      char tmpl[] = "/tmp/strogino-nl_types-XXXXXX";
+      return std::string(mkdtemp(tmpl));
+    }();
+
+    mkdir((root + "/" + language).c_str(), 0755);
+
+    return root;
+  }
+}
+
+TEST(catopen, path)
+{
+  std::string root = make_catalog_dir("path");
+  std::string path = root + "/path/app.cat";
+  write_cat(path, {{1, 1, "Hello"}, {1, 2, "Bye"}, {2, 1, "Other set"}},
+            false);
+
+  strogino_nl_catd catd = rs_catopen(path.c_str(), 0);
+  ASSERT_NE(STROGINO_CATD_INVALID, catd);
+
+  ASSERT_STREQ("Hello", rs_catgets(catd, 1, 1, "default"));
+  ASSERT_STREQ("Bye", rs_catgets(catd, 1, 2, "default"));
+  ASSERT_STREQ("Other set", rs_catgets(catd, 2, 1, "default"));
+
+  rs_errno = 0;
+  ASSERT_STREQ("default", rs_catgets(catd, 2, 2, "default"));
+  ASSERT_EQ(ENOMSG, rs_errno);
+
+  ASSERT_EQ(0, rs_catclose(catd));
+}
+
+TEST(catopen, big_endian)
+{
+  std::string root = make_catalog_dir("big");
+  std::string path = root + "/big/app.cat";
+  write_cat(path, {{1, 3, "Big"}, {1, 6, "Endian"}}, true);
+
+  strogino_nl_catd catd = rs_catopen(path.c_str(), 0);
+  ASSERT_NE(STROGINO_CATD_INVALID, catd);
+
+  ASSERT_STREQ("Big", rs_catgets(catd, 1, 3, "default"));
+  ASSERT_STREQ("Endian", rs_catgets(catd, 1, 6, "default"));
+
+  ASSERT_EQ(0, rs_catclose(catd));
+}
+
+TEST(catopen, missing)
+{
+  rs_errno = 0;
+  strogino_nl_catd catd = rs_catopen("/nonexistent/app.cat", 0);
+  ASSERT_EQ(STROGINO_CATD_INVALID, catd);
+  ASSERT_EQ(ENOENT, rs_errno);
+
+  rs_errno = 0;
+  ASSERT_STREQ("default", rs_catgets(catd, 1, 1, "default"));
+  ASSERT_EQ(EBADF, rs_errno);
+
+  rs_errno = 0;
+  ASSERT_EQ(-1, rs_catclose(catd));
+  ASSERT_EQ(EBADF, rs_errno);
+}
+
+TEST(catopen, nlspath)
+{
+  std::string root = make_catalog_dir("de");
+  make_catalog_dir("fr");
+  write_cat(root + "/de/app.cat", {{1, 1, "Hallo"}}, false);
+  write_cat(root + "/fr/app.cat", {{1, 1, "Bonjour"}}, false);
+  setenv("NLSPATH", (root + "/%l/%N.cat").c_str(), 1);
+
+  // LANG for an oflag of 0
+  setenv("LANG", "fr_FR.UTF-8", 1);
+  strogino_nl_catd catd = rs_catopen("app", 0);
+  ASSERT_NE(STROGINO_CATD_INVALID, catd);
+  ASSERT_STREQ("Bonjour", rs_catgets(catd, 1, 1, "Hello"));
+  ASSERT_EQ(0, rs_catclose(catd));
+
+  // And the LC_MESSAGES locale for NL_CAT_LOCALE
+  ASSERT_STREQ("de_DE.UTF-8", rs_setlocale(LC_ALL, "de_DE.UTF-8"));
+  catd = rs_catopen("app", NL_CAT_LOCALE);
+  ASSERT_NE(STROGINO_CATD_INVALID, catd);
+  ASSERT_STREQ("Hallo", rs_catgets(catd, 1, 1, "Hello"));
+  ASSERT_EQ(0, rs_catclose(catd));
+
+  // Of the calling thread
+  strogino_locale_t fr =
+    rs_newlocale(LC_MESSAGES_MASK, "fr_FR.UTF-8", nullptr);
+  ASSERT_NE(nullptr, fr);
+  strogino_locale_t old = rs_uselocale(fr);
+  catd = rs_catopen("app", NL_CAT_LOCALE);
+  ASSERT_NE(STROGINO_CATD_INVALID, catd);
+  ASSERT_STREQ("Bonjour", rs_catgets(catd, 1, 1, "Hello"));
+  ASSERT_EQ(0, rs_catclose(catd));
+  rs_uselocale(old);
+  rs_freelocale(fr);
+
+  rs_errno = 0;
+  ASSERT_EQ(STROGINO_CATD_INVALID, rs_catopen("missing", NL_CAT_LOCALE));
+  ASSERT_EQ(ENOENT, rs_errno);
+
+  unsetenv("NLSPATH");
+  unsetenv("LANG");
+  ASSERT_STREQ("C", rs_setlocale(LC_ALL, "C"));
+}
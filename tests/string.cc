@@ -32,6 +32,28 @@ extern "C"
   char* rs_strtok(char* __restrict, const char* __restrict);
   int rs_strcoll(const char*, const char*);
   size_t rs_strxfrm(char* __restrict, const char* __restrict, size_t);
+  size_t rs_strfold_l(char* __restrict,
+                      const char* __restrict,
+                      size_t,
+                      strogino_locale_t);
+  size_t rs_strlower_l(char* __restrict,
+                     const char* __restrict,
+                     size_t,
+                     strogino_locale_t);
+  size_t rs_strtonative(char* __restrict, const char* __restrict, size_t);
+  size_t rs_strtonative_l(char* __restrict,
+                          const char* __restrict,
+                          size_t,
+                          strogino_locale_t);
+  size_t rs_strfromnative_l(char* __restrict,
+                            const char* __restrict,
+                            size_t,
+                            strogino_locale_t);
+  size_t rs_strupper(char* __restrict, const char* __restrict, size_t);
+  size_t rs_strupper_l(char* __restrict,
+                     const char* __restrict,
+                     size_t,
+                     strogino_locale_t);
   int rs_posix_strerror_r(int, char*, size_t);
   char* rs_gnu_strerror_r(int, char*, size_t);
   char* rs_strerror(int);
@@ -284,6 +306,209 @@ TEST(strcoll, uca)
   test_strxfrm(coll);
 }
 
+TEST(strcoll, phonebook)
+{
+  ASSERT_STREQ("de_DE.UTF-8", rs_setlocale(LC_COLLATE, "de_DE.UTF-8"));
+  ASSERT_EQ(1, sign(rs_strcoll("Müller", "Muff")));
+
+  ASSERT_STREQ("de_DE.UTF-8@collation=phonebook",
+               rs_setlocale(LC_COLLATE, "de_DE.UTF-8@collation=phonebook"));
+  ASSERT_EQ(-1, sign(rs_strcoll("Müller", "Muff")));
+
+  ASSERT_STREQ("de_DE-u-co-phonebk.UTF-8",
+               rs_setlocale(LC_COLLATE, "de_DE-u-co-phonebk.UTF-8"));
+  ASSERT_EQ(-1, sign(rs_strcoll("Müller", "Muff")));
+}
+
+TEST(strcoll, tailoring)
+{
+  ASSERT_NE(nullptr, rs_setlocale(LC_COLLATE, "en_US.UTF-8"));
+  ASSERT_EQ(-1, sign(rs_strcoll("file10", "file9")));
+  ASSERT_EQ(-1, sign(rs_strcoll("a", "A")));
+
+  ASSERT_NE(nullptr, rs_setlocale(LC_COLLATE, "en_US.UTF-8@numeric"));
+  ASSERT_EQ(1, sign(rs_strcoll("file10", "file9")));
+
+  ASSERT_NE(nullptr, rs_setlocale(LC_COLLATE, "en_US-u-kn.UTF-8"));
+  ASSERT_EQ(1, sign(rs_strcoll("file10", "file9")));
+
+  ASSERT_NE(nullptr, rs_setlocale(LC_COLLATE, "en_US.UTF-8@case-first=upper"));
+  ASSERT_EQ(1, sign(rs_strcoll("a", "A")));
+
+  ASSERT_NE(nullptr, rs_setlocale(LC_COLLATE, "en_US-u-ks-level1.UTF-8"));
+  ASSERT_EQ(0, sign(rs_strcoll("a", "A")));
+
+  ASSERT_NE(nullptr, rs_setlocale(LC_COLLATE, "en_US.UTF-8@strength=primary"));
+  ASSERT_EQ(0, sign(rs_strcoll("a", "A")));
+
+  const coll_data coll[] = {
+    { "file10", "file9", 1 },
+    { "file09", "file10", -1 },
+    { NULL, NULL, 0 },
+  };
+
+  ASSERT_NE(nullptr, rs_setlocale(LC_COLLATE, "en_US.UTF-8@colNumeric=yes"));
+  test_strcoll(coll);
+  test_strxfrm(coll);
+
+  ASSERT_EQ(nullptr, rs_setlocale(LC_COLLATE, "en_US.UTF-8@collation=bogus"));
+}
+
+TEST(strxfrm, invalid_utf8)
+{
+  ASSERT_NE(nullptr, rs_setlocale(LC_COLLATE, "en_US.UTF-8"));
+
+  const char* strings[] = { "",       "abc",     "ab\xff",  "\xff",
+                            "\xfe",   "a\xc3",   "a\xc3\xa4", "\xe2\x82",
+                            "\xe2\x82\xac", "\xc0\x80", "zzz",     NULL };
+
+  for (unsigned int i = 0; strings[i] != NULL; ++i) {
+    for (unsigned int j = 0; strings[j] != NULL; ++j) {
+      char sortKeyA[100], sortKeyB[100];
+      ASSERT_GT(100, rs_strxfrm(sortKeyA, strings[i], 100));
+      ASSERT_GT(100, rs_strxfrm(sortKeyB, strings[j], 100));
+      ASSERT_EQ(sign(rs_strcoll(strings[i], strings[j])),
+                sign(rs_strcmp(sortKeyA, sortKeyB)));
+    }
+  }
+}
+
+TEST(strtonative, example)
+{
+  ASSERT_NE(nullptr, rs_setlocale(LC_ALL, "C"));
+
+  char buf[64];
+  ASSERT_EQ(8u, rs_strtonative(buf, "-1234.50", sizeof(buf)));
+  ASSERT_STREQ("-1234.50", buf);
+
+  strogino_locale_t loc = rs_newlocale(LC_ALL_MASK, "ar_EG.UTF-8", 0);
+  ASSERT_NE(nullptr, loc);
+
+  ASSERT_EQ(strlen("-١٢٣٤.٥٠"),
+            rs_strtonative_l(buf, "-1234.50", sizeof(buf), loc));
+  ASSERT_STREQ("-١٢٣٤.٥٠", buf);
+
+  // Like strxfrm, only the length is returned when the buffer is too short
+  memset(buf, 'A', sizeof(buf));
+  ASSERT_EQ(strlen("١٢"), rs_strtonative_l(buf, "12", 4, loc));
+  ASSERT_EQ('A', buf[0]);
+
+  rs_freelocale(loc);
+
+  // The digits have to be representable in the codeset of LC_CTYPE
+  loc = rs_newlocale(LC_NUMERIC_MASK, "ar_EG.UTF-8", 0);
+  ASSERT_NE(nullptr, loc);
+
+  rs_errno = 0;
+  ASSERT_EQ((size_t)-1, rs_strtonative_l(buf, "12", sizeof(buf), loc));
+  ASSERT_EQ(EILSEQ, rs_errno);
+
+  rs_freelocale(loc);
+}
+
+TEST(strfromnative, example)
+{
+  strogino_locale_t loc =
+    rs_newlocale(LC_ALL_MASK, "hi_IN.UTF-8@numbers=deva", 0);
+  ASSERT_NE(nullptr, loc);
+
+  // Both the digits of the locale and ASCII ones are read
+  char buf[64];
+  ASSERT_EQ(7u, rs_strfromnative_l(buf, "१२३4 x९", sizeof(buf), loc));
+  ASSERT_STREQ("1234 x9", buf);
+
+  rs_freelocale(loc);
+}
+
+TEST(strupper, example)
+{
+  ASSERT_NE(nullptr, rs_setlocale(LC_ALL, "C"));
+
+  char buf[16];
+  ASSERT_EQ(5u, rs_strupper(buf, "hEllo", sizeof(buf)));
+  ASSERT_STREQ("HELLO", buf);
+
+  // Like strxfrm, only the length is returned when the buffer is too short
+  memset(buf, 'A', sizeof(buf));
+  ASSERT_EQ(5u, rs_strupper(buf, "hello", 5));
+  ASSERT_EQ('A', buf[0]);
+
+  rs_errno = 0;
+  ASSERT_EQ((size_t)-1, rs_strupper(buf, "stra\xc3\x9f" "e", sizeof(buf)));
+  ASSERT_EQ(EILSEQ, rs_errno);
+}
+
+TEST(strupper, full_mapping)
+{
+  strogino_locale_t loc = rs_newlocale(LC_CTYPE_MASK, "de_DE.UTF-8", 0);
+  ASSERT_NE(nullptr, loc);
+
+  char buf[32];
+  ASSERT_EQ(7u, rs_strupper_l(buf, "straße", sizeof(buf), loc));
+  ASSERT_STREQ("STRASSE", buf);
+
+  rs_freelocale(loc);
+
+  loc = rs_newlocale(LC_CTYPE_MASK, "tr_TR.UTF-8", 0);
+  ASSERT_NE(nullptr, loc);
+
+  ASSERT_EQ(strlen("İSTANBUL"),
+            rs_strupper_l(buf, "istanbul", sizeof(buf), loc));
+  ASSERT_STREQ("İSTANBUL", buf);
+
+  rs_freelocale(loc);
+
+  // Greek drops the accents when uppercasing
+  ASSERT_STREQ("el_GR.UTF-8", rs_setlocale(LC_CTYPE, "el_GR.UTF-8"));
+
+  ASSERT_EQ(strlen("ΑΔΕΙΑ"), rs_strupper(buf, "άδεια", sizeof(buf)));
+  ASSERT_STREQ("ΑΔΕΙΑ", buf);
+
+  ASSERT_NE(nullptr, rs_setlocale(LC_ALL, "C"));
+}
+
+TEST(strlower, full_mapping)
+{
+  strogino_locale_t loc = rs_newlocale(LC_CTYPE_MASK, "tr_TR.UTF-8", 0);
+  ASSERT_NE(nullptr, loc);
+
+  char buf[32];
+  rs_strlower_l(buf, "DİYARBAKIR", sizeof(buf), loc);
+  ASSERT_STREQ("diyarbakır", buf);
+
+  rs_freelocale(loc);
+
+  loc = rs_newlocale(LC_CTYPE_MASK, "de_DE.UTF-8", 0);
+  ASSERT_NE(nullptr, loc);
+
+  // Without Turkish rules the dotted capital I keeps its dot as a mark
+  rs_strlower_l(buf, "DİYARBAKIR", sizeof(buf), loc);
+  ASSERT_STREQ("di\u0307yarbakir", buf);
+
+  rs_freelocale(loc);
+
+}
+
+TEST(strfold, example)
+{
+  strogino_locale_t loc = rs_newlocale(LC_CTYPE_MASK, "de_DE.UTF-8", 0);
+  ASSERT_NE(nullptr, loc);
+
+  char buf[32];
+  ASSERT_EQ(7u, rs_strfold_l(buf, "STRAßE", sizeof(buf), loc));
+  ASSERT_STREQ("strasse", buf);
+
+  rs_freelocale(loc);
+
+  loc = rs_newlocale(LC_CTYPE_MASK, "az_AZ.UTF-8", 0);
+  ASSERT_NE(nullptr, loc);
+
+  rs_strfold_l(buf, "Iİ", sizeof(buf), loc);
+  ASSERT_STREQ("ıi", buf);
+
+  rs_freelocale(loc);
+}
+
 TEST(strcpy, example)
 {
   char buf[] = "AAAAAAAAAA";
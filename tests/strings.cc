@@ -37,6 +37,31 @@ TEST(strcasecmp, unicode)
   rs_freelocale(loc);
 }
 
+TEST(strcasecmp, full_folding)
+{
+  strogino_locale_t loc = rs_newlocale(LC_CTYPE_MASK, "de_DE.UTF-8", 0);
+  ASSERT_NE(nullptr, loc);
+
+  ASSERT_EQ(0, rs_strcasecmp_l("Straße", "STRASSE", loc));
+  ASSERT_EQ(0, rs_strcasecmp_l("ß", "ss", loc));
+  ASSERT_GT(0, rs_strcasecmp_l("ß", "sst", loc));
+  ASSERT_LT(0, rs_strcasecmp_l("Straßen", "STRASSE", loc));
+
+  rs_freelocale(loc);
+}
+
+TEST(strcasecmp, turkish)
+{
+  strogino_locale_t loc = rs_newlocale(LC_CTYPE_MASK, "tr_TR.UTF-8", 0);
+  ASSERT_NE(nullptr, loc);
+
+  ASSERT_EQ(0, rs_strcasecmp_l("İstanbul", "istanbul", loc));
+  ASSERT_EQ(0, rs_strcasecmp_l("ILIK", "ılık", loc));
+  ASSERT_NE(0, rs_strcasecmp_l("ILIK", "ilik", loc));
+
+  rs_freelocale(loc);
+}
+
 TEST(strncasecmp, null)
 {
   ASSERT_NE(rs_setlocale(LC_ALL, "C"), nullptr);
@@ -67,5 +92,10 @@ TEST(strncasecmp, unicode)
   ASSERT_EQ(0, rs_strncasecmp_l("München?", "MÜNCHEN!", 8, loc));
   ASSERT_LT(0, rs_strncasecmp_l("München?", "MÜNCHEN!", 9, loc));
 
+  // A character that starts within n bytes is compared whole
+  ASSERT_EQ(0, rs_strncasecmp_l("ÄÖ", "äö", 3, loc));
+  ASSERT_EQ(0, rs_strncasecmp_l("ÄÖx", "äöy", 4, loc));
+  ASSERT_GT(0, rs_strncasecmp_l("ÄÖx", "äöy", 5, loc));
+
   rs_freelocale(loc);
 }
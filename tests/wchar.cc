@@ -29,6 +29,7 @@ extern "C"
   wchar_t* rs_wcsstr(const wchar_t*, const wchar_t*);
   wchar_t* rs_wcstok(wchar_t*, const wchar_t*, wchar_t**);
   size_t rs_wcsxfrm(wchar_t*, const wchar_t*, size_t);
+  size_t rs_strxfrm(char*, const char*, size_t);
   wint_t rs_btowc(int);
   size_t rs_mbrlen(const char*, size_t, strogino_mbstate_t*);
   size_t rs_mbrtowc(wchar_t*, const char*, size_t, strogino_mbstate_t*);
@@ -56,6 +57,12 @@ extern "C"
                        const wchar_t* ws2,
                        size_t n,
                        strogino_locale_t locale);
+  size_t rs_wcsfold_l(wchar_t*, const wchar_t*, size_t, strogino_locale_t);
+  size_t rs_wcslwr_l(wchar_t*, const wchar_t*, size_t, strogino_locale_t);
+  size_t rs_wcsupr_l(wchar_t*, const wchar_t*, size_t, strogino_locale_t);
+  int rs_wcsgwidth(const wchar_t*, size_t);
+  int rs_mbsgwidth(const char*, size_t);
+  int rs_mbsgwidth_l(const char*, size_t, strogino_locale_t);
   int rs_wcwidth(wchar_t);
   int rs_wcswidth(const wchar_t*, size_t);
 }
@@ -399,6 +406,57 @@ TEST(wcscoll, uca)
   test_wcsxfrm(coll);
 }
 
+TEST(wcsxfrm, order_preserving)
+{
+  ASSERT_NE(nullptr, rs_setlocale(LC_COLLATE, "de_DE.UTF-8"));
+
+  const wchar_t* strings[] = { L"",      L"a",       L"A",      L"ä",
+                               L"Äpfel", L"apfel",   L"Straße", L"Strasse",
+                               L"ß",     L"日本語",  L"😀",     L"z\xd800",
+                               L"z",     L"\xffff",  NULL };
+
+  for (unsigned int i = 0; strings[i] != NULL; ++i) {
+    for (unsigned int j = 0; strings[j] != NULL; ++j) {
+      wchar_t sortKeyA[100], sortKeyB[100];
+      ASSERT_GT(100, rs_wcsxfrm(sortKeyA, strings[i], 100));
+      ASSERT_GT(100, rs_wcsxfrm(sortKeyB, strings[j], 100));
+      ASSERT_EQ(sign(rs_wcscoll(strings[i], strings[j])),
+                sign(rs_wcscmp(sortKeyA, sortKeyB)));
+    }
+  }
+}
+
+TEST(wcsxfrm, stable)
+{
+  ASSERT_NE(nullptr, rs_setlocale(LC_COLLATE, "en_US.UTF-8"));
+
+  char bytes[100];
+  wchar_t units[100];
+  size_t nbytes = rs_strxfrm(bytes, "Strogino", sizeof(bytes));
+  size_t nunits = rs_wcsxfrm(units, L"Strogino", 100);
+
+  ASSERT_EQ((nbytes + 2) / 3, nunits);
+  for (size_t i = 0; i < nbytes; ++i) {
+    unsigned char b = (units[i / 3] >> (8 * (2 - i % 3))) & 0xff;
+    ASSERT_EQ((unsigned char)bytes[i], b);
+  }
+}
+
+TEST(wcscoll, supplementary)
+{
+  rs_setlocale(LC_COLLATE, "en_US");
+
+  const coll_data coll[] = {
+    { L"a😀b", L"a😀b", 0 },
+    { L"a😀", L"a😀b", -1 },
+    { L"😀b", L"😀a", 1 },
+    { L"𝒜", L"𝒜", 0 },
+    { NULL, NULL, 0 },
+  };
+
+  test_wcscoll(coll);
+}
+
 #if 0
 TEST(btowc, simple) {
   rs_setlocale(LC_CTYPE, "C");
@@ -633,6 +691,45 @@ TEST(wcscasecmp, unicode)
   rs_freelocale(loc);
 }
 
+TEST(wcscasecmp, full_folding)
+{
+  strogino_locale_t loc = rs_newlocale(LC_CTYPE_MASK, "de_DE.UTF-8", 0);
+  ASSERT_NE(nullptr, loc);
+
+  ASSERT_EQ(0, rs_wcscasecmp_l(L"Straße", L"STRASSE", loc));
+  ASSERT_EQ(0, rs_wcscasecmp_l(L"ß", L"SS", loc));
+  ASSERT_GT(0, rs_wcscasecmp_l(L"dog", L"DOGS", loc));
+  ASSERT_EQ(0, rs_wcsncasecmp_l(L"ẞ?", L"ß!", 1, loc));
+
+  rs_freelocale(loc);
+}
+
+TEST(wcsupr, full_mapping)
+{
+  strogino_locale_t loc = rs_newlocale(LC_CTYPE_MASK, "tr_TR.UTF-8", 0);
+  ASSERT_NE(nullptr, loc);
+
+  wchar_t buf[16];
+  ASSERT_EQ(5u, rs_wcsupr_l(buf, L"ıiße", std::size(buf), loc));
+  ASSERT_STREQ(L"IİSSE", buf);
+
+  ASSERT_EQ(4u, rs_wcslwr_l(buf, L"IİSS", std::size(buf), loc));
+  ASSERT_STREQ(L"ıiss", buf);
+
+  buf[0] = L'A';
+  ASSERT_EQ(4u, rs_wcsfold_l(buf, L"İße", 4, loc));
+  ASSERT_EQ(L'A', buf[0]);
+  ASSERT_EQ(4u, rs_wcsfold_l(buf, L"İße", 5, loc));
+  ASSERT_STREQ(L"isse", buf);
+
+  const wchar_t invalid[] = { L'a', (wchar_t)0xd800, L'\0' };
+  rs_errno = 0;
+  ASSERT_EQ((size_t)-1, rs_wcsupr_l(buf, invalid, std::size(buf), loc));
+  ASSERT_EQ(EILSEQ, rs_errno);
+
+  rs_freelocale(loc);
+}
+
 TEST(wcsncasecmp, null)
 {
   ASSERT_NE(rs_setlocale(LC_ALL, "C"), nullptr);
@@ -682,6 +779,41 @@ TEST(wcwidth, korean_jeongeul_syllables)
   EXPECT_EQ(2, rs_wcwidth(0xd7a3));
 }
 
+TEST(wcwidth, ambiguous)
+{
+  ASSERT_NE(rs_setlocale(LC_CTYPE, "en_US.UTF-8"), nullptr);
+  EXPECT_EQ(1, rs_wcwidth(L'─'));
+  EXPECT_EQ(1, rs_wcwidth(L'α'));
+  EXPECT_EQ(1, rs_wcwidth(L'Ж'));
+
+  ASSERT_NE(rs_setlocale(LC_CTYPE, "ja_JP.UTF-8"), nullptr);
+  EXPECT_EQ(2, rs_wcwidth(L'─'));
+  EXPECT_EQ(2, rs_wcwidth(L'α'));
+  EXPECT_EQ(2, rs_wcwidth(L'Ж'));
+  EXPECT_EQ(1, rs_wcwidth(L'a'));
+  EXPECT_EQ(1, rs_wcwidth(L'ｱ'));
+
+  ASSERT_NE(rs_setlocale(LC_CTYPE, "zh_CN.UTF-8@cjk_narrow"), nullptr);
+  EXPECT_EQ(1, rs_wcwidth(L'─'));
+  EXPECT_EQ(2, rs_wcwidth(L'中'));
+
+  ASSERT_NE(rs_setlocale(LC_CTYPE, "de_DE.UTF-8@cjk_wide"), nullptr);
+  EXPECT_EQ(2, rs_wcwidth(L'─'));
+
+  ASSERT_NE(rs_setlocale(LC_CTYPE, "C"), nullptr);
+}
+
+TEST(wcwidth, nonprintable)
+{
+  ASSERT_NE(rs_setlocale(LC_CTYPE, "en_US.UTF-8"), nullptr);
+
+  EXPECT_EQ(0, rs_wcwidth(L'\0'));
+  EXPECT_EQ(-1, rs_wcwidth(L'\n'));
+  EXPECT_EQ(-1, rs_wcwidth(0x7f));
+  EXPECT_EQ(-1, rs_wcwidth(0x85));
+  EXPECT_EQ(-1, rs_wcwidth(0xd800));
+}
+
 TEST(wcswidth, simple)
 {
   ASSERT_NE(rs_setlocale(LC_CTYPE, "en_US.UTF-8"), nullptr);
@@ -722,3 +854,63 @@ TEST(wcswidth, zalgo)
   const wchar_t str[] = L"T̫̺̳o̬̜ ì̬͎̲̟nv̖̗̻̣̹̕o͖̗̠̜̤k͍͚̹͖̼e̦̗̪͍̪͍ ̬ͅt̕h̠͙̮͕͓e̱̜̗͙̭ ̥͔̫͙̪͍̣͝ḥi̼̦͈̼v҉̩̟͚̞͎e͈̟̻͙̦̤-m̷̘̝̱í͚̞̦̳n̝̲̯̙̮͞d̴̺̦͕̫ ̗̭̘͎͖r̞͎̜̜͖͎̫͢ep͇r̝̯̝͖͉͎̺e̴s̥e̵̖̳͉͍̩̗n̢͓̪͕̜̰̠̦t̺̞̰i͟n҉̮̦̖̟g̮͍̱̻͍̜̳ ̳c̖̮̙̣̰̠̩h̷̗͍̖͙̭͇͈a̧͎̯̹̲̺̫ó̭̞̜̣̯͕s̶̤̮̩̘.̨̻̪̖͔";
   ASSERT_EQ(43, rs_wcswidth(str, std::size(str)));
 }
+
+TEST(wcswidth, nonprintable)
+{
+  ASSERT_NE(rs_setlocale(LC_CTYPE, "en_US.UTF-8"), nullptr);
+
+  const wchar_t str[] = L"abc\tdef";
+  ASSERT_EQ(3, rs_wcswidth(str, 3));
+  ASSERT_EQ(-1, rs_wcswidth(str, std::size(str)));
+}
+
+TEST(wcsgwidth, emoji)
+{
+  ASSERT_NE(rs_setlocale(LC_CTYPE, "en_US.UTF-8"), nullptr);
+
+  // Family: man, ZWJ, woman, ZWJ, girl
+  const wchar_t family[] = L"\U0001F468\u200D\U0001F469\u200D\U0001F467";
+  ASSERT_EQ(6, rs_wcswidth(family, std::size(family)));
+  ASSERT_EQ(2, rs_wcsgwidth(family, std::size(family)));
+
+  // Thumbs up with a skin tone modifier
+  ASSERT_EQ(2, rs_wcsgwidth(L"\U0001F44D\U0001F3FD", 2));
+  // Flags pair up regional indicators
+  ASSERT_EQ(2, rs_wcsgwidth(L"\U0001F1EF\U0001F1F5", 2));
+  ASSERT_EQ(4, rs_wcsgwidth(L"\U0001F1EF\U0001F1F5\U0001F1EB\U0001F1F7", 4));
+  // Keycap sequence and presentation selectors
+  ASSERT_EQ(2, rs_wcsgwidth(L"1\uFE0F\u20E3", 3));
+  ASSERT_EQ(1, rs_wcsgwidth(L"\u263A\uFE0E", 2));
+}
+
+TEST(wcsgwidth, text)
+{
+  ASSERT_NE(rs_setlocale(LC_CTYPE, "en_US.UTF-8"), nullptr);
+
+  ASSERT_EQ(0, rs_wcsgwidth(L"", 10));
+  ASSERT_EQ(5, rs_wcsgwidth(L"hello", 10));
+  ASSERT_EQ(3, rs_wcsgwidth(L"hello", 3));
+  ASSERT_EQ(3, rs_wcsgwidth(L"e\u0301te\u0301", 10));
+  ASSERT_EQ(4, rs_wcsgwidth(L"日本", 10));
+  // Conjoining jamo form a single syllable
+  ASSERT_EQ(2, rs_wcsgwidth(L"\u1100\u1161\u11A8", 10));
+  ASSERT_EQ(-1, rs_wcsgwidth(L"a\tb", 10));
+}
+
+TEST(mbsgwidth, example)
+{
+  ASSERT_NE(rs_setlocale(LC_CTYPE, "en_US.UTF-8"), nullptr);
+
+  const char family[] = "\xF0\x9F\x91\xA8\xE2\x80\x8D\xF0\x9F\x91\xA9";
+  ASSERT_EQ(2, rs_mbsgwidth(family, sizeof(family)));
+  ASSERT_EQ(4, rs_mbsgwidth("naïve", 5));
+
+  rs_errno = 0;
+  ASSERT_EQ(-1, rs_mbsgwidth("a\xff", 10));
+  ASSERT_EQ(EILSEQ, rs_errno);
+
+  strogino_locale_t loc = rs_newlocale(LC_CTYPE_MASK, "ja_JP.UTF-8", 0);
+  ASSERT_NE(nullptr, loc);
+  ASSERT_EQ(4, rs_mbsgwidth_l("αβ", 10, loc));
+  rs_freelocale(loc);
+}
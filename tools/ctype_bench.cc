@@ -0,0 +1,55 @@
+// Times the character classification and wcwidth in a UTF-8 locale, over
+// ASCII and over the code points up to U+2FFFF. Build the library and
+// the benchmark (one command, wrapped here) and run it:
+//
+//   cargo build --release
+//   c++ -std=c++20 -O2 -o ctype_bench tools/ctype_bench.cc
+//     target/release/libc.so -Wl,-rpath,target/release
+//   ./ctype_bench
+//
+// Comparing the numbers of two revisions built the same way shows the cost
+// of a change to the ctype tables.
+
+#include <chrono>
+#include <clocale>
+#include <cstdio>
+#include <cwchar>
+
+extern "C"
+{
+  char* rs_setlocale(int, const char*);
+  int rs_iswalpha(wint_t);
+  int rs_iswpunct(wint_t);
+  int rs_wcwidth(wchar_t);
+}
+
+template<typename F>
+static void measure(const char* name, wint_t end, int rounds, F f)
+{
+  long sum = 0;
+  auto start = std::chrono::steady_clock::now();
+  for (int round = 0; round < rounds; round++) {
+    for (wint_t c = 0; c < end; c++) {
+      sum += !!f(c);
+    }
+  }
+  std::chrono::duration<double, std::nano> elapsed =
+    std::chrono::steady_clock::now() - start;
+  std::printf("%-16s %6.2f ns/call (%ld)\n", name,
+              elapsed.count() / (double(end) * rounds), sum);
+}
+
+int main()
+{
+  if (rs_setlocale(LC_ALL, "en_US.UTF-8") == nullptr) {
+    std::fprintf(stderr, "en_US.UTF-8 is not available\n");
+    return 1;
+  }
+
+  measure("iswalpha ascii", 0x80, 20000, rs_iswalpha);
+  measure("iswpunct ascii", 0x80, 20000, rs_iswpunct);
+  measure("iswalpha all", 0x30000, 20, rs_iswalpha);
+  measure("iswpunct all", 0x30000, 20, rs_iswpunct);
+  measure("wcwidth all", 0x30000, 20,
+          [](wint_t c) { return rs_wcwidth(wchar_t(c)); });
+}
@@ -0,0 +1,299 @@
+#!/usr/bin/env -S cargo +nightly -Zscript
+---
+[package]
+edition = "2024"
+
+[dependencies]
+icu_properties = { version = "=2.1.2", default-features = false, features = ["compiled_data"] }
+---
+
+// Generates src/support/locale/ctype/unicode_data.rs, the built-in tables
+// behind wcwidth and the grapheme cluster functions, from the same ICU data
+// as the rest of the library. Keep the icu_properties version above in sync
+// with Cargo.toml and run:
+//
+//   tools/unicode_data.rs > src/support/locale/ctype/unicode_data.rs
+
+use {
+  icu_properties::{CodePointMapData, CodePointSetData, props::*},
+  std::ops::RangeInclusive
+};
+
+const CODE_POINTS: usize = 0x110000;
+
+const WIDTH_ZERO: u8 = 0;
+const WIDTH_ONE: u8 = 1;
+const WIDTH_TWO: u8 = 2;
+const WIDTH_AMBIGUOUS: u8 = 3;
+const WIDTH_NONPRINTABLE: u8 = 4;
+
+fn paint(
+  flat: &mut [u8],
+  ranges: impl Iterator<Item = RangeInclusive<u32>>,
+  value: u8
+) {
+  for range in ranges {
+    let end = (*range.end() as usize).min(CODE_POINTS - 1);
+    flat[*range.start() as usize..=end].fill(value);
+  }
+}
+
+// Widths are painted from the lowest to the highest precedence
+fn widths() -> Vec<u8> {
+  let mut flat = vec![WIDTH_ONE; CODE_POINTS];
+  let gc = CodePointMapData::<GeneralCategory>::new();
+
+  for r in CodePointMapData::<EastAsianWidth>::new().iter_ranges() {
+    let width = match r.value {
+      | EastAsianWidth::Fullwidth | EastAsianWidth::Wide => WIDTH_TWO,
+      | EastAsianWidth::Ambiguous => WIDTH_AMBIGUOUS,
+      | _ => WIDTH_ONE
+    };
+    paint(&mut flat, std::iter::once(r.range), width);
+  }
+  paint(&mut flat, [0x3248..=0x324f, 0x4dc0..=0x4dff].into_iter(), WIDTH_TWO);
+
+  for r in CodePointMapData::<HangulSyllableType>::new().iter_ranges() {
+    let width = match r.value {
+      | HangulSyllableType::VowelJamo | HangulSyllableType::TrailingJamo => {
+        WIDTH_ZERO
+      },
+      | HangulSyllableType::LeadingJamo |
+      HangulSyllableType::LeadingVowelSyllable |
+      HangulSyllableType::LeadingVowelTrailingSyllable => WIDTH_TWO,
+      | _ => continue
+    };
+    paint(&mut flat, std::iter::once(r.range), width);
+  }
+
+  paint(
+    &mut flat,
+    CodePointSetData::new::<Emoji>().iter_ranges(),
+    WIDTH_TWO
+  );
+  paint(
+    &mut flat,
+    gc.iter_ranges()
+      .filter(|r| {
+        matches!(
+          r.value,
+          GeneralCategory::NonspacingMark | GeneralCategory::EnclosingMark
+        )
+      })
+      .map(|r| r.range),
+    WIDTH_ZERO
+  );
+  for set in [
+    CodePointSetData::new::<GraphemeExtend>(),
+    CodePointSetData::new::<VariationSelector>(),
+    CodePointSetData::new::<JoinControl>(),
+    CodePointSetData::new::<DefaultIgnorableCodePoint>()
+  ] {
+    paint(&mut flat, set.iter_ranges(), WIDTH_ZERO);
+  }
+  paint(
+    &mut flat,
+    [0x01..=0x1f, 0x7f..=0x9f, 0xd800..=0xdfff].into_iter(),
+    WIDTH_NONPRINTABLE
+  );
+  paint(&mut flat, std::iter::once(0x00..=0x00), WIDTH_ZERO);
+  paint(&mut flat, std::iter::once(0x20..=0x7e), WIDTH_ONE);
+
+  flat
+}
+
+// Collapses a flat table into ranges, leaving out the default value
+fn ranges(
+  flat: &[u8],
+  default: u8
+) -> Vec<(u32, u32, u8)> {
+  let mut result: Vec<(u32, u32, u8)> = Vec::new();
+
+  for (c, &value) in flat.iter().enumerate() {
+    if value == default {
+      continue;
+    }
+    match result.last_mut() {
+      | Some(last) if last.1 + 1 == c as u32 && last.2 == value => {
+        last.1 = c as u32
+      },
+      | _ => result.push((c as u32, c as u32, value))
+    }
+  }
+
+  result
+}
+
+fn print_table(
+  comment: &str,
+  name: &str,
+  ty: &str,
+  ranges: &[(u32, u32, u8)],
+  names: &[&str]
+) {
+  println!();
+  println!("// {comment}");
+  println!("#[rustfmt::skip]");
+  println!("pub static {name}: [(u32, u32, {ty}); {}] = [", ranges.len());
+  let entries: Vec<String> = ranges
+    .iter()
+    .map(|(start, end, value)| {
+      format!("(0x{start:x}, 0x{end:x}, {})", names[*value as usize])
+    })
+    .collect();
+  print_entries(&entries);
+  println!("];");
+}
+
+fn print_set(
+  comment: &str,
+  name: &str,
+  ranges: &[(u32, u32, u8)]
+) {
+  println!();
+  println!("// {comment}");
+  println!("#[rustfmt::skip]");
+  println!("pub static {name}: [(u32, u32); {}] = [", ranges.len());
+  let entries: Vec<String> = ranges
+    .iter()
+    .map(|(start, end, _)| format!("(0x{start:x}, 0x{end:x})"))
+    .collect();
+  print_entries(&entries);
+  println!("];");
+}
+
+fn print_entries(entries: &[String]) {
+  let mut line = String::from(" ");
+
+  for (i, entry) in entries.iter().enumerate() {
+    let entry = if i + 1 < entries.len() {
+      format!(" {entry},")
+    } else {
+      format!(" {entry}")
+    };
+    if line.len() + entry.len() > 80 {
+      println!("{line}");
+      line = String::from(" ");
+    }
+    line.push_str(&entry);
+  }
+  println!("{line}");
+}
+
+fn main() {
+  let widths = ranges(&widths(), WIDTH_ONE);
+
+  let gcb = CodePointMapData::<GraphemeClusterBreak>::new();
+  let mut breaks = vec![0u8; CODE_POINTS];
+  for (c, value) in breaks.iter_mut().enumerate() {
+    *value = match gcb.get32(c as u32) {
+      | GraphemeClusterBreak::Control => 1,
+      | GraphemeClusterBreak::CR => 2,
+      | GraphemeClusterBreak::LF => 3,
+      | GraphemeClusterBreak::Extend => 4,
+      | GraphemeClusterBreak::ZWJ => 5,
+      | GraphemeClusterBreak::RegionalIndicator => 6,
+      | GraphemeClusterBreak::Prepend => 7,
+      | GraphemeClusterBreak::SpacingMark => 8,
+      | GraphemeClusterBreak::L => 9,
+      | GraphemeClusterBreak::V => 10,
+      | GraphemeClusterBreak::T => 11,
+      | GraphemeClusterBreak::LV => 12,
+      | GraphemeClusterBreak::LVT => 13,
+      | _ => 0
+    };
+  }
+
+  let incb = CodePointMapData::<IndicConjunctBreak>::new();
+  let mut conjuncts = vec![0u8; CODE_POINTS];
+  for (c, value) in conjuncts.iter_mut().enumerate() {
+    *value = match incb.get32(c as u32) {
+      | IndicConjunctBreak::Consonant => 1,
+      | IndicConjunctBreak::Linker => 2,
+      | IndicConjunctBreak::Extend => 3,
+      | _ => 0
+    };
+  }
+
+  let pictographic = CodePointSetData::new::<ExtendedPictographic>();
+  let mut pictographics = vec![0u8; CODE_POINTS];
+  for (c, value) in pictographics.iter_mut().enumerate() {
+    *value = u8::from(pictographic.contains32(c as u32));
+  }
+
+  println!("// Generated by tools/unicode_data.rs, do not edit.");
+  println!();
+  println!("use {{");
+  println!("  super::table::{{WIDTH_AMBIGUOUS, WIDTH_NONPRINTABLE, WIDTH_TWO, WIDTH_ZERO}},");
+  println!("  GraphemeBreak::*,");
+  println!("  IndicConjunctBreak as InCB");
+  println!("}};");
+  println!();
+  println!("#[derive(Clone, Copy, PartialEq)]");
+  println!("pub enum GraphemeBreak {{");
+  println!("  Other,");
+  println!("  Control,");
+  println!("  CR,");
+  println!("  LF,");
+  println!("  Extend,");
+  println!("  Zwj,");
+  println!("  RegionalIndicator,");
+  println!("  Prepend,");
+  println!("  SpacingMark,");
+  println!("  L,");
+  println!("  V,");
+  println!("  T,");
+  println!("  LV,");
+  println!("  Lvt");
+  println!("}}");
+  println!();
+  println!("#[derive(Clone, Copy, PartialEq)]");
+  println!("pub enum IndicConjunctBreak {{");
+  println!("  None,");
+  println!("  Consonant,");
+  println!("  Linker,");
+  println!("  Extend");
+  println!("}}");
+
+  print_table(
+    "Code points that are not one column wide",
+    "WIDTHS",
+    "u16",
+    &widths,
+    &["WIDTH_ZERO", "", "WIDTH_TWO", "WIDTH_AMBIGUOUS", "WIDTH_NONPRINTABLE"]
+  );
+  print_table(
+    "Grapheme_Cluster_Break values other than Other",
+    "GRAPHEME_BREAKS",
+    "GraphemeBreak",
+    &ranges(&breaks, 0),
+    &[
+      "Other",
+      "Control",
+      "CR",
+      "LF",
+      "Extend",
+      "Zwj",
+      "RegionalIndicator",
+      "Prepend",
+      "SpacingMark",
+      "L",
+      "V",
+      "T",
+      "LV",
+      "Lvt"
+    ]
+  );
+  print_table(
+    "Indic_Conjunct_Break values other than None",
+    "CONJUNCT_BREAKS",
+    "IndicConjunctBreak",
+    &ranges(&conjuncts, 0),
+    &["InCB::None", "InCB::Consonant", "InCB::Linker", "InCB::Extend"]
+  );
+  print_set(
+    "Extended_Pictographic code points",
+    "EXTENDED_PICTOGRAPHIC",
+    &ranges(&pictographics, 0)
+  );
+}